@@ -0,0 +1,310 @@
+use wasm_bindgen::JsValue;
+
+use crate::{report::error_message, Pid, RunOptions, ScriptArg, Server, ToastVariant, NS};
+
+/// The part of the Netscript API that script logic usually needs,
+/// implemented by the game's [`NS`] and by [`MockNs`](crate::MockNs) so that
+/// logic written against this trait can be tested natively with `cargo test`.
+///
+/// Errors are the messages thrown by the game, as plain strings so they do
+/// not need a JavaScript engine either.
+#[allow(async_fn_in_trait)]
+pub trait NetscriptApi {
+    /// Hostnames of the servers connected to `host`, or to the server the
+    /// script runs on if `None`.
+    fn scan(&self, host: Option<&str>) -> Result<Vec<String>, String>;
+    /// All the properties of `host`, or of the server the script runs on if
+    /// `None`.
+    fn get_server(&self, host: Option<&str>) -> Result<Server, String>;
+    fn has_root_access(&self, host: &str) -> Result<bool, String>;
+    fn get_hacking_level(&self) -> Result<f64, String>;
+
+    fn get_server_money_available(&self, host: &str) -> Result<f64, String>;
+    fn get_server_max_money(&self, host: &str) -> Result<f64, String>;
+    fn get_server_security_level(&self, host: &str) -> Result<f64, String>;
+    fn get_server_min_security_level(&self, host: &str) -> Result<f64, String>;
+    fn get_server_required_hacking_level(&self, host: &str) -> Result<f64, String>;
+    fn get_server_num_ports_required(&self, host: &str) -> Result<u32, String>;
+    fn get_server_max_ram(&self, host: &str) -> Result<f64, String>;
+    fn get_server_used_ram(&self, host: &str) -> Result<f64, String>;
+
+    /// Milliseconds a hack of `host` takes at the current security level.
+    fn get_hack_time(&self, host: &str) -> Result<f64, String>;
+    fn get_grow_time(&self, host: &str) -> Result<f64, String>;
+    fn get_weaken_time(&self, host: &str) -> Result<f64, String>;
+
+    /// Threads of hack stealing `amount` from `host` at its current money and
+    /// security level.
+    fn hack_analyze_threads(&self, host: &str, amount: f64) -> Result<f64, String>;
+    /// Threads of grow multiplying the money of `host` by `multiplier`.
+    fn growth_analyze(&self, host: &str, multiplier: f64, cores: u32) -> Result<f64, String>;
+    /// Security removed by `threads` threads of weaken.
+    fn weaken_analyze(&self, threads: u32, cores: u32) -> Result<f64, String>;
+    /// Security added to `host` by `threads` threads of hack.
+    fn hack_analyze_security(&self, threads: u32, host: &str) -> Result<f64, String>;
+    /// Security added to `host` by `threads` threads of grow.
+    fn growth_analyze_security(&self, threads: u32, host: &str, cores: u32) -> Result<f64, String>;
+
+    /// Whether `filename` exists on `host`, or on the server the script runs
+    /// on if `None`.
+    fn file_exists(&self, filename: &str, host: Option<&str>) -> Result<bool, String>;
+    /// Files on `host`, only those containing `substring` if given.
+    fn ls(&self, host: &str, substring: Option<&str>) -> Result<Vec<String>, String>;
+    /// Copies `files` from `source`, or from the server the script runs on if
+    /// `None`, to `destination`. Returns whether all of them were copied.
+    fn scp(&self, files: &[&str], destination: &str, source: Option<&str>) -> Result<bool, String>;
+    /// RAM (GB) a thread of `script` needs, 0 if it is not on `host`.
+    fn get_script_ram(&self, script: &str, host: Option<&str>) -> Result<f64, String>;
+    /// Starts `script` on `host`. Returns `None` if it could not be started,
+    /// e.g. for lack of RAM.
+    fn exec(
+        &self,
+        script: &str,
+        host: &str,
+        threads: u32,
+        args: &[ScriptArg],
+    ) -> Result<Option<Pid>, String>;
+    /// Kills every script on `host`, or on the server the script runs on if
+    /// `None`, except the calling script unless `safetyguard` is
+    /// `Some(false)`. Returns whether any script was killed.
+    fn kill_all(&self, host: Option<&str>, safetyguard: Option<bool>) -> Result<bool, String>;
+
+    /// Opens the SSH port of `host`, needs `BruteSSH.exe`.
+    fn brutessh(&self, host: &str) -> Result<(), String>;
+    /// Opens the FTP port of `host`, needs `FTPCrack.exe`.
+    fn ftpcrack(&self, host: &str) -> Result<(), String>;
+    /// Opens the SMTP port of `host`, needs `relaySMTP.exe`.
+    fn relaysmtp(&self, host: &str) -> Result<(), String>;
+    /// Opens the HTTP port of `host`, needs `HTTPWorm.exe`.
+    fn httpworm(&self, host: &str) -> Result<(), String>;
+    /// Opens the SQL port of `host`, needs `SQLInject.exe`.
+    fn sqlinject(&self, host: &str) -> Result<(), String>;
+    /// Gains root access to `host`, which needs enough open ports.
+    fn nuke(&self, host: &str) -> Result<(), String>;
+    /// Steals money from `host`, returns the amount stolen.
+    async fn hack(&self, host: &str) -> Result<f64, String>;
+    /// Grows the money of `host`, returns the multiplier it grew by.
+    async fn grow(&self, host: &str) -> Result<f64, String>;
+    /// Lowers the security of `host`, returns how much it was lowered by.
+    async fn weaken(&self, host: &str) -> Result<f64, String>;
+    async fn sleep(&self, millis: f64) -> Result<(), String>;
+
+    /// Writes to the script log.
+    fn print(&self, message: &str) -> Result<(), String>;
+    /// Writes to the terminal.
+    fn tprint(&self, message: &str) -> Result<(), String>;
+    /// Shows a toast in the corner of the screen.
+    fn toast(&self, message: &str, variant: ToastVariant) -> Result<(), String>;
+    /// Stops `function` from writing to the script log, or every function if
+    /// `"ALL"`.
+    fn disable_log(&self, function: &str) -> Result<(), String>;
+    /// Lets `function` write to the script log again, or every function if
+    /// `"ALL"`.
+    fn enable_log(&self, function: &str) -> Result<(), String>;
+    /// Whether `function` writes to the script log. Fails for `"ALL"`, which
+    /// only exists to disable and enable the logs.
+    fn is_log_enabled(&self, function: &str) -> Result<bool, String>;
+}
+
+impl NetscriptApi for NS {
+    fn scan(&self, host: Option<&str>) -> Result<Vec<String>, String> {
+        NS::scan(self, host).map_err(|err| error_message(&err))
+    }
+
+    fn get_server(&self, host: Option<&str>) -> Result<Server, String> {
+        NS::get_server(self, host).map_err(|err| error_message(&err))
+    }
+
+    fn has_root_access(&self, host: &str) -> Result<bool, String> {
+        self.hasRootAccess(host).map_err(|err| error_message(&err))
+    }
+
+    fn get_hacking_level(&self) -> Result<f64, String> {
+        self.getHackingLevel().map_err(|err| error_message(&err))
+    }
+
+    fn get_server_money_available(&self, host: &str) -> Result<f64, String> {
+        self.getServerMoneyAvailable(host)
+            .map_err(|err| error_message(&err))
+    }
+
+    fn get_server_max_money(&self, host: &str) -> Result<f64, String> {
+        self.getServerMaxMoney(host)
+            .map_err(|err| error_message(&err))
+    }
+
+    fn get_server_security_level(&self, host: &str) -> Result<f64, String> {
+        self.getServerSecurityLevel(host)
+            .map_err(|err| error_message(&err))
+    }
+
+    fn get_server_min_security_level(&self, host: &str) -> Result<f64, String> {
+        self.getServerMinSecurityLevel(host)
+            .map_err(|err| error_message(&err))
+    }
+
+    fn get_server_required_hacking_level(&self, host: &str) -> Result<f64, String> {
+        self.getServerRequiredHackingLevel(host)
+            .map_err(|err| error_message(&err))
+    }
+
+    fn get_server_num_ports_required(&self, host: &str) -> Result<u32, String> {
+        self.getServerNumPortsRequired(host)
+            .map(|ports| ports as u32)
+            .map_err(|err| error_message(&err))
+    }
+
+    fn get_server_max_ram(&self, host: &str) -> Result<f64, String> {
+        self.getServerMaxRam(host)
+            .map_err(|err| error_message(&err))
+    }
+
+    fn get_server_used_ram(&self, host: &str) -> Result<f64, String> {
+        self.getServerUsedRam(host)
+            .map_err(|err| error_message(&err))
+    }
+
+    fn get_hack_time(&self, host: &str) -> Result<f64, String> {
+        self.getHackTime(host).map_err(|err| error_message(&err))
+    }
+
+    fn get_grow_time(&self, host: &str) -> Result<f64, String> {
+        self.getGrowTime(host).map_err(|err| error_message(&err))
+    }
+
+    fn get_weaken_time(&self, host: &str) -> Result<f64, String> {
+        self.getWeakenTime(host).map_err(|err| error_message(&err))
+    }
+
+    fn hack_analyze_threads(&self, host: &str, amount: f64) -> Result<f64, String> {
+        self.hackAnalyzeThreads(host, amount)
+            .map_err(|err| error_message(&err))
+    }
+
+    fn growth_analyze(&self, host: &str, multiplier: f64, cores: u32) -> Result<f64, String> {
+        self.growthAnalyze(host, multiplier, Some(cores.into()))
+            .map_err(|err| error_message(&err))
+    }
+
+    fn weaken_analyze(&self, threads: u32, cores: u32) -> Result<f64, String> {
+        self.weakenAnalyze(threads.into(), Some(cores.into()))
+            .map_err(|err| error_message(&err))
+    }
+
+    fn hack_analyze_security(&self, threads: u32, host: &str) -> Result<f64, String> {
+        self.hackAnalyzeSecurity(threads.into(), Some(host))
+            .map_err(|err| error_message(&err))
+    }
+
+    fn growth_analyze_security(&self, threads: u32, host: &str, cores: u32) -> Result<f64, String> {
+        self.growthAnalyzeSecurity(threads.into(), Some(host), Some(cores.into()))
+            .map_err(|err| error_message(&err))
+    }
+
+    fn file_exists(&self, filename: &str, host: Option<&str>) -> Result<bool, String> {
+        self.fileExists(filename, host)
+            .map_err(|err| error_message(&err))
+    }
+
+    fn ls(&self, host: &str, substring: Option<&str>) -> Result<Vec<String>, String> {
+        NS::ls(self, host, substring).map_err(|err| error_message(&err))
+    }
+
+    fn scp(&self, files: &[&str], destination: &str, source: Option<&str>) -> Result<bool, String> {
+        let files: js_sys::Array = files.iter().map(|file| JsValue::from_str(file)).collect();
+        NS::scp(self, &files, destination, source).map_err(|err| error_message(&err))
+    }
+
+    fn get_script_ram(&self, script: &str, host: Option<&str>) -> Result<f64, String> {
+        self.getScriptRam(script, host)
+            .map_err(|err| error_message(&err))
+    }
+
+    fn exec(
+        &self,
+        script: &str,
+        host: &str,
+        threads: u32,
+        args: &[ScriptArg],
+    ) -> Result<Option<Pid>, String> {
+        self.exec_script(script, host, &RunOptions::threads(threads), args)
+            .map_err(|err| error_message(&err))
+    }
+
+    fn kill_all(&self, host: Option<&str>, safetyguard: Option<bool>) -> Result<bool, String> {
+        NS::kill_all(self, host, safetyguard).map_err(|err| error_message(&err))
+    }
+
+    fn brutessh(&self, host: &str) -> Result<(), String> {
+        NS::brutessh(self, host).map_err(|err| error_message(&err))
+    }
+
+    fn ftpcrack(&self, host: &str) -> Result<(), String> {
+        NS::ftpcrack(self, host).map_err(|err| error_message(&err))
+    }
+
+    fn relaysmtp(&self, host: &str) -> Result<(), String> {
+        NS::relaysmtp(self, host).map_err(|err| error_message(&err))
+    }
+
+    fn httpworm(&self, host: &str) -> Result<(), String> {
+        NS::httpworm(self, host).map_err(|err| error_message(&err))
+    }
+
+    fn sqlinject(&self, host: &str) -> Result<(), String> {
+        NS::sqlinject(self, host).map_err(|err| error_message(&err))
+    }
+
+    fn nuke(&self, host: &str) -> Result<(), String> {
+        NS::nuke(self, host).map_err(|err| error_message(&err))
+    }
+
+    async fn hack(&self, host: &str) -> Result<f64, String> {
+        NS::hack(self, host, None)
+            .await
+            .map_err(|err| error_message(&err))
+    }
+
+    async fn grow(&self, host: &str) -> Result<f64, String> {
+        NS::grow(self, host, None)
+            .await
+            .map_err(|err| error_message(&err))
+    }
+
+    async fn weaken(&self, host: &str) -> Result<f64, String> {
+        NS::weaken(self, host, None)
+            .await
+            .map_err(|err| error_message(&err))
+    }
+
+    async fn sleep(&self, millis: f64) -> Result<(), String> {
+        NS::sleep(self, millis)
+            .await
+            .map(|_| ())
+            .map_err(|err| error_message(&err))
+    }
+
+    fn print(&self, message: &str) -> Result<(), String> {
+        NS::print(self, &[message.into()]).map_err(|err| error_message(&err))
+    }
+
+    fn tprint(&self, message: &str) -> Result<(), String> {
+        NS::tprint(self, &[message.into()]).map_err(|err| error_message(&err))
+    }
+
+    fn toast(&self, message: &str, variant: ToastVariant) -> Result<(), String> {
+        NS::toast(self, message, Some(variant), None).map_err(|err| error_message(&err))
+    }
+
+    fn disable_log(&self, function: &str) -> Result<(), String> {
+        NS::disableLog(self, function).map_err(|err| error_message(&err))
+    }
+
+    fn enable_log(&self, function: &str) -> Result<(), String> {
+        NS::enableLog(self, function).map_err(|err| error_message(&err))
+    }
+
+    fn is_log_enabled(&self, function: &str) -> Result<bool, String> {
+        NS::isLogEnabled(self, function).map_err(|err| error_message(&err))
+    }
+}
@@ -0,0 +1,544 @@
+//! HWGW batching: hack a prepared target, weaken back the security the hack
+//! added, grow back the money it stole and weaken again, with the four
+//! operations landing in that order.
+//!
+//! A [`BatchScheduler`] plans as many batches as the free RAM of the rooted
+//! servers holds, then dispatches them to tiny worker scripts that wait the
+//! computed `additionalMsec` before acting. Planning only reads the game, so
+//! it runs natively against a [`MockNs`](crate::MockNs):
+//!
+//! ```
+//! use bitburner_api::batch::{BatchScheduler, Operation, Workers};
+//! use bitburner_api::{mock, MockNs, Server};
+//!
+//! let mut ns = MockNs::new();
+//! ns.add_server(
+//!     Server {
+//!         has_admin_rights: true,
+//!         money_available: Some(1e6),
+//!         money_max: Some(1e6),
+//!         server_growth: Some(20.0),
+//!         ..mock::server("n00dles")
+//!     },
+//!     &["home"],
+//! );
+//! ns.add_server(
+//!     Server {
+//!         has_admin_rights: true,
+//!         max_ram: 64.0,
+//!         ..mock::server("pserv-0")
+//!     },
+//!     &["home"],
+//! );
+//! let workers = Workers::default();
+//! for script in workers.scripts() {
+//!     ns.add_script("home", script, 1.75);
+//! }
+//!
+//! let scheduler = BatchScheduler::new("n00dles").spacing(5.0);
+//! let plan = scheduler.plan(&ns).unwrap();
+//! assert_eq!(plan.batches.len(), 10);
+//! let first: Vec<Operation> = plan.batches[0].iter().map(|job| job.operation).collect();
+//! assert_eq!(
+//!     first,
+//!     [Operation::Hack, Operation::Weaken, Operation::Grow, Operation::Weaken]
+//! );
+//! // every operation lands after the one before it
+//! let ends: Vec<f64> = plan.batches.iter().flatten().map(|job| job.end).collect();
+//! assert!(ends.windows(2).all(|pair| pair[0] < pair[1]));
+//!
+//! let pids = scheduler.dispatch(&ns, &plan).unwrap();
+//! assert_eq!(pids.len(), 40);
+//! ```
+//!
+//! In the game, the worker scripts are written once on `home`:
+//!
+//! ```ignore
+//! use bitburner_api::batch::{self, BatchScheduler, Workers};
+//!
+//! let workers = Workers::default();
+//! for (script, source) in workers.scripts().into_iter().zip(batch::WORKER_SOURCES) {
+//!     ns.write(script, Some(source), Some("w"))?;
+//! }
+//! let scheduler = BatchScheduler::new("joesguns").hack_fraction(0.25);
+//! if batch::is_prepared(ns, "joesguns")? {
+//!     let plan = scheduler.plan(ns)?;
+//!     scheduler.dispatch(ns, &plan)?;
+//!     ns.sleep(plan.duration).await?;
+//! }
+//! ```
+
+use crate::{
+    network::{Network, HOME},
+    NetscriptApi, Pid, ScriptArg,
+};
+
+/// Sources of the hack, grow and weaken workers. They take the target and
+/// the delay in milliseconds as arguments, and a tag keeping their
+/// arguments unique.
+pub const WORKER_SOURCES: [&str; 3] = [
+    "export async function main(ns) {\n  await ns.hack(ns.args[0], { additionalMsec: ns.args[1] });\n}\n",
+    "export async function main(ns) {\n  await ns.grow(ns.args[0], { additionalMsec: ns.args[1] });\n}\n",
+    "export async function main(ns) {\n  await ns.weaken(ns.args[0], { additionalMsec: ns.args[1] });\n}\n",
+];
+
+/// An operation of a batch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Operation {
+    Hack,
+    Grow,
+    Weaken,
+}
+
+/// Filenames of the worker scripts, on `home`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Workers {
+    pub hack: String,
+    pub grow: String,
+    pub weaken: String,
+}
+
+impl Default for Workers {
+    fn default() -> Self {
+        Workers {
+            hack: "/batch/hack.js".to_owned(),
+            grow: "/batch/grow.js".to_owned(),
+            weaken: "/batch/weaken.js".to_owned(),
+        }
+    }
+}
+
+impl Workers {
+    /// The hack, grow and weaken workers, in the order of
+    /// [`WORKER_SOURCES`].
+    pub fn scripts(&self) -> [&str; 3] {
+        [&self.hack, &self.grow, &self.weaken]
+    }
+
+    pub fn script(&self, operation: Operation) -> &str {
+        match operation {
+            Operation::Hack => &self.hack,
+            Operation::Grow => &self.grow,
+            Operation::Weaken => &self.weaken,
+        }
+    }
+}
+
+/// Whether `target` is at its minimum security and maximum money, which
+/// batches assume.
+pub fn is_prepared<N: NetscriptApi>(ns: &N, target: &str) -> Result<bool, String> {
+    Ok(
+        ns.get_server_security_level(target)? <= ns.get_server_min_security_level(target)?
+            && ns.get_server_money_available(target)? >= ns.get_server_max_money(target)?,
+    )
+}
+
+/// Threads of each operation of a batch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BatchThreads {
+    pub hack: u32,
+    /// Weakening the security added by the hack
+    pub hack_weaken: u32,
+    pub grow: u32,
+    /// Weakening the security added by the grow
+    pub grow_weaken: u32,
+}
+
+impl BatchThreads {
+    /// Threads stealing about `hack_fraction` of the money of a prepared
+    /// `target` and restoring it, with a single core.
+    pub fn compute<N: NetscriptApi>(
+        ns: &N,
+        target: &str,
+        hack_fraction: f64,
+    ) -> Result<BatchThreads, String> {
+        let amount = ns.get_server_max_money(target)? * hack_fraction;
+        let exact = ns.hack_analyze_threads(target, amount)?;
+        if !exact.is_finite() || exact <= 0.0 {
+            return Err(format!("cannot hack {target}"));
+        }
+        let hack = (exact.floor() as u32).max(1);
+        let stolen = (hack_fraction * f64::from(hack) / exact).min(0.99);
+        let grow = ns.growth_analyze(target, 1.0 / (1.0 - stolen), 1)?.ceil() as u32;
+
+        let weaken = ns.weaken_analyze(1, 1)?;
+        let weaken_threads = |security: f64| (security / weaken).ceil().max(1.0) as u32;
+        Ok(BatchThreads {
+            hack,
+            hack_weaken: weaken_threads(ns.hack_analyze_security(hack, target)?),
+            grow: grow.max(1),
+            grow_weaken: weaken_threads(ns.growth_analyze_security(grow, target, 1)?),
+        })
+    }
+
+    /// The operations and their threads, in landing order.
+    pub fn operations(&self) -> [(Operation, u32); 4] {
+        [
+            (Operation::Hack, self.hack),
+            (Operation::Weaken, self.hack_weaken),
+            (Operation::Grow, self.grow),
+            (Operation::Weaken, self.grow_weaken),
+        ]
+    }
+}
+
+/// Free RAM of the servers that can run workers.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RamPool {
+    /// Hostnames and their free RAM (GB)
+    hosts: Vec<(String, f64)>,
+}
+
+impl RamPool {
+    pub fn new(hosts: Vec<(String, f64)>) -> Self {
+        RamPool { hosts }
+    }
+
+    /// The free RAM of every rooted server of the network, keeping
+    /// `home_reserve` GB free on `home`.
+    pub fn rooted<N: NetscriptApi>(ns: &N, home_reserve: f64) -> Result<RamPool, String> {
+        let mut hosts = vec![];
+        for host in Network::crawl(ns)?.hostnames() {
+            if !ns.has_root_access(host)? {
+                continue;
+            }
+            let mut free = ns.get_server_max_ram(host)? - ns.get_server_used_ram(host)?;
+            if host == HOME {
+                free -= home_reserve;
+            }
+            if free > 0.0 {
+                hosts.push((host.to_owned(), free));
+            }
+        }
+        Ok(RamPool { hosts })
+    }
+
+    /// Hostnames and their free RAM (GB).
+    pub fn hosts(&self) -> impl Iterator<Item = (&str, f64)> {
+        self.hosts.iter().map(|(host, free)| (host.as_str(), *free))
+    }
+
+    /// Total free RAM (GB).
+    pub fn free(&self) -> f64 {
+        self.hosts.iter().map(|(_, free)| free).sum()
+    }
+
+    /// Takes `ram` GB from the server with the least free RAM that fits it,
+    /// leaving the larger ones for larger jobs.
+    pub fn allocate(&mut self, ram: f64) -> Option<String> {
+        let (host, free) = self
+            .hosts
+            .iter_mut()
+            .filter(|(_, free)| *free >= ram)
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))?;
+        *free -= ram;
+        Some(host.clone())
+    }
+}
+
+/// An operation of a batch placed on a server.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Job {
+    pub operation: Operation,
+    pub host: String,
+    pub threads: u32,
+    /// Milliseconds the worker waits on top of the operation's duration
+    pub delay: f64,
+    /// Milliseconds after dispatch at which the operation lands
+    pub end: f64,
+}
+
+/// Batches placed on servers and timed, see [`BatchScheduler::plan`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct BatchPlan {
+    pub target: String,
+    pub threads: BatchThreads,
+    /// The jobs of every batch, in landing order
+    pub batches: Vec<Vec<Job>>,
+    /// Milliseconds until the last job lands
+    pub duration: f64,
+}
+
+/// Plans and dispatches HWGW batches against a target.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BatchScheduler {
+    target: String,
+    hack_fraction: f64,
+    spacing: f64,
+    max_batches: usize,
+    home_reserve: f64,
+    workers: Workers,
+}
+
+impl BatchScheduler {
+    pub fn new(target: &str) -> Self {
+        BatchScheduler {
+            target: target.to_owned(),
+            hack_fraction: 0.1,
+            spacing: 40.0,
+            max_batches: usize::MAX,
+            home_reserve: 0.0,
+            workers: Workers::default(),
+        }
+    }
+
+    /// Share of the target's money a batch steals, defaults to 0.1.
+    pub fn hack_fraction(mut self, fraction: f64) -> Self {
+        self.hack_fraction = fraction;
+        self
+    }
+
+    /// Milliseconds between two operations landing, defaults to 40.
+    pub fn spacing(mut self, millis: f64) -> Self {
+        self.spacing = millis;
+        self
+    }
+
+    /// Most batches to plan, unlimited by default.
+    pub fn max_batches(mut self, batches: usize) -> Self {
+        self.max_batches = batches;
+        self
+    }
+
+    /// RAM (GB) left free on `home`, defaults to 0.
+    pub fn home_reserve(mut self, ram: f64) -> Self {
+        self.home_reserve = ram;
+        self
+    }
+
+    /// Worker scripts to run, defaults to [`Workers::default`].
+    pub fn workers(mut self, workers: Workers) -> Self {
+        self.workers = workers;
+        self
+    }
+
+    /// Plans as many batches as fit in the free RAM, each landing its
+    /// operations `spacing` apart and after the previous batch. Durations are
+    /// read now, so the target must be prepared and the plan dispatched
+    /// right away.
+    pub fn plan<N: NetscriptApi>(&self, ns: &N) -> Result<BatchPlan, String> {
+        let target = self.target.as_str();
+        let threads = BatchThreads::compute(ns, target, self.hack_fraction)?;
+        let mut pool = RamPool::rooted(ns, self.home_reserve)?;
+
+        let mut operations = vec![];
+        for (operation, threads) in threads.operations() {
+            let script = self.workers.script(operation);
+            let ram = ns.get_script_ram(script, Some(HOME))?;
+            if ram == 0.0 {
+                return Err(format!("{script} is not on {HOME}"));
+            }
+            let duration = match operation {
+                Operation::Hack => ns.get_hack_time(target)?,
+                Operation::Grow => ns.get_grow_time(target)?,
+                Operation::Weaken => ns.get_weaken_time(target)?,
+            };
+            operations.push((operation, threads, ram * f64::from(threads), duration));
+        }
+
+        // the first weaken starts without delay, the first hack lands before it
+        let weaken_time = operations[1].3;
+        let mut batches = vec![];
+        'batches: while batches.len() < self.max_batches {
+            let mut batch_pool = pool.clone();
+            let mut jobs = vec![];
+            for (index, &(operation, threads, ram, duration)) in operations.iter().enumerate() {
+                let Some(host) = batch_pool.allocate(ram) else {
+                    break 'batches;
+                };
+                let end =
+                    weaken_time + self.spacing * (4 * batches.len() + index) as f64 - self.spacing;
+                jobs.push(Job {
+                    operation,
+                    host,
+                    threads,
+                    delay: end - duration,
+                    end,
+                });
+            }
+            pool = batch_pool;
+            batches.push(jobs);
+        }
+
+        let duration = batches
+            .iter()
+            .flatten()
+            .map(|job| job.end)
+            .fold(0.0, f64::max);
+        Ok(BatchPlan {
+            target: self.target.clone(),
+            threads,
+            batches,
+            duration,
+        })
+    }
+
+    /// Copies the workers to the servers of `plan` and starts its jobs.
+    /// Fails on the first job that cannot be started.
+    pub fn dispatch<N: NetscriptApi>(&self, ns: &N, plan: &BatchPlan) -> Result<Vec<Pid>, String> {
+        let mut deployed: Vec<&str> = vec![];
+        let mut pids = vec![];
+        for (index, job) in plan.batches.iter().flatten().enumerate() {
+            if job.host != HOME && !deployed.contains(&job.host.as_str()) {
+                ns.scp(&self.workers.scripts(), &job.host, Some(HOME))?;
+                deployed.push(&job.host);
+            }
+            let script = self.workers.script(job.operation);
+            let args = [
+                ScriptArg::from(plan.target.as_str()),
+                ScriptArg::from(job.delay),
+                ScriptArg::from(format!("batch-{index}")),
+            ];
+            match ns.exec(script, &job.host, job.threads, &args)? {
+                Some(pid) => pids.push(pid),
+                None => return Err(format!("could not start {script} on {}", job.host)),
+            }
+        }
+        Ok(pids)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{mock, MockNs, Server};
+
+    const WORKER_RAM: f64 = 1.75;
+
+    /// A prepared `n00dles` and a `pserv-0` with `ram` GB to run workers.
+    fn network(ram: f64) -> MockNs {
+        let mut ns = MockNs::new();
+        ns.add_server(
+            Server {
+                has_admin_rights: true,
+                money_available: Some(1e6),
+                money_max: Some(1e6),
+                server_growth: Some(20.0),
+                ..mock::server("n00dles")
+            },
+            &[HOME],
+        );
+        ns.add_server(
+            Server {
+                has_admin_rights: true,
+                max_ram: ram,
+                ..mock::server("pserv-0")
+            },
+            &[HOME],
+        );
+        for script in Workers::default().scripts() {
+            ns.add_script(HOME, script, WORKER_RAM);
+        }
+        ns
+    }
+
+    /// A scheduler leaving `home` out of the RAM pool.
+    fn scheduler() -> BatchScheduler {
+        BatchScheduler::new("n00dles").home_reserve(8.0)
+    }
+
+    fn batch_ram(threads: &BatchThreads) -> f64 {
+        let threads: u32 = threads
+            .operations()
+            .iter()
+            .map(|(_, threads)| threads)
+            .sum();
+        f64::from(threads) * WORKER_RAM
+    }
+
+    #[test]
+    fn insufficient_ram_plans_nothing() {
+        let ns = network(0.0);
+        let plan = scheduler().plan(&ns).unwrap();
+        assert!(plan.batches.is_empty());
+        assert_eq!(plan.duration, 0.0);
+        assert!(scheduler().dispatch(&ns, &plan).unwrap().is_empty());
+
+        // no batch is planned halfway
+        let threads = scheduler().plan(&network(1024.0)).unwrap().threads;
+        let ns = network(batch_ram(&threads) - WORKER_RAM);
+        assert!(scheduler().plan(&ns).unwrap().batches.is_empty());
+    }
+
+    #[test]
+    fn batches_fill_the_free_ram() {
+        let threads = scheduler().plan(&network(1024.0)).unwrap().threads;
+        let ns = network(batch_ram(&threads) * 2.5);
+        let plan = scheduler().plan(&ns).unwrap();
+        assert_eq!(plan.batches.len(), 2);
+        let pids = scheduler().dispatch(&ns, &plan).unwrap();
+        assert_eq!(pids.len(), 8);
+    }
+
+    #[test]
+    fn max_batches() {
+        let ns = network(1024.0);
+        assert_eq!(
+            scheduler().max_batches(3).plan(&ns).unwrap().batches.len(),
+            3
+        );
+        assert!(scheduler()
+            .max_batches(0)
+            .plan(&ns)
+            .unwrap()
+            .batches
+            .is_empty());
+        let unlimited = scheduler().plan(&ns).unwrap().batches.len();
+        assert!(unlimited > 3, "{unlimited}");
+    }
+
+    #[test]
+    fn operations_land_in_order() {
+        let ns = network(1024.0);
+        let spacing = 25.0;
+        let plan = scheduler().spacing(spacing).plan(&ns).unwrap();
+        let weaken_time = ns.get_weaken_time("n00dles").unwrap();
+
+        let jobs: Vec<&Job> = plan.batches.iter().flatten().collect();
+        for pair in jobs.windows(2) {
+            assert_eq!(pair[1].end - pair[0].end, spacing);
+        }
+        for job in &jobs {
+            let duration = match job.operation {
+                Operation::Hack => ns.get_hack_time("n00dles").unwrap(),
+                Operation::Grow => ns.get_grow_time("n00dles").unwrap(),
+                Operation::Weaken => ns.get_weaken_time("n00dles").unwrap(),
+            };
+            assert!(job.delay >= 0.0, "{job:?}");
+            assert_eq!(job.delay + duration, job.end);
+        }
+        // the first weaken starts right away
+        assert_eq!(jobs[1].delay, 0.0);
+        assert_eq!(jobs[1].end, weaken_time);
+        assert_eq!(plan.duration, jobs.last().unwrap().end);
+    }
+
+    #[test]
+    fn missing_workers_and_targets() {
+        let mut ns = network(1024.0);
+        let workers = Workers {
+            hack: "/missing.js".to_owned(),
+            ..Workers::default()
+        };
+        let err = scheduler().workers(workers).plan(&ns).unwrap_err();
+        assert_eq!(err, "/missing.js is not on home");
+
+        ns.add_server(
+            Server {
+                has_admin_rights: true,
+                ..mock::server("darkweb")
+            },
+            &[HOME],
+        );
+        assert!(BatchScheduler::new("darkweb").plan(&ns).is_err());
+    }
+
+    #[test]
+    fn allocation_prefers_the_tightest_fit() {
+        let mut pool = RamPool::new(vec![("big".to_owned(), 64.0), ("small".to_owned(), 4.0)]);
+        assert_eq!(pool.allocate(2.0).as_deref(), Some("small"));
+        assert_eq!(pool.allocate(3.0).as_deref(), Some("big"));
+        assert_eq!(pool.allocate(100.0), None);
+        assert_eq!(pool.free(), 61.0 + 2.0);
+    }
+}
@@ -0,0 +1,11867 @@
+// This file is generated by `cargo xtask gen-bindings` from `bitburner.d.ts`.
+// Do not edit it by hand, change the definitions or the generator instead.
+#![allow(non_snake_case, clippy::too_many_arguments)]
+
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    #[derive(Debug, Clone, PartialEq)]
+    pub type HP;
+
+    #[wasm_bindgen(method, getter)]
+    pub fn current(this: &HP) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_current(this: &HP, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn max(this: &HP) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_max(this: &HP, value: f64);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[derive(Debug, Clone, PartialEq)]
+    pub type Skills;
+
+    #[wasm_bindgen(method, getter)]
+    pub fn hacking(this: &Skills) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_hacking(this: &Skills, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn strength(this: &Skills) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_strength(this: &Skills, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn defense(this: &Skills) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_defense(this: &Skills, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn dexterity(this: &Skills) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_dexterity(this: &Skills, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn agility(this: &Skills) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_agility(this: &Skills, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn charisma(this: &Skills) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_charisma(this: &Skills, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn intelligence(this: &Skills) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_intelligence(this: &Skills, value: f64);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[derive(Debug, Clone, PartialEq)]
+    pub type Person;
+
+    #[wasm_bindgen(method, getter)]
+    pub fn hp(this: &Person) -> HP;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_hp(this: &Person, value: &HP);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn skills(this: &Person) -> Skills;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_skills(this: &Person, value: &Skills);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn exp(this: &Person) -> Skills;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_exp(this: &Person, value: &Skills);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn mults(this: &Person) -> Multipliers;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_mults(this: &Person, value: &Multipliers);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn city(this: &Person) -> CityName;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_city(this: &Person, value: CityName);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(extends = Person)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub type Player;
+
+    #[wasm_bindgen(method, getter)]
+    pub fn money(this: &Player) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_money(this: &Player, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn numPeopleKilled(this: &Player) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_numPeopleKilled(this: &Player, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn entropy(this: &Player) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_entropy(this: &Player, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn jobs(this: &Player) -> JsValue;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_jobs(this: &Player, value: &JsValue);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn factions(this: &Player) -> Vec<String>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_factions(this: &Player, value: Vec<String>);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn totalPlaytime(this: &Player) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_totalPlaytime(this: &Player, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn location(this: &Player) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_location(this: &Player, value: &str);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(extends = Person)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub type SleevePerson;
+
+    /// Number 0-100 Experience earned and shared is multiplied with shock% before sync%
+    #[wasm_bindgen(method, getter)]
+    pub fn shock(this: &SleevePerson) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_shock(this: &SleevePerson, value: f64);
+
+    /// Number 1-100 Experience earned by this sleeve and shared with the player is multiplied with sync% after shock%
+    #[wasm_bindgen(method, getter)]
+    pub fn sync(this: &SleevePerson) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_sync(this: &SleevePerson, value: f64);
+
+    /// Number 1-100 initial Value of sync on BN start
+    #[wasm_bindgen(method, getter)]
+    pub fn memory(this: &SleevePerson) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_memory(this: &SleevePerson, value: f64);
+
+    /// Number of 200ms cycles which are stored as bonus time
+    #[wasm_bindgen(method, getter)]
+    pub fn storedCycles(this: &SleevePerson) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_storedCycles(this: &SleevePerson, value: f64);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// Various info about resets
+    #[derive(Debug, Clone, PartialEq)]
+    pub type ResetInfo;
+
+    /// Numeric timestamp (from Date.now()) of last augmentation reset
+    #[wasm_bindgen(method, getter)]
+    pub fn lastAugReset(this: &ResetInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_lastAugReset(this: &ResetInfo, value: f64);
+
+    /// Numeric timestamp (from Date.now()) of last bitnode reset
+    #[wasm_bindgen(method, getter)]
+    pub fn lastNodeReset(this: &ResetInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_lastNodeReset(this: &ResetInfo, value: f64);
+
+    /// The current bitnode
+    #[wasm_bindgen(method, getter)]
+    pub fn currentNode(this: &ResetInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_currentNode(this: &ResetInfo, value: f64);
+
+    /// A map of owned augmentations to their levels. Keyed by the augmentation name. Map values are the augmentation level (e.g. for NeuroFlux governor).
+    #[wasm_bindgen(method, getter)]
+    pub fn ownedAugs(this: &ResetInfo) -> js_sys::Map;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_ownedAugs(this: &ResetInfo, value: &js_sys::Map);
+
+    /// A map of owned SF to their levels. Keyed by the SF number. Map values are the SF level.
+    #[wasm_bindgen(method, getter)]
+    pub fn ownedSF(this: &ResetInfo) -> js_sys::Map;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_ownedSF(this: &ResetInfo, value: &js_sys::Map);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[derive(Debug, Clone, PartialEq)]
+    pub type MoneySource;
+
+    #[wasm_bindgen(method, getter)]
+    pub fn bladeburner(this: &MoneySource) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_bladeburner(this: &MoneySource, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn casino(this: &MoneySource) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_casino(this: &MoneySource, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn class(this: &MoneySource) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_class(this: &MoneySource, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn codingcontract(this: &MoneySource) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_codingcontract(this: &MoneySource, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn corporation(this: &MoneySource) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_corporation(this: &MoneySource, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn crime(this: &MoneySource) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_crime(this: &MoneySource, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn gang(this: &MoneySource) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_gang(this: &MoneySource, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn hacking(this: &MoneySource) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_hacking(this: &MoneySource, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn hacknet(this: &MoneySource) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_hacknet(this: &MoneySource, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn hacknet_expenses(this: &MoneySource) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_hacknet_expenses(this: &MoneySource, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn hospitalization(this: &MoneySource) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_hospitalization(this: &MoneySource, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn infiltration(this: &MoneySource) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_infiltration(this: &MoneySource, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn sleeves(this: &MoneySource) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_sleeves(this: &MoneySource, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn stock(this: &MoneySource) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_stock(this: &MoneySource, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn total(this: &MoneySource) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_total(this: &MoneySource, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn work(this: &MoneySource) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_work(this: &MoneySource, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn servers(this: &MoneySource) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_servers(this: &MoneySource, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn other(this: &MoneySource) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_other(this: &MoneySource, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn augmentations(this: &MoneySource) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_augmentations(this: &MoneySource, value: f64);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[derive(Debug, Clone, PartialEq)]
+    pub type MoneySources;
+
+    #[wasm_bindgen(method, getter)]
+    pub fn sinceInstall(this: &MoneySources) -> MoneySource;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_sinceInstall(this: &MoneySources, value: &MoneySource);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn sinceStart(this: &MoneySources) -> MoneySource;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_sinceStart(this: &MoneySources, value: &MoneySource);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[derive(Debug, Clone, PartialEq)]
+    pub type Multipliers;
+
+    /// Multiplier to hacking skill
+    #[wasm_bindgen(method, getter)]
+    pub fn hacking(this: &Multipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_hacking(this: &Multipliers, value: f64);
+
+    /// Multiplier to strength skill
+    #[wasm_bindgen(method, getter)]
+    pub fn strength(this: &Multipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_strength(this: &Multipliers, value: f64);
+
+    /// Multiplier to defense skill
+    #[wasm_bindgen(method, getter)]
+    pub fn defense(this: &Multipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_defense(this: &Multipliers, value: f64);
+
+    /// Multiplier to dexterity skill
+    #[wasm_bindgen(method, getter)]
+    pub fn dexterity(this: &Multipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_dexterity(this: &Multipliers, value: f64);
+
+    /// Multiplier to agility skill
+    #[wasm_bindgen(method, getter)]
+    pub fn agility(this: &Multipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_agility(this: &Multipliers, value: f64);
+
+    /// Multiplier to charisma skill
+    #[wasm_bindgen(method, getter)]
+    pub fn charisma(this: &Multipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_charisma(this: &Multipliers, value: f64);
+
+    /// Multiplier to hacking experience gain rate
+    #[wasm_bindgen(method, getter)]
+    pub fn hacking_exp(this: &Multipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_hacking_exp(this: &Multipliers, value: f64);
+
+    /// Multiplier to strength experience gain rate
+    #[wasm_bindgen(method, getter)]
+    pub fn strength_exp(this: &Multipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_strength_exp(this: &Multipliers, value: f64);
+
+    /// Multiplier to defense experience gain rate
+    #[wasm_bindgen(method, getter)]
+    pub fn defense_exp(this: &Multipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_defense_exp(this: &Multipliers, value: f64);
+
+    /// Multiplier to dexterity experience gain rate
+    #[wasm_bindgen(method, getter)]
+    pub fn dexterity_exp(this: &Multipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_dexterity_exp(this: &Multipliers, value: f64);
+
+    /// Multiplier to agility experience gain rate
+    #[wasm_bindgen(method, getter)]
+    pub fn agility_exp(this: &Multipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_agility_exp(this: &Multipliers, value: f64);
+
+    /// Multiplier to charisma experience gain rate
+    #[wasm_bindgen(method, getter)]
+    pub fn charisma_exp(this: &Multipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_charisma_exp(this: &Multipliers, value: f64);
+
+    /// Multiplier to chance of successfully performing a hack
+    #[wasm_bindgen(method, getter)]
+    pub fn hacking_chance(this: &Multipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_hacking_chance(this: &Multipliers, value: f64);
+
+    /// Multiplier to hacking speed
+    #[wasm_bindgen(method, getter)]
+    pub fn hacking_speed(this: &Multipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_hacking_speed(this: &Multipliers, value: f64);
+
+    /// Multiplier to amount of money the player gains from hacking
+    #[wasm_bindgen(method, getter)]
+    pub fn hacking_money(this: &Multipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_hacking_money(this: &Multipliers, value: f64);
+
+    /// Multiplier to amount of money injected into servers using grow
+    #[wasm_bindgen(method, getter)]
+    pub fn hacking_grow(this: &Multipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_hacking_grow(this: &Multipliers, value: f64);
+
+    /// Multiplier to amount of reputation gained when working
+    #[wasm_bindgen(method, getter)]
+    pub fn company_rep(this: &Multipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_company_rep(this: &Multipliers, value: f64);
+
+    /// Multiplier to amount of reputation gained when working
+    #[wasm_bindgen(method, getter)]
+    pub fn faction_rep(this: &Multipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_faction_rep(this: &Multipliers, value: f64);
+
+    /// Multiplier to amount of money gained from crimes
+    #[wasm_bindgen(method, getter)]
+    pub fn crime_money(this: &Multipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_crime_money(this: &Multipliers, value: f64);
+
+    /// Multiplier to crime success rate
+    #[wasm_bindgen(method, getter)]
+    pub fn crime_success(this: &Multipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_crime_success(this: &Multipliers, value: f64);
+
+    /// Multiplier to amount of money gained from working
+    #[wasm_bindgen(method, getter)]
+    pub fn work_money(this: &Multipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_work_money(this: &Multipliers, value: f64);
+
+    /// Multiplier to amount of money produced by Hacknet Nodes
+    #[wasm_bindgen(method, getter)]
+    pub fn hacknet_node_money(this: &Multipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_hacknet_node_money(this: &Multipliers, value: f64);
+
+    /// Multiplier to cost of purchasing a Hacknet Node
+    #[wasm_bindgen(method, getter)]
+    pub fn hacknet_node_purchase_cost(this: &Multipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_hacknet_node_purchase_cost(this: &Multipliers, value: f64);
+
+    /// Multiplier to cost of ram for a Hacknet Node
+    #[wasm_bindgen(method, getter)]
+    pub fn hacknet_node_ram_cost(this: &Multipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_hacknet_node_ram_cost(this: &Multipliers, value: f64);
+
+    /// Multiplier to cost of core for a Hacknet Node
+    #[wasm_bindgen(method, getter)]
+    pub fn hacknet_node_core_cost(this: &Multipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_hacknet_node_core_cost(this: &Multipliers, value: f64);
+
+    /// Multiplier to cost of leveling up a Hacknet Node
+    #[wasm_bindgen(method, getter)]
+    pub fn hacknet_node_level_cost(this: &Multipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_hacknet_node_level_cost(this: &Multipliers, value: f64);
+
+    /// Multiplier to Bladeburner max stamina
+    #[wasm_bindgen(method, getter)]
+    pub fn bladeburner_max_stamina(this: &Multipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_bladeburner_max_stamina(this: &Multipliers, value: f64);
+
+    /// Multiplier to Bladeburner stamina gain rate
+    #[wasm_bindgen(method, getter)]
+    pub fn bladeburner_stamina_gain(this: &Multipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_bladeburner_stamina_gain(this: &Multipliers, value: f64);
+
+    /// Multiplier to effectiveness in Bladeburner Field Analysis
+    #[wasm_bindgen(method, getter)]
+    pub fn bladeburner_analysis(this: &Multipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_bladeburner_analysis(this: &Multipliers, value: f64);
+
+    /// Multiplier to success chance in Bladeburner contracts/operations
+    #[wasm_bindgen(method, getter)]
+    pub fn bladeburner_success_chance(this: &Multipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_bladeburner_success_chance(this: &Multipliers, value: f64);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[derive(Debug, Clone, PartialEq)]
+    pub type TailProperties;
+
+    /// X-coordinate of the log window
+    #[wasm_bindgen(method, getter)]
+    pub fn x(this: &TailProperties) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_x(this: &TailProperties, value: f64);
+
+    /// Y-coordinate of the log window
+    #[wasm_bindgen(method, getter)]
+    pub fn y(this: &TailProperties) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_y(this: &TailProperties, value: f64);
+
+    /// Width of the log window content area
+    #[wasm_bindgen(method, getter)]
+    pub fn width(this: &TailProperties) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_width(this: &TailProperties, value: f64);
+
+    /// Height of the log window content area
+    #[wasm_bindgen(method, getter)]
+    pub fn height(this: &TailProperties) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_height(this: &TailProperties, value: f64);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// A stand-in for the real React.ReactElement.
+    /// Use React.createElement to make these.
+    /// See {@link https://react.dev/reference/react/createElement#creating-an-element-without-jsx | creating an element without jsx} from the official React documentation.
+    #[derive(Debug, Clone, PartialEq)]
+    pub type ReactElement;
+
+    #[wasm_bindgen(method, getter = "type")]
+    pub fn r#type(this: &ReactElement) -> JsValue;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_type(this: &ReactElement, value: &JsValue);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn props(this: &ReactElement) -> JsValue;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_props(this: &ReactElement, value: &JsValue);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn key(this: &ReactElement) -> JsValue;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_key(this: &ReactElement, value: &JsValue);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[derive(Debug, Clone, PartialEq)]
+    pub type RunningScript;
+
+    /// Arguments the script was called with
+    #[wasm_bindgen(method, getter)]
+    pub fn args(this: &RunningScript) -> Vec<JsValue>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_args(this: &RunningScript, value: Vec<JsValue>);
+
+    /// Filename of the script
+    #[wasm_bindgen(method, getter)]
+    pub fn filename(this: &RunningScript) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_filename(this: &RunningScript, value: &str);
+
+    /// Script logs as an array. The newest log entries are at the bottom.
+    /// Timestamps, if enabled, are placed inside `[brackets]` at the start of each line.
+    #[wasm_bindgen(method, getter)]
+    pub fn logs(this: &RunningScript) -> Vec<String>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_logs(this: &RunningScript, value: Vec<String>);
+
+    /// Total amount of hacking experience earned from this script when offline
+    #[wasm_bindgen(method, getter)]
+    pub fn offlineExpGained(this: &RunningScript) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_offlineExpGained(this: &RunningScript, value: f64);
+
+    /// Total amount of money made by this script when offline
+    #[wasm_bindgen(method, getter)]
+    pub fn offlineMoneyMade(this: &RunningScript) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_offlineMoneyMade(this: &RunningScript, value: f64);
+
+    /// Number of seconds that the script has been running offline
+    #[wasm_bindgen(method, getter)]
+    pub fn offlineRunningTime(this: &RunningScript) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_offlineRunningTime(this: &RunningScript, value: f64);
+
+    /// Total amount of hacking experience earned from this script when online
+    #[wasm_bindgen(method, getter)]
+    pub fn onlineExpGained(this: &RunningScript) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_onlineExpGained(this: &RunningScript, value: f64);
+
+    /// Total amount of money made by this script when online
+    #[wasm_bindgen(method, getter)]
+    pub fn onlineMoneyMade(this: &RunningScript) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_onlineMoneyMade(this: &RunningScript, value: f64);
+
+    /// Number of seconds that this script has been running online
+    #[wasm_bindgen(method, getter)]
+    pub fn onlineRunningTime(this: &RunningScript) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_onlineRunningTime(this: &RunningScript, value: f64);
+
+    /// Process ID. Must be an integer
+    #[wasm_bindgen(method, getter)]
+    pub fn pid(this: &RunningScript) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_pid(this: &RunningScript, value: f64);
+
+    /// How much RAM this script uses for ONE thread
+    #[wasm_bindgen(method, getter)]
+    pub fn ramUsage(this: &RunningScript) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_ramUsage(this: &RunningScript, value: f64);
+
+    /// Hostname of the server on which this script runs
+    #[wasm_bindgen(method, getter)]
+    pub fn server(this: &RunningScript) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_server(this: &RunningScript, value: &str);
+
+    /// Properties of the tail window, or null if it is not shown
+    #[wasm_bindgen(method, getter)]
+    pub fn tailProperties(this: &RunningScript) -> Option<TailProperties>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_tailProperties(this: &RunningScript, value: Option<&TailProperties>);
+
+    /// The title, as shown in the script's log box. Defaults to the name + args,
+    /// but can be changed by the user. If it is set to a React element (only by
+    /// the user), that will not be persisted, and will be restored to default on
+    /// load.
+    #[wasm_bindgen(method, getter)]
+    pub fn title(this: &RunningScript) -> JsValue;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_title(this: &RunningScript, value: &JsValue);
+
+    /// Number of threads that this script runs with
+    #[wasm_bindgen(method, getter)]
+    pub fn threads(this: &RunningScript) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_threads(this: &RunningScript, value: f64);
+
+    /// Whether this RunningScript is excluded from saves
+    #[wasm_bindgen(method, getter)]
+    pub fn temporary(this: &RunningScript) -> bool;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_temporary(this: &RunningScript, value: bool);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[derive(Debug, Clone, PartialEq)]
+    pub type RunOptions;
+
+    /// Number of threads that the script will run with, defaults to 1
+    #[wasm_bindgen(method, getter)]
+    pub fn threads(this: &RunOptions) -> Option<f64>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_threads(this: &RunOptions, value: Option<f64>);
+
+    /// Whether this script is excluded from saves, defaults to false
+    #[wasm_bindgen(method, getter)]
+    pub fn temporary(this: &RunOptions) -> Option<bool>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_temporary(this: &RunOptions, value: Option<bool>);
+
+    /// The RAM allocation to launch each thread of the script with.
+    ///
+    /// Lowering this will <i>not</i> automatically let you get away with using less RAM:
+    /// the dynamic RAM check enforces that all {@link NS} functions actually called incur their cost.
+    /// However, if you know that certain functions that are statically present (and thus included
+    /// in the static RAM cost) will never be called in a particular circumstance, you can use
+    /// this to avoid paying for them.
+    ///
+    /// You can also use this to <i>increase</i> the RAM if the static RAM checker has missed functions
+    /// that you need to call.
+    ///
+    /// Must be greater-or-equal to the base RAM cost. Defaults to the statically calculated cost.
+    #[wasm_bindgen(method, getter)]
+    pub fn ramOverride(this: &RunOptions) -> Option<f64>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_ramOverride(this: &RunOptions, value: Option<f64>);
+
+    /// Should we fail to run if another instance is running with the exact same arguments?
+    /// This used to be the default behavior, now defaults to false.
+    #[wasm_bindgen(method, getter)]
+    pub fn preventDuplicates(this: &RunOptions) -> Option<bool>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_preventDuplicates(this: &RunOptions, value: Option<bool>);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(extends = RunOptions)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub type SpawnOptions;
+
+    /// Number of milliseconds to delay before spawning script, defaults to 10000 (10s). Must be a positive integer.
+    #[wasm_bindgen(method, getter)]
+    pub fn spawnDelay(this: &SpawnOptions) -> Option<f64>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_spawnDelay(this: &SpawnOptions, value: Option<f64>);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(extends = RunningScript)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub type RecentScript;
+
+    /// Timestamp of when the script was killed
+    #[wasm_bindgen(method, getter)]
+    pub fn timeOfDeath(this: &RecentScript) -> js_sys::Date;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_timeOfDeath(this: &RecentScript, value: &js_sys::Date);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// Data representing the internal values of a crime.
+    #[derive(Debug, Clone, PartialEq)]
+    pub type CrimeStats;
+
+    /// Number representing the difficulty of the crime. Used for success chance calculations
+    #[wasm_bindgen(method, getter)]
+    pub fn difficulty(this: &CrimeStats) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_difficulty(this: &CrimeStats, value: f64);
+
+    /// Amount of karma lost for successfully committing this crime
+    #[wasm_bindgen(method, getter)]
+    pub fn karma(this: &CrimeStats) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_karma(this: &CrimeStats, value: f64);
+
+    /// How many people die as a result of this crime
+    #[wasm_bindgen(method, getter)]
+    pub fn kills(this: &CrimeStats) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_kills(this: &CrimeStats, value: f64);
+
+    /// How much money is given
+    #[wasm_bindgen(method, getter)]
+    pub fn money(this: &CrimeStats) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_money(this: &CrimeStats, value: f64);
+
+    /// Milliseconds it takes to attempt the crime
+    #[wasm_bindgen(method, getter)]
+    pub fn time(this: &CrimeStats) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_time(this: &CrimeStats, value: f64);
+
+    /// Description of the crime activity
+    #[wasm_bindgen(method, getter = "type")]
+    pub fn r#type(this: &CrimeStats) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_type(this: &CrimeStats, value: &str);
+
+    /// hacking level impact on success change of the crime
+    #[wasm_bindgen(method, getter)]
+    pub fn hacking_success_weight(this: &CrimeStats) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_hacking_success_weight(this: &CrimeStats, value: f64);
+
+    /// strength level impact on success change of the crime
+    #[wasm_bindgen(method, getter)]
+    pub fn strength_success_weight(this: &CrimeStats) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_strength_success_weight(this: &CrimeStats, value: f64);
+
+    /// defense level impact on success change of the crime
+    #[wasm_bindgen(method, getter)]
+    pub fn defense_success_weight(this: &CrimeStats) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_defense_success_weight(this: &CrimeStats, value: f64);
+
+    /// dexterity level impact on success change of the crime
+    #[wasm_bindgen(method, getter)]
+    pub fn dexterity_success_weight(this: &CrimeStats) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_dexterity_success_weight(this: &CrimeStats, value: f64);
+
+    /// agility level impact on success change of the crime
+    #[wasm_bindgen(method, getter)]
+    pub fn agility_success_weight(this: &CrimeStats) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_agility_success_weight(this: &CrimeStats, value: f64);
+
+    /// charisma level impact on success change of the crime
+    #[wasm_bindgen(method, getter)]
+    pub fn charisma_success_weight(this: &CrimeStats) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_charisma_success_weight(this: &CrimeStats, value: f64);
+
+    /// hacking exp gained from crime
+    #[wasm_bindgen(method, getter)]
+    pub fn hacking_exp(this: &CrimeStats) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_hacking_exp(this: &CrimeStats, value: f64);
+
+    /// strength exp gained from crime
+    #[wasm_bindgen(method, getter)]
+    pub fn strength_exp(this: &CrimeStats) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_strength_exp(this: &CrimeStats, value: f64);
+
+    /// defense exp gained from crime
+    #[wasm_bindgen(method, getter)]
+    pub fn defense_exp(this: &CrimeStats) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_defense_exp(this: &CrimeStats, value: f64);
+
+    /// dexterity exp gained from crime
+    #[wasm_bindgen(method, getter)]
+    pub fn dexterity_exp(this: &CrimeStats) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_dexterity_exp(this: &CrimeStats, value: f64);
+
+    /// agility exp gained from crime
+    #[wasm_bindgen(method, getter)]
+    pub fn agility_exp(this: &CrimeStats) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_agility_exp(this: &CrimeStats, value: f64);
+
+    /// charisma exp gained from crime
+    #[wasm_bindgen(method, getter)]
+    pub fn charisma_exp(this: &CrimeStats) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_charisma_exp(this: &CrimeStats, value: f64);
+
+    /// intelligence exp gained from crime
+    #[wasm_bindgen(method, getter)]
+    pub fn intelligence_exp(this: &CrimeStats) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_intelligence_exp(this: &CrimeStats, value: f64);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// Options to affect the behavior of {@link NS.hack | hack}, {@link NS.grow | grow}, and {@link NS.weaken | weaken}.
+    #[derive(Debug, Clone, PartialEq)]
+    pub type BasicHGWOptions;
+
+    /// Number of threads to use for this function.
+    /// Must be less than or equal to the number of threads the script is running with.
+    #[wasm_bindgen(method, getter)]
+    pub fn threads(this: &BasicHGWOptions) -> Option<f64>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_threads(this: &BasicHGWOptions, value: Option<f64>);
+
+    /// Set to true this action will affect the stock market.
+    #[wasm_bindgen(method, getter)]
+    pub fn stock(this: &BasicHGWOptions) -> Option<bool>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_stock(this: &BasicHGWOptions, value: Option<bool>);
+
+    /// Number of additional milliseconds that will be spent waiting between the start of the function and when it
+    /// completes.
+    #[wasm_bindgen(method, getter)]
+    pub fn additionalMsec(this: &BasicHGWOptions) -> Option<f64>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_additionalMsec(this: &BasicHGWOptions, value: Option<f64>);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// Return value of {@link Sleeve.getSleevePurchasableAugs | getSleevePurchasableAugs}
+    #[derive(Debug, Clone, PartialEq)]
+    pub type AugmentPair;
+
+    /// augmentation name
+    #[wasm_bindgen(method, getter)]
+    pub fn name(this: &AugmentPair) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_name(this: &AugmentPair, value: &str);
+
+    /// augmentation cost
+    #[wasm_bindgen(method, getter)]
+    pub fn cost(this: &AugmentPair) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_cost(this: &AugmentPair, value: f64);
+}
+
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PositionType {
+    Long = "L",
+    Short = "S",
+}
+
+impl PositionType {
+    /// All the variants in declaration order
+    pub const ALL: [PositionType; 2] = [PositionType::Long, PositionType::Short];
+
+    /// The value of this variant in JavaScript
+    pub fn as_str(&self) -> &'static str {
+        self.to_str()
+    }
+}
+
+string_enum!(PositionType);
+
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OrderType {
+    LimitBuy = "Limit Buy Order",
+    LimitSell = "Limit Sell Order",
+    StopBuy = "Stop Buy Order",
+    StopSell = "Stop Sell Order",
+}
+
+impl OrderType {
+    /// All the variants in declaration order
+    pub const ALL: [OrderType; 4] = [
+        OrderType::LimitBuy,
+        OrderType::LimitSell,
+        OrderType::StopBuy,
+        OrderType::StopSell,
+    ];
+
+    /// The value of this variant in JavaScript
+    pub fn as_str(&self) -> &'static str {
+        self.to_str()
+    }
+}
+
+string_enum!(OrderType);
+
+#[wasm_bindgen]
+extern "C" {
+    /// Value in map of {@link StockOrder}
+    #[derive(Debug, Clone, PartialEq)]
+    pub type StockOrderObject;
+
+    /// Number of shares
+    #[wasm_bindgen(method, getter)]
+    pub fn shares(this: &StockOrderObject) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_shares(this: &StockOrderObject, value: f64);
+
+    /// Price per share
+    #[wasm_bindgen(method, getter)]
+    pub fn price(this: &StockOrderObject) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_price(this: &StockOrderObject, value: f64);
+
+    /// Order type
+    #[wasm_bindgen(method, getter = "type")]
+    pub fn r#type(this: &StockOrderObject) -> OrderType;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_type(this: &StockOrderObject, value: OrderType);
+
+    /// Order position
+    #[wasm_bindgen(method, getter)]
+    pub fn position(this: &StockOrderObject) -> PositionType;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_position(this: &StockOrderObject, value: PositionType);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// Return value of {@link TIX.getOrders | getOrders}
+    ///
+    /// Keys are stock symbols, properties are arrays of {@link StockOrderObject}
+    #[derive(Debug, Clone, PartialEq)]
+    pub type StockOrder;
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// Constants used for the stockmarket game mechanic.
+    #[derive(Debug, Clone, PartialEq)]
+    pub type StockMarketConstants;
+
+    /// Normal time in ms between stock market updates
+    #[wasm_bindgen(method, getter)]
+    pub fn msPerStockUpdate(this: &StockMarketConstants) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_msPerStockUpdate(this: &StockMarketConstants, value: f64);
+
+    /// Minimum time in ms between stock market updates if there is stored offline/bonus time
+    #[wasm_bindgen(method, getter)]
+    pub fn msPerStockUpdateMin(this: &StockMarketConstants) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_msPerStockUpdateMin(this: &StockMarketConstants, value: f64);
+
+    /// An internal constant used while determining when to flip a stock's forecast
+    #[wasm_bindgen(method, getter)]
+    pub fn TicksPerCycle(this: &StockMarketConstants) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_TicksPerCycle(this: &StockMarketConstants, value: f64);
+
+    /// Cost of the WSE account
+    #[wasm_bindgen(method, getter)]
+    pub fn WSEAccountCost(this: &StockMarketConstants) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_WSEAccountCost(this: &StockMarketConstants, value: f64);
+
+    /// Cost of the TIX API
+    #[wasm_bindgen(method, getter)]
+    pub fn TIXAPICost(this: &StockMarketConstants) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_TIXAPICost(this: &StockMarketConstants, value: f64);
+
+    /// Cost of the 4S Market Data
+    #[wasm_bindgen(method, getter)]
+    pub fn MarketData4SCost(this: &StockMarketConstants) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_MarketData4SCost(this: &StockMarketConstants, value: f64);
+
+    /// Cost of the 4S Market Data TIX API integration
+    #[wasm_bindgen(method, getter)]
+    pub fn MarketDataTixApi4SCost(this: &StockMarketConstants) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_MarketDataTixApi4SCost(this: &StockMarketConstants, value: f64);
+
+    /// Commission fee for transactions
+    #[wasm_bindgen(method, getter)]
+    pub fn StockMarketCommission(this: &StockMarketConstants) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_StockMarketCommission(this: &StockMarketConstants, value: f64);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// A single process on a server.
+    #[derive(Debug, Clone, PartialEq)]
+    pub type ProcessInfo;
+
+    /// Script name.
+    #[wasm_bindgen(method, getter)]
+    pub fn filename(this: &ProcessInfo) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_filename(this: &ProcessInfo, value: &str);
+
+    /// Number of threads script is running with
+    #[wasm_bindgen(method, getter)]
+    pub fn threads(this: &ProcessInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_threads(this: &ProcessInfo, value: f64);
+
+    /// Script's arguments
+    #[wasm_bindgen(method, getter)]
+    pub fn args(this: &ProcessInfo) -> Vec<JsValue>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_args(this: &ProcessInfo, value: Vec<JsValue>);
+
+    /// Process ID
+    #[wasm_bindgen(method, getter)]
+    pub fn pid(this: &ProcessInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_pid(this: &ProcessInfo, value: f64);
+
+    /// Whether this process is excluded from saves
+    #[wasm_bindgen(method, getter)]
+    pub fn temporary(this: &ProcessInfo) -> bool;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_temporary(this: &ProcessInfo, value: bool);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// Hack related multipliers.
+    #[derive(Debug, Clone, PartialEq)]
+    pub type HackingMultipliers;
+
+    /// Player's hacking chance multiplier.
+    #[wasm_bindgen(method, getter)]
+    pub fn chance(this: &HackingMultipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_chance(this: &HackingMultipliers, value: f64);
+
+    /// Player's hacking speed multiplier.
+    #[wasm_bindgen(method, getter)]
+    pub fn speed(this: &HackingMultipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_speed(this: &HackingMultipliers, value: f64);
+
+    /// Player's hacking money stolen multiplier.
+    #[wasm_bindgen(method, getter)]
+    pub fn money(this: &HackingMultipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_money(this: &HackingMultipliers, value: f64);
+
+    /// Player's hacking growth multiplier
+    #[wasm_bindgen(method, getter)]
+    pub fn growth(this: &HackingMultipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_growth(this: &HackingMultipliers, value: f64);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// Hacknet related multipliers.
+    #[derive(Debug, Clone, PartialEq)]
+    pub type HacknetMultipliers;
+
+    /// Player's hacknet production multiplier
+    #[wasm_bindgen(method, getter)]
+    pub fn production(this: &HacknetMultipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_production(this: &HacknetMultipliers, value: f64);
+
+    /// Player's hacknet purchase cost multiplier
+    #[wasm_bindgen(method, getter)]
+    pub fn purchaseCost(this: &HacknetMultipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_purchaseCost(this: &HacknetMultipliers, value: f64);
+
+    /// Player's hacknet ram cost multiplier
+    #[wasm_bindgen(method, getter)]
+    pub fn ramCost(this: &HacknetMultipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_ramCost(this: &HacknetMultipliers, value: f64);
+
+    /// Player's hacknet core cost multiplier
+    #[wasm_bindgen(method, getter)]
+    pub fn coreCost(this: &HacknetMultipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_coreCost(this: &HacknetMultipliers, value: f64);
+
+    /// Player's hacknet level cost multiplier
+    #[wasm_bindgen(method, getter)]
+    pub fn levelCost(this: &HacknetMultipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_levelCost(this: &HacknetMultipliers, value: f64);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// Hacknet node related constants
+    #[derive(Debug, Clone, PartialEq)]
+    pub type HacknetNodeConstants;
+
+    /// Amount of money gained per level
+    #[wasm_bindgen(method, getter)]
+    pub fn MoneyGainPerLevel(this: &HacknetNodeConstants) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_MoneyGainPerLevel(this: &HacknetNodeConstants, value: f64);
+
+    /// Base cost for a new node
+    #[wasm_bindgen(method, getter)]
+    pub fn BaseCost(this: &HacknetNodeConstants) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_BaseCost(this: &HacknetNodeConstants, value: f64);
+
+    /// Base cost per level
+    #[wasm_bindgen(method, getter)]
+    pub fn LevelBaseCost(this: &HacknetNodeConstants) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_LevelBaseCost(this: &HacknetNodeConstants, value: f64);
+
+    /// Base cost to increase RAM
+    #[wasm_bindgen(method, getter)]
+    pub fn RamBaseCost(this: &HacknetNodeConstants) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_RamBaseCost(this: &HacknetNodeConstants, value: f64);
+
+    /// Base cost to increase cores
+    #[wasm_bindgen(method, getter)]
+    pub fn CoreBaseCost(this: &HacknetNodeConstants) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_CoreBaseCost(this: &HacknetNodeConstants, value: f64);
+
+    /// Multiplier to purchase new node
+    #[wasm_bindgen(method, getter)]
+    pub fn PurchaseNextMult(this: &HacknetNodeConstants) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_PurchaseNextMult(this: &HacknetNodeConstants, value: f64);
+
+    /// Multiplier to increase node level
+    #[wasm_bindgen(method, getter)]
+    pub fn UpgradeLevelMult(this: &HacknetNodeConstants) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_UpgradeLevelMult(this: &HacknetNodeConstants, value: f64);
+
+    /// Multiplier to increase RAM
+    #[wasm_bindgen(method, getter)]
+    pub fn UpgradeRamMult(this: &HacknetNodeConstants) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_UpgradeRamMult(this: &HacknetNodeConstants, value: f64);
+
+    /// Multiplier to increase cores
+    #[wasm_bindgen(method, getter)]
+    pub fn UpgradeCoreMult(this: &HacknetNodeConstants) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_UpgradeCoreMult(this: &HacknetNodeConstants, value: f64);
+
+    /// Max node level
+    #[wasm_bindgen(method, getter)]
+    pub fn MaxLevel(this: &HacknetNodeConstants) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_MaxLevel(this: &HacknetNodeConstants, value: f64);
+
+    /// Max amount of RAM in GB
+    #[wasm_bindgen(method, getter)]
+    pub fn MaxRam(this: &HacknetNodeConstants) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_MaxRam(this: &HacknetNodeConstants, value: f64);
+
+    /// Max number of cores
+    #[wasm_bindgen(method, getter)]
+    pub fn MaxCores(this: &HacknetNodeConstants) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_MaxCores(this: &HacknetNodeConstants, value: f64);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// Hacknet server related constants
+    #[derive(Debug, Clone, PartialEq)]
+    pub type HacknetServerConstants;
+
+    /// Number of hashes calculated per level
+    #[wasm_bindgen(method, getter)]
+    pub fn HashesPerLevel(this: &HacknetServerConstants) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_HashesPerLevel(this: &HacknetServerConstants, value: f64);
+
+    /// Base cost for a new server
+    #[wasm_bindgen(method, getter)]
+    pub fn BaseCost(this: &HacknetServerConstants) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_BaseCost(this: &HacknetServerConstants, value: f64);
+
+    /// Base cost to increase RAM
+    #[wasm_bindgen(method, getter)]
+    pub fn RamBaseCost(this: &HacknetServerConstants) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_RamBaseCost(this: &HacknetServerConstants, value: f64);
+
+    /// Base cost to increase cores
+    #[wasm_bindgen(method, getter)]
+    pub fn CoreBaseCost(this: &HacknetServerConstants) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_CoreBaseCost(this: &HacknetServerConstants, value: f64);
+
+    /// Base cost to upgrade cache
+    #[wasm_bindgen(method, getter)]
+    pub fn CacheBaseCost(this: &HacknetServerConstants) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_CacheBaseCost(this: &HacknetServerConstants, value: f64);
+
+    /// Multiplier to purchase a new server
+    #[wasm_bindgen(method, getter)]
+    pub fn PurchaseMult(this: &HacknetServerConstants) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_PurchaseMult(this: &HacknetServerConstants, value: f64);
+
+    /// Multiplier to increase server level
+    #[wasm_bindgen(method, getter)]
+    pub fn UpgradeLevelMult(this: &HacknetServerConstants) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_UpgradeLevelMult(this: &HacknetServerConstants, value: f64);
+
+    /// Multiplier to increase RAM
+    #[wasm_bindgen(method, getter)]
+    pub fn UpgradeRamMult(this: &HacknetServerConstants) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_UpgradeRamMult(this: &HacknetServerConstants, value: f64);
+
+    /// Multiplier to increase cores
+    #[wasm_bindgen(method, getter)]
+    pub fn UpgradeCoreMult(this: &HacknetServerConstants) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_UpgradeCoreMult(this: &HacknetServerConstants, value: f64);
+
+    /// Multiplier to upgrade cache
+    #[wasm_bindgen(method, getter)]
+    pub fn UpgradeCacheMult(this: &HacknetServerConstants) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_UpgradeCacheMult(this: &HacknetServerConstants, value: f64);
+
+    /// Max number of servers
+    #[wasm_bindgen(method, getter)]
+    pub fn MaxServers(this: &HacknetServerConstants) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_MaxServers(this: &HacknetServerConstants, value: f64);
+
+    /// Max level for a server
+    #[wasm_bindgen(method, getter)]
+    pub fn MaxLevel(this: &HacknetServerConstants) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_MaxLevel(this: &HacknetServerConstants, value: f64);
+
+    /// Max amount of RAM in GB
+    #[wasm_bindgen(method, getter)]
+    pub fn MaxRam(this: &HacknetServerConstants) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_MaxRam(this: &HacknetServerConstants, value: f64);
+
+    /// Max number of cores
+    #[wasm_bindgen(method, getter)]
+    pub fn MaxCores(this: &HacknetServerConstants) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_MaxCores(this: &HacknetServerConstants, value: f64);
+
+    /// Max cache size
+    #[wasm_bindgen(method, getter)]
+    pub fn MaxCache(this: &HacknetServerConstants) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_MaxCache(this: &HacknetServerConstants, value: f64);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// A server. Not all servers have all of these properties - optional properties are missing on certain servers.
+    #[derive(Debug, Clone, PartialEq)]
+    pub type Server;
+
+    /// Hostname. Must be unique
+    #[wasm_bindgen(method, getter)]
+    pub fn hostname(this: &Server) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_hostname(this: &Server, value: &str);
+
+    /// IP Address. Must be unique
+    #[wasm_bindgen(method, getter)]
+    pub fn ip(this: &Server) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_ip(this: &Server, value: &str);
+
+    /// Whether or not the SSH Port is open
+    #[wasm_bindgen(method, getter)]
+    pub fn sshPortOpen(this: &Server) -> bool;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_sshPortOpen(this: &Server, value: bool);
+
+    /// Whether or not the FTP port is open
+    #[wasm_bindgen(method, getter)]
+    pub fn ftpPortOpen(this: &Server) -> bool;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_ftpPortOpen(this: &Server, value: bool);
+
+    /// Whether or not the SMTP Port is open
+    #[wasm_bindgen(method, getter)]
+    pub fn smtpPortOpen(this: &Server) -> bool;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_smtpPortOpen(this: &Server, value: bool);
+
+    /// Whether or not the HTTP Port is open
+    #[wasm_bindgen(method, getter)]
+    pub fn httpPortOpen(this: &Server) -> bool;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_httpPortOpen(this: &Server, value: bool);
+
+    /// Whether or not the SQL Port is open
+    #[wasm_bindgen(method, getter)]
+    pub fn sqlPortOpen(this: &Server) -> bool;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_sqlPortOpen(this: &Server, value: bool);
+
+    /// Flag indicating whether player has admin/root access to this server
+    #[wasm_bindgen(method, getter)]
+    pub fn hasAdminRights(this: &Server) -> bool;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_hasAdminRights(this: &Server, value: bool);
+
+    /// How many CPU cores this server has. Affects magnitude of grow and weaken ran from this server.
+    #[wasm_bindgen(method, getter)]
+    pub fn cpuCores(this: &Server) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_cpuCores(this: &Server, value: f64);
+
+    /// Flag indicating whether player is currently connected to this server
+    #[wasm_bindgen(method, getter)]
+    pub fn isConnectedTo(this: &Server) -> bool;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_isConnectedTo(this: &Server, value: bool);
+
+    /// RAM (GB) used. i.e. unavailable RAM
+    #[wasm_bindgen(method, getter)]
+    pub fn ramUsed(this: &Server) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_ramUsed(this: &Server, value: f64);
+
+    /// RAM (GB) available on this server
+    #[wasm_bindgen(method, getter)]
+    pub fn maxRam(this: &Server) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_maxRam(this: &Server, value: f64);
+
+    /// Name of company/faction/etc. that this server belongs to, not applicable to all Servers
+    #[wasm_bindgen(method, getter)]
+    pub fn organizationName(this: &Server) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_organizationName(this: &Server, value: &str);
+
+    /// Flag indicating whether this is a purchased server
+    #[wasm_bindgen(method, getter)]
+    pub fn purchasedByPlayer(this: &Server) -> bool;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_purchasedByPlayer(this: &Server, value: bool);
+
+    /// Flag indicating whether this server has a backdoor installed by a player
+    #[wasm_bindgen(method, getter)]
+    pub fn backdoorInstalled(this: &Server) -> Option<bool>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_backdoorInstalled(this: &Server, value: Option<bool>);
+
+    /// Server's initial server security level at creation.
+    #[wasm_bindgen(method, getter)]
+    pub fn baseDifficulty(this: &Server) -> Option<f64>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_baseDifficulty(this: &Server, value: Option<f64>);
+
+    /// Server Security Level
+    #[wasm_bindgen(method, getter)]
+    pub fn hackDifficulty(this: &Server) -> Option<f64>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_hackDifficulty(this: &Server, value: Option<f64>);
+
+    /// Minimum server security level that this server can be weakened to
+    #[wasm_bindgen(method, getter)]
+    pub fn minDifficulty(this: &Server) -> Option<f64>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_minDifficulty(this: &Server, value: Option<f64>);
+
+    /// How much money currently resides on the server and can be hacked
+    #[wasm_bindgen(method, getter)]
+    pub fn moneyAvailable(this: &Server) -> Option<f64>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_moneyAvailable(this: &Server, value: Option<f64>);
+
+    /// Maximum amount of money that this server can hold
+    #[wasm_bindgen(method, getter)]
+    pub fn moneyMax(this: &Server) -> Option<f64>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_moneyMax(this: &Server, value: Option<f64>);
+
+    /// Number of open ports required in order to gain admin/root access
+    #[wasm_bindgen(method, getter)]
+    pub fn numOpenPortsRequired(this: &Server) -> Option<f64>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_numOpenPortsRequired(this: &Server, value: Option<f64>);
+
+    /// How many ports are currently opened on the server
+    #[wasm_bindgen(method, getter)]
+    pub fn openPortCount(this: &Server) -> Option<f64>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_openPortCount(this: &Server, value: Option<f64>);
+
+    /// Hacking level required to hack this server
+    #[wasm_bindgen(method, getter)]
+    pub fn requiredHackingSkill(this: &Server) -> Option<f64>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_requiredHackingSkill(this: &Server, value: Option<f64>);
+
+    /// Growth effectiveness statistic. Higher values produce more growth with ns.grow()
+    #[wasm_bindgen(method, getter)]
+    pub fn serverGrowth(this: &Server) -> Option<f64>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_serverGrowth(this: &Server, value: Option<f64>);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// All multipliers affecting the difficulty of the current challenge.
+    #[derive(Debug, Clone, PartialEq)]
+    pub type BitNodeMultipliers;
+
+    /// Influences how quickly the player's agility level (not exp) scales
+    #[wasm_bindgen(method, getter)]
+    pub fn AgilityLevelMultiplier(this: &BitNodeMultipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_AgilityLevelMultiplier(this: &BitNodeMultipliers, value: f64);
+
+    /// Influences the base cost to purchase an augmentation.
+    #[wasm_bindgen(method, getter)]
+    pub fn AugmentationMoneyCost(this: &BitNodeMultipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_AugmentationMoneyCost(this: &BitNodeMultipliers, value: f64);
+
+    /// Influences the base rep the player must have with a faction to purchase an augmentation.
+    #[wasm_bindgen(method, getter)]
+    pub fn AugmentationRepCost(this: &BitNodeMultipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_AugmentationRepCost(this: &BitNodeMultipliers, value: f64);
+
+    /// Influences how quickly the player can gain rank within Bladeburner.
+    #[wasm_bindgen(method, getter)]
+    pub fn BladeburnerRank(this: &BitNodeMultipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_BladeburnerRank(this: &BitNodeMultipliers, value: f64);
+
+    /// Influences the cost of skill levels from Bladeburner.
+    #[wasm_bindgen(method, getter)]
+    pub fn BladeburnerSkillCost(this: &BitNodeMultipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_BladeburnerSkillCost(this: &BitNodeMultipliers, value: f64);
+
+    /// Influences how quickly the player's charisma level (not exp) scales
+    #[wasm_bindgen(method, getter)]
+    pub fn CharismaLevelMultiplier(this: &BitNodeMultipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_CharismaLevelMultiplier(this: &BitNodeMultipliers, value: f64);
+
+    /// Influences the experience gained for each ability when a player completes a class.
+    #[wasm_bindgen(method, getter)]
+    pub fn ClassGymExpGain(this: &BitNodeMultipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_ClassGymExpGain(this: &BitNodeMultipliers, value: f64);
+
+    /// Influences the amount of money gained from completing Coding Contracts
+    #[wasm_bindgen(method, getter)]
+    pub fn CodingContractMoney(this: &BitNodeMultipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_CodingContractMoney(this: &BitNodeMultipliers, value: f64);
+
+    /// Influences the experience gained for each ability when the player completes working their job.
+    #[wasm_bindgen(method, getter)]
+    pub fn CompanyWorkExpGain(this: &BitNodeMultipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_CompanyWorkExpGain(this: &BitNodeMultipliers, value: f64);
+
+    /// Influences how much money the player earns when completing working their job.
+    #[wasm_bindgen(method, getter)]
+    pub fn CompanyWorkMoney(this: &BitNodeMultipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_CompanyWorkMoney(this: &BitNodeMultipliers, value: f64);
+
+    /// Influences the amount of divisions a corporation can have at the same time
+    #[wasm_bindgen(method, getter)]
+    pub fn CorporationDivisions(this: &BitNodeMultipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_CorporationDivisions(this: &BitNodeMultipliers, value: f64);
+
+    /// Influences the money gain from dividends of corporations created by the player.
+    #[wasm_bindgen(method, getter)]
+    pub fn CorporationSoftcap(this: &BitNodeMultipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_CorporationSoftcap(this: &BitNodeMultipliers, value: f64);
+
+    /// Influences the valuation of corporations created by the player.
+    #[wasm_bindgen(method, getter)]
+    pub fn CorporationValuation(this: &BitNodeMultipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_CorporationValuation(this: &BitNodeMultipliers, value: f64);
+
+    /// Influences the base experience gained for each ability when the player commits a crime.
+    #[wasm_bindgen(method, getter)]
+    pub fn CrimeExpGain(this: &BitNodeMultipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_CrimeExpGain(this: &BitNodeMultipliers, value: f64);
+
+    /// Influences the base money gained when the player commits a crime.
+    #[wasm_bindgen(method, getter)]
+    pub fn CrimeMoney(this: &BitNodeMultipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_CrimeMoney(this: &BitNodeMultipliers, value: f64);
+
+    /// Influences how many Augmentations you need in order to get invited to the Daedalus faction
+    #[wasm_bindgen(method, getter)]
+    pub fn DaedalusAugsRequirement(this: &BitNodeMultipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_DaedalusAugsRequirement(this: &BitNodeMultipliers, value: f64);
+
+    /// Influences how quickly the player's defense level (not exp) scales
+    #[wasm_bindgen(method, getter)]
+    pub fn DefenseLevelMultiplier(this: &BitNodeMultipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_DefenseLevelMultiplier(this: &BitNodeMultipliers, value: f64);
+
+    /// Influences how quickly the player's dexterity level (not exp) scales
+    #[wasm_bindgen(method, getter)]
+    pub fn DexterityLevelMultiplier(this: &BitNodeMultipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_DexterityLevelMultiplier(this: &BitNodeMultipliers, value: f64);
+
+    /// Influences how much rep the player gains in each faction simply by being a member.
+    #[wasm_bindgen(method, getter)]
+    pub fn FactionPassiveRepGain(this: &BitNodeMultipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_FactionPassiveRepGain(this: &BitNodeMultipliers, value: f64);
+
+    /// Influences the experience gained for each ability when the player completes work for a Faction.
+    #[wasm_bindgen(method, getter)]
+    pub fn FactionWorkExpGain(this: &BitNodeMultipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_FactionWorkExpGain(this: &BitNodeMultipliers, value: f64);
+
+    /// Influences how much rep the player gains when performing work for a faction.
+    #[wasm_bindgen(method, getter)]
+    pub fn FactionWorkRepGain(this: &BitNodeMultipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_FactionWorkRepGain(this: &BitNodeMultipliers, value: f64);
+
+    /// Influences how much it costs to unlock the stock market's 4S Market Data API
+    #[wasm_bindgen(method, getter)]
+    pub fn FourSigmaMarketDataApiCost(this: &BitNodeMultipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_FourSigmaMarketDataApiCost(this: &BitNodeMultipliers, value: f64);
+
+    /// Influences how much it costs to unlock the stock market's 4S Market Data (NOT API)
+    #[wasm_bindgen(method, getter)]
+    pub fn FourSigmaMarketDataCost(this: &BitNodeMultipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_FourSigmaMarketDataCost(this: &BitNodeMultipliers, value: f64);
+
+    /// Influences the respect gain and money gain of your gang.
+    #[wasm_bindgen(method, getter)]
+    pub fn GangSoftcap(this: &BitNodeMultipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_GangSoftcap(this: &BitNodeMultipliers, value: f64);
+
+    /// Influences the experienced gained when hacking a server.
+    #[wasm_bindgen(method, getter)]
+    pub fn HackExpGain(this: &BitNodeMultipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_HackExpGain(this: &BitNodeMultipliers, value: f64);
+
+    /// Influences how quickly the player's hacking level (not experience) scales
+    #[wasm_bindgen(method, getter)]
+    pub fn HackingLevelMultiplier(this: &BitNodeMultipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_HackingLevelMultiplier(this: &BitNodeMultipliers, value: f64);
+
+    /// Influences how much money is produced by Hacknet Nodes
+    /// and the hash rate of Hacknet Servers (unlocked in BitNode-9)
+    #[wasm_bindgen(method, getter)]
+    pub fn HacknetNodeMoney(this: &BitNodeMultipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_HacknetNodeMoney(this: &BitNodeMultipliers, value: f64);
+
+    /// Influences how much money it costs to upgrade your home computer's RAM
+    #[wasm_bindgen(method, getter)]
+    pub fn HomeComputerRamCost(this: &BitNodeMultipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_HomeComputerRamCost(this: &BitNodeMultipliers, value: f64);
+
+    /// Influences how much money is gained when the player infiltrates a company.
+    #[wasm_bindgen(method, getter)]
+    pub fn InfiltrationMoney(this: &BitNodeMultipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_InfiltrationMoney(this: &BitNodeMultipliers, value: f64);
+
+    /// Influences how much rep the player can gain from factions when selling stolen documents and secrets
+    #[wasm_bindgen(method, getter)]
+    pub fn InfiltrationRep(this: &BitNodeMultipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_InfiltrationRep(this: &BitNodeMultipliers, value: f64);
+
+    /// Influences how much money can be stolen from a server when the player
+    /// performs a hack against it through the Terminal.
+    #[wasm_bindgen(method, getter)]
+    pub fn ManualHackMoney(this: &BitNodeMultipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_ManualHackMoney(this: &BitNodeMultipliers, value: f64);
+
+    /// Influence how much it costs to purchase a server
+    #[wasm_bindgen(method, getter)]
+    pub fn PurchasedServerCost(this: &BitNodeMultipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_PurchasedServerCost(this: &BitNodeMultipliers, value: f64);
+
+    /// Influences the maximum number of purchased servers you can have
+    #[wasm_bindgen(method, getter)]
+    pub fn PurchasedServerLimit(this: &BitNodeMultipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_PurchasedServerLimit(this: &BitNodeMultipliers, value: f64);
+
+    /// Influences the maximum allowed RAM for a purchased server
+    #[wasm_bindgen(method, getter)]
+    pub fn PurchasedServerMaxRam(this: &BitNodeMultipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_PurchasedServerMaxRam(this: &BitNodeMultipliers, value: f64);
+
+    /// Influences cost of any purchased server at or above 128GB
+    #[wasm_bindgen(method, getter)]
+    pub fn PurchasedServerSoftcap(this: &BitNodeMultipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_PurchasedServerSoftcap(this: &BitNodeMultipliers, value: f64);
+
+    /// Influences the minimum favor the player must have with a faction before they can donate to gain rep.
+    #[wasm_bindgen(method, getter)]
+    pub fn RepToDonateToFaction(this: &BitNodeMultipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_RepToDonateToFaction(this: &BitNodeMultipliers, value: f64);
+
+    /// Influences how much the money on a server can be reduced when a script performs a hack against it.
+    #[wasm_bindgen(method, getter)]
+    pub fn ScriptHackMoney(this: &BitNodeMultipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_ScriptHackMoney(this: &BitNodeMultipliers, value: f64);
+
+    /// Influences how much of the money stolen by a scripted hack will be added to the player's money.
+    #[wasm_bindgen(method, getter)]
+    pub fn ScriptHackMoneyGain(this: &BitNodeMultipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_ScriptHackMoneyGain(this: &BitNodeMultipliers, value: f64);
+
+    /// Influences the growth percentage per cycle against a server.
+    #[wasm_bindgen(method, getter)]
+    pub fn ServerGrowthRate(this: &BitNodeMultipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_ServerGrowthRate(this: &BitNodeMultipliers, value: f64);
+
+    /// Influences the maximum money that a server can grow to.
+    #[wasm_bindgen(method, getter)]
+    pub fn ServerMaxMoney(this: &BitNodeMultipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_ServerMaxMoney(this: &BitNodeMultipliers, value: f64);
+
+    /// Influences the initial money that a server starts with.
+    #[wasm_bindgen(method, getter)]
+    pub fn ServerStartingMoney(this: &BitNodeMultipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_ServerStartingMoney(this: &BitNodeMultipliers, value: f64);
+
+    /// Influences the initial security level (hackDifficulty) of a server.
+    #[wasm_bindgen(method, getter)]
+    pub fn ServerStartingSecurity(this: &BitNodeMultipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_ServerStartingSecurity(this: &BitNodeMultipliers, value: f64);
+
+    /// Influences the weaken amount per invocation against a server.
+    #[wasm_bindgen(method, getter)]
+    pub fn ServerWeakenRate(this: &BitNodeMultipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_ServerWeakenRate(this: &BitNodeMultipliers, value: f64);
+
+    /// Influences how quickly the player's strength level (not exp) scales
+    #[wasm_bindgen(method, getter)]
+    pub fn StrengthLevelMultiplier(this: &BitNodeMultipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_StrengthLevelMultiplier(this: &BitNodeMultipliers, value: f64);
+
+    /// Influences the power of the gift
+    #[wasm_bindgen(method, getter)]
+    pub fn StaneksGiftPowerMultiplier(this: &BitNodeMultipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_StaneksGiftPowerMultiplier(this: &BitNodeMultipliers, value: f64);
+
+    /// Influences the size of the gift
+    #[wasm_bindgen(method, getter)]
+    pub fn StaneksGiftExtraSize(this: &BitNodeMultipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_StaneksGiftExtraSize(this: &BitNodeMultipliers, value: f64);
+
+    /// Influences the hacking skill required to backdoor the world daemon.
+    #[wasm_bindgen(method, getter)]
+    pub fn WorldDaemonDifficulty(this: &BitNodeMultipliers) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_WorldDaemonDifficulty(this: &BitNodeMultipliers, value: f64);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// Object representing all the values related to a hacknet node.
+    #[derive(Debug, Clone, PartialEq)]
+    pub type NodeStats;
+
+    /// Node's name
+    #[wasm_bindgen(method, getter)]
+    pub fn name(this: &NodeStats) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_name(this: &NodeStats, value: &str);
+
+    /// Node's level
+    #[wasm_bindgen(method, getter)]
+    pub fn level(this: &NodeStats) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_level(this: &NodeStats, value: f64);
+
+    /// Node's RAM (GB)
+    #[wasm_bindgen(method, getter)]
+    pub fn ram(this: &NodeStats) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_ram(this: &NodeStats, value: f64);
+
+    /// Node's used RAM (GB)
+    #[wasm_bindgen(method, getter)]
+    pub fn ramUsed(this: &NodeStats) -> Option<f64>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_ramUsed(this: &NodeStats, value: Option<f64>);
+
+    /// Node's number of cores
+    #[wasm_bindgen(method, getter)]
+    pub fn cores(this: &NodeStats) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_cores(this: &NodeStats, value: f64);
+
+    /// Cache level. Only applicable for Hacknet Servers
+    #[wasm_bindgen(method, getter)]
+    pub fn cache(this: &NodeStats) -> Option<f64>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_cache(this: &NodeStats, value: Option<f64>);
+
+    /// Hash Capacity provided by this Node. Only applicable for Hacknet Servers
+    #[wasm_bindgen(method, getter)]
+    pub fn hashCapacity(this: &NodeStats) -> Option<f64>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_hashCapacity(this: &NodeStats, value: Option<f64>);
+
+    /// Node's production per second
+    #[wasm_bindgen(method, getter)]
+    pub fn production(this: &NodeStats) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_production(this: &NodeStats, value: f64);
+
+    /// Number of seconds since Node has been purchased
+    #[wasm_bindgen(method, getter)]
+    pub fn timeOnline(this: &NodeStats) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_timeOnline(this: &NodeStats, value: f64);
+
+    /// Total number of money Node has produced
+    #[wasm_bindgen(method, getter)]
+    pub fn totalProduction(this: &NodeStats) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_totalProduction(this: &NodeStats, value: f64);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[derive(Debug, Clone, PartialEq)]
+    pub type SourceFileLvl;
+
+    /// The number of the source file
+    #[wasm_bindgen(method, getter)]
+    pub fn n(this: &SourceFileLvl) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_n(this: &SourceFileLvl, value: f64);
+
+    /// The level of the source file
+    #[wasm_bindgen(method, getter)]
+    pub fn lvl(this: &SourceFileLvl) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_lvl(this: &SourceFileLvl, value: f64);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// Bladeburner current action.
+    #[derive(Debug, Clone, PartialEq)]
+    pub type BladeburnerCurAction;
+
+    /// Type of Action
+    #[wasm_bindgen(method, getter = "type")]
+    pub fn r#type(this: &BladeburnerCurAction) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_type(this: &BladeburnerCurAction, value: &str);
+
+    /// Name of Action
+    #[wasm_bindgen(method, getter)]
+    pub fn name(this: &BladeburnerCurAction) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_name(this: &BladeburnerCurAction, value: &str);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// Gang general info.
+    #[derive(Debug, Clone, PartialEq)]
+    pub type GangGenInfo;
+
+    /// Name of faction that the gang belongs to ("Slum Snakes", etc.)
+    #[wasm_bindgen(method, getter)]
+    pub fn faction(this: &GangGenInfo) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_faction(this: &GangGenInfo, value: &str);
+
+    /// Indicating whether or not it's a hacking gang
+    #[wasm_bindgen(method, getter)]
+    pub fn isHacking(this: &GangGenInfo) -> bool;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_isHacking(this: &GangGenInfo, value: bool);
+
+    /// Money earned per game cycle
+    #[wasm_bindgen(method, getter)]
+    pub fn moneyGainRate(this: &GangGenInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_moneyGainRate(this: &GangGenInfo, value: f64);
+
+    /// Gang's power for territory warfare
+    #[wasm_bindgen(method, getter)]
+    pub fn power(this: &GangGenInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_power(this: &GangGenInfo, value: f64);
+
+    /// Gang's respect
+    #[wasm_bindgen(method, getter)]
+    pub fn respect(this: &GangGenInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_respect(this: &GangGenInfo, value: f64);
+
+    /// Respect earned per game cycle
+    #[wasm_bindgen(method, getter)]
+    pub fn respectGainRate(this: &GangGenInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_respectGainRate(this: &GangGenInfo, value: f64);
+
+    /// Amount of Respect needed for next gang recruit, if possible
+    #[wasm_bindgen(method, getter)]
+    pub fn respectForNextRecruit(this: &GangGenInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_respectForNextRecruit(this: &GangGenInfo, value: f64);
+
+    /// Amount of territory held
+    #[wasm_bindgen(method, getter)]
+    pub fn territory(this: &GangGenInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_territory(this: &GangGenInfo, value: f64);
+
+    /// Clash chance
+    #[wasm_bindgen(method, getter)]
+    pub fn territoryClashChance(this: &GangGenInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_territoryClashChance(this: &GangGenInfo, value: f64);
+
+    /// Gang's wanted level
+    #[wasm_bindgen(method, getter)]
+    pub fn wantedLevel(this: &GangGenInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_wantedLevel(this: &GangGenInfo, value: f64);
+
+    /// Wanted level gained/lost per game cycle (negative for losses)
+    #[wasm_bindgen(method, getter)]
+    pub fn wantedLevelGainRate(this: &GangGenInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_wantedLevelGainRate(this: &GangGenInfo, value: f64);
+
+    /// Indicating if territory clashes are enabled
+    #[wasm_bindgen(method, getter)]
+    pub fn territoryWarfareEngaged(this: &GangGenInfo) -> bool;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_territoryWarfareEngaged(this: &GangGenInfo, value: bool);
+
+    /// Number indicating the current wanted penalty
+    #[wasm_bindgen(method, getter)]
+    pub fn wantedPenalty(this: &GangGenInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_wantedPenalty(this: &GangGenInfo, value: f64);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[derive(Debug, Clone, PartialEq)]
+    pub type GangOtherInfoObject;
+
+    /// Gang power
+    #[wasm_bindgen(method, getter)]
+    pub fn power(this: &GangOtherInfoObject) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_power(this: &GangOtherInfoObject, value: f64);
+
+    /// Gang territory, in decimal form
+    #[wasm_bindgen(method, getter)]
+    pub fn territory(this: &GangOtherInfoObject) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_territory(this: &GangOtherInfoObject, value: f64);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[derive(Debug, Clone, PartialEq)]
+    pub type GangOtherInfo;
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// Object representing data representing a gang member task.
+    #[derive(Debug, Clone, PartialEq)]
+    pub type GangTaskStats;
+
+    /// Task name
+    #[wasm_bindgen(method, getter)]
+    pub fn name(this: &GangTaskStats) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_name(this: &GangTaskStats, value: &str);
+
+    /// Task Description
+    #[wasm_bindgen(method, getter)]
+    pub fn desc(this: &GangTaskStats) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_desc(this: &GangTaskStats, value: &str);
+
+    /// Is a task of a hacking gang
+    #[wasm_bindgen(method, getter)]
+    pub fn isHacking(this: &GangTaskStats) -> bool;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_isHacking(this: &GangTaskStats, value: bool);
+
+    /// Is a task of a combat gang
+    #[wasm_bindgen(method, getter)]
+    pub fn isCombat(this: &GangTaskStats) -> bool;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_isCombat(this: &GangTaskStats, value: bool);
+
+    /// Base respect earned
+    #[wasm_bindgen(method, getter)]
+    pub fn baseRespect(this: &GangTaskStats) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_baseRespect(this: &GangTaskStats, value: f64);
+
+    /// Base wanted earned
+    #[wasm_bindgen(method, getter)]
+    pub fn baseWanted(this: &GangTaskStats) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_baseWanted(this: &GangTaskStats, value: f64);
+
+    /// Base money earned
+    #[wasm_bindgen(method, getter)]
+    pub fn baseMoney(this: &GangTaskStats) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_baseMoney(this: &GangTaskStats, value: f64);
+
+    /// Hacking skill impact on task scaling
+    #[wasm_bindgen(method, getter)]
+    pub fn hackWeight(this: &GangTaskStats) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_hackWeight(this: &GangTaskStats, value: f64);
+
+    /// Strength skill impact on task scaling
+    #[wasm_bindgen(method, getter)]
+    pub fn strWeight(this: &GangTaskStats) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_strWeight(this: &GangTaskStats, value: f64);
+
+    /// Defense skill impact on task scaling
+    #[wasm_bindgen(method, getter)]
+    pub fn defWeight(this: &GangTaskStats) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_defWeight(this: &GangTaskStats, value: f64);
+
+    /// Dexterity skill impact on task scaling
+    #[wasm_bindgen(method, getter)]
+    pub fn dexWeight(this: &GangTaskStats) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_dexWeight(this: &GangTaskStats, value: f64);
+
+    /// Agility skill impact on task scaling
+    #[wasm_bindgen(method, getter)]
+    pub fn agiWeight(this: &GangTaskStats) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_agiWeight(this: &GangTaskStats, value: f64);
+
+    /// Charisma skill impact on task scaling
+    #[wasm_bindgen(method, getter)]
+    pub fn chaWeight(this: &GangTaskStats) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_chaWeight(this: &GangTaskStats, value: f64);
+
+    /// Number representing the difficulty of the task
+    #[wasm_bindgen(method, getter)]
+    pub fn difficulty(this: &GangTaskStats) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_difficulty(this: &GangTaskStats, value: f64);
+
+    /// Territory impact on task scaling
+    #[wasm_bindgen(method, getter)]
+    pub fn territory(this: &GangTaskStats) -> GangTerritory;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_territory(this: &GangTaskStats, value: &GangTerritory);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// Object representing data representing a gang member equipment.
+    #[derive(Debug, Clone, PartialEq)]
+    pub type EquipmentStats;
+
+    /// Strength multiplier
+    #[wasm_bindgen(method, getter)]
+    pub fn str(this: &EquipmentStats) -> Option<f64>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_str(this: &EquipmentStats, value: Option<f64>);
+
+    /// Defense multiplier
+    #[wasm_bindgen(method, getter)]
+    pub fn def(this: &EquipmentStats) -> Option<f64>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_def(this: &EquipmentStats, value: Option<f64>);
+
+    /// Dexterity multiplier
+    #[wasm_bindgen(method, getter)]
+    pub fn dex(this: &EquipmentStats) -> Option<f64>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_dex(this: &EquipmentStats, value: Option<f64>);
+
+    /// Agility multiplier
+    #[wasm_bindgen(method, getter)]
+    pub fn agi(this: &EquipmentStats) -> Option<f64>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_agi(this: &EquipmentStats, value: Option<f64>);
+
+    /// Charisma multiplier
+    #[wasm_bindgen(method, getter)]
+    pub fn cha(this: &EquipmentStats) -> Option<f64>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_cha(this: &EquipmentStats, value: Option<f64>);
+
+    /// Hacking multiplier
+    #[wasm_bindgen(method, getter)]
+    pub fn hack(this: &EquipmentStats) -> Option<f64>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_hack(this: &EquipmentStats, value: Option<f64>);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[derive(Debug, Clone, PartialEq)]
+    pub type GangTerritory;
+
+    /// Money gain impact on task scaling
+    #[wasm_bindgen(method, getter)]
+    pub fn money(this: &GangTerritory) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_money(this: &GangTerritory, value: f64);
+
+    /// Respect gain impact on task scaling
+    #[wasm_bindgen(method, getter)]
+    pub fn respect(this: &GangTerritory) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_respect(this: &GangTerritory, value: f64);
+
+    /// Wanted gain impact on task scaling
+    #[wasm_bindgen(method, getter)]
+    pub fn wanted(this: &GangTerritory) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_wanted(this: &GangTerritory, value: f64);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[derive(Debug, Clone, PartialEq)]
+    pub type GangMemberInfo;
+
+    /// Name of the gang member
+    #[wasm_bindgen(method, getter)]
+    pub fn name(this: &GangMemberInfo) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_name(this: &GangMemberInfo, value: &str);
+
+    /// Currently assigned task
+    #[wasm_bindgen(method, getter)]
+    pub fn task(this: &GangMemberInfo) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_task(this: &GangMemberInfo, value: &str);
+
+    /// Amount of Respect earned by member since they last Ascended
+    #[wasm_bindgen(method, getter)]
+    pub fn earnedRespect(this: &GangMemberInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_earnedRespect(this: &GangMemberInfo, value: f64);
+
+    /// Hack skill level
+    #[wasm_bindgen(method, getter)]
+    pub fn hack(this: &GangMemberInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_hack(this: &GangMemberInfo, value: f64);
+
+    /// Strength skill level
+    #[wasm_bindgen(method, getter)]
+    pub fn str(this: &GangMemberInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_str(this: &GangMemberInfo, value: f64);
+
+    /// Defense skill level
+    #[wasm_bindgen(method, getter)]
+    pub fn def(this: &GangMemberInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_def(this: &GangMemberInfo, value: f64);
+
+    /// Dexterity skill level
+    #[wasm_bindgen(method, getter)]
+    pub fn dex(this: &GangMemberInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_dex(this: &GangMemberInfo, value: f64);
+
+    /// Agility skill level
+    #[wasm_bindgen(method, getter)]
+    pub fn agi(this: &GangMemberInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_agi(this: &GangMemberInfo, value: f64);
+
+    /// Charisma skill level
+    #[wasm_bindgen(method, getter)]
+    pub fn cha(this: &GangMemberInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_cha(this: &GangMemberInfo, value: f64);
+
+    /// Current hack experience
+    #[wasm_bindgen(method, getter)]
+    pub fn hack_exp(this: &GangMemberInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_hack_exp(this: &GangMemberInfo, value: f64);
+
+    /// Current strength experience
+    #[wasm_bindgen(method, getter)]
+    pub fn str_exp(this: &GangMemberInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_str_exp(this: &GangMemberInfo, value: f64);
+
+    /// Current defense experience
+    #[wasm_bindgen(method, getter)]
+    pub fn def_exp(this: &GangMemberInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_def_exp(this: &GangMemberInfo, value: f64);
+
+    /// Current dexterity experience
+    #[wasm_bindgen(method, getter)]
+    pub fn dex_exp(this: &GangMemberInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_dex_exp(this: &GangMemberInfo, value: f64);
+
+    /// Current agility experience
+    #[wasm_bindgen(method, getter)]
+    pub fn agi_exp(this: &GangMemberInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_agi_exp(this: &GangMemberInfo, value: f64);
+
+    /// Current charisma experience
+    #[wasm_bindgen(method, getter)]
+    pub fn cha_exp(this: &GangMemberInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_cha_exp(this: &GangMemberInfo, value: f64);
+
+    /// Hack multiplier from equipment
+    #[wasm_bindgen(method, getter)]
+    pub fn hack_mult(this: &GangMemberInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_hack_mult(this: &GangMemberInfo, value: f64);
+
+    /// Strength multiplier from equipment
+    #[wasm_bindgen(method, getter)]
+    pub fn str_mult(this: &GangMemberInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_str_mult(this: &GangMemberInfo, value: f64);
+
+    /// Defense multiplier from equipment
+    #[wasm_bindgen(method, getter)]
+    pub fn def_mult(this: &GangMemberInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_def_mult(this: &GangMemberInfo, value: f64);
+
+    /// Dexterity multiplier from equipment
+    #[wasm_bindgen(method, getter)]
+    pub fn dex_mult(this: &GangMemberInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_dex_mult(this: &GangMemberInfo, value: f64);
+
+    /// Agility multiplier from equipment
+    #[wasm_bindgen(method, getter)]
+    pub fn agi_mult(this: &GangMemberInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_agi_mult(this: &GangMemberInfo, value: f64);
+
+    /// Charisma multiplier from equipment
+    #[wasm_bindgen(method, getter)]
+    pub fn cha_mult(this: &GangMemberInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_cha_mult(this: &GangMemberInfo, value: f64);
+
+    /// Hack multiplier from ascensions
+    #[wasm_bindgen(method, getter)]
+    pub fn hack_asc_mult(this: &GangMemberInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_hack_asc_mult(this: &GangMemberInfo, value: f64);
+
+    /// Strength multiplier from ascensions
+    #[wasm_bindgen(method, getter)]
+    pub fn str_asc_mult(this: &GangMemberInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_str_asc_mult(this: &GangMemberInfo, value: f64);
+
+    /// Defense multiplier from ascensions
+    #[wasm_bindgen(method, getter)]
+    pub fn def_asc_mult(this: &GangMemberInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_def_asc_mult(this: &GangMemberInfo, value: f64);
+
+    /// Dexterity multiplier from ascensions
+    #[wasm_bindgen(method, getter)]
+    pub fn dex_asc_mult(this: &GangMemberInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_dex_asc_mult(this: &GangMemberInfo, value: f64);
+
+    /// Agility multiplier from ascensions
+    #[wasm_bindgen(method, getter)]
+    pub fn agi_asc_mult(this: &GangMemberInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_agi_asc_mult(this: &GangMemberInfo, value: f64);
+
+    /// Charisma multiplier from ascensions
+    #[wasm_bindgen(method, getter)]
+    pub fn cha_asc_mult(this: &GangMemberInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_cha_asc_mult(this: &GangMemberInfo, value: f64);
+
+    /// Total Hack Ascension points accumulated
+    #[wasm_bindgen(method, getter)]
+    pub fn hack_asc_points(this: &GangMemberInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_hack_asc_points(this: &GangMemberInfo, value: f64);
+
+    /// Total Strength Ascension points accumulated
+    #[wasm_bindgen(method, getter)]
+    pub fn str_asc_points(this: &GangMemberInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_str_asc_points(this: &GangMemberInfo, value: f64);
+
+    /// Total Defense Ascension points accumulated
+    #[wasm_bindgen(method, getter)]
+    pub fn def_asc_points(this: &GangMemberInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_def_asc_points(this: &GangMemberInfo, value: f64);
+
+    /// Total Dexterity Ascension points accumulated
+    #[wasm_bindgen(method, getter)]
+    pub fn dex_asc_points(this: &GangMemberInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_dex_asc_points(this: &GangMemberInfo, value: f64);
+
+    /// Total Agility Ascension points accumulated
+    #[wasm_bindgen(method, getter)]
+    pub fn agi_asc_points(this: &GangMemberInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_agi_asc_points(this: &GangMemberInfo, value: f64);
+
+    /// Total Charisma Ascension points accumulated
+    #[wasm_bindgen(method, getter)]
+    pub fn cha_asc_points(this: &GangMemberInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_cha_asc_points(this: &GangMemberInfo, value: f64);
+
+    /// List of all non-Augmentation Equipment owned by gang member
+    #[wasm_bindgen(method, getter)]
+    pub fn upgrades(this: &GangMemberInfo) -> Vec<String>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_upgrades(this: &GangMemberInfo, value: Vec<String>);
+
+    /// List of all Augmentations currently installed on gang member
+    #[wasm_bindgen(method, getter)]
+    pub fn augmentations(this: &GangMemberInfo) -> Vec<String>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_augmentations(this: &GangMemberInfo, value: Vec<String>);
+
+    /// Per Cycle Rate this member is currently gaining Respect
+    #[wasm_bindgen(method, getter)]
+    pub fn respectGain(this: &GangMemberInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_respectGain(this: &GangMemberInfo, value: f64);
+
+    /// Per Cycle Rate by which this member is affecting your gang's Wanted Level
+    #[wasm_bindgen(method, getter)]
+    pub fn wantedLevelGain(this: &GangMemberInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_wantedLevelGain(this: &GangMemberInfo, value: f64);
+
+    /// Per Cycle Income for this gang member
+    #[wasm_bindgen(method, getter)]
+    pub fn moneyGain(this: &GangMemberInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_moneyGain(this: &GangMemberInfo, value: f64);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[derive(Debug, Clone, PartialEq)]
+    pub type GangMemberAscension;
+
+    /// Amount of respect lost from ascending
+    #[wasm_bindgen(method, getter)]
+    pub fn respect(this: &GangMemberAscension) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_respect(this: &GangMemberAscension, value: f64);
+
+    /// Factor by which the hacking ascension multiplier was increased (newMult / oldMult)
+    #[wasm_bindgen(method, getter)]
+    pub fn hack(this: &GangMemberAscension) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_hack(this: &GangMemberAscension, value: f64);
+
+    /// Factor by which the strength ascension multiplier was increased (newMult / oldMult)
+    #[wasm_bindgen(method, getter)]
+    pub fn str(this: &GangMemberAscension) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_str(this: &GangMemberAscension, value: f64);
+
+    /// Factor by which the defense ascension multiplier was increased (newMult / oldMult)
+    #[wasm_bindgen(method, getter)]
+    pub fn def(this: &GangMemberAscension) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_def(this: &GangMemberAscension, value: f64);
+
+    /// Factor by which the dexterity ascension multiplier was increased (newMult / oldMult)
+    #[wasm_bindgen(method, getter)]
+    pub fn dex(this: &GangMemberAscension) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_dex(this: &GangMemberAscension, value: f64);
+
+    /// Factor by which the agility ascension multiplier was increased (newMult / oldMult)
+    #[wasm_bindgen(method, getter)]
+    pub fn agi(this: &GangMemberAscension) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_agi(this: &GangMemberAscension, value: f64);
+
+    /// Factor by which the charisma ascension multiplier was increased (newMult / oldMult)
+    #[wasm_bindgen(method, getter)]
+    pub fn cha(this: &GangMemberAscension) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_cha(this: &GangMemberAscension, value: f64);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// Object representing a port. A port is a serialized queue.
+    #[derive(Debug, Clone, PartialEq)]
+    pub type NetscriptPort;
+
+    /// Write data to a port.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// @returns The data popped off the queue if it was full.
+    #[wasm_bindgen(method, catch)]
+    pub fn write(this: &NetscriptPort, value: &JsValue) -> Result<JsValue, JsValue>;
+
+    /// Attempt to write data to the port.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// @returns True if the data was added to the port, false if the port was full
+    #[wasm_bindgen(method, catch)]
+    pub fn tryWrite(this: &NetscriptPort, value: &JsValue) -> Result<bool, JsValue>;
+
+    /// Sleeps until the port is written to.
+    /// @remarks
+    /// RAM cost: 0 GB
+    #[wasm_bindgen(method, catch)]
+    pub async fn nextWrite(this: &NetscriptPort) -> Result<(), JsValue>;
+
+    /// Shift an element out of the port.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// This function will remove the first element from the port and return it.
+    /// If the port is empty, then the string “NULL PORT DATA” will be returned.
+    /// @returns the data read.
+    #[wasm_bindgen(method, catch)]
+    pub fn read(this: &NetscriptPort) -> Result<JsValue, JsValue>;
+
+    /// Retrieve the first element from the port without removing it.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// This function is used to peek at the data from a port. It returns the
+    /// first element in the specified port without removing that element. If
+    /// the port is empty, the string “NULL PORT DATA” will be returned.
+    /// @returns the data read
+    #[wasm_bindgen(method, catch)]
+    pub fn peek(this: &NetscriptPort) -> Result<JsValue, JsValue>;
+
+    /// Check if the port is full.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// @returns true if the port is full, otherwise false
+    #[wasm_bindgen(method, catch)]
+    pub fn full(this: &NetscriptPort) -> Result<bool, JsValue>;
+
+    /// Check if the port is empty.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// @returns true if the port is empty, otherwise false
+    #[wasm_bindgen(method, catch)]
+    pub fn empty(this: &NetscriptPort) -> Result<bool, JsValue>;
+
+    /// Empties all data from the port.
+    /// @remarks
+    /// RAM cost: 0 GB
+    #[wasm_bindgen(method, catch)]
+    pub fn clear(this: &NetscriptPort) -> Result<(), JsValue>;
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// Stock market API
+    #[derive(Debug, Clone, PartialEq)]
+    pub type TIX;
+
+    /// Get game constants for the stock market mechanic.
+    /// @remarks RAM cost: 0 GB
+    #[wasm_bindgen(method, catch)]
+    pub fn getConstants(this: &TIX) -> Result<StockMarketConstants, JsValue>;
+
+    /// Returns true if the player has access to a WSE Account
+    /// @remarks RAM cost: 0.05 GB
+    #[wasm_bindgen(method, catch)]
+    pub fn hasWSEAccount(this: &TIX) -> Result<bool, JsValue>;
+
+    /// Returns true if the player has access to the TIX API
+    /// @remarks RAM cost: 0.05 GB
+    #[wasm_bindgen(method, catch)]
+    pub fn hasTIXAPIAccess(this: &TIX) -> Result<bool, JsValue>;
+
+    /// Returns true if the player has access to the 4S Data
+    /// @remarks RAM cost: 0.05 GB
+    #[wasm_bindgen(method, catch)]
+    pub fn has4SData(this: &TIX) -> Result<bool, JsValue>;
+
+    /// Returns true if the player has access to the 4SData TIX API
+    /// @remarks RAM cost: 0.05 GB
+    #[wasm_bindgen(method, catch)]
+    pub fn has4SDataTIXAPI(this: &TIX) -> Result<bool, JsValue>;
+
+    /// Returns an array of the symbols of the tradable stocks
+    ///
+    /// @remarks RAM cost: 2 GB
+    /// @returns Array of the symbols of the tradable stocks.
+    #[wasm_bindgen(method, catch)]
+    pub fn getSymbols(this: &TIX) -> Result<Vec<String>, JsValue>;
+
+    /// Returns the price of a stock.
+    ///
+    /// @remarks
+    /// RAM cost: 2 GB
+    ///
+    /// The stock’s price is the average of its bid and ask prices. This function requires
+    /// that you have the following:
+    ///
+    /// 1. WSE Account
+    ///
+    /// 1. TIX API Access
+    ///
+    /// @example
+    /// ```js
+    /// const fourSigmaStockPrice = ns.stock.getPrice("FSIG");
+    ///
+    /// // Choose the first stock symbol from the array of stock symbols.  Get the price
+    /// // of the corresponding stock.
+    /// const sym = ns.stock.getSymbols()[0];
+    /// ns.tprint("Stock symbol: " + sym);
+    /// ns.tprint("Stock price: " + ns.stock.getPrice(sym));
+    /// ```
+    /// @param sym - Stock symbol.
+    /// @returns The price of a stock.
+    #[wasm_bindgen(method, catch)]
+    pub fn getPrice(this: &TIX, sym: &str) -> Result<f64, JsValue>;
+
+    /// Returns the organization associated with a stock symbol.
+    ///
+    /// @remarks
+    /// RAM cost: 2 GB
+    ///
+    /// The organization associated with the corresponding stock symbol. This function
+    /// requires that you have the following:
+    ///
+    /// 1. WSE Account
+    ///
+    /// 1. TIX API Access
+    ///
+    /// @example
+    /// ```js
+    /// ns.stock.getOrganization("FSIG");
+    ///
+    /// // Choose the first stock symbol from the array of stock symbols. Get the
+    /// // organization associated with the corresponding stock symbol.
+    /// const sym = ns.stock.getSymbols()[0];
+    /// ns.tprint("Stock symbol: " + sym);
+    /// ns.tprint("Stock organization: " + ns.stock.getOrganization(sym));
+    /// ```
+    /// @param sym - Stock symbol.
+    /// @returns The organization assicated with the stock symbol.
+    #[wasm_bindgen(method, catch)]
+    pub fn getOrganization(this: &TIX, sym: &str) -> Result<String, JsValue>;
+
+    /// Returns the ask price of that stock.
+    /// @remarks RAM cost: 2 GB
+    ///
+    /// @param sym - Stock symbol.
+    /// @returns The ask price of a stock.
+    #[wasm_bindgen(method, catch)]
+    pub fn getAskPrice(this: &TIX, sym: &str) -> Result<f64, JsValue>;
+
+    /// Returns the bid price of that stock.
+    /// @remarks RAM cost: 2 GB
+    ///
+    /// @param sym - Stock symbol.
+    /// @returns The bid price of a stock.
+    #[wasm_bindgen(method, catch)]
+    pub fn getBidPrice(this: &TIX, sym: &str) -> Result<f64, JsValue>;
+
+    /// Returns the player’s position in a stock.
+    /// @remarks
+    /// RAM cost: 2 GB
+    /// Returns an array of four elements that represents the player’s position in a stock.
+    ///
+    /// The first element in the returned array is the number of shares the player owns of
+    /// the stock in the Long position. The second element in the array is the average price
+    /// of the player’s shares in the Long position.
+    ///
+    /// The third element in the array is the number of shares the player owns of the stock
+    /// in the Short position. The fourth element in the array is the average price of the
+    /// player’s Short position.
+    ///
+    /// All elements in the returned array are numeric.
+    ///
+    /// @example
+    /// ```js
+    /// const [sharesLong, avgLongPrice, sharesShort, avgShortPrice] = ns.stock.getPosition("ECP");
+    /// ```
+    /// @param sym - Stock symbol.
+    /// @returns Array of four elements that represents the player’s position in a stock.
+    #[wasm_bindgen(method, catch)]
+    pub fn getPosition(this: &TIX, sym: &str) -> Result<Vec<f64>, JsValue>;
+
+    /// Returns the maximum number of shares of a stock.
+    /// @remarks
+    /// RAM cost: 2 GB
+    /// This is the maximum amount of the stock that can be purchased
+    /// in both the Long and Short positions combined.
+    ///
+    /// @param sym - Stock symbol.
+    /// @returns Maximum number of shares that the stock has.
+    #[wasm_bindgen(method, catch)]
+    pub fn getMaxShares(this: &TIX, sym: &str) -> Result<f64, JsValue>;
+
+    /// Calculates cost of buying stocks.
+    /// @remarks
+    /// RAM cost: 2 GB
+    /// Calculates and returns how much it would cost to buy a given number of shares of a stock.
+    /// This takes into account spread, large transactions influencing the price of the stock and commission fees.
+    ///
+    /// @param sym - Stock symbol.
+    /// @param shares - Number of shares to purchase.
+    /// @param posType - Specifies whether the order is a “Long” or “Short” position.
+    /// @returns Cost to buy a given number of shares of a stock.
+    #[wasm_bindgen(method, catch)]
+    pub fn getPurchaseCost(
+        this: &TIX,
+        sym: &str,
+        shares: f64,
+        posType: &str,
+    ) -> Result<f64, JsValue>;
+
+    /// Calculate profit of selling stocks.
+    /// @remarks
+    /// RAM cost: 2 GB
+    /// Calculates and returns how much you would gain from selling a given number of shares of a stock.
+    /// This takes into account spread, large transactions influencing the price of the stock and commission fees.
+    ///
+    /// @param sym - Stock symbol.
+    /// @param shares - Number of shares to sell.
+    /// @param posType - Specifies whether the order is a “Long” or “Short” position.
+    /// @returns Gain from selling a given number of shares of a stock.
+    #[wasm_bindgen(method, catch)]
+    pub fn getSaleGain(this: &TIX, sym: &str, shares: f64, posType: &str) -> Result<f64, JsValue>;
+
+    /// Buy stocks.
+    /// @remarks
+    /// RAM cost: 2.5 GB
+    /// Attempts to purchase shares of a stock using a Market Order.
+    ///
+    /// If the player does not have enough money to purchase the specified number of shares,
+    /// then no shares will be purchased. Remember that every transaction on the stock exchange
+    /// costs a certain commission fee.
+    ///
+    /// If this function successfully purchases the shares, it will return the stock price at which
+    /// each share was purchased. Otherwise, it will return 0.
+    ///
+    /// @param sym - Stock symbol.
+    /// @param shares - Number of shares to purchase. Must be positive. Will be rounded to the nearest integer.
+    /// @returns The stock price at which each share was purchased, otherwise 0 if the shares weren't purchased.
+    #[wasm_bindgen(method, catch)]
+    pub fn buyStock(this: &TIX, sym: &str, shares: f64) -> Result<f64, JsValue>;
+
+    /// Sell stocks.
+    /// @remarks
+    /// RAM cost: 2.5 GB
+    /// Attempts to sell shares of a stock using a Market Order.
+    ///
+    /// If the specified number of shares in the function exceeds the amount that the player
+    /// actually owns, then this function will sell all owned shares. Remember that every
+    /// transaction on the stock exchange costs a certain commission fee.
+    ///
+    /// The net profit made from selling stocks with this function is reflected in the script’s
+    /// statistics. This net profit is calculated as:
+    ///
+    /// shares * (sell_price - average_price_of_purchased_shares)
+    ///
+    /// If the sale is successful, this function will return the stock price at
+    /// which each share was sold. Otherwise, it will return 0.
+    ///
+    /// @param sym - Stock symbol.
+    /// @param shares - Number of shares to sell. Must be positive. Will be rounded to the nearest integer.
+    /// @returns The stock price at which each share was sold, otherwise 0 if the shares weren't sold.
+    #[wasm_bindgen(method, catch)]
+    pub fn sellStock(this: &TIX, sym: &str, shares: f64) -> Result<f64, JsValue>;
+
+    /// Short stocks.
+    /// @remarks
+    /// RAM cost: 2.5 GB
+    /// Attempts to purchase a short position of a stock using a Market Order.
+    ///
+    /// The ability to short a stock is **not** immediately available to the player and
+    /// must be unlocked later on in the game.
+    ///
+    /// If the player does not have enough money to purchase the specified number of shares,
+    /// then no shares will be purchased. Remember that every transaction on the stock exchange
+    /// costs a certain commission fee.
+    ///
+    /// If the purchase is successful, this function will return the stock price at which each
+    /// share was purchased. Otherwise, it will return 0.
+    ///
+    /// @param sym - Stock symbol.
+    /// @param shares - Number of shares to short. Must be positive. Will be rounded to the nearest integer.
+    /// @returns The stock price at which each share was purchased, otherwise 0 if the shares weren't purchased.
+    #[wasm_bindgen(method, catch)]
+    pub fn buyShort(this: &TIX, sym: &str, shares: f64) -> Result<f64, JsValue>;
+
+    /// Sell short stock.
+    /// @remarks
+    /// RAM cost: 2.5 GB
+    /// Attempts to sell a short position of a stock using a Market Order.
+    ///
+    /// The ability to short a stock is **not** immediately available to the player and
+    /// must be unlocked later on in the game.
+    ///
+    /// If the specified number of shares exceeds the amount that the player actually owns,
+    /// then this function will sell all owned shares. Remember that every transaction on
+    /// the stock exchange costs a certain commission fee.
+    ///
+    /// If the sale is successful, this function will return the stock price at which each
+    /// share was sold. Otherwise, it will return 0.
+    ///
+    /// @param sym - Stock symbol.
+    /// @param shares - Number of shares to sell. Must be positive. Will be rounded to the nearest integer.
+    /// @returns The stock price at which each share was sold, otherwise 0 if the shares weren't sold.
+    #[wasm_bindgen(method, catch)]
+    pub fn sellShort(this: &TIX, sym: &str, shares: f64) -> Result<f64, JsValue>;
+
+    /// Place order for stocks.
+    /// @remarks
+    /// RAM cost: 2.5 GB
+    /// Places an order on the stock market. This function only works for Limit and Stop Orders.
+    ///
+    /// The ability to place limit and stop orders is **not** immediately available to the player and
+    /// must be unlocked later on in the game.
+    ///
+    /// Returns true if the order is successfully placed, and false otherwise.
+    ///
+    /// @param sym - Stock symbol.
+    /// @param shares - Number of shares for order. Must be positive. Will be rounded to the nearest integer.
+    /// @param price - Execution price for the order.
+    /// @param type - Type of order.
+    /// @param pos - Specifies whether the order is a “Long” or “Short” position.
+    /// @returns True if the order is successfully placed, and false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn placeOrder(
+        this: &TIX,
+        sym: &str,
+        shares: f64,
+        price: f64,
+        r#type: &str,
+        pos: &str,
+    ) -> Result<bool, JsValue>;
+
+    /// Cancel order for stocks.
+    /// @remarks
+    /// RAM cost: 2.5 GB
+    /// Cancels an outstanding Limit or Stop order on the stock market.
+    ///
+    /// The ability to use limit and stop orders is **not** immediately available to the player and
+    /// must be unlocked later on in the game.
+    ///
+    /// @param sym - Stock symbol.
+    /// @param shares - Number of shares for order. Must be positive. Will be rounded to the nearest integer.
+    /// @param price - Execution price for the order.
+    /// @param type - Type of order.
+    /// @param pos - Specifies whether the order is a “Long” or “Short” position.
+    #[wasm_bindgen(method, catch)]
+    pub fn cancelOrder(
+        this: &TIX,
+        sym: &str,
+        shares: f64,
+        price: f64,
+        r#type: &str,
+        pos: &str,
+    ) -> Result<(), JsValue>;
+
+    /// Returns your order book for the stock market.
+    /// @remarks
+    /// RAM cost: 2.5 GB
+    /// This is an object containing information for all the Limit and Stop Orders you have in the stock market.
+    /// For each symbol you have a position in, the returned object will have a key with that symbol's name.
+    /// The object's properties are each an array of {@link StockOrderObject}
+    /// The object has the following structure:
+    ///
+    /// ```ts
+    /// {
+    ///  string1: [ // Array of orders for this stock
+    ///      {
+    ///          shares: Order quantity
+    ///          price: Order price
+    ///          type: Order type
+    ///          position: Either "L" or "S" for Long or Short position
+    ///      },
+    ///      {
+    ///          ...
+    ///      },
+    ///      ...
+    ///  ],
+    ///  string2: [ // Array of orders for this stock
+    ///      ...
+    ///  ],
+    ///  ...
+    /// }
+    /// ```
+    /// The “Order type” property can have one of the following four values: "Limit Buy Order", "Limit Sell Order",
+    /// "Stop Buy Order", "Stop Sell Order".
+    /// Note that the order book will only contain information for stocks that you actually have orders in.
+    ///
+    /// @example
+    /// ```ts
+    /// "If you do not have orders in Nova Medical (NVMD), then the returned object will not have a “NVMD” property."
+    /// {
+    ///  ECP: [
+    ///      {
+    ///          shares: 5,
+    ///          price: 100,000
+    ///          type: "Stop Buy Order",
+    ///          position: "S",
+    ///      },
+    ///      {
+    ///          shares: 25,
+    ///          price: 125,000
+    ///          type: "Limit Sell Order",
+    ///          position: "L",
+    ///      },
+    ///  ],
+    ///  SYSC: [
+    ///      {
+    ///          shares: 100,
+    ///          price: 10,000
+    ///          type: "Limit Buy Order",
+    ///          position: "L",
+    ///      },
+    ///  ],
+    /// }
+    /// ```
+    /// @returns Object containing information for all the Limit and Stop Orders you have in the stock market.
+    #[wasm_bindgen(method, catch)]
+    pub fn getOrders(this: &TIX) -> Result<StockOrder, JsValue>;
+
+    /// Returns the volatility of the specified stock.
+    /// @remarks
+    /// RAM cost: 2.5 GB
+    /// Volatility represents the maximum percentage by which a stock’s price can change every tick.
+    /// The volatility is returned as a decimal value, NOT a percentage
+    /// (e.g. if a stock has a volatility of 3%, then this function will return 0.03, NOT 3).
+    ///
+    /// In order to use this function, you must first purchase access to the Four Sigma (4S) Market Data TIX API.
+    ///
+    /// @param sym - Stock symbol.
+    /// @returns Volatility of the specified stock.
+    #[wasm_bindgen(method, catch)]
+    pub fn getVolatility(this: &TIX, sym: &str) -> Result<f64, JsValue>;
+
+    /// Returns the probability that the specified stock’s price will increase (as opposed to decrease) during the next
+    /// tick.
+    /// @remarks
+    /// RAM cost: 2.5 GB
+    /// The probability is returned as a decimal value, NOT a percentage
+    /// (e.g. if a stock has a 60% chance of increasing, then this function will return 0.6, NOT 60).
+    ///
+    /// In other words, if this function returned 0.30 for a stock, then this means that the stock’s price has a
+    /// 30% chance of increasing and a 70% chance of decreasing during the next tick.
+    ///
+    /// In order to use this function, you must first purchase access to the Four Sigma (4S) Market Data TIX API.
+    ///
+    /// @param sym - Stock symbol.
+    /// @returns Probability that the specified stock’s price will increase (as opposed to decrease) during the next tick.
+    #[wasm_bindgen(method, catch)]
+    pub fn getForecast(this: &TIX, sym: &str) -> Result<f64, JsValue>;
+
+    /// Purchase 4S Market Data Access.
+    /// @remarks RAM cost: 2.5 GB
+    /// @returns True if you successfully purchased it or if you already have access, false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn purchase4SMarketData(this: &TIX) -> Result<bool, JsValue>;
+
+    /// Purchase 4S Market Data TIX API Access.
+    /// @remarks RAM cost: 2.5 GB
+    /// @returns True if you successfully purchased it or if you already have access, false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn purchase4SMarketDataTixApi(this: &TIX) -> Result<bool, JsValue>;
+
+    /// Purchase WSE Account.
+    /// @remarks RAM cost: 2.5 GB
+    /// @returns True if you successfully purchased it or if you already have access, false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn purchaseWseAccount(this: &TIX) -> Result<bool, JsValue>;
+
+    /// Purchase TIX API Access
+    /// @remarks RAM cost: 2.5 GB
+    /// @returns True if you successfully purchased it or if you already have access, false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn purchaseTixApi(this: &TIX) -> Result<bool, JsValue>;
+
+    /// Get Stock Market bonus time.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// “Bonus time” is accumulated when the game is offline or if the game is inactive in the browser.
+    ///
+    /// Stock Market prices update more frequently during “bonus time”.
+    ///
+    /// @returns Amount of accumulated “bonus time” (milliseconds) for the Stock Market mechanic.
+    #[wasm_bindgen(method, catch)]
+    pub fn getBonusTime(this: &TIX) -> Result<f64, JsValue>;
+
+    /// Sleep until the next Stock Market price update has happened.
+    /// @remarks
+    /// RAM cost: 1 GB
+    ///
+    /// The amount of real time spent asleep between updates can vary due to "bonus time"
+    /// (usually 4 seconds - 6 seconds).
+    ///
+    /// @returns Promise that resolves to the number of milliseconds of Stock Market time
+    /// that were processed in the previous update (always 6000 ms).
+    ///
+    /// @example
+    /// ```js
+    /// while (true) {
+    ///   await ns.stock.nextUpdate();
+    ///   // Manage your stock portfolio
+    /// }
+    /// ```
+    #[wasm_bindgen(method, catch)]
+    pub async fn nextUpdate(this: &TIX) -> Result<f64, JsValue>;
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// Singularity API
+    /// @remarks
+    /// This API requires Source-File 4 to use. The RAM cost of all these functions is multiplied by 16/4/1 based on
+    /// Source-File 4 levels.
+    #[derive(Debug, Clone, PartialEq)]
+    pub type Singularity;
+
+    /// Backup game save.
+    /// @remarks
+    /// RAM cost: 1 GB * 16/4/1
+    ///
+    ///
+    /// This function will automatically open the backup save prompt and claim the free faction favour if available.
+    #[wasm_bindgen(method, catch)]
+    pub fn exportGame(this: &Singularity) -> Result<(), JsValue>;
+
+    /// Returns Backup save bonus availability.
+    /// @remarks
+    /// RAM cost: 0.5 GB * 16/4/1
+    ///
+    ///
+    /// This function will check if there is a bonus for backing up your save.
+    #[wasm_bindgen(method, catch)]
+    pub fn exportGameBonus(this: &Singularity) -> Result<bool, JsValue>;
+
+    /// Take university class.
+    ///
+    /// @remarks
+    /// RAM cost: 2 GB * 16/4/1
+    ///
+    ///
+    /// This function will automatically set you to start taking a course at a university.
+    /// If you are already in the middle of some “working” action (such as working at a
+    /// company, for a faction, or on a program), then running this function will automatically
+    /// cancel that action and give you your earnings.
+    ///
+    /// The cost and experience gains for all of these universities and classes are the same as
+    /// if you were to manually visit and take these classes.
+    ///
+    /// @param universityName - Name of university. You must be in the correct city for whatever university you specify.
+    /// @param courseName - Name of course.
+    /// @param focus - Acquire player focus on this class. Optional. Defaults to true.
+    /// @returns True if action is successfully started, false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn universityCourse(
+        this: &Singularity,
+        universityName: &str,
+        courseName: &str,
+        focus: Option<bool>,
+    ) -> Result<bool, JsValue>;
+
+    /// Workout at the gym.
+    ///
+    /// @remarks
+    /// RAM cost: 2 GB * 16/4/1
+    ///
+    ///
+    /// This function will automatically set you to start working out at a gym to train
+    /// a particular stat. If you are already in the middle of some “working” action
+    /// (such as working at a company, for a faction, or on a program), then running
+    /// this function will automatically cancel that action and give you your earnings.
+    ///
+    /// The cost and experience gains for all of these gyms are the same as if you were
+    /// to manually visit these gyms and train
+    ///
+    /// @param gymName - Name of gym. You must be in the correct city for whatever gym you specify.
+    /// @param stat - The stat you want to train.
+    /// @param focus - Acquire player focus on this gym workout. Optional. Defaults to true.
+    /// @returns True if action is successfully started, false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn gymWorkout(
+        this: &Singularity,
+        gymName: &str,
+        stat: &str,
+        focus: Option<bool>,
+    ) -> Result<bool, JsValue>;
+
+    /// Travel to another city.
+    /// @remarks
+    /// RAM cost: 2 GB * 16/4/1
+    ///
+    ///
+    /// This function allows the player to travel to any city. The cost for using this
+    /// function is the same as the cost for traveling through the Travel Agency.
+    ///
+    /// @param city - City to travel to.
+    /// @returns True if action is successful, false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn travelToCity(this: &Singularity, city: CityName) -> Result<bool, JsValue>;
+
+    /// Purchase the TOR router.
+    /// @remarks
+    /// RAM cost: 2 GB * 16/4/1
+    ///
+    ///
+    /// This function allows you to automatically purchase a TOR router. The cost for
+    /// purchasing a TOR router using this function is the same as if you were to
+    /// manually purchase one.
+    ///
+    /// @returns True if action is successful or if you already own TOR router, false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn purchaseTor(this: &Singularity) -> Result<bool, JsValue>;
+
+    /// Purchase a program from the dark web.
+    /// @remarks
+    /// RAM cost: 2 GB * 16/4/1
+    ///
+    ///
+    /// This function allows you to automatically purchase programs. You MUST have a
+    /// TOR router in order to use this function. The cost of purchasing programs
+    /// using this function is the same as if you were purchasing them through the Dark
+    /// Web using the Terminal buy command.
+    ///
+    /// @example
+    /// ```js
+    /// const programName = "BruteSSH.exe"
+    /// const success = ns.purchaseProgram(programName);
+    /// if (!success) ns.tprint("ERROR: Failed to purchase ${programName}")
+    /// ```
+    /// @param programName - Name of program to purchase.
+    /// @returns True if the specified program is purchased, and false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn purchaseProgram(this: &Singularity, programName: &str) -> Result<bool, JsValue>;
+
+    /// Check if the player is busy.
+    /// @remarks
+    /// RAM cost: 0.5 GB * 16/4/1
+    ///
+    ///
+    /// Returns a boolean indicating whether or not the player is currently performing an
+    /// ‘action’. These actions include working for a company/faction, studying at a university,
+    /// working out at a gym, creating a program, committing a crime, or carrying out a Hacking Mission.
+    ///
+    /// @returns True if the player is currently performing an ‘action’, false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn isBusy(this: &Singularity) -> Result<bool, JsValue>;
+
+    /// Stop the current action.
+    /// @remarks
+    /// RAM cost: 1 GB * 16/4/1
+    ///
+    ///
+    /// This function is used to end whatever ‘action’ the player is currently performing.
+    /// The player will receive whatever money/experience/etc. he has earned from that action.
+    ///
+    /// The actions that can be stopped with this function are:
+    ///
+    /// * Studying at a university
+    /// * Working out at a gym
+    /// * Working for a company/faction
+    /// * Creating a program
+    /// * Committing a crime
+    ///
+    /// This function will return true if the player’s action was ended.
+    /// It will return false if the player was not performing an action when this function was called.
+    ///
+    /// @returns True if the player’s action was ended, false if the player was not performing an action.
+    #[wasm_bindgen(method, catch)]
+    pub fn stopAction(this: &Singularity) -> Result<bool, JsValue>;
+
+    /// Upgrade home computer RAM.
+    /// @remarks
+    /// RAM cost: 3 GB * 16/4/1
+    ///
+    ///
+    /// This function will upgrade amount of RAM on the player’s home computer. The cost is
+    /// the same as if you were to do it manually.
+    ///
+    /// This function will return true if the player’s home computer RAM is successfully upgraded, and false otherwise.
+    ///
+    /// @returns True if the player’s home computer RAM is successfully upgraded, and false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn upgradeHomeRam(this: &Singularity) -> Result<bool, JsValue>;
+
+    /// Upgrade home computer cores.
+    /// @remarks
+    /// RAM cost: 3 GB * 16/4/1
+    ///
+    ///
+    /// This function will upgrade amount of cores on the player’s home computer. The cost is
+    /// the same as if you were to do it manually.
+    ///
+    /// This function will return true if the player’s home computer cores is successfully upgraded, and false otherwise.
+    ///
+    /// @returns True if the player’s home computer cores is successfully upgraded, and false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn upgradeHomeCores(this: &Singularity) -> Result<bool, JsValue>;
+
+    /// Get the price of upgrading home RAM.
+    /// @remarks
+    /// RAM cost: 1.5 GB * 16/4/1
+    ///
+    ///
+    /// Returns the cost of upgrading the player’s home computer RAM.
+    ///
+    /// @returns Cost of upgrading the player’s home computer RAM.
+    #[wasm_bindgen(method, catch)]
+    pub fn getUpgradeHomeRamCost(this: &Singularity) -> Result<f64, JsValue>;
+
+    /// Get the price of upgrading home cores.
+    /// @remarks
+    /// RAM cost: 1.5 GB * 16/4/1
+    ///
+    ///
+    /// Returns the cost of upgrading the player’s home computer cores.
+    ///
+    /// @returns Cost of upgrading the player’s home computer cores.
+    #[wasm_bindgen(method, catch)]
+    pub fn getUpgradeHomeCoresCost(this: &Singularity) -> Result<f64, JsValue>;
+
+    /// Get Requirements for Company Position.
+    /// @remarks
+    /// RAM cost: 2 GB * 16/4/1
+    ///
+    ///
+    /// This function will return an object that contains the requirements for
+    /// a specific position at a specific country.
+    ///
+    /// @example
+    /// ```js
+    /// const companyName = "ECorp";
+    /// const position = "Chief Executive Officer";
+    ///
+    /// let requirements = ns.singularity.getCompanyPositionInfo(companyName, position);
+    /// ```
+    /// @param companyName - Name of company to get the requirements for. Must be an exact match.
+    /// @param positionName - Name of position to get the requirements for. Must be an exact match.
+    /// @returns CompanyPositionInfo object.
+    #[wasm_bindgen(method, catch)]
+    pub fn getCompanyPositionInfo(
+        this: &Singularity,
+        companyName: CompanyName,
+        positionName: JobName,
+    ) -> Result<CompanyPositionInfo, JsValue>;
+
+    /// Get List of Company Positions.
+    /// @remarks
+    /// RAM cost: 2 GB * 16/4/1
+    ///
+    ///
+    /// This function will return a list of positions at a specific company.
+    ///
+    /// This function will return the position list if the company name is valid.
+    ///
+    /// @example
+    /// ```js
+    /// const companyName = "Noodle Bar";
+    /// const jobList = ns.singularity.getCompanyPositions(companyName);
+    /// ```
+    /// @param companyName - Name of company to get the position list for. Must be an exact match.
+    /// @returns The position list if the company name is valid.
+    #[wasm_bindgen(method, catch)]
+    pub fn getCompanyPositions(
+        this: &Singularity,
+        companyName: CompanyName,
+    ) -> Result<Vec<String>, JsValue>;
+
+    /// Work for a company.
+    /// @remarks
+    /// RAM cost: 3 GB * 16/4/1
+    ///
+    ///
+    /// This function will set you to start working at your current job at a specified company at which you are employed.
+    /// If you are already in the middle of some “working” action (such as working for a faction, training at a gym, or
+    /// creating a program), then running this function will cancel that action.
+    ///
+    /// This function will return true if the player starts working, and false otherwise.
+    ///
+    /// @example
+    /// ```js
+    /// const companyName = "Noodle Bar";
+    /// const success = ns.singularity.workForCompany(companyName);
+    /// if (!success) ns.tprint(`ERROR: Failed to start work at ${companyName}.`);
+    /// ```
+    /// @param companyName - Name of company to work for. Must be an exact match. Optional. If not specified, this
+    /// argument defaults to the last job that you worked.
+    /// @param focus - Acquire player focus on this work operation. Optional. Defaults to true.
+    /// @returns True if the player starts working, and false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn workForCompany(
+        this: &Singularity,
+        companyName: CompanyName,
+        focus: Option<bool>,
+    ) -> Result<bool, JsValue>;
+
+    /// Quit jobs by company.
+    /// @remarks
+    /// RAM cost: 3 GB * 16/4/1
+    ///
+    ///
+    /// This function will finish work with the company provided and quit any jobs.
+    ///
+    /// @param companyName - Name of the company.
+    #[wasm_bindgen(method, catch)]
+    pub fn quitJob(this: &Singularity, companyName: Option<CompanyName>) -> Result<(), JsValue>;
+
+    /// Apply for a job at a company.
+    /// @remarks
+    /// RAM cost: 3 GB * 16/4/1
+    ///
+    ///
+    /// This function will automatically try to apply to the specified company
+    /// for a position in the specified field. This function can also be used to
+    /// apply for promotions by specifying the company and field you are already
+    /// employed at.
+    ///
+    /// This function will return true if you successfully get a job/promotion,
+    /// and false otherwise. Note that if you are trying to use this function to
+    /// apply for a promotion and don’t get one, the function will return false.
+    ///
+    /// @param companyName - Name of company to apply to.
+    /// @param field - Field to which you want to apply.
+    /// @returns True if the player successfully get a job/promotion, and false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn applyToCompany(
+        this: &Singularity,
+        companyName: CompanyName,
+        field: JobField,
+    ) -> Result<bool, JsValue>;
+
+    /// Get company reputation.
+    /// @remarks
+    /// RAM cost: 1 GB * 16/4/1
+    ///
+    ///
+    /// This function will return the amount of reputation you have at the specified company.
+    /// If the company passed in as an argument is invalid, -1 will be returned.
+    ///
+    /// @param companyName - Name of the company.
+    /// @returns Amount of reputation you have at the specified company.
+    #[wasm_bindgen(method, catch)]
+    pub fn getCompanyRep(this: &Singularity, companyName: CompanyName) -> Result<f64, JsValue>;
+
+    /// Get company favor.
+    /// @remarks
+    /// RAM cost: 1 GB * 16/4/1
+    ///
+    ///
+    /// This function will return the amount of favor you have at the specified company.
+    /// If the company passed in as an argument is invalid, -1 will be returned.
+    ///
+    /// @param companyName - Name of the company.
+    /// @returns Amount of favor you have at the specified company.
+    #[wasm_bindgen(method, catch)]
+    pub fn getCompanyFavor(this: &Singularity, companyName: CompanyName) -> Result<f64, JsValue>;
+
+    /// Get company favor gain.
+    /// @remarks
+    /// RAM cost: 0.75 GB * 16/4/1
+    ///
+    ///
+    /// This function will return the amount of favor you will gain for the specified
+    /// company when you reset by installing Augmentations.
+    ///
+    /// @param companyName - Name of the company.
+    /// @returns Amount of favor you gain at the specified company when you reset by installing Augmentations.
+    #[wasm_bindgen(method, catch)]
+    pub fn getCompanyFavorGain(
+        this: &Singularity,
+        companyName: CompanyName,
+    ) -> Result<f64, JsValue>;
+
+    /// List all current faction invitations.
+    /// @remarks
+    /// RAM cost: 3 GB * 16/4/1
+    ///
+    ///
+    /// Performs an immediate check for which factions you qualify for invites from, then returns an array with the name
+    /// of all Factions you have outstanding invitations from.
+    ///
+    /// @returns Array with the name of all Factions you currently have outstanding invitations from.
+    #[wasm_bindgen(method, catch)]
+    pub fn checkFactionInvitations(this: &Singularity) -> Result<Vec<String>, JsValue>;
+
+    /// Join a faction.
+    /// @remarks
+    /// RAM cost: 3 GB * 16/4/1
+    ///
+    ///
+    /// This function will automatically accept an invitation from a faction and join it.
+    ///
+    /// @param faction - Name of faction to join.
+    /// @returns True if player joined the faction, and false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn joinFaction(this: &Singularity, faction: &str) -> Result<bool, JsValue>;
+
+    /// Work for a faction.
+    /// @remarks
+    /// RAM cost: 3 GB * 16/4/1
+    ///
+    ///
+    /// This function will set you to start working for the specified faction. You must be a member of the faction and
+    /// that faction must have the specified work type, or else this function will fail. If you are already in the
+    /// middle of some “working” action (such as working for a company, training at a gym, or creating a program), then
+    /// running this function will cancel that action.
+    ///
+    /// This function will return true if you successfully start working for the specified faction, and false otherwise.
+    ///
+    /// @example
+    /// ```js
+    /// const factionName = "CyberSec";
+    /// const workType = "hacking";
+    ///
+    /// let success = ns.singularity.workForFaction(factionName, workType);
+    /// if (!success) ns.tprint(`ERROR: Failed to start work for ${factionName} with work type ${workType}.`)
+    /// ```
+    /// @param faction - Name of faction to work for.
+    /// @param workType - Type of work to perform for the faction.
+    /// @param focus - Acquire player focus on this work operation. Optional. Defaults to true.
+    /// @returns True if the player starts working, and false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn workForFaction(
+        this: &Singularity,
+        faction: &str,
+        workType: FactionWorkType,
+        focus: Option<bool>,
+    ) -> Result<bool, JsValue>;
+
+    /// Get faction reputation.
+    /// @remarks
+    /// RAM cost: 1 GB * 16/4/1
+    ///
+    ///
+    /// This function returns the amount of reputation you have for the specified faction.
+    ///
+    /// @param faction - Name of faction to work for.
+    /// @returns Amount of reputation you have for the specified faction.
+    #[wasm_bindgen(method, catch)]
+    pub fn getFactionRep(this: &Singularity, faction: &str) -> Result<f64, JsValue>;
+
+    /// Get faction favor.
+    /// @remarks
+    /// RAM cost: 1 GB * 16/4/1
+    ///
+    ///
+    /// This function returns the amount of favor you have for the specified faction.
+    ///
+    /// @param faction - Name of faction.
+    /// @returns Amount of favor you have for the specified faction.
+    #[wasm_bindgen(method, catch)]
+    pub fn getFactionFavor(this: &Singularity, faction: &str) -> Result<f64, JsValue>;
+
+    /// Get faction favor gain.
+    /// @remarks
+    /// RAM cost: 0.75 GB * 16/4/1
+    ///
+    ///
+    /// This function returns the amount of favor you will gain for the specified
+    /// faction when you reset by installing Augmentations.
+    ///
+    /// @param faction - Name of faction.
+    /// @returns Amount of favor you will gain for the specified faction when you reset by installing Augmentations.
+    #[wasm_bindgen(method, catch)]
+    pub fn getFactionFavorGain(this: &Singularity, faction: &str) -> Result<f64, JsValue>;
+
+    /// Donate to a faction.
+    /// @remarks
+    /// RAM cost: 5 GB * 16/4/1
+    ///
+    ///
+    /// Attempts to donate money to the specified faction in exchange for reputation.
+    /// Returns true if you successfully donate the money, and false otherwise.
+    ///
+    /// @param faction - Name of faction to donate to.
+    /// @param amount - Amount of money to donate.
+    /// @returns True if the money was donated, and false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn donateToFaction(this: &Singularity, faction: &str, amount: f64)
+        -> Result<bool, JsValue>;
+
+    /// Create a program.
+    /// @remarks
+    /// RAM cost: 5 GB * 16/4/1
+    ///
+    ///
+    /// This function will automatically set you to start working on creating the
+    /// specified program. If you are already in the middle of some “working” action
+    /// (such as working for a company, training at a gym, or taking a course), then
+    /// running this function will automatically cancel that action and give you your
+    /// earnings.
+    ///
+    /// This function returns true if you successfully start working on the specified program, and false otherwise.
+    ///
+    /// Note that creating a program using this function has the same hacking level requirements as it normally would.
+    /// These level requirements are:
+    /// * BruteSSH.exe: 50
+    /// * FTPCrack.exe: 100
+    /// * relaySMTP.exe: 250
+    /// * HTTPWorm.exe: 500
+    /// * SQLInject.exe: 750
+    /// * DeepscanV1.exe: 75
+    /// * DeepscanV2.exe: 400
+    /// * ServerProfiler.exe: 75
+    /// * AutoLink.exe: 25
+    ///
+    /// @example
+    /// ```js
+    /// const programName = "BruteSSH.exe";
+    /// const success = ns.createProgram(programName);
+    /// if (!success) ns.tprint("ERROR: Failed to start working on ${programName}")
+    /// ```
+    /// @param program - Name of program to create.
+    /// @param focus - Acquire player focus on this program creation. Optional. Defaults to true.
+    /// @returns True if you successfully start working on the specified program, and false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn createProgram(
+        this: &Singularity,
+        program: &str,
+        focus: Option<bool>,
+    ) -> Result<bool, JsValue>;
+
+    /// Commit a crime.
+    /// @remarks
+    /// RAM cost: 5 GB * 16/4/1
+    ///
+    ///
+    /// This function is used to automatically attempt to commit crimes.
+    /// If you are already in the middle of some ‘working’ action (such
+    /// as working for a company or training at a gym), then running this
+    /// function will automatically cancel that action and give you your
+    /// earnings.
+    ///
+    /// This function returns the number of milliseconds it takes to attempt the
+    /// specified crime (e.g. It takes 60 seconds to attempt the ‘Rob Store’ crime,
+    /// so running `commitCrime('Rob Store')` will return 60,000).
+    ///
+    /// @param crime - Name of crime to attempt.
+    /// @param focus - Acquire player focus on this crime. Optional. Defaults to true.
+    /// @returns The number of milliseconds it takes to attempt the specified crime.
+    #[wasm_bindgen(method, catch)]
+    pub fn commitCrime(
+        this: &Singularity,
+        crime: CrimeType,
+        focus: Option<bool>,
+    ) -> Result<f64, JsValue>;
+
+    /// Get chance to successfully commit a crime.
+    /// @remarks
+    /// RAM cost: 5 GB * 16/4/1
+    ///
+    ///
+    /// This function returns your chance of success at committing the specified crime.
+    ///
+    /// @param crime - Name of crime.
+    /// @returns Chance of success at committing the specified crime.
+    #[wasm_bindgen(method, catch)]
+    pub fn getCrimeChance(this: &Singularity, crime: CrimeType) -> Result<f64, JsValue>;
+
+    /// Get stats related to a crime.
+    /// @remarks
+    /// RAM cost: 5 GB * 16/4/1
+    ///
+    ///
+    /// Returns the stats of the crime.
+    ///
+    /// @param crime - Name of crime.
+    /// @returns The stats of the crime.
+    #[wasm_bindgen(method, catch)]
+    pub fn getCrimeStats(this: &Singularity, crime: CrimeType) -> Result<CrimeStats, JsValue>;
+
+    /// Get a list of owned augmentation.
+    /// @remarks
+    /// RAM cost: 5 GB * 16/4/1
+    ///
+    ///
+    /// This function returns an array containing the names (as strings) of all Augmentations you have.
+    ///
+    /// @param purchased - Specifies whether the returned array should include Augmentations you have purchased but not
+    /// yet installed. By default, this argument is false which means that the return value will NOT have the purchased
+    /// Augmentations.
+    /// @returns Array containing the names (as strings) of all Augmentations you have.
+    #[wasm_bindgen(method, catch)]
+    pub fn getOwnedAugmentations(
+        this: &Singularity,
+        purchased: Option<bool>,
+    ) -> Result<Vec<String>, JsValue>;
+
+    /// Get a list of acquired Source-Files.
+    /// @remarks
+    /// RAM cost: 5 GB
+    ///
+    ///
+    /// Returns an array of source files
+    ///
+    /// @returns Array containing an object with number and level of the source file.
+    #[wasm_bindgen(method, catch)]
+    pub fn getOwnedSourceFiles(this: &Singularity) -> Result<Vec<SourceFileLvl>, JsValue>;
+
+    /// Get a list of faction(s) that have a specific Augmentation.
+    /// @remarks
+    /// RAM cost: 5 GB * 16/4/1
+    ///
+    ///
+    /// Returns an array containing the names (as strings) of all factions
+    /// that offer the specified Augmentation.
+    /// If no factions offer the Augmentation, a blank array is returned.
+    ///
+    /// @param augName - Name of Augmentation.
+    /// @returns Array containing the names of all factions.
+    #[wasm_bindgen(method, catch)]
+    pub fn getAugmentationFactions(
+        this: &Singularity,
+        augName: &str,
+    ) -> Result<Vec<String>, JsValue>;
+
+    /// Get a list of augmentation available from a faction.
+    /// @remarks
+    /// RAM cost: 5 GB * 16/4/1
+    ///
+    ///
+    /// Returns an array containing the names (as strings) of all Augmentations
+    /// that are available from the specified faction.
+    ///
+    /// @param faction - Name of faction.
+    /// @returns Array containing the names of all Augmentations.
+    #[wasm_bindgen(method, catch)]
+    pub fn getAugmentationsFromFaction(
+        this: &Singularity,
+        faction: &str,
+    ) -> Result<Vec<String>, JsValue>;
+
+    /// Get the pre-requisite of an augmentation.
+    /// @remarks
+    /// RAM cost: 5 GB * 16/4/1
+    ///
+    ///
+    /// This function returns an array with the names of the prerequisite Augmentation(s) for the specified Augmentation.
+    /// If there are no prerequisites, a blank array is returned.
+    ///
+    /// @param augName - Name of Augmentation.
+    /// @returns Array with the names of the prerequisite Augmentation(s) for the specified Augmentation.
+    #[wasm_bindgen(method, catch)]
+    pub fn getAugmentationPrereq(this: &Singularity, augName: &str)
+        -> Result<Vec<String>, JsValue>;
+
+    /// Get price of an augmentation.
+    /// @remarks
+    /// RAM cost: 2.5 GB * 16/4/1
+    ///
+    ///
+    /// @param augName - Name of Augmentation.
+    /// @returns Price of the augmentation.
+    #[wasm_bindgen(method, catch)]
+    pub fn getAugmentationPrice(this: &Singularity, augName: &str) -> Result<f64, JsValue>;
+
+    /// Get base price of an augmentation.
+    /// @remarks
+    /// RAM cost: 2.5 GB * 16/4/1
+    ///
+    ///
+    /// @param augName - Name of Augmentation.
+    /// @returns Base price of the augmentation, before price multiplier.
+    #[wasm_bindgen(method, catch)]
+    pub fn getAugmentationBasePrice(this: &Singularity, augName: &str) -> Result<f64, JsValue>;
+
+    /// Get reputation requirement of an augmentation.
+    /// @remarks
+    /// RAM cost: 2.5 GB * 16/4/1
+    ///
+    ///
+    /// @param augName - Name of Augmentation.
+    /// @returns Reputation requirement of the augmentation.
+    #[wasm_bindgen(method, catch)]
+    pub fn getAugmentationRepReq(this: &Singularity, augName: &str) -> Result<f64, JsValue>;
+
+    /// Purchase an augmentation
+    /// @remarks
+    /// RAM cost: 5 GB * 16/4/1
+    ///
+    ///
+    /// This function will try to purchase the specified Augmentation through the given Faction.
+    ///
+    /// This function will return true if the Augmentation is successfully purchased, and false otherwise.
+    ///
+    /// @param faction - Name of faction to purchase Augmentation from.
+    /// @param augmentation - Name of Augmentation to purchase.
+    /// @returns True if the Augmentation is successfully purchased, and false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn purchaseAugmentation(
+        this: &Singularity,
+        faction: &str,
+        augmentation: &str,
+    ) -> Result<bool, JsValue>;
+
+    /// Get the stats of an augmentation.
+    /// @remarks
+    /// RAM cost: 5 GB * 16/4/1
+    ///
+    ///
+    /// This function returns augmentation stats.
+    ///
+    /// @param name - Name of Augmentation. CASE-SENSITIVE.
+    /// @returns Augmentation stats.
+    #[wasm_bindgen(method, catch)]
+    pub fn getAugmentationStats(this: &Singularity, name: &str) -> Result<Multipliers, JsValue>;
+
+    /// Install your purchased augmentations.
+    /// @remarks
+    /// RAM cost: 5 GB * 16/4/1
+    ///
+    ///
+    /// This function will automatically install your Augmentations, resetting the game as usual. If you do not own uninstalled Augmentations then the game will not reset.
+    ///
+    /// @param cbScript - This is a script that will automatically be run after Augmentations are installed (after the reset). This script will be run with no arguments and 1 thread. It must be located on your home computer.
+    #[wasm_bindgen(method, catch)]
+    pub fn installAugmentations(this: &Singularity, cbScript: Option<&str>) -> Result<(), JsValue>;
+
+    /// Hospitalize the player.
+    /// @remarks
+    /// RAM cost: 0.25 GB * 16/4/1
+    #[wasm_bindgen(method, catch)]
+    pub fn hospitalize(this: &Singularity) -> Result<(), JsValue>;
+
+    /// Soft reset the game.
+    /// @remarks
+    /// RAM cost: 5 GB * 16/4/1
+    ///
+    ///
+    /// This function will perform a reset even if you don’t have any augmentation installed.
+    ///
+    /// @param cbScript - This is a script that will automatically be run after Augmentations are installed (after the reset). This script will be run with no arguments and 1 thread. It must be located on your home computer.
+    #[wasm_bindgen(method, catch)]
+    pub fn softReset(this: &Singularity, cbScript: &str) -> Result<(), JsValue>;
+
+    /// Go to a location.
+    /// @remarks
+    /// RAM cost: 5 GB * 16/4/1
+    ///
+    ///
+    /// Move the player to a specific location.
+    ///
+    /// @param locationName - Name of the location.
+    /// @returns True if the player was moved there, false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn goToLocation(this: &Singularity, locationName: &str) -> Result<bool, JsValue>;
+
+    /// Get the current server.
+    /// @remarks
+    /// RAM cost: 2 GB * 16/4/1
+    ///
+    ///
+    /// @returns Name of the current server.
+    #[wasm_bindgen(method, catch)]
+    pub fn getCurrentServer(this: &Singularity) -> Result<String, JsValue>;
+
+    /// Connect to a server.
+    /// @remarks
+    /// RAM cost: 2 GB * 16/4/1
+    ///
+    ///
+    /// Run the connect HOSTNAME command in the terminal. Can only connect to neighbors.
+    ///
+    /// @returns True if the connect command was successful, false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn connect(this: &Singularity, hostname: &str) -> Result<bool, JsValue>;
+
+    /// Run the hack command in the terminal.
+    /// @remarks
+    /// RAM cost: 2 GB * 16/4/1
+    ///
+    ///
+    /// @returns Amount of money stolen by manual hacking.
+    #[wasm_bindgen(method, catch)]
+    pub async fn manualHack(this: &Singularity) -> Result<f64, JsValue>;
+
+    /// Run the backdoor command in the terminal.
+    /// @remarks
+    /// RAM cost: 2 GB * 16/4/1
+    ///
+    ///
+    /// @returns Promise waiting for the installation to finish.
+    #[wasm_bindgen(method, catch)]
+    pub async fn installBackdoor(this: &Singularity) -> Result<(), JsValue>;
+
+    /// Check if the player is focused.
+    /// @remarks
+    /// RAM cost: 0.1 GB * 16/4/1
+    ///
+    ///
+    /// @returns True if the player is focused.
+    #[wasm_bindgen(method, catch)]
+    pub fn isFocused(this: &Singularity) -> Result<bool, JsValue>;
+
+    /// Set the players focus.
+    /// @remarks
+    /// RAM cost: 0.1 GB * 16/4/1
+    ///
+    /// @returns True if the focus was changed.
+    #[wasm_bindgen(method, catch)]
+    pub fn setFocus(this: &Singularity, focus: bool) -> Result<bool, JsValue>;
+
+    /// Get a list of programs offered on the dark web.
+    /// @remarks
+    /// RAM cost: 1 GB * 16/4/1
+    ///
+    ///
+    /// This function allows the player to get a list of programs available for purchase
+    /// on the dark web. Players MUST have purchased Tor to get the list of programs
+    /// available. If Tor has not been purchased yet, this function will return an
+    /// empty list.
+    ///
+    /// @example
+    /// ```js
+    /// const programs = ns.getDarkwebPrograms();
+    /// ns.tprint(`Available programs are: ${programs.split(", ")}`);
+    /// ```
+    /// @returns - a list of programs available for purchase on the dark web, or [] if Tor has not
+    /// been purchased
+    #[wasm_bindgen(method, catch)]
+    pub fn getDarkwebPrograms(this: &Singularity) -> Result<Vec<String>, JsValue>;
+
+    /// Check the price of an exploit on the dark web
+    /// @remarks
+    /// RAM cost: 0.5 GB * 16/4/1
+    ///
+    ///
+    /// This function allows you to check the price of a darkweb exploit/program.
+    /// You MUST have a TOR router in order to use this function. The price returned
+    /// by this function is the same price you would see with buy -l from the terminal.
+    /// Returns the cost of the program if it has not been purchased yet, 0 if it
+    /// has already been purchased, or -1 if Tor has not been purchased (and thus
+    /// the program/exploit is not available for purchase).
+    ///
+    /// If the program does not exist, an error is thrown.
+    ///
+    ///
+    /// @example
+    /// ```js
+    /// const programName = "BruteSSH.exe";
+    /// const cost = ns.getDarkwebProgramCost(programName);
+    /// if (cost > 0) ns.tprint(`${programName} costs ${ns.formatMoney(cost)}`);
+    /// ```
+    /// @param programName - Name of program to check the price of
+    /// @returns Price of the specified darkweb program
+    /// (if not yet purchased), 0 if it has already been purchased, or -1 if Tor has not been
+    /// purchased. Throws an error if the specified program/exploit does not exist
+    #[wasm_bindgen(method, catch)]
+    pub fn getDarkwebProgramCost(this: &Singularity, programName: &str) -> Result<f64, JsValue>;
+
+    /// b1t_flum3 into a different BN.
+    /// @remarks
+    /// RAM cost: 16 GB * 16/4/1
+    ///
+    /// @param nextBN - BN number to jump to
+    /// @param callbackScript - Name of the script to launch in the next BN.
+    #[wasm_bindgen(method, catch)]
+    pub fn b1tflum3(
+        this: &Singularity,
+        nextBN: f64,
+        callbackScript: Option<&str>,
+    ) -> Result<(), JsValue>;
+
+    /// Destroy the w0r1d_d43m0n and move on to the next BN.
+    /// @remarks
+    /// RAM cost: 32 GB * 16/4/1
+    ///
+    /// You must have the special augment installed and the required hacking level
+    /// OR
+    /// Completed the final black op.
+    ///
+    /// @param nextBN - BN number to jump to
+    /// @param callbackScript - Name of the script to launch in the next BN.
+    #[wasm_bindgen(method, catch)]
+    pub fn destroyW0r1dD43m0n(
+        this: &Singularity,
+        nextBN: f64,
+        callbackScript: Option<&str>,
+    ) -> Result<(), JsValue>;
+
+    /// Get the current work the player is doing.
+    /// @remarks
+    /// RAM cost: 0.5 GB * 16/4/1
+    ///
+    /// @returns - An object representing the current work. Fields depend on the kind of work.
+    #[wasm_bindgen(method, catch)]
+    pub fn getCurrentWork(this: &Singularity) -> Result<JsValue, JsValue>;
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// Company position requirements and salary.
+    ///
+    /// @returns - An object representing the requirements and salary for a company/position combination.
+    #[derive(Debug, Clone, PartialEq)]
+    pub type CompanyPositionInfo;
+
+    #[wasm_bindgen(method, getter)]
+    pub fn name(this: &CompanyPositionInfo) -> JobName;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_name(this: &CompanyPositionInfo, value: JobName);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn field(this: &CompanyPositionInfo) -> JobField;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_field(this: &CompanyPositionInfo, value: JobField);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn nextPosition(this: &CompanyPositionInfo) -> Option<JobName>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_nextPosition(this: &CompanyPositionInfo, value: Option<JobName>);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn salary(this: &CompanyPositionInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_salary(this: &CompanyPositionInfo, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn requiredReputation(this: &CompanyPositionInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_requiredReputation(this: &CompanyPositionInfo, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn requiredSkills(this: &CompanyPositionInfo) -> Skills;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_requiredSkills(this: &CompanyPositionInfo, value: &Skills);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// Hacknet API
+    /// @remarks
+    /// Not all these functions are immediately available.
+    #[derive(Debug, Clone, PartialEq)]
+    pub type Hacknet;
+
+    /// Get the number of hacknet nodes you own.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// Returns the number of Hacknet Nodes you own.
+    ///
+    /// @returns Number of hacknet nodes.
+    #[wasm_bindgen(method, catch)]
+    pub fn numNodes(this: &Hacknet) -> Result<f64, JsValue>;
+
+    /// Get the maximum number of hacknet nodes.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// @returns Maximum number of hacknet nodes.
+    #[wasm_bindgen(method, catch)]
+    pub fn maxNumNodes(this: &Hacknet) -> Result<f64, JsValue>;
+
+    /// Purchase a new hacknet node.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// Purchases a new Hacknet Node. Returns a number with the index of the
+    /// Hacknet Node. This index is equivalent to the number at the end of
+    /// the Hacknet Node’s name (e.g. The Hacknet Node named `hacknet-node-4`
+    /// will have an index of 4).
+    ///
+    /// If the player cannot afford to purchase a new Hacknet Node then the function will return -1.
+    ///
+    /// @returns The index of the Hacknet Node or if the player cannot afford to purchase a new Hacknet Node the function will return -1.
+    #[wasm_bindgen(method, catch)]
+    pub fn purchaseNode(this: &Hacknet) -> Result<f64, JsValue>;
+
+    /// Get the price of the next hacknet node.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// Returns the cost of purchasing a new Hacknet Node.
+    ///
+    /// @returns Cost of purchasing a new Hacknet Node.
+    #[wasm_bindgen(method, catch)]
+    pub fn getPurchaseNodeCost(this: &Hacknet) -> Result<f64, JsValue>;
+
+    /// Get the stats of a hacknet node.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// Returns an object containing a variety of stats about the specified Hacknet Node.
+    ///
+    /// Note that for Hacknet Nodes, production refers to the amount of money the node generates.
+    /// For Hacknet Servers (the upgraded version of Hacknet Nodes), production refers to the
+    /// amount of hashes the node generates.
+    ///
+    /// @param index - Index/Identifier of Hacknet Node
+    /// @returns Object containing a variety of stats about the specified Hacknet Node.
+    #[wasm_bindgen(method, catch)]
+    pub fn getNodeStats(this: &Hacknet, index: f64) -> Result<NodeStats, JsValue>;
+
+    /// Upgrade the level of a hacknet node.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// Tries to upgrade the level of the specified Hacknet Node by n.
+    ///
+    /// Returns true if the Hacknet Node’s level is successfully upgraded by n
+    /// or if it is upgraded by some positive amount and the Node reaches its max level.
+    ///
+    /// Returns false otherwise.
+    ///
+    /// @param index - Index/Identifier of Hacknet Node.
+    /// @param n - Number of levels to purchase. Must be positive. Will be rounded to the nearest integer. Defaults to 1 if not specified.
+    /// @returns True if the Hacknet Node’s level is successfully upgraded, false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn upgradeLevel(this: &Hacknet, index: f64, n: Option<f64>) -> Result<bool, JsValue>;
+
+    /// Upgrade the RAM of a hacknet node.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// Tries to upgrade the specified Hacknet Node’s RAM n times.
+    /// Note that each upgrade doubles the Node’s RAM.
+    /// So this is equivalent to multiplying the Node’s RAM by 2 n.
+    ///
+    /// Returns true if the Hacknet Node’s RAM is successfully upgraded n times
+    /// or if it is upgraded some positive number of times and the Node reaches its max RAM.
+    ///
+    /// Returns false otherwise.
+    ///
+    /// @param index - Index/Identifier of Hacknet Node.
+    /// @param n - Number of times to upgrade RAM. Must be positive. Will be rounded to the nearest integer. Defaults to 1 if not specified.
+    /// @returns True if the Hacknet Node’s RAM is successfully upgraded, false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn upgradeRam(this: &Hacknet, index: f64, n: Option<f64>) -> Result<bool, JsValue>;
+
+    /// Upgrade the core of a hacknet node.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// Tries to purchase n cores for the specified Hacknet Node.
+    ///
+    /// Returns true if it successfully purchases n cores for the Hacknet Node
+    /// or if it purchases some positive amount and the Node reaches its max number of cores.
+    ///
+    /// Returns false otherwise.
+    ///
+    /// @param index - Index/Identifier of Hacknet Node.
+    /// @param n - Number of cores to purchase. Must be positive. Will be rounded to the nearest integer. Defaults to 1 if not specified.
+    /// @returns True if the Hacknet Node’s cores are successfully purchased, false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn upgradeCore(this: &Hacknet, index: f64, n: Option<f64>) -> Result<bool, JsValue>;
+
+    /// Upgrade the cache of a hacknet node.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// This function is only applicable for Hacknet Servers (the upgraded version of a Hacknet Node).
+    ///
+    /// Tries to upgrade the specified Hacknet Server’s cache n times.
+    ///
+    /// Returns true if it successfully upgrades the Server’s cache n times,
+    /// or if it purchases some positive amount and the Server reaches its max cache level.
+    ///
+    /// Returns false otherwise.
+    ///
+    /// @param index - Index/Identifier of Hacknet Node.
+    /// @param n - Number of cache levels to purchase. Must be positive. Will be rounded to the nearest integer. Defaults to 1 if not specified.
+    /// @returns True if the Hacknet Node’s cache level is successfully upgraded, false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn upgradeCache(this: &Hacknet, index: f64, n: Option<f64>) -> Result<bool, JsValue>;
+
+    /// Calculate the cost of upgrading hacknet node levels.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// Returns the cost of upgrading the specified Hacknet Node by n levels.
+    ///
+    /// If an invalid value for n is provided, then this function returns 0.
+    /// If the specified Hacknet Node is already at max level, then Infinity is returned.
+    ///
+    /// @param index - Index/Identifier of Hacknet Node.
+    /// @param n - Number of levels to upgrade. Must be positive. Will be rounded to the nearest integer. Defaults to 1 if not specified.
+    /// @returns Cost of upgrading the specified Hacknet Node.
+    #[wasm_bindgen(method, catch)]
+    pub fn getLevelUpgradeCost(this: &Hacknet, index: f64, n: Option<f64>) -> Result<f64, JsValue>;
+
+    /// Calculate the cost of upgrading hacknet node RAM.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// Returns the cost of upgrading the RAM of the specified Hacknet Node n times.
+    ///
+    /// If an invalid value for n is provided, then this function returns 0.
+    /// If the specified Hacknet Node already has max RAM, then Infinity is returned.
+    ///
+    /// @param index - Index/Identifier of Hacknet Node.
+    /// @param n - Number of times to upgrade RAM. Must be positive. Will be rounded to the nearest integer. Defaults to 1 if not specified.
+    /// @returns Cost of upgrading the specified Hacknet Node's RAM.
+    #[wasm_bindgen(method, catch)]
+    pub fn getRamUpgradeCost(this: &Hacknet, index: f64, n: Option<f64>) -> Result<f64, JsValue>;
+
+    /// Calculate the cost of upgrading hacknet node cores.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// Returns the cost of upgrading the number of cores of the specified Hacknet Node by n.
+    ///
+    /// If an invalid value for n is provided, then this function returns 0.
+    /// If the specified Hacknet Node is already at max level, then Infinity is returned.
+    ///
+    /// @param index - Index/Identifier of Hacknet Node.
+    /// @param n - Number of times to upgrade cores. Must be positive. Will be rounded to the nearest integer. Defaults to 1 if not specified.
+    /// @returns Cost of upgrading the specified Hacknet Node's number of cores.
+    #[wasm_bindgen(method, catch)]
+    pub fn getCoreUpgradeCost(this: &Hacknet, index: f64, n: Option<f64>) -> Result<f64, JsValue>;
+
+    /// Calculate the cost of upgrading hacknet node cache.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// This function is only applicable for Hacknet Servers (the upgraded version of a Hacknet Node).
+    ///
+    /// Returns the cost of upgrading the cache level of the specified Hacknet Server by n.
+    ///
+    /// If an invalid value for n is provided, then this function returns 0.
+    /// If the specified Hacknet Node is already at max level, then Infinity is returned.
+    ///
+    /// @param index - Index/Identifier of Hacknet Node.
+    /// @param n - Number of times to upgrade cache. Must be positive. Will be rounded to the nearest integer. Defaults to 1 if not specified.
+    /// @returns Cost of upgrading the specified Hacknet Node's cache.
+    #[wasm_bindgen(method, catch)]
+    pub fn getCacheUpgradeCost(this: &Hacknet, index: f64, n: Option<f64>) -> Result<f64, JsValue>;
+
+    /// Get the total number of hashes stored.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// This function is only applicable for Hacknet Servers (the upgraded version of a Hacknet Node).
+    ///
+    /// Returns the number of hashes you have.
+    ///
+    /// @returns Number of hashes you have.
+    #[wasm_bindgen(method, catch)]
+    pub fn numHashes(this: &Hacknet) -> Result<f64, JsValue>;
+
+    /// Get the maximum number of hashes you can store.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// This function is only applicable for Hacknet Servers (the upgraded version of a Hacknet Node).
+    ///
+    /// Returns the number of hashes you can store.
+    ///
+    /// @returns Number of hashes you can store.
+    #[wasm_bindgen(method, catch)]
+    pub fn hashCapacity(this: &Hacknet) -> Result<f64, JsValue>;
+
+    /// Get the cost of a hash upgrade.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// This function is only applicable for Hacknet Servers (the upgraded version of a Hacknet Node).
+    ///
+    /// Returns the number of hashes required for the specified upgrade. The name of the upgrade must be an exact match.
+    ///
+    /// @example
+    /// ```js
+    /// const upgradeName = "Sell for Corporation Funds";
+    /// if (ns.hacknet.numHashes() > ns.hacknet.hashCost(upgradeName)) {
+    ///   ns.hacknet.spendHashes(upgradeName);
+    /// }
+    /// ```
+    /// @param upgName - Name of the upgrade of Hacknet Node.
+    /// @param count - Number of upgrades to buy at once. Defaults to 1 if not specified.
+    /// @returns Number of hashes required for the specified upgrade.
+    #[wasm_bindgen(method, catch)]
+    pub fn hashCost(this: &Hacknet, upgName: &str, count: Option<f64>) -> Result<f64, JsValue>;
+
+    /// Purchase a hash upgrade.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// This function is only applicable for Hacknet Servers (the upgraded version of a Hacknet Node).
+    ///
+    /// Spend the hashes generated by your Hacknet Servers on an upgrade.
+    /// Returns a boolean value - true if the upgrade is successfully purchased, and false otherwise.
+    ///
+    /// The name of the upgrade must be an exact match.
+    /// The `upgTarget` argument is used for upgrades such as `Reduce Minimum Security`, which applies to a specific server.
+    /// In this case, the `upgTarget` argument must be the hostname of the server.
+    ///
+    /// @example
+    /// ```js
+    /// // For upgrades where no target is required
+    /// ns.hacknet.spendHashes("Sell for Corporation Funds");
+    /// // For upgrades requiring a target
+    /// ns.hacknet.spendHashes("Increase Maximum Money", "foodnstuff");
+    /// ```
+    /// @param upgName - Name of the upgrade of Hacknet Node.
+    /// @param upgTarget - Object to which upgrade applies. Required for certain upgrades.
+    /// @param count - Number of upgrades to buy at once. Defaults to 1 if not specified.
+    /// For compatibility reasons, upgTarget must be specified, even if it is not used, in order to specify count.
+    /// @returns True if the upgrade is successfully purchased, and false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn spendHashes(
+        this: &Hacknet,
+        upgName: &str,
+        upgTarget: Option<&str>,
+        count: Option<f64>,
+    ) -> Result<bool, JsValue>;
+
+    /// Get the list of hash upgrades
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// This function is only applicable for Hacknet Servers (the upgraded version of a Hacknet Node).
+    ///
+    /// Returns the list of all available hash upgrades that can be used in the spendHashes function.
+    /// @example
+    /// ```js
+    /// const upgrades = ns.hacknet.getHashUpgrades(); // ["Sell for Money","Sell for Corporation Funds",...]
+    /// ```
+    /// @returns An array containing the available upgrades
+    #[wasm_bindgen(method, catch)]
+    pub fn getHashUpgrades(this: &Hacknet) -> Result<Vec<String>, JsValue>;
+
+    /// Get the level of a hash upgrade.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// This function is only applicable for Hacknet Servers (the upgraded version of a Hacknet Node).
+    ///
+    /// @returns Level of the upgrade.
+    #[wasm_bindgen(method, catch)]
+    pub fn getHashUpgradeLevel(this: &Hacknet, upgName: &str) -> Result<f64, JsValue>;
+
+    /// Get the multiplier to study.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// This function is only applicable for Hacknet Servers (the upgraded version of a Hacknet Node).
+    ///
+    /// @returns Multiplier.
+    #[wasm_bindgen(method, catch)]
+    pub fn getStudyMult(this: &Hacknet) -> Result<f64, JsValue>;
+
+    /// Get the multiplier to training.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// This function is only applicable for Hacknet Servers (the upgraded version of a Hacknet Node).
+    ///
+    /// @returns Multiplier.
+    #[wasm_bindgen(method, catch)]
+    pub fn getTrainingMult(this: &Hacknet) -> Result<f64, JsValue>;
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// Bladeburner API
+    /// @remarks
+    /// You have to be employed in the Bladeburner division and be in BitNode-7
+    /// or have Source-File 7 in order to use this API.
+    #[derive(Debug, Clone, PartialEq)]
+    pub type Bladeburner;
+
+    /// List all contracts.
+    /// @remarks
+    /// RAM cost: 0.4 GB
+    ///
+    /// Returns an array of strings containing the names of all Bladeburner contracts.
+    ///
+    /// @returns Array of strings containing the names of all Bladeburner contracts.
+    #[wasm_bindgen(method, catch)]
+    pub fn getContractNames(this: &Bladeburner) -> Result<Vec<String>, JsValue>;
+
+    /// List all operations.
+    /// @remarks
+    /// RAM cost: 0.4 GB
+    ///
+    /// Returns an array of strings containing the names of all Bladeburner operations.
+    ///
+    /// @returns Array of strings containing the names of all Bladeburner operations.
+    #[wasm_bindgen(method, catch)]
+    pub fn getOperationNames(this: &Bladeburner) -> Result<Vec<String>, JsValue>;
+
+    /// List all black ops.
+    /// @remarks
+    /// RAM cost: 0.4 GB
+    ///
+    /// Returns an array of strings containing the names of all Bladeburner Black Ops.
+    ///
+    /// @returns Array of strings containing the names of all Bladeburner Black Ops.
+    #[wasm_bindgen(method, catch)]
+    pub fn getBlackOpNames(this: &Bladeburner) -> Result<Vec<String>, JsValue>;
+
+    /// Get an object with the name and rank requirement of the next BlackOp that can be completed.
+    /// @remarks
+    /// RAM cost: 2 GB
+    ///
+    /// Returns the name and rank requirement for the available BlackOp.
+    /// Returns `null` if no BlackOps remain in the BitNode.
+    ///
+    /// @returns An object with the `.name` and `.rank` properties of the available BlackOp, or `null`.
+    #[wasm_bindgen(method, catch)]
+    pub fn getNextBlackOp(this: &Bladeburner) -> Result<JsValue, JsValue>;
+
+    /// List all general actions.
+    /// @remarks
+    /// RAM cost: 0.4 GB
+    ///
+    /// Returns an array of strings containing the names of all general Bladeburner actions.
+    ///
+    /// @returns Array of strings containing the names of all general Bladeburner actions.
+    #[wasm_bindgen(method, catch)]
+    pub fn getGeneralActionNames(this: &Bladeburner) -> Result<Vec<String>, JsValue>;
+
+    /// List all skills.
+    /// @remarks
+    /// RAM cost: 0.4 GB
+    ///
+    /// Returns an array of strings containing the names of all general Bladeburner skills.
+    ///
+    /// @returns Array of strings containing the names of all general Bladeburner skills.
+    #[wasm_bindgen(method, catch)]
+    pub fn getSkillNames(this: &Bladeburner) -> Result<Vec<String>, JsValue>;
+
+    /// Start an action.
+    /// @remarks
+    /// RAM cost: 4 GB
+    ///
+    /// Attempts to start the specified Bladeburner action.
+    /// Returns true if the action was started successfully, and false otherwise.
+    /// @example
+    /// ```js
+    /// ns.bladeburner.startAction("Contracts", "Tracking")
+    ///
+    /// // This will start the Bladeburner Contracts action of Tracking
+    /// ```
+    ///
+    /// @param type - Type of action.
+    /// @param name - Name of action. Must be an exact match
+    /// @returns True if the action was started successfully, and false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn startAction(this: &Bladeburner, r#type: &str, name: &str) -> Result<bool, JsValue>;
+
+    /// Stop current action.
+    /// @remarks
+    /// RAM cost: 2 GB
+    ///
+    /// Stops the current Bladeburner action.
+    #[wasm_bindgen(method, catch)]
+    pub fn stopBladeburnerAction(this: &Bladeburner) -> Result<(), JsValue>;
+
+    /// Get current action.
+    /// @remarks
+    /// RAM cost: 1 GB
+    ///
+    /// Returns an object that represents the player’s current Bladeburner action.
+    /// If the player is not performing an action, the function will return an object with the ‘type’ property set to “Idle”.
+    ///
+    /// @returns Object that represents the player’s current Bladeburner action.
+    #[wasm_bindgen(method, catch)]
+    pub fn getCurrentAction(this: &Bladeburner) -> Result<BladeburnerCurAction, JsValue>;
+
+    /// Get the time to complete an action.
+    /// @remarks
+    /// RAM cost: 4 GB
+    ///
+    /// Returns the number of milliseconds it takes to complete the specified action
+    ///
+    /// @param type - Type of action.
+    /// @param name - Name of action. Must be an exact match.
+    /// @returns Number of milliseconds it takes to complete the specified action.
+    #[wasm_bindgen(method, catch)]
+    pub fn getActionTime(this: &Bladeburner, r#type: &str, name: &str) -> Result<f64, JsValue>;
+
+    /// Get the time elapsed on current action.
+    /// @remarks
+    /// RAM cost: 4 GB
+    ///
+    /// Returns the number of milliseconds already spent on the current action.
+    ///
+    /// @returns Number of milliseconds already spent on the current action.
+    #[wasm_bindgen(method, catch)]
+    pub fn getActionCurrentTime(this: &Bladeburner) -> Result<f64, JsValue>;
+
+    /// Get estimate success chance of an action.
+    /// @remarks
+    /// RAM cost: 4 GB
+    ///
+    /// Returns the estimated success chance for the specified action.
+    /// This chance is returned as a decimal value, NOT a percentage
+    /// (e.g. if you have an estimated success chance of 80%, then this function will return 0.80, NOT 80).
+    /// Returns 2 values, value[0] - MIN Chance, value[1] - MAX Chance
+    ///
+    /// @param type - Type of action.
+    /// @param name - Name of action. Must be an exact match.
+    /// @returns Estimated success chance for the specified action.
+    #[wasm_bindgen(method, catch)]
+    pub fn getActionEstimatedSuccessChance(
+        this: &Bladeburner,
+        r#type: &str,
+        name: &str,
+    ) -> Result<Vec<f64>, JsValue>;
+
+    /// Get the reputation gain of an action.
+    /// @remarks
+    /// RAM cost: 4 GB
+    ///
+    /// Returns the average Bladeburner reputation gain for successfully
+    /// completing the specified action.
+    /// Note that this value is an ‘average’ and the real reputation gain may vary slightly from this value.
+    ///
+    /// @param type - Type of action.
+    /// @param name - Name of action. Must be an exact match.
+    /// @param level - Optional number. Action level at which to calculate the gain. Will be the action's current level if not given.
+    /// @returns Average Bladeburner reputation gain for successfully completing the specified action.
+    #[wasm_bindgen(method, catch)]
+    pub fn getActionRepGain(
+        this: &Bladeburner,
+        r#type: &str,
+        name: &str,
+        level: f64,
+    ) -> Result<f64, JsValue>;
+
+    /// Get action count remaining.
+    /// @remarks
+    /// RAM cost: 4 GB
+    ///
+    /// Returns the remaining count of the specified action.
+    ///
+    /// Note that this is meant to be used for Contracts and Operations.
+    /// This function will return ‘Infinity’ for actions such as Training and Field Analysis.
+    /// This function will return 1 for BlackOps not yet completed regardless of whether
+    /// the player has the required rank to attempt the mission or not.
+    ///
+    /// @param type - Type of action.
+    /// @param name - Name of action. Must be an exact match.
+    /// @returns Remaining count of the specified action.
+    #[wasm_bindgen(method, catch)]
+    pub fn getActionCountRemaining(
+        this: &Bladeburner,
+        r#type: &str,
+        name: &str,
+    ) -> Result<f64, JsValue>;
+
+    /// Get the maximum level of an action.
+    /// @remarks
+    /// RAM cost: 4 GB
+    ///
+    /// Returns the maximum level for this action.
+    ///
+    /// Returns -1 if an invalid action is specified.
+    ///
+    /// @param type - Type of action.
+    /// @param name - Name of action. Must be an exact match.
+    /// @returns Maximum level of the specified action.
+    #[wasm_bindgen(method, catch)]
+    pub fn getActionMaxLevel(this: &Bladeburner, r#type: &str, name: &str) -> Result<f64, JsValue>;
+
+    /// Get the current level of an action.
+    /// @remarks
+    /// RAM cost: 4 GB
+    ///
+    /// Returns the current level of this action.
+    ///
+    /// Returns -1 if an invalid action is specified.
+    ///
+    /// @param type - Type of action.
+    /// @param name - Name of action. Must be an exact match.
+    /// @returns Current level of the specified action.
+    #[wasm_bindgen(method, catch)]
+    pub fn getActionCurrentLevel(
+        this: &Bladeburner,
+        r#type: &str,
+        name: &str,
+    ) -> Result<f64, JsValue>;
+
+    /// Get whether an action is set to autolevel.
+    /// @remarks
+    /// RAM cost: 4 GB
+    ///
+    /// Return a boolean indicating whether or not this action is currently set to autolevel.
+    ///
+    /// Returns false if an invalid action is specified.
+    ///
+    /// @param type - Type of action.
+    /// @param name - Name of action. Must be an exact match.
+    /// @returns True if the action is set to autolevel, and false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn getActionAutolevel(
+        this: &Bladeburner,
+        r#type: &str,
+        name: &str,
+    ) -> Result<bool, JsValue>;
+
+    /// Get action successes.
+    /// @remarks
+    /// RAM cost: 4 GB
+    ///
+    /// Return a number with how many successes you have with action.
+    ///
+    /// @param type - Type of action.
+    /// @param name - Name of action. Must be an exact match.
+    /// @returns a number with how many successes you have with action.
+    #[wasm_bindgen(method, catch)]
+    pub fn getActionSuccesses(this: &Bladeburner, r#type: &str, name: &str)
+        -> Result<f64, JsValue>;
+
+    /// Set an action autolevel.
+    /// @remarks
+    /// RAM cost: 4 GB
+    ///
+    /// Enable/disable autoleveling for the specified action.
+    ///
+    /// @param type - Type of action.
+    /// @param name - Name of action. Must be an exact match.
+    /// @param autoLevel - Whether or not to autolevel this action
+    #[wasm_bindgen(method, catch)]
+    pub fn setActionAutolevel(
+        this: &Bladeburner,
+        r#type: &str,
+        name: &str,
+        autoLevel: bool,
+    ) -> Result<(), JsValue>;
+
+    /// Set the level of an action.
+    /// @remarks
+    /// RAM cost: 4 GB
+    ///
+    /// Set the level for the specified action.
+    ///
+    /// @param type - Type of action.
+    /// @param name - Name of action. Must be an exact match.
+    /// @param level - Level to set this action to.
+    #[wasm_bindgen(method, catch)]
+    pub fn setActionLevel(
+        this: &Bladeburner,
+        r#type: &str,
+        name: &str,
+        level: f64,
+    ) -> Result<(), JsValue>;
+
+    /// Get player bladeburner rank.
+    /// @remarks
+    /// RAM cost: 4 GB
+    ///
+    /// Returns the player’s Bladeburner Rank.
+    ///
+    /// @returns Player’s Bladeburner Rank.
+    #[wasm_bindgen(method, catch)]
+    pub fn getRank(this: &Bladeburner) -> Result<f64, JsValue>;
+
+    /// Get black op required rank.
+    /// @remarks
+    /// RAM cost: 2 GB
+    ///
+    /// Returns the rank required to complete this BlackOp.
+    ///
+    /// Returns -1 if an invalid action is specified.
+    ///
+    /// @param name - Name of BlackOp. Must be an exact match.
+    /// @returns Rank required to complete this BlackOp.
+    #[wasm_bindgen(method, catch)]
+    pub fn getBlackOpRank(this: &Bladeburner, name: &str) -> Result<f64, JsValue>;
+
+    /// Get bladeburner skill points.
+    /// @remarks
+    /// RAM cost: 4 GB
+    ///
+    /// Returns the number of Bladeburner skill points you have.
+    ///
+    /// @returns Number of Bladeburner skill points you have.
+    #[wasm_bindgen(method, catch)]
+    pub fn getSkillPoints(this: &Bladeburner) -> Result<f64, JsValue>;
+
+    /// Get skill level.
+    /// @remarks
+    /// RAM cost: 4 GB
+    ///
+    /// This function returns your level in the specified skill.
+    ///
+    /// The function returns -1 if an invalid skill name is passed in.
+    ///
+    /// @param skillName - Name of skill. Case-sensitive and must be an exact match.
+    /// @returns Level in the specified skill.
+    #[wasm_bindgen(method, catch)]
+    pub fn getSkillLevel(this: &Bladeburner, skillName: &str) -> Result<f64, JsValue>;
+
+    /// Get cost to upgrade skill.
+    /// @remarks
+    /// RAM cost: 4 GB
+    ///
+    /// This function returns the number of skill points needed to upgrade the specified skill the specified number of times.
+    ///
+    /// The function returns -1 if an invalid skill name is passed in.
+    ///
+    /// @param skillName - Name of skill. Case-sensitive and must be an exact match.
+    /// @param count - Number of times to upgrade the skill. Defaults to 1 if not specified.
+    /// @returns Number of skill points needed to upgrade the specified skill.
+    #[wasm_bindgen(method, catch)]
+    pub fn getSkillUpgradeCost(
+        this: &Bladeburner,
+        skillName: &str,
+        count: Option<f64>,
+    ) -> Result<f64, JsValue>;
+
+    /// Upgrade skill.
+    /// @remarks
+    /// RAM cost: 4 GB
+    ///
+    /// Attempts to upgrade the specified Bladeburner skill the specified number of times.
+    ///
+    /// Returns true if the skill is successfully upgraded, and false otherwise.
+    ///
+    /// @param skillName - Name of skill to be upgraded. Case-sensitive and must be an exact match.
+    /// @param count - Number of times to upgrade the skill. Defaults to 1 if not specified.
+    /// @returns true if the skill is successfully upgraded, and false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn upgradeSkill(
+        this: &Bladeburner,
+        skillName: &str,
+        count: Option<f64>,
+    ) -> Result<bool, JsValue>;
+
+    /// Get team size.
+    /// @remarks
+    /// RAM cost: 4 GB
+    ///
+    /// Returns the number of Bladeburner team members you have assigned to the specified action.
+    ///
+    /// Setting a team is only applicable for Operations and BlackOps. This function will return 0 for other action types.
+    ///
+    /// @param type - Type of action.
+    /// @param name - Name of action. Must be an exact match.
+    /// @returns Number of Bladeburner team members that were assigned to the specified action.
+    #[wasm_bindgen(method, catch)]
+    pub fn getTeamSize(this: &Bladeburner, r#type: &str, name: &str) -> Result<f64, JsValue>;
+
+    /// Set team size.
+    /// @remarks
+    /// RAM cost: 4 GB
+    ///
+    /// Set the team size for the specified Bladeburner action.
+    ///
+    /// Returns the team size that was set, or -1 if the function failed.
+    ///
+    /// @param type - Type of action.
+    /// @param name - Name of action. Must be an exact match.
+    /// @param size - Number of team members to set. Will be converted using Math.round().
+    /// @returns Number of Bladeburner team members you assigned to the specified action.
+    #[wasm_bindgen(method, catch)]
+    pub fn setTeamSize(
+        this: &Bladeburner,
+        r#type: &str,
+        name: &str,
+        size: f64,
+    ) -> Result<f64, JsValue>;
+
+    /// Get estimated population in city.
+    /// @remarks
+    /// RAM cost: 4 GB
+    ///
+    /// Returns the estimated number of Synthoids in the specified city,
+    /// or -1 if an invalid city was specified.
+    ///
+    /// @param city - Name of city. Case-sensitive
+    /// @returns Estimated number of Synthoids in the specified city.
+    #[wasm_bindgen(method, catch)]
+    pub fn getCityEstimatedPopulation(this: &Bladeburner, city: CityName) -> Result<f64, JsValue>;
+
+    /// Get number of communities in a city.
+    /// @remarks
+    /// RAM cost: 4 GB
+    ///
+    /// Returns the estimated number of Synthoid communities in the specified city,
+    /// or -1 if an invalid city was specified.
+    ///
+    /// @param city - Name of city. Case-sensitive
+    /// @returns Number of Synthoids communities in the specified city.
+    #[wasm_bindgen(method, catch)]
+    pub fn getCityCommunities(this: &Bladeburner, city: CityName) -> Result<f64, JsValue>;
+
+    /// Get chaos of a city.
+    /// @remarks
+    /// RAM cost: 4 GB
+    ///
+    /// Returns the chaos in the specified city,
+    /// or -1 if an invalid city was specified.
+    ///
+    /// @param city - Name of city. Case-sensitive
+    /// @returns Chaos in the specified city.
+    #[wasm_bindgen(method, catch)]
+    pub fn getCityChaos(this: &Bladeburner, city: CityName) -> Result<f64, JsValue>;
+
+    /// Get current city.
+    /// @remarks
+    /// RAM cost: 4 GB
+    ///
+    /// Returns the city that the player is currently in (for Bladeburner).
+    ///
+    /// @returns City that the player is currently in (for Bladeburner).
+    #[wasm_bindgen(method, catch)]
+    pub fn getCity(this: &Bladeburner) -> Result<CityName, JsValue>;
+
+    /// Travel to another city in Bladeburner.
+    /// @remarks
+    /// RAM cost: 4 GB
+    /// Attempts to switch to the specified city (for Bladeburner only).
+    ///
+    /// Returns true if successful, and false otherwise
+    ///
+    /// @param city - Name of city. Case-sensitive
+    /// @returns true if successful, and false otherwise
+    #[wasm_bindgen(method, catch)]
+    pub fn switchCity(this: &Bladeburner, city: CityName) -> Result<bool, JsValue>;
+
+    /// Get Bladeburner stamina.
+    /// @remarks
+    /// RAM cost: 4 GB
+    /// Returns an array with two elements:
+    /// * [Current stamina, Max stamina]
+    /// @example
+    /// ```js
+    /// function getStaminaPercentage() {
+    ///    const [current, max] = ns.bladeburner.getStamina();
+    ///    return current / max;
+    /// }
+    /// ```
+    /// @returns Array containing current stamina and max stamina.
+    #[wasm_bindgen(method, catch)]
+    pub fn getStamina(this: &Bladeburner) -> Result<Vec<f64>, JsValue>;
+
+    /// Join the Bladeburner faction.
+    /// @remarks
+    /// RAM cost: 4 GB
+    /// Attempts to join the Bladeburner faction.
+    ///
+    /// Returns true if you successfully join the Bladeburner faction, or if you are already a member.
+    ///
+    /// Returns false otherwise.
+    ///
+    /// @returns True if you successfully join the Bladeburner faction, or if you are already a member, false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn joinBladeburnerFaction(this: &Bladeburner) -> Result<bool, JsValue>;
+
+    /// Join the Bladeburner division.
+    /// @remarks
+    /// RAM cost: 4 GB
+    ///
+    /// Attempts to join the Bladeburner division.
+    ///
+    /// Returns true if you successfully join the Bladeburner division, or if you are already a member.
+    ///
+    /// Returns false otherwise.
+    ///
+    /// @returns True if you successfully join the Bladeburner division, or if you are already a member, false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn joinBladeburnerDivision(this: &Bladeburner) -> Result<bool, JsValue>;
+
+    /// Get Bladeburner bonus time.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// Returns the amount of accumulated “bonus time” (milliseconds) for the Bladeburner mechanic.
+    ///
+    /// “Bonus time” is accumulated when the game is offline or if the game is inactive in the browser.
+    ///
+    /// “Bonus time” makes the game progress faster, up to 5x the normal speed.
+    /// For example, if an action takes 30 seconds to complete, but you’ve accumulated over
+    /// 30 seconds in bonus time, then the action will only take 6 seconds in real life to complete.
+    ///
+    /// @returns Amount of accumulated “bonus time” (milliseconds) for the Bladeburner mechanic.
+    #[wasm_bindgen(method, catch)]
+    pub fn getBonusTime(this: &Bladeburner) -> Result<f64, JsValue>;
+
+    /// Sleep until the next Bladeburner update has happened.
+    /// @remarks
+    /// RAM cost: 1 GB
+    ///
+    /// The amount of real time spent asleep between updates can vary due to "bonus time"
+    /// (usually 1 second).
+    ///
+    /// @returns Promise that resolves to the number of milliseconds of Bladeburner time
+    /// that were processed in the previous update (1000 - 5000 ms).
+    ///
+    /// @example
+    /// ```js
+    /// while (true) {
+    ///   const duration = await ns.bladeburner.nextUpdate();
+    ///   ns.print(`Bladeburner Division completed ${ns.tFormat(duration)} of actions.`);
+    ///   ns.print(`Bonus time remaining: ${ns.tFormat(ns.bladeburner.getBonusTime())}`);
+    ///   // Manage the Bladeburner division
+    /// }
+    /// ```
+    #[wasm_bindgen(method, catch)]
+    pub async fn nextUpdate(this: &Bladeburner) -> Result<f64, JsValue>;
+
+    /// Returns whether player is a member of Bladeburner division. Does not require API access.
+    /// @remarks
+    /// RAM cost: 1 GB
+    ///
+    /// @returns whether player is a member of Bladeburner division.
+    #[wasm_bindgen(method, catch)]
+    pub fn inBladeburner(this: &Bladeburner) -> Result<bool, JsValue>;
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// Coding Contract API
+    #[derive(Debug, Clone, PartialEq)]
+    pub type CodingContract;
+
+    /// Attempts a coding contract, returning a reward string on success or empty string on failure.
+    /// @remarks
+    /// RAM cost: 10 GB
+    ///
+    /// Attempts to solve the Coding Contract with the provided solution.
+    ///
+    /// @example
+    /// ```js
+    /// const reward = codingcontract.attempt(yourSolution, filename, hostname);
+    /// if (reward) {
+    ///   ns.tprint(`Contract solved successfully! Reward: ${reward}`)
+    /// } else ns.tprint("Failed to solve contract.")
+    /// ```
+    ///
+    /// @param answer - Attempted solution for the contract.
+    /// @param filename - Filename of the contract.
+    /// @param host - Hostname of the server containing the contract. Optional. Defaults to current server if not
+    /// provided.
+    /// @returns A reward description string on success, or an empty string on failure.
+    #[wasm_bindgen(method, catch)]
+    pub fn attempt(
+        this: &CodingContract,
+        answer: &JsValue,
+        filename: &str,
+        host: Option<&str>,
+    ) -> Result<String, JsValue>;
+
+    /// Get the type of a coding contract.
+    /// @remarks
+    /// RAM cost: 5 GB
+    ///
+    /// Returns a name describing the type of problem posed by the Coding Contract.
+    /// (e.g. Find Largest Prime Factor, Total Ways to Sum, etc.)
+    ///
+    /// @param filename - Filename of the contract.
+    /// @param host - Hostname of the server containing the contract. Optional. Defaults to current server if not provided.
+    /// @returns Name describing the type of problem posed by the Coding Contract.
+    #[wasm_bindgen(method, catch)]
+    pub fn getContractType(
+        this: &CodingContract,
+        filename: &str,
+        host: Option<&str>,
+    ) -> Result<String, JsValue>;
+
+    /// Get the description.
+    /// @remarks
+    /// RAM cost: 5 GB
+    ///
+    /// Get the full text description for the problem posed by the Coding Contract.
+    ///
+    /// @param filename - Filename of the contract.
+    /// @param host - Hostname of the server containing the contract. Optional. Defaults to current server if not provided.
+    /// @returns Contract’s text description.
+    #[wasm_bindgen(method, catch)]
+    pub fn getDescription(
+        this: &CodingContract,
+        filename: &str,
+        host: Option<&str>,
+    ) -> Result<String, JsValue>;
+
+    /// Get the input data.
+    /// @remarks
+    /// RAM cost: 5 GB
+    ///
+    /// Get the data associated with the specific Coding Contract.
+    /// Note that this is not the same as the contract’s description.
+    /// This is just the data that the contract wants you to act on in order to solve the contract.
+    ///
+    /// @param filename - Filename of the contract.
+    /// @param host - Host of the server containing the contract. Optional. Defaults to current server if not provided.
+    /// @returns The specified contract’s data, data type depends on contract type.
+    #[wasm_bindgen(method, catch)]
+    pub fn getData(
+        this: &CodingContract,
+        filename: &str,
+        host: Option<&str>,
+    ) -> Result<JsValue, JsValue>;
+
+    /// Get the number of attempts remaining.
+    /// @remarks
+    /// RAM cost: 2 GB
+    ///
+    /// Get the number of tries remaining on the contract before it self-destructs.
+    ///
+    /// @param filename - Filename of the contract.
+    /// @param host - Hostname of the server containing the contract. Optional. Defaults to current server if not provided.
+    /// @returns How many attempts are remaining for the contract.
+    #[wasm_bindgen(method, catch)]
+    pub fn getNumTriesRemaining(
+        this: &CodingContract,
+        filename: &str,
+        host: Option<&str>,
+    ) -> Result<f64, JsValue>;
+
+    /// Generate a dummy contract.
+    /// @remarks
+    /// RAM cost: 2 GB
+    ///
+    /// Generate a dummy contract on the home computer with no reward. Used to test various algorithms.
+    ///
+    /// @param type - Type of contract to generate
+    #[wasm_bindgen(method, catch)]
+    pub fn createDummyContract(this: &CodingContract, r#type: &str) -> Result<(), JsValue>;
+
+    /// List all contract types.
+    /// @remarks
+    /// RAM cost: 2 GB
+    #[wasm_bindgen(method, catch)]
+    pub fn getContractTypes(this: &CodingContract) -> Result<Vec<String>, JsValue>;
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// Gang API
+    /// @remarks
+    /// If you are not in BitNode-2, then you must have Source-File 2 in order to use this API.
+    #[derive(Debug, Clone, PartialEq)]
+    pub type Gang;
+
+    /// Create a gang.
+    /// @remarks
+    /// RAM cost: 1GB
+    ///
+    /// Create a gang with the specified faction.
+    /// @returns True if the gang was created, false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn createGang(this: &Gang, faction: &str) -> Result<bool, JsValue>;
+
+    /// Check if you're in a gang.
+    /// @remarks
+    /// RAM cost: 1GB
+    /// @returns True if you're in a gang, false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn inGang(this: &Gang) -> Result<bool, JsValue>;
+
+    /// List all gang members.
+    /// @remarks
+    /// RAM cost: 1 GB
+    ///
+    /// Get the names of all Gang members
+    ///
+    /// @returns Names of all Gang members.
+    #[wasm_bindgen(method, catch)]
+    pub fn getMemberNames(this: &Gang) -> Result<Vec<String>, JsValue>;
+
+    /// Rename a Gang member to a new unique name.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// Rename a Gang Member if none already has the new name.
+    /// @param memberName - Name of the member to change.
+    /// @param newName - New name for that gang member.
+    /// @returns True if successful, and false if not.
+    #[wasm_bindgen(method, catch)]
+    pub fn renameMember(this: &Gang, memberName: &str, newName: &str) -> Result<bool, JsValue>;
+
+    /// Get information about your gang.
+    /// @remarks
+    /// RAM cost: 2 GB
+    ///
+    /// Get general information about the gang.
+    ///
+    /// @returns Object containing general information about the gang.
+    #[wasm_bindgen(method, catch)]
+    pub fn getGangInformation(this: &Gang) -> Result<GangGenInfo, JsValue>;
+
+    /// Get information about the other gangs.
+    /// @remarks
+    /// RAM cost: 2 GB
+    ///
+    /// Get territory and power information about all gangs.
+    ///
+    /// @returns Object containing territory and power information about all gangs.
+    #[wasm_bindgen(method, catch)]
+    pub fn getOtherGangInformation(this: &Gang) -> Result<GangOtherInfo, JsValue>;
+
+    /// Get information about a specific gang member.
+    /// @remarks
+    /// RAM cost: 2 GB
+    ///
+    /// Get stat and equipment-related information about a Gang Member
+    ///
+    /// @param name - Name of member.
+    /// @returns Object containing stat and equipment-related information about a Gang Member.
+    #[wasm_bindgen(method, catch)]
+    pub fn getMemberInformation(this: &Gang, name: &str) -> Result<GangMemberInfo, JsValue>;
+
+    /// Check if you can recruit a new gang member.
+    /// @remarks
+    /// RAM cost: 1 GB
+    ///
+    /// Returns a boolean indicating whether a member can currently be recruited.
+    ///
+    /// Once you have successfully created a gang by using the function
+    /// {@link Gang.createGang | createGang}, you can immediately recruit a small
+    /// number of members to your gang. After you have recruited the founding
+    /// members, to recruit another member you must increase your respect. The
+    /// more members you want to recruit, the more respect you require. If your
+    /// gang has the maximum number of members, then this function would return
+    /// false.
+    ///
+    /// @returns True if a member can currently be recruited, false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn canRecruitMember(this: &Gang) -> Result<bool, JsValue>;
+
+    /// Check how many gang members you can currently recruit.
+    /// @remarks
+    /// RAM cost: 1 GB
+    ///
+    /// @returns Number indicating how many members can be recruited,
+    /// considering current reputation and gang size.
+    #[wasm_bindgen(method, catch)]
+    pub fn getRecruitsAvailable(this: &Gang) -> Result<f64, JsValue>;
+
+    /// Check the amount of Respect needed for your next gang recruit.
+    /// @remarks
+    /// RAM cost: 1 GB
+    ///
+    /// @returns The static number value of Respect needed for the next
+    /// recruit, with consideration to your current gang size.
+    /// Returns `Infinity` if you have reached the gang size limit.
+    #[wasm_bindgen(method, catch)]
+    pub fn respectForNextRecruit(this: &Gang) -> Result<f64, JsValue>;
+
+    /// Recruit a new gang member.
+    /// @remarks
+    /// RAM cost: 2 GB
+    ///
+    /// Attempt to recruit a new gang member.
+    ///
+    /// Possible reasons for failure:
+    /// * Cannot currently recruit a new member
+    /// * There already exists a member with the specified name
+    ///
+    /// @param name - Name of member to recruit.
+    /// @returns True if the member was successfully recruited, false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn recruitMember(this: &Gang, name: &str) -> Result<bool, JsValue>;
+
+    /// List member task names.
+    /// @remarks
+    /// RAM cost: 1 GB
+    ///
+    /// Get the name of all valid tasks that Gang members can be assigned to.
+    ///
+    /// @returns All valid tasks that Gang members can be assigned to.
+    #[wasm_bindgen(method, catch)]
+    pub fn getTaskNames(this: &Gang) -> Result<Vec<String>, JsValue>;
+
+    /// Set gang member to task.
+    /// @remarks
+    /// RAM cost: 2 GB
+    ///
+    /// Attempts to assign the specified Gang Member to the specified task.
+    /// If an invalid task is specified, the Gang member will be set to idle (“Unassigned”).
+    ///
+    /// @param memberName - Name of Gang member to assign.
+    /// @param taskName - Task to assign.
+    /// @returns True if the Gang Member was successfully assigned to the task, false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn setMemberTask(this: &Gang, memberName: &str, taskName: &str) -> Result<bool, JsValue>;
+
+    /// Get stats of a task.
+    /// @remarks
+    /// RAM cost: 1 GB
+    ///
+    /// Get the stats of a gang task stats. This is typically used to evaluate which action should be executed next.
+    ///
+    /// @param name -  Name of the task.
+    /// @returns Detailed stats of a task.
+    #[wasm_bindgen(method, catch)]
+    pub fn getTaskStats(this: &Gang, name: &str) -> Result<GangTaskStats, JsValue>;
+
+    /// List equipment names.
+    /// @remarks
+    /// RAM cost: 1 GB
+    ///
+    /// Get the name of all possible equipment/upgrades you can purchase for your Gang Members.
+    /// This includes Augmentations.
+    ///
+    /// @returns Names of all Equipments/Augmentations.
+    #[wasm_bindgen(method, catch)]
+    pub fn getEquipmentNames(this: &Gang) -> Result<Vec<String>, JsValue>;
+
+    /// Get cost of equipment.
+    /// @remarks
+    /// RAM cost: 2 GB
+    ///
+    /// Get the amount of money it takes to purchase a piece of Equipment or an Augmentation.
+    /// If an invalid Equipment/Augmentation is specified, this function will return Infinity.
+    ///
+    /// @param equipName - Name of equipment.
+    /// @returns Cost to purchase the specified Equipment/Augmentation (number). Infinity for invalid arguments
+    #[wasm_bindgen(method, catch)]
+    pub fn getEquipmentCost(this: &Gang, equipName: &str) -> Result<f64, JsValue>;
+
+    /// Get type of an equipment.
+    /// @remarks
+    /// RAM cost: 2 GB
+    ///
+    /// Get the specified equipment type.
+    ///
+    /// @param equipName - Name of equipment.
+    /// @returns Type of the equipment.
+    #[wasm_bindgen(method, catch)]
+    pub fn getEquipmentType(this: &Gang, equipName: &str) -> Result<String, JsValue>;
+
+    /// Get stats of an equipment.
+    /// @remarks
+    /// RAM cost: 2 GB
+    ///
+    /// Get the specified equipment stats.
+    ///
+    /// @param equipName - Name of equipment.
+    /// @returns A dictionary containing the stats of the equipment.
+    #[wasm_bindgen(method, catch)]
+    pub fn getEquipmentStats(this: &Gang, equipName: &str) -> Result<EquipmentStats, JsValue>;
+
+    /// Purchase an equipment for a gang member.
+    /// @remarks
+    /// RAM cost: 4 GB
+    ///
+    /// Attempt to purchase the specified Equipment/Augmentation for the specified Gang member.
+    ///
+    /// @param memberName - Name of Gang member to purchase the equipment for.
+    /// @param equipName - Name of Equipment/Augmentation to purchase.
+    /// @returns True if the equipment was successfully purchased. False otherwise
+    #[wasm_bindgen(method, catch)]
+    pub fn purchaseEquipment(
+        this: &Gang,
+        memberName: &str,
+        equipName: &str,
+    ) -> Result<bool, JsValue>;
+
+    /// Ascend a gang member.
+    /// @remarks
+    /// RAM cost: 4 GB
+    ///
+    /// Ascend the specified Gang Member.
+    ///
+    /// @param memberName - Name of member to ascend.
+    /// @returns Object with info about the ascension results, or undefined if ascension did not occur.
+    #[wasm_bindgen(method, catch)]
+    pub fn ascendMember(
+        this: &Gang,
+        memberName: &str,
+    ) -> Result<Option<GangMemberAscension>, JsValue>;
+
+    /// Get the result of an ascension without ascending.
+    /// @remarks
+    /// RAM cost: 2 GB
+    ///
+    /// Get a {@link GangMemberAscension} result for ascending a gang member without performing the ascension.
+    ///
+    /// @param memberName - Name of member.
+    /// @returns Object with info about the ascension results, or undefined if ascension is not possible.
+    #[wasm_bindgen(method, catch)]
+    pub fn getAscensionResult(
+        this: &Gang,
+        memberName: &str,
+    ) -> Result<Option<GangMemberAscension>, JsValue>;
+
+    /// Enable/Disable territory clashes.
+    /// @remarks
+    /// RAM cost: 2 GB
+    ///
+    /// Set whether or not the gang should engage in territory clashes
+    ///
+    /// @param engage - Whether or not to engage in territory clashes.
+    #[wasm_bindgen(method, catch)]
+    pub fn setTerritoryWarfare(this: &Gang, engage: bool) -> Result<(), JsValue>;
+
+    /// Get chance to win clash with other gang.
+    /// @remarks
+    /// RAM cost: 4 GB
+    ///
+    /// Returns the chance you have to win a clash with the specified gang. The chance is returned in decimal form, not percentage
+    ///
+    /// @param gangName - Target gang
+    /// @returns Chance you have to win a clash with the specified gang.
+    #[wasm_bindgen(method, catch)]
+    pub fn getChanceToWinClash(this: &Gang, gangName: &str) -> Result<f64, JsValue>;
+
+    /// Get bonus time.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// Returns the amount of accumulated “bonus time” (milliseconds) for the Gang mechanic.
+    ///
+    /// “Bonus time” is accumulated when the game is offline or if the game is inactive in the browser.
+    ///
+    /// “Bonus time” makes the game progress faster, up to 25x the normal speed.
+    ///
+    /// @returns Bonus time for the Gang mechanic in milliseconds.
+    #[wasm_bindgen(method, catch)]
+    pub fn getBonusTime(this: &Gang) -> Result<f64, JsValue>;
+
+    /// Sleeps until the next Gang update has happened.
+    /// @remarks
+    /// RAM cost: 1 GB
+    ///
+    /// The amount of real time spent asleep between updates can vary due to "bonus time".
+    ///
+    /// @returns Promise that resolves to the number of milliseconds of Gang time
+    /// that were processed in the previous update (2000 - 5000 ms).
+    ///
+    /// @example
+    /// ```js
+    /// while (true) {
+    ///   const duration = await ns.gang.nextUpdate();
+    ///   ns.print(`Gang completed ${ns.tFormat(duration)} of activity.`);
+    ///   ns.print(`Bonus time remaining: ${ns.tFormat(ns.gang.getBonusTime())}`);
+    ///   // Manage the Gang
+    /// }
+    /// ```
+    #[wasm_bindgen(method, catch)]
+    pub async fn nextUpdate(this: &Gang) -> Result<f64, JsValue>;
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// Sleeve API
+    /// @remarks
+    /// If you are not in BitNode-10, then you must have Source-File 10 in order to use this API.
+    #[derive(Debug, Clone, PartialEq)]
+    pub type Sleeve;
+
+    /// Get the number of sleeves you own.
+    /// @remarks
+    /// RAM cost: 4 GB
+    ///
+    /// Return the number of duplicate sleeves the player has.
+    ///
+    /// @returns Number of duplicate sleeves the player has.
+    #[wasm_bindgen(method, catch)]
+    pub fn getNumSleeves(this: &Sleeve) -> Result<f64, JsValue>;
+
+    /// Get information about a sleeve.
+    /// @remarks
+    /// RAM cost: 4 GB
+    ///
+    /// Return a person object for this sleeve
+    ///
+    /// storedCycles is the amount of Bonus Time in cycles, each translates to 200ms
+    ///
+    /// @param sleeveNumber - Index of the sleeve to retrieve information.
+    /// @returns Object containing information about this sleeve.
+    #[wasm_bindgen(method, catch)]
+    pub fn getSleeve(this: &Sleeve, sleeveNumber: f64) -> Result<SleevePerson, JsValue>;
+
+    /// Get task of a sleeve.
+    /// @remarks
+    /// RAM cost: 4 GB
+    ///
+    /// Return the current task that the sleeve is performing, or null if the sleeve is idle. All tasks have a "type"
+    /// property, and other available properties depend on the type of task.
+    ///
+    /// @param sleeveNumber - Index of the sleeve to retrieve task from.
+    /// @returns Object containing information for the current task that the sleeve is performing.
+    #[wasm_bindgen(method, catch)]
+    pub fn getTask(this: &Sleeve, sleeveNumber: f64) -> Result<JsValue, JsValue>;
+
+    /// Set a sleeve to idle.
+    /// @remarks
+    /// RAM cost: 4 GB
+    ///
+    /// @param sleeveNumber - Index of the sleeve to idle.
+    #[wasm_bindgen(method, catch)]
+    pub fn setToIdle(this: &Sleeve, sleeveNumber: f64) -> Result<(), JsValue>;
+
+    /// Set a sleeve to shock recovery.
+    /// @remarks
+    /// RAM cost: 4 GB
+    ///
+    /// Return a boolean indicating whether or not this action was set successfully.
+    ///
+    /// @param sleeveNumber - Index of the sleeve to start recovery.
+    /// @returns True if this action was set successfully, false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn setToShockRecovery(this: &Sleeve, sleeveNumber: f64) -> Result<bool, JsValue>;
+
+    /// Set a sleeve to synchronize.
+    /// @remarks
+    /// RAM cost: 4 GB
+    ///
+    /// Return a boolean indicating whether or not this action was set successfully.
+    ///
+    /// @param sleeveNumber - Index of the sleeve to start synchronizing.
+    /// @returns True if this action was set successfully, false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn setToSynchronize(this: &Sleeve, sleeveNumber: f64) -> Result<bool, JsValue>;
+
+    /// Set a sleeve to commit crime.
+    /// @remarks
+    /// RAM cost: 4 GB
+    ///
+    /// Return a boolean indicating whether or not this action was set successfully (false if an invalid action is specified).
+    ///
+    /// @example
+    /// ```ts
+    /// // Assigns the first sleeve to Homicide.
+    /// ns.sleeve.setToCommitCrime(0, "Homicide");
+    ///
+    /// // Assigns the second sleeve to Grand Theft Auto, using enum
+    /// const crimes = ns.enums.CrimeType;
+    /// ns.sleeve.setToCommitCrime(1, crimes.grandTheftAuto)
+    /// ```
+    ///
+    /// @param sleeveNumber - Index of the sleeve to start committing crime. Sleeves are numbered starting from 0.
+    /// @param crimeType - Name of the crime.
+    /// @returns True if this action was set successfully, false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn setToCommitCrime(
+        this: &Sleeve,
+        sleeveNumber: f64,
+        crimeType: CrimeType,
+    ) -> Result<bool, JsValue>;
+
+    /// Set a sleeve to work for a faction.
+    /// @remarks
+    /// RAM cost: 4 GB
+    ///
+    /// Return a boolean indicating whether or not the sleeve started working for a faction.
+    ///
+    /// @param sleeveNumber - Index of the sleeve to work for the faction.
+    /// @param factionName - Name of the faction to work for.
+    /// @param factionWorkType - Name of the action to perform for this faction.
+    /// @returns True if the sleeve started working for this faction, false otherwise. Can also throw on errors.
+    #[wasm_bindgen(method, catch)]
+    pub fn setToFactionWork(
+        this: &Sleeve,
+        sleeveNumber: f64,
+        factionName: &str,
+        factionWorkType: FactionWorkType,
+    ) -> Result<Option<bool>, JsValue>;
+
+    /// Set a sleeve to work for a company.
+    /// @remarks
+    /// RAM cost: 4 GB
+    ///
+    /// Return a boolean indicating whether or not the sleeve started working for a company.
+    ///
+    /// @param sleeveNumber - Index of the sleeve to work for the company.
+    /// @param companyName - Name of the company to work for.
+    /// @returns True if the sleeve started working for this company, false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn setToCompanyWork(
+        this: &Sleeve,
+        sleeveNumber: f64,
+        companyName: CompanyName,
+    ) -> Result<bool, JsValue>;
+
+    /// Set a sleeve to take a class at a university.
+    /// @remarks
+    /// RAM cost: 4 GB
+    ///
+    /// Return a boolean indicating whether or not this action was set successfully.
+    ///
+    /// @param sleeveNumber - Index of the sleeve to start taking class.
+    /// @param university - Name of the university to attend.
+    /// @param className - Name of the class to follow.
+    /// @returns True if this action was set successfully, false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn setToUniversityCourse(
+        this: &Sleeve,
+        sleeveNumber: f64,
+        university: &str,
+        className: &str,
+    ) -> Result<bool, JsValue>;
+
+    /// Set a sleeve to workout at the gym.
+    /// @remarks
+    /// RAM cost: 4 GB
+    ///
+    /// Return a boolean indicating whether or not the sleeve started working out.
+    ///
+    /// @param sleeveNumber - Index of the sleeve to workout at the gym.
+    /// @param gymName - Name of the gym.
+    /// @param stat - Name of the stat to train.
+    /// @returns True if the sleeve started working out, false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn setToGymWorkout(
+        this: &Sleeve,
+        sleeveNumber: f64,
+        gymName: &str,
+        stat: &str,
+    ) -> Result<bool, JsValue>;
+
+    /// Make a sleeve travel to another city.
+    /// @remarks
+    /// RAM cost: 4 GB
+    ///
+    /// Return a boolean indicating whether or not the sleeve reached destination.
+    ///
+    /// @param sleeveNumber - Index of the sleeve to travel.
+    /// @param city - Name of the destination city.
+    /// @returns True if the sleeve reached destination, false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn travel(this: &Sleeve, sleeveNumber: f64, city: CityName) -> Result<bool, JsValue>;
+
+    /// Get augmentations installed on a sleeve.
+    /// @remarks
+    /// RAM cost: 4 GB
+    ///
+    /// Return a list of augmentation names that this sleeve has installed.
+    ///
+    /// @param sleeveNumber - Index of the sleeve to retrieve augmentations from.
+    /// @returns List of augmentation names that this sleeve has installed.
+    #[wasm_bindgen(method, catch)]
+    pub fn getSleeveAugmentations(this: &Sleeve, sleeveNumber: f64)
+        -> Result<Vec<String>, JsValue>;
+
+    /// Get price of an augmentation.
+    /// @remarks
+    /// RAM cost: 4 GB
+    ///
+    ///
+    /// @param augName - Name of Augmentation.
+    /// @returns Price of the augmentation.
+    #[wasm_bindgen(method, catch)]
+    pub fn getSleeveAugmentationPrice(this: &Sleeve, augName: &str) -> Result<f64, JsValue>;
+
+    /// Get reputation requirement of an augmentation.
+    /// @remarks
+    /// RAM cost: 4 GB
+    ///
+    ///
+    /// @param augName - Name of Augmentation.
+    /// @returns Reputation requirement of the augmentation.
+    #[wasm_bindgen(method, catch)]
+    pub fn getSleeveAugmentationRepReq(this: &Sleeve, augName: &str) -> Result<f64, JsValue>;
+
+    /// List purchasable augs for a sleeve.
+    /// @remarks
+    /// RAM cost: 4 GB
+    ///
+    /// Return a list of augmentations that the player can buy for this sleeve.
+    ///
+    /// @param sleeveNumber - Index of the sleeve to retrieve purchasable augmentations from.
+    /// @returns List of augmentations that the player can buy for this sleeve.
+    #[wasm_bindgen(method, catch)]
+    pub fn getSleevePurchasableAugs(
+        this: &Sleeve,
+        sleeveNumber: f64,
+    ) -> Result<Vec<AugmentPair>, JsValue>;
+
+    /// Purchase an aug for a sleeve.
+    /// @remarks
+    /// RAM cost: 4 GB
+    ///
+    /// Return true if the aug was purchased and installed on the sleeve.
+    ///
+    /// @param sleeveNumber - Index of the sleeve to buy an aug for.
+    /// @param augName - Name of the aug to buy. Must be an exact match.
+    /// @returns True if the aug was purchased and installed on the sleeve, false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn purchaseSleeveAug(
+        this: &Sleeve,
+        sleeveNumber: f64,
+        augName: &str,
+    ) -> Result<bool, JsValue>;
+
+    /// Set a sleeve to perform Bladeburner actions.
+    /// @remarks
+    /// RAM cost: 4 GB
+    ///
+    /// Return a boolean indicating whether or not the sleeve started a Bladeburner action.
+    ///
+    /// @param sleeveNumber - Index of the sleeve to perform a Bladeburner action.
+    /// @param action - Name of the action to be performed.
+    /// @param contract - Name of the contract if applicable.
+    /// @returns True if the sleeve started the given Bladeburner action, false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn setToBladeburnerAction(
+        this: &Sleeve,
+        sleeveNumber: f64,
+        action: &str,
+        contract: Option<&str>,
+    ) -> Result<bool, JsValue>;
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// Grafting API
+    /// @remarks
+    /// This API requires Source-File 10 to use.
+    #[derive(Debug, Clone, PartialEq)]
+    pub type Grafting;
+
+    /// Retrieve the grafting cost of an aug.
+    /// @remarks
+    /// RAM cost: 3.75 GB
+    ///
+    /// @param augName - Name of the aug to check the price of. Must be an exact match.
+    /// @returns The cost required to graft the named augmentation.
+    /// @throws Will error if an invalid Augmentation name is provided.
+    #[wasm_bindgen(method, catch)]
+    pub fn getAugmentationGraftPrice(this: &Grafting, augName: &str) -> Result<f64, JsValue>;
+
+    /// Retrieves the time required to graft an aug.
+    /// @remarks
+    /// RAM cost: 3.75 GB
+    ///
+    /// @param augName - Name of the aug to check the grafting time of. Must be an exact match.
+    /// @returns The time required, in millis, to graft the named augmentation.
+    /// @throws Will error if an invalid Augmentation name is provided.
+    #[wasm_bindgen(method, catch)]
+    pub fn getAugmentationGraftTime(this: &Grafting, augName: &str) -> Result<f64, JsValue>;
+
+    /// Retrieves a list of Augmentations that can be grafted.
+    /// @remarks
+    /// RAM cost: 5 GB
+    ///
+    /// Note that this function returns a list of currently graftable Augmentations,
+    /// based off of the Augmentations that you already own.
+    ///
+    /// @returns An array of graftable Augmentations.
+    #[wasm_bindgen(method, catch)]
+    pub fn getGraftableAugmentations(this: &Grafting) -> Result<Vec<String>, JsValue>;
+
+    /// Begins grafting the named aug. You must be in New Tokyo to use this.
+    /// @remarks
+    /// RAM cost: 7.5 GB
+    ///
+    /// @param augName - The name of the aug to begin grafting. Must be an exact match.
+    /// @param focus - Acquire player focus on this Augmentation grafting. Optional. Defaults to true.
+    /// @returns True if the aug successfully began grafting, false otherwise (e.g. not enough money, or
+    /// invalid Augmentation name provided).
+    /// @throws Will error if called while you are not in New Tokyo.
+    #[wasm_bindgen(method, catch)]
+    pub fn graftAugmentation(
+        this: &Grafting,
+        augName: &str,
+        focus: Option<bool>,
+    ) -> Result<bool, JsValue>;
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// Skills formulas
+    #[derive(Debug, Clone, PartialEq)]
+    pub type SkillsFormulas;
+
+    /// Calculate skill level.
+    /// @param exp - experience for that skill
+    /// @param skillMult - Multiplier for that skill, defaults to 1.
+    /// @returns The calculated skill level.
+    #[wasm_bindgen(method, catch)]
+    pub fn calculateSkill(
+        this: &SkillsFormulas,
+        exp: f64,
+        skillMult: Option<f64>,
+    ) -> Result<f64, JsValue>;
+
+    /// Calculate exp for skill level.
+    /// @param skill - target skill level
+    /// @param skillMult - Multiplier for that skill, defaults to 1.
+    /// @returns The calculated exp required.
+    #[wasm_bindgen(method, catch)]
+    pub fn calculateExp(
+        this: &SkillsFormulas,
+        skill: f64,
+        skillMult: Option<f64>,
+    ) -> Result<f64, JsValue>;
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[derive(Debug, Clone, PartialEq)]
+    pub type WorkStats;
+
+    #[wasm_bindgen(method, getter)]
+    pub fn money(this: &WorkStats) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_money(this: &WorkStats, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn reputation(this: &WorkStats) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_reputation(this: &WorkStats, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn hackExp(this: &WorkStats) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_hackExp(this: &WorkStats, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn strExp(this: &WorkStats) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_strExp(this: &WorkStats, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn defExp(this: &WorkStats) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_defExp(this: &WorkStats, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn dexExp(this: &WorkStats) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_dexExp(this: &WorkStats, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn agiExp(this: &WorkStats) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_agiExp(this: &WorkStats, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn chaExp(this: &WorkStats) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_chaExp(this: &WorkStats, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn intExp(this: &WorkStats) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_intExp(this: &WorkStats, value: f64);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// Work formulas
+    #[derive(Debug, Clone, PartialEq)]
+    pub type WorkFormulas;
+
+    #[wasm_bindgen(method, catch)]
+    pub fn crimeSuccessChance(
+        this: &WorkFormulas,
+        person: &Person,
+        crimeType: CrimeType,
+    ) -> Result<f64, JsValue>;
+
+    /// @returns The WorkStats gained when completing one instance of the specified crime.
+    #[wasm_bindgen(method, catch)]
+    pub fn crimeGains(
+        this: &WorkFormulas,
+        person: &Person,
+        crimeType: CrimeType,
+    ) -> Result<WorkStats, JsValue>;
+
+    /// @returns The WorkStats applied every game cycle (200ms) by taking the specified gym class.
+    #[wasm_bindgen(method, catch)]
+    pub fn gymGains(
+        this: &WorkFormulas,
+        person: &Person,
+        gymType: GymType,
+        locationName: &str,
+    ) -> Result<WorkStats, JsValue>;
+
+    /// @returns The WorkStats applied every game cycle (200ms) by taking the specified university class.
+    #[wasm_bindgen(method, catch)]
+    pub fn universityGains(
+        this: &WorkFormulas,
+        person: &Person,
+        classType: UniversityClassType,
+        locationName: &str,
+    ) -> Result<WorkStats, JsValue>;
+
+    /// @returns The WorkStats applied every game cycle (200ms) by performing the specified faction work.
+    #[wasm_bindgen(method, catch)]
+    pub fn factionGains(
+        this: &WorkFormulas,
+        person: &Person,
+        workType: FactionWorkType,
+        favor: f64,
+    ) -> Result<WorkStats, JsValue>;
+
+    /// @returns The WorkStats applied every game cycle (200ms) by performing the specified company work.
+    #[wasm_bindgen(method, catch)]
+    pub fn companyGains(
+        this: &WorkFormulas,
+        person: &Person,
+        companyName: CompanyName,
+        workType: JobName,
+        favor: f64,
+    ) -> Result<WorkStats, JsValue>;
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// Reputation formulas
+    #[derive(Debug, Clone, PartialEq)]
+    pub type ReputationFormulas;
+
+    /// Calculate the total required amount of faction reputation to reach a target favor.
+    /// @param favor - target faction favor.
+    /// @returns The calculated faction reputation required.
+    #[wasm_bindgen(method, catch)]
+    pub fn calculateFavorToRep(this: &ReputationFormulas, favor: f64) -> Result<f64, JsValue>;
+
+    /// Calculate the resulting faction favor of a total amount of reputation.
+    /// (Faction favor is gained whenever you install an Augmentation.)
+    /// @param rep - amount of reputation.
+    /// @returns The calculated faction favor.
+    #[wasm_bindgen(method, catch)]
+    pub fn calculateRepToFavor(this: &ReputationFormulas, rep: f64) -> Result<f64, JsValue>;
+
+    /// Calculate how much rep would be gained.
+    /// @param amount - Amount of money donated
+    /// @param player - Player info, typically from {@link NS.getPlayer | getPlayer}
+    #[wasm_bindgen(method, catch)]
+    pub fn repFromDonation(
+        this: &ReputationFormulas,
+        amount: f64,
+        player: &Person,
+    ) -> Result<f64, JsValue>;
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// Hacking formulas
+    #[derive(Debug, Clone, PartialEq)]
+    pub type HackingFormulas;
+
+    /// Calculate hack chance.
+    /// (Ex: 0.25 would indicate a 25% chance of success.)
+    /// @param server - Server info, typically from {@link NS.getServer | getServer}
+    /// @param player - Player info, typically from {@link NS.getPlayer | getPlayer}
+    /// @returns The calculated hack chance.
+    #[wasm_bindgen(method, catch)]
+    pub fn hackChance(
+        this: &HackingFormulas,
+        server: &Server,
+        player: &Person,
+    ) -> Result<f64, JsValue>;
+
+    /// Calculate hack exp for one thread.
+    /// @remarks
+    /// Multiply by thread to get total exp
+    /// @param server - Server info, typically from {@link NS.getServer | getServer}
+    /// @param player - Player info, typically from {@link NS.getPlayer | getPlayer}
+    /// @returns The calculated hack exp.
+    #[wasm_bindgen(method, catch)]
+    pub fn hackExp(
+        this: &HackingFormulas,
+        server: &Server,
+        player: &Person,
+    ) -> Result<f64, JsValue>;
+
+    /// Calculate hack percent for one thread.
+    /// (Ex: 0.25 would steal 25% of the server's current value.)
+    /// @remarks
+    /// Multiply by thread to get total percent hacked.
+    /// @param server - Server info, typically from {@link NS.getServer | getServer}
+    /// @param player - Player info, typically from {@link NS.getPlayer | getPlayer}
+    /// @returns The calculated hack percent.
+    #[wasm_bindgen(method, catch)]
+    pub fn hackPercent(
+        this: &HackingFormulas,
+        server: &Server,
+        player: &Person,
+    ) -> Result<f64, JsValue>;
+
+    /// Calculate the percent a server would grow to.
+    /// Not exact due to limitations of mathematics.
+    /// (Ex: 3.0 would grow the server to 300% of its current value.)
+    /// @param server - Server info, typically from {@link NS.getServer | getServer}
+    /// @param threads - Amount of thread.
+    /// @param player - Player info, typically from {@link NS.getPlayer | getPlayer}
+    /// @param cores - Number of cores on the computer that will execute grow.
+    /// @returns The calculated grow percent.
+    #[wasm_bindgen(method, catch)]
+    pub fn growPercent(
+        this: &HackingFormulas,
+        server: &Server,
+        threads: f64,
+        player: &Person,
+        cores: Option<f64>,
+    ) -> Result<f64, JsValue>;
+
+    /// Calculate how many threads it will take to grow server to targetMoney. Starting money is server.moneyAvailable.
+    /// @param server - Server info, typically from {@link NS.getServer | getServer}
+    /// @param player - Player info, typically from {@link NS.getPlayer | getPlayer}
+    /// @param targetMoney - Desired final money, capped to server's moneyMax
+    /// @param cores - Number of cores on the computer that will execute grow.
+    /// @returns The calculated grow threads as an integer, rounded up.
+    #[wasm_bindgen(method, catch)]
+    pub fn growThreads(
+        this: &HackingFormulas,
+        server: &Server,
+        player: &Person,
+        targetMoney: f64,
+        cores: Option<f64>,
+    ) -> Result<f64, JsValue>;
+
+    /// Calculate hack time.
+    /// @param server - Server info, typically from {@link NS.getServer | getServer}
+    /// @param player - Player info, typically from {@link NS.getPlayer | getPlayer}
+    /// @returns The calculated hack time.
+    #[wasm_bindgen(method, catch)]
+    pub fn hackTime(
+        this: &HackingFormulas,
+        server: &Server,
+        player: &Person,
+    ) -> Result<f64, JsValue>;
+
+    /// Calculate grow time.
+    /// @param server - Server info, typically from {@link NS.getServer | getServer}
+    /// @param player - Player info, typically from {@link NS.getPlayer | getPlayer}
+    /// @returns The calculated grow time.
+    #[wasm_bindgen(method, catch)]
+    pub fn growTime(
+        this: &HackingFormulas,
+        server: &Server,
+        player: &Person,
+    ) -> Result<f64, JsValue>;
+
+    /// Calculate weaken time.
+    /// @param server - Server info, typically from {@link NS.getServer | getServer}
+    /// @param player - Player info, typically from {@link NS.getPlayer | getPlayer}
+    /// @returns The calculated weaken time.
+    #[wasm_bindgen(method, catch)]
+    pub fn weakenTime(
+        this: &HackingFormulas,
+        server: &Server,
+        player: &Person,
+    ) -> Result<f64, JsValue>;
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// Hacknet Node formulas
+    #[derive(Debug, Clone, PartialEq)]
+    pub type HacknetNodesFormulas;
+
+    /// Calculate money gain rate.
+    /// @param level - level of the node.
+    /// @param ram - ram of the node.
+    /// @param cores - cores of the node.
+    /// @param mult - player production mult (default to 1)
+    /// @returns The calculated money gain rate.
+    #[wasm_bindgen(method, catch)]
+    pub fn moneyGainRate(
+        this: &HacknetNodesFormulas,
+        level: f64,
+        ram: f64,
+        cores: f64,
+        mult: Option<f64>,
+    ) -> Result<f64, JsValue>;
+
+    /// Calculate cost of upgrading hacknet node level.
+    /// @param startingLevel - starting level
+    /// @param extraLevels - amount of level to purchase (defaults to 1)
+    /// @param costMult - player cost reduction (default to 1)
+    /// @returns The calculated cost.
+    #[wasm_bindgen(method, catch)]
+    pub fn levelUpgradeCost(
+        this: &HacknetNodesFormulas,
+        startingLevel: f64,
+        extraLevels: Option<f64>,
+        costMult: Option<f64>,
+    ) -> Result<f64, JsValue>;
+
+    /// Calculate cost of upgrading hacknet node ram.
+    /// @param startingRam - starting ram
+    /// @param extraLevels - amount of level of ram to purchase (defaults to 1)
+    /// @param costMult - player cost reduction (default to 1)
+    /// @returns The calculated cost.
+    #[wasm_bindgen(method, catch)]
+    pub fn ramUpgradeCost(
+        this: &HacknetNodesFormulas,
+        startingRam: f64,
+        extraLevels: Option<f64>,
+        costMult: Option<f64>,
+    ) -> Result<f64, JsValue>;
+
+    /// Calculate cost of upgrading hacknet node cores.
+    /// @param startingCore - starting cores
+    /// @param extraCores - amount of cores to purchase (defaults to 1)
+    /// @param costMult - player cost reduction (default to 1)
+    /// @returns The calculated cost.
+    #[wasm_bindgen(method, catch)]
+    pub fn coreUpgradeCost(
+        this: &HacknetNodesFormulas,
+        startingCore: f64,
+        extraCores: Option<f64>,
+        costMult: Option<f64>,
+    ) -> Result<f64, JsValue>;
+
+    /// Calculate the cost of a hacknet node.
+    /// @param n - number of the hacknet node
+    /// @param mult - player cost reduction (defaults to 1)
+    /// @returns The calculated cost.
+    #[wasm_bindgen(method, catch)]
+    pub fn hacknetNodeCost(this: &HacknetNodesFormulas, n: f64, mult: f64) -> Result<f64, JsValue>;
+
+    /// All constants used by the game.
+    /// @returns An object with all hacknet node constants used by the game.
+    #[wasm_bindgen(method, catch)]
+    pub fn constants(this: &HacknetNodesFormulas) -> Result<HacknetNodeConstants, JsValue>;
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// Hacknet Server formulas
+    #[derive(Debug, Clone, PartialEq)]
+    pub type HacknetServersFormulas;
+
+    /// Calculate hash gain rate.
+    /// @param level - level of the server.
+    /// @param ramUsed - ramUsed of the server.
+    /// @param maxRam - maxRam of the server.
+    /// @param cores - cores of the server.
+    /// @param mult - player production mult (default to 1)
+    /// @returns The calculated hash gain rate.
+    #[wasm_bindgen(method, catch)]
+    pub fn hashGainRate(
+        this: &HacknetServersFormulas,
+        level: f64,
+        ramUsed: f64,
+        maxRam: f64,
+        cores: f64,
+        mult: Option<f64>,
+    ) -> Result<f64, JsValue>;
+
+    /// Calculate cost of upgrading hacknet server level.
+    /// @param startingLevel - starting level
+    /// @param extraLevels - amount of level to purchase (defaults to 1)
+    /// @param costMult - player cost reduction (default to 1)
+    /// @returns The calculated cost.
+    #[wasm_bindgen(method, catch)]
+    pub fn levelUpgradeCost(
+        this: &HacknetServersFormulas,
+        startingLevel: f64,
+        extraLevels: Option<f64>,
+        costMult: Option<f64>,
+    ) -> Result<f64, JsValue>;
+
+    /// Calculate cost of upgrading hacknet server ram.
+    /// @param startingRam - starting ram
+    /// @param extraLevels - amount of level of ram to purchase (defaults to 1)
+    /// @param costMult - player cost reduction (default to 1)
+    /// @returns The calculated cost.
+    #[wasm_bindgen(method, catch)]
+    pub fn ramUpgradeCost(
+        this: &HacknetServersFormulas,
+        startingRam: f64,
+        extraLevels: Option<f64>,
+        costMult: Option<f64>,
+    ) -> Result<f64, JsValue>;
+
+    /// Calculate cost of upgrading hacknet server cores.
+    /// @param startingCore - starting cores
+    /// @param extraCores - amount of cores to purchase (defaults to 1)
+    /// @param costMult - player cost reduction (default to 1)
+    /// @returns The calculated cost.
+    #[wasm_bindgen(method, catch)]
+    pub fn coreUpgradeCost(
+        this: &HacknetServersFormulas,
+        startingCore: f64,
+        extraCores: Option<f64>,
+        costMult: Option<f64>,
+    ) -> Result<f64, JsValue>;
+
+    /// Calculate cost of upgrading hacknet server cache.
+    /// @param startingCache - starting cache level
+    /// @param extraCache - amount of levels of cache to purchase (defaults to 1)
+    /// @returns The calculated cost.
+    #[wasm_bindgen(method, catch)]
+    pub fn cacheUpgradeCost(
+        this: &HacknetServersFormulas,
+        startingCache: f64,
+        extraCache: Option<f64>,
+    ) -> Result<f64, JsValue>;
+
+    /// Calculate hash cost of an upgrade.
+    /// @param upgName - name of the upgrade
+    /// @param level - level of the upgrade
+    /// @returns The calculated hash cost.
+    #[wasm_bindgen(method, catch)]
+    pub fn hashUpgradeCost(
+        this: &HacknetServersFormulas,
+        upgName: f64,
+        level: f64,
+    ) -> Result<f64, JsValue>;
+
+    /// Calculate the cost of a hacknet server.
+    /// @param n - number of the hacknet server
+    /// @param mult - player cost reduction (defaults to 1)
+    /// @returns The calculated cost.
+    #[wasm_bindgen(method, catch)]
+    pub fn hacknetServerCost(
+        this: &HacknetServersFormulas,
+        n: f64,
+        mult: Option<f64>,
+    ) -> Result<f64, JsValue>;
+
+    /// All constants used by the game.
+    /// @returns An object with all hacknet server constants used by the game.
+    #[wasm_bindgen(method, catch)]
+    pub fn constants(this: &HacknetServersFormulas) -> Result<HacknetServerConstants, JsValue>;
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// Gang formulas
+    #[derive(Debug, Clone, PartialEq)]
+    pub type GangFormulas;
+
+    /// Calculate the wanted penalty.
+    /// @param gang - Gang info from {@link Gang.getGangInformation | getGangInformation}
+    /// @returns The calculated wanted penalty.
+    #[wasm_bindgen(method, catch)]
+    pub fn wantedPenalty(this: &GangFormulas, gang: &GangGenInfo) -> Result<f64, JsValue>;
+
+    /// Calculate respect gain per tick.
+    /// @param gang - Gang info from {@link Gang.getGangInformation | getGangInformation}
+    /// @param member - Gang info from {@link Gang.getMemberInformation | getMemberInformation}
+    /// @param task - Gang info from {@link Gang.getTaskStats | getTaskStats}
+    /// @returns The calculated respect gain.
+    #[wasm_bindgen(method, catch)]
+    pub fn respectGain(
+        this: &GangFormulas,
+        gang: &GangGenInfo,
+        member: &GangMemberInfo,
+        task: &GangTaskStats,
+    ) -> Result<f64, JsValue>;
+
+    /// Calculate wanted gain per tick.
+    /// @param gang - Gang info from {@link Gang.getGangInformation | getGangInformation}
+    /// @param member - Member info from {@link Gang.getMemberInformation | getMemberInformation}
+    /// @param task - Task info from {@link Gang.getTaskStats | getTaskStats}
+    /// @returns The calculated wanted gain.
+    #[wasm_bindgen(method, catch)]
+    pub fn wantedLevelGain(
+        this: &GangFormulas,
+        gang: &GangGenInfo,
+        member: &GangMemberInfo,
+        task: &GangTaskStats,
+    ) -> Result<f64, JsValue>;
+
+    /// Calculate money gain per tick.
+    /// @param gang - Gang info from {@link Gang.getGangInformation | getGangInformation}
+    /// @param member - Member info from {@link Gang.getMemberInformation | getMemberInformation}
+    /// @param task - Task info from {@link Gang.getTaskStats | getTaskStats}
+    /// @returns The calculated money gain.
+    #[wasm_bindgen(method, catch)]
+    pub fn moneyGain(
+        this: &GangFormulas,
+        gang: &GangGenInfo,
+        member: &GangMemberInfo,
+        task: &GangTaskStats,
+    ) -> Result<f64, JsValue>;
+
+    /// Calculate ascension point gain.
+    /// @param exp - Experience point before ascension.
+    /// @returns The calculated ascension point gain.
+    #[wasm_bindgen(method, catch)]
+    pub fn ascensionPointsGain(this: &GangFormulas, exp: f64) -> Result<f64, JsValue>;
+
+    /// Calculate ascension mult.
+    /// @param points - Amount of ascension points.
+    /// @returns The calculated ascension mult.
+    #[wasm_bindgen(method, catch)]
+    pub fn ascensionMultiplier(this: &GangFormulas, points: f64) -> Result<f64, JsValue>;
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// Formulas API
+    /// @remarks
+    /// You need Formulas.exe on your home computer to use this API.
+    #[derive(Debug, Clone, PartialEq)]
+    pub type Formulas;
+
+    #[wasm_bindgen(method, catch)]
+    pub fn mockServer(this: &Formulas) -> Result<Server, JsValue>;
+
+    #[wasm_bindgen(method, catch)]
+    pub fn mockPlayer(this: &Formulas) -> Result<Player, JsValue>;
+
+    #[wasm_bindgen(method, catch)]
+    pub fn mockPerson(this: &Formulas) -> Result<Person, JsValue>;
+
+    /// Reputation formulas
+    #[wasm_bindgen(method, getter)]
+    pub fn reputation(this: &Formulas) -> ReputationFormulas;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_reputation(this: &Formulas, value: &ReputationFormulas);
+
+    /// Skills formulas
+    #[wasm_bindgen(method, getter)]
+    pub fn skills(this: &Formulas) -> SkillsFormulas;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_skills(this: &Formulas, value: &SkillsFormulas);
+
+    /// Hacking formulas
+    #[wasm_bindgen(method, getter)]
+    pub fn hacking(this: &Formulas) -> HackingFormulas;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_hacking(this: &Formulas, value: &HackingFormulas);
+
+    /// Hacknet Nodes formulas
+    #[wasm_bindgen(method, getter)]
+    pub fn hacknetNodes(this: &Formulas) -> HacknetNodesFormulas;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_hacknetNodes(this: &Formulas, value: &HacknetNodesFormulas);
+
+    /// Hacknet Servers formulas
+    #[wasm_bindgen(method, getter)]
+    pub fn hacknetServers(this: &Formulas) -> HacknetServersFormulas;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_hacknetServers(this: &Formulas, value: &HacknetServersFormulas);
+
+    /// Gang formulas
+    #[wasm_bindgen(method, getter)]
+    pub fn gang(this: &Formulas) -> GangFormulas;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_gang(this: &Formulas, value: &GangFormulas);
+
+    /// Work formulas
+    #[wasm_bindgen(method, getter)]
+    pub fn work(this: &Formulas) -> WorkFormulas;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_work(this: &Formulas, value: &WorkFormulas);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[derive(Debug, Clone, PartialEq)]
+    pub type Fragment;
+
+    #[wasm_bindgen(method, getter)]
+    pub fn id(this: &Fragment) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_id(this: &Fragment, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn shape(this: &Fragment) -> Vec<JsValue>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_shape(this: &Fragment, value: Vec<JsValue>);
+
+    #[wasm_bindgen(method, getter = "type")]
+    pub fn r#type(this: &Fragment) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_type(this: &Fragment, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn power(this: &Fragment) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_power(this: &Fragment, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn limit(this: &Fragment) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_limit(this: &Fragment, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn effect(this: &Fragment) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_effect(this: &Fragment, value: &str);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[derive(Debug, Clone, PartialEq)]
+    pub type ActiveFragment;
+
+    #[wasm_bindgen(method, getter)]
+    pub fn id(this: &ActiveFragment) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_id(this: &ActiveFragment, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn highestCharge(this: &ActiveFragment) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_highestCharge(this: &ActiveFragment, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn numCharge(this: &ActiveFragment) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_numCharge(this: &ActiveFragment, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn rotation(this: &ActiveFragment) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_rotation(this: &ActiveFragment, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn x(this: &ActiveFragment) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_x(this: &ActiveFragment, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn y(this: &ActiveFragment) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_y(this: &ActiveFragment, value: f64);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// Stanek's Gift API.
+    #[derive(Debug, Clone, PartialEq)]
+    pub type Stanek;
+
+    /// Stanek's Gift width.
+    /// @remarks
+    /// RAM cost: 0.4 GB
+    /// @returns The width of the gift.
+    #[wasm_bindgen(method, catch)]
+    pub fn giftWidth(this: &Stanek) -> Result<f64, JsValue>;
+
+    /// Stanek's Gift height.
+    /// @remarks
+    /// RAM cost: 0.4 GB
+    /// @returns The height of the gift.
+    #[wasm_bindgen(method, catch)]
+    pub fn giftHeight(this: &Stanek) -> Result<f64, JsValue>;
+
+    /// Charge a fragment, increasing its power.
+    /// @remarks
+    /// RAM cost: 0.4 GB
+    /// @param rootX - Root X against which to align the top left of the fragment.
+    /// @param rootY - Root Y against which to align the top left of the fragment.
+    /// @returns Promise that lasts until the charge action is over.
+    #[wasm_bindgen(method, catch)]
+    pub async fn chargeFragment(this: &Stanek, rootX: f64, rootY: f64) -> Result<(), JsValue>;
+
+    /// List possible fragments.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// @returns List of possible fragments.
+    #[wasm_bindgen(method, catch)]
+    pub fn fragmentDefinitions(this: &Stanek) -> Result<Vec<Fragment>, JsValue>;
+
+    /// List of fragments in Stanek's Gift.
+    /// @remarks
+    /// RAM cost: 5 GB
+    ///
+    /// @returns List of active fragments placed on Stanek's Gift.
+    #[wasm_bindgen(method, catch)]
+    pub fn activeFragments(this: &Stanek) -> Result<Vec<ActiveFragment>, JsValue>;
+
+    /// Clear the board of all fragments.
+    /// @remarks
+    /// RAM cost: 0 GB
+    #[wasm_bindgen(method, catch)]
+    pub fn clearGift(this: &Stanek) -> Result<(), JsValue>;
+
+    /// Check if fragment can be placed at specified location.
+    /// @remarks
+    /// RAM cost: 0.5 GB
+    ///
+    /// @param rootX - Root X against which to align the top left of the fragment.
+    /// @param rootY - Root Y against which to align the top left of the fragment.
+    /// @param rotation - A number from 0 to 3, the amount of 90-degree turns to take.
+    /// @param fragmentId - ID of the fragment to place.
+    /// @returns true if the fragment can be placed at that position. false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn canPlaceFragment(
+        this: &Stanek,
+        rootX: f64,
+        rootY: f64,
+        rotation: f64,
+        fragmentId: f64,
+    ) -> Result<bool, JsValue>;
+
+    /// Place fragment on Stanek's Gift.
+    /// @remarks
+    /// RAM cost: 5 GB
+    ///
+    /// @param rootX - X against which to align the top left of the fragment.
+    /// @param rootY - Y against which to align the top left of the fragment.
+    /// @param rotation - A number from 0 to 3, the mount of 90 degree turn to take.
+    /// @param fragmentId - ID of the fragment to place.
+    /// @returns true if the fragment can be placed at that position. false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn placeFragment(
+        this: &Stanek,
+        rootX: f64,
+        rootY: f64,
+        rotation: f64,
+        fragmentId: f64,
+    ) -> Result<bool, JsValue>;
+
+    /// Get placed fragment at location.
+    /// @remarks
+    /// RAM cost: 5 GB
+    ///
+    /// @param rootX - X against which to align the top left of the fragment.
+    /// @param rootY - Y against which to align the top left of the fragment.
+    /// @returns The fragment at [rootX, rootY], if any.
+    #[wasm_bindgen(method, catch)]
+    pub fn getFragment(
+        this: &Stanek,
+        rootX: f64,
+        rootY: f64,
+    ) -> Result<Option<ActiveFragment>, JsValue>;
+
+    /// Remove fragment at location.
+    /// @remarks
+    /// RAM cost: 0.15 GB
+    ///
+    /// @param rootX - X against which to align the top left of the fragment.
+    /// @param rootY - Y against which to align the top left of the fragment.
+    /// @returns The fragment at [rootX, rootY], if any.
+    #[wasm_bindgen(method, catch)]
+    pub fn removeFragment(this: &Stanek, rootX: f64, rootY: f64) -> Result<bool, JsValue>;
+
+    /// Accept Stanek's Gift by joining the Church of the Machine God
+    /// @remarks
+    /// RAM cost: 2 GB
+    ///
+    /// @returns true if the player is a member of the church and has the gift installed,
+    /// false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn acceptGift(this: &Stanek) -> Result<bool, JsValue>;
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[derive(Debug, Clone, PartialEq)]
+    pub type InfiltrationReward;
+
+    #[wasm_bindgen(method, getter)]
+    pub fn tradeRep(this: &InfiltrationReward) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_tradeRep(this: &InfiltrationReward, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn sellCash(this: &InfiltrationReward) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_sellCash(this: &InfiltrationReward, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn SoARep(this: &InfiltrationReward) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_SoARep(this: &InfiltrationReward, value: f64);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[derive(Debug, Clone, PartialEq)]
+    pub type ILocation;
+
+    #[wasm_bindgen(method, getter)]
+    pub fn city(this: &ILocation) -> CityName;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_city(this: &ILocation, value: CityName);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn name(this: &ILocation) -> LocationName;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_name(this: &ILocation, value: LocationName);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[derive(Debug, Clone, PartialEq)]
+    pub type InfiltrationLocation;
+
+    #[wasm_bindgen(method, getter)]
+    pub fn location(this: &InfiltrationLocation) -> ILocation;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_location(this: &InfiltrationLocation, value: &ILocation);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn reward(this: &InfiltrationLocation) -> InfiltrationReward;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_reward(this: &InfiltrationLocation, value: &InfiltrationReward);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn difficulty(this: &InfiltrationLocation) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_difficulty(this: &InfiltrationLocation, value: f64);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// Infiltration API.
+    #[derive(Debug, Clone, PartialEq)]
+    pub type Infiltration;
+
+    /// Get all locations that can be infiltrated.
+    /// @remarks
+    /// RAM cost: 5 GB
+    ///
+    /// @returns all locations that can be infiltrated.
+    #[wasm_bindgen(method, catch)]
+    pub fn getPossibleLocations(this: &Infiltration) -> Result<Vec<ILocation>, JsValue>;
+
+    /// Get all infiltrations with difficulty, location and rewards.
+    /// @remarks
+    /// RAM cost: 15 GB
+    ///
+    /// @returns Infiltration data for given location.
+    #[wasm_bindgen(method, catch)]
+    pub fn getInfiltration(
+        this: &Infiltration,
+        location: &str,
+    ) -> Result<InfiltrationLocation, JsValue>;
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// User Interface API.
+    #[derive(Debug, Clone, PartialEq)]
+    pub type UserInterface;
+
+    /// Get the current window size
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// @returns An array of 2 value containing the window width and height.
+    #[wasm_bindgen(method, catch)]
+    pub fn windowSize(this: &UserInterface) -> Result<Vec<f64>, JsValue>;
+
+    /// Get the current theme
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// @returns An object containing the theme's colors
+    #[wasm_bindgen(method, catch)]
+    pub fn getTheme(this: &UserInterface) -> Result<UserInterfaceTheme, JsValue>;
+
+    /// Sets the current theme
+    /// @remarks
+    /// RAM cost: 0 GB
+    /// @example
+    /// Usage example (NS2)
+    /// ```ts
+    /// const theme = ns.ui.getTheme();
+    /// theme.primary = '#ff5500';
+    /// ns.ui.setTheme(theme);
+    /// ```
+    #[wasm_bindgen(method, catch)]
+    pub fn setTheme(this: &UserInterface, newTheme: &UserInterfaceTheme) -> Result<(), JsValue>;
+
+    /// Resets the player's theme to the default values
+    /// @remarks
+    /// RAM cost: 0 GB
+    #[wasm_bindgen(method, catch)]
+    pub fn resetTheme(this: &UserInterface) -> Result<(), JsValue>;
+
+    /// Get the current styles
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// @returns An object containing the player's styles
+    #[wasm_bindgen(method, catch)]
+    pub fn getStyles(this: &UserInterface) -> Result<IStyleSettings, JsValue>;
+
+    /// Sets the current styles
+    /// @remarks
+    /// RAM cost: 0 GB
+    /// @example
+    /// Usage example (NS2)
+    /// ```ts
+    /// const styles = ns.ui.getStyles();
+    /// styles.fontFamily = 'Comic Sans Ms';
+    /// ns.ui.setStyles(styles);
+    /// ```
+    #[wasm_bindgen(method, catch)]
+    pub fn setStyles(this: &UserInterface, newStyles: &IStyleSettings) -> Result<(), JsValue>;
+
+    /// Resets the player's styles to the default values
+    /// @remarks
+    /// RAM cost: 0 GB
+    #[wasm_bindgen(method, catch)]
+    pub fn resetStyles(this: &UserInterface) -> Result<(), JsValue>;
+
+    /// Gets the current game information (version, commit, ...)
+    /// @remarks
+    /// RAM cost: 0 GB
+    #[wasm_bindgen(method, catch)]
+    pub fn getGameInfo(this: &UserInterface) -> Result<GameInfo, JsValue>;
+
+    /// Clear the Terminal window, as if the player ran `clear` in the terminal
+    /// @remarks
+    /// RAM cost: 0.2 GB
+    #[wasm_bindgen(method, catch)]
+    pub fn clearTerminal(this: &UserInterface) -> Result<(), JsValue>;
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// Collection of all functions passed to scripts
+    ///
+    /// @remarks
+    /// <b>Basic usage example:</b>
+    /// ```js
+    /// export async function main(ns) {
+    ///  // Basic ns functions can be accessed on the ns object
+    ///  ns.getHostname();
+    ///  // Some related functions are gathered under a sub-property of the ns object
+    ///  ns.stock.getPrice();
+    ///  // Most functions that return a promise need to be awaited.
+    ///  await ns.hack('n00dles');
+    /// }
+    /// ```
+    #[derive(Debug, Clone, PartialEq)]
+    pub type NS;
+
+    /// Namespace for hacknet functions. Some of this API contains spoilers.
+    /// @remarks RAM cost: 4 GB.
+    #[wasm_bindgen(method, getter)]
+    pub fn hacknet(this: &NS) -> Hacknet;
+
+    /// Namespace for bladeburner functions. Contains spoilers.
+    /// @remarks RAM cost: 0 GB
+    #[wasm_bindgen(method, getter)]
+    pub fn bladeburner(this: &NS) -> Bladeburner;
+
+    /// Namespace for codingcontract functions.
+    /// @remarks RAM cost: 0 GB
+    #[wasm_bindgen(method, getter)]
+    pub fn codingcontract(this: &NS) -> CodingContract;
+
+    /// Namespace for gang functions. Contains spoilers.
+    /// @remarks RAM cost: 0 GB
+    #[wasm_bindgen(method, getter)]
+    pub fn gang(this: &NS) -> Gang;
+
+    /// Namespace for sleeve functions. Contains spoilers.
+    /// @remarks RAM cost: 0 GB
+    #[wasm_bindgen(method, getter)]
+    pub fn sleeve(this: &NS) -> Sleeve;
+
+    /// Namespace for stock functions.
+    /// @remarks RAM cost: 0 GB
+    #[wasm_bindgen(method, getter)]
+    pub fn stock(this: &NS) -> TIX;
+
+    /// Namespace for formulas functions.
+    /// @remarks RAM cost: 0 GB
+    #[wasm_bindgen(method, getter)]
+    pub fn formulas(this: &NS) -> Formulas;
+
+    /// Namespace for stanek functions. Contains spoilers.
+    /// @remarks RAM cost: 0 GB
+    #[wasm_bindgen(method, getter)]
+    pub fn stanek(this: &NS) -> Stanek;
+
+    /// Namespace for infiltration functions.
+    /// @remarks RAM cost: 0 GB
+    #[wasm_bindgen(method, getter)]
+    pub fn infiltration(this: &NS) -> Infiltration;
+
+    /// Namespace for corporation functions. Contains spoilers.
+    /// @remarks RAM cost: 0 GB
+    #[wasm_bindgen(method, getter)]
+    pub fn corporation(this: &NS) -> Corporation;
+
+    /// Namespace for user interface functions.
+    /// @remarks RAM cost: 0 GB
+    #[wasm_bindgen(method, getter)]
+    pub fn ui(this: &NS) -> UserInterface;
+
+    /// Namespace for singularity functions. Contains spoilers.
+    /// @remarks RAM cost: 0 GB
+    #[wasm_bindgen(method, getter)]
+    pub fn singularity(this: &NS) -> Singularity;
+
+    /// Namespace for grafting functions. Contains spoilers.
+    /// @remarks RAM cost: 0 GB
+    #[wasm_bindgen(method, getter)]
+    pub fn grafting(this: &NS) -> Grafting;
+
+    /// Arguments passed into the script.
+    ///
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// Arguments passed into a script can be accessed as a normal array by using the `[]` operator
+    /// (`args[0]`, `args[1]`, etc...).
+    /// Arguments can be string, number, or boolean.
+    /// Use `args.length` to get the number of arguments that were passed into a script.
+    ///
+    /// @example
+    /// `run example.js 7 text true`
+    ///
+    /// ```js
+    /// // example.js
+    /// export async function main(ns) {
+    ///   ns.tprint(ns.args.length) // 3
+    ///   ns.tprint(ns.args[0]); // 7 (number)
+    ///   ns.tprint(ns.args[1]); // "text" (string)
+    ///   ns.tprint(ns.args[2]); // true (boolean)
+    ///   ns.tprint(ns.args[3]); // undefined, because only 3 arguments were provided
+    /// }
+    /// ```
+    #[wasm_bindgen(method, getter)]
+    pub fn args(this: &NS) -> Vec<JsValue>;
+
+    /// The current script's PID
+    #[wasm_bindgen(method, getter)]
+    pub fn pid(this: &NS) -> f64;
+
+    /// Steal a server's money.
+    /// @remarks
+    /// RAM cost: 0.1 GB
+    ///
+    /// Function that is used to try and hack servers to steal money and gain hacking experience.
+    /// The runtime for this command depends on your hacking level and the target server’s
+    /// security level when this function is called. In order to hack a server you must first gain root access to that server
+    /// and also have the required hacking level.
+    ///
+    /// A script can hack a server from anywhere. It does not need to be running on the same
+    /// server to hack that server. For example, you can create a script that hacks the `foodnstuff`
+    /// server and run that script on any server in the game.
+    ///
+    /// A successful `hack()` on a server will raise that server’s security level by 0.002.
+    ///
+    /// @example
+    /// ```js
+    /// let earnedMoney = await ns.hack("foodnstuff");
+    /// ```
+    /// @param host - Hostname of the target server to hack.
+    /// @param opts - Optional parameters for configuring function behavior.
+    /// @returns A promise that resolves to the amount of money stolen (which is zero if the hack is unsuccessful).
+    #[wasm_bindgen(method, catch)]
+    pub async fn hack(
+        this: &NS,
+        host: &str,
+        opts: Option<&BasicHGWOptions>,
+    ) -> Result<f64, JsValue>;
+
+    /// Spoof money in a server's bank account, increasing the amount available.
+    /// @remarks
+    /// RAM cost: 0.15 GB
+    ///
+    /// Use your hacking skills to increase the amount of money available on a server.
+    ///
+    /// Once the grow is complete, $1 is added to the server's available money for every script thread. This additive
+    /// growth allows for rescuing a server even after it is emptied.
+    ///
+    /// After this addition, the thread count is also used to determine a multiplier, which the server's money is then
+    /// multiplied by.
+    ///
+    /// The multiplier scales exponentially with thread count, and its base depends on the server's security
+    /// level and in inherent "growth" statistic that varies between different servers.
+    ///
+    /// {@link NS.getServerGrowth | getServerGrowth} can be used to check the inherent growth statistic of a server.
+    ///
+    /// {@link NS.growthAnalyze | growthAnalyze} can be used to determine the number of threads needed for a specified
+    /// multiplicative portion of server growth.
+    ///
+    /// To determine the effect of a single grow, obtain access to the Formulas API and use
+    /// {@link HackingFormulas.growPercent | formulas.hacking.growPercent}, or invert {@link NS.growthAnalyze | growthAnalyze}.
+    ///
+    /// Like {@link NS.hack | hack}, `grow` can be called on any hackable server, regardless of where the script is
+    /// running. Hackable servers are any servers not owned by the player.
+    ///
+    /// The grow() command requires root access to the target server, but there is no required hacking
+    /// level to run the command. It also raises the security level of the target server based on the number of threads.
+    /// The security increase can be determined using {@link NS.growthAnalyzeSecurity | growthAnalyzeSecurity}.
+    ///
+    /// @example
+    /// ```js
+    /// let currentMoney = ns.getServerMoneyAvailable("n00dles");
+    /// currentMoney *= await ns.grow("foodnstuff");
+    /// ```
+    /// @param host - Hostname of the target server to grow.
+    /// @param opts - Optional parameters for configuring function behavior.
+    /// @returns The total effective multiplier that was applied to the server's money (after both additive and multiplicative growth).
+    #[wasm_bindgen(method, catch)]
+    pub async fn grow(
+        this: &NS,
+        host: &str,
+        opts: Option<&BasicHGWOptions>,
+    ) -> Result<f64, JsValue>;
+
+    /// Reduce a server's security level.
+    /// @remarks
+    /// RAM cost: 0.15 GB
+    ///
+    /// Use your hacking skills to attack a server’s security, lowering the server’s security level.
+    /// The runtime for this function depends on your hacking level and the target server’s security
+    /// level when this function is called. This function lowers the security level of the target server by 0.05.
+    ///
+    /// Like {@link NS.hack | hack} and {@link NS.grow| grow}, `weaken` can be called on any server, regardless of
+    /// where the script is running. This function requires root access to the target server, but
+    /// there is no required hacking level to run the function.
+    ///
+    /// @example
+    /// ```js
+    /// let currentSecurity = ns.getServerSecurityLevel("foodnstuff");
+    /// currentSecurity -= await ns.weaken("foodnstuff");
+    /// ```
+    /// @param host - Hostname of the target server to weaken.
+    /// @param opts - Optional parameters for configuring function behavior.
+    /// @returns A promise that resolves to the value by which security was reduced.
+    #[wasm_bindgen(method, catch)]
+    pub async fn weaken(
+        this: &NS,
+        host: &str,
+        opts: Option<&BasicHGWOptions>,
+    ) -> Result<f64, JsValue>;
+
+    /// Predict the effect of weaken.
+    /// @remarks
+    /// RAM cost: 1 GB
+    ///
+    /// Returns the security decrease that would occur if a weaken with this many threads happened.
+    ///
+    /// @param threads - Amount of threads that will be used.
+    /// @param cores - Optional. The number of cores of the server that would run weaken.
+    /// @returns The security decrease.
+    #[wasm_bindgen(method, catch)]
+    pub fn weakenAnalyze(this: &NS, threads: f64, cores: Option<f64>) -> Result<f64, JsValue>;
+
+    /// Calculate the decimal number of threads needed to hack a specified amount of money from a target host.
+    /// @remarks
+    /// RAM cost: 1 GB
+    ///
+    /// This function returns the decimal number of script threads you need when running the hack command
+    /// to steal the specified amount of money from the target server.
+    /// If hackAmount is less than zero or greater than the amount of money available on the server,
+    /// then this function returns -1.
+    ///
+    ///
+    /// @example
+    /// ```ts
+    /// // Calculate threadcount of a single hack that would take $100k from n00dles
+    /// const hackThreads = hackAnalyzeThreads("n00dles", 1e5);
+    ///
+    /// // Launching a script requires an integer thread count. The below would take less than the targeted $100k.
+    /// ns.run("noodleHack.js", Math.floor(hackThreads))
+    ///
+    /// ```
+    /// @param host - Hostname of the target server to analyze.
+    /// @param hackAmount - Amount of money you want to hack from the server.
+    /// @returns The number of threads needed to hack the server for hackAmount money.
+    #[wasm_bindgen(method, catch)]
+    pub fn hackAnalyzeThreads(this: &NS, host: &str, hackAmount: f64) -> Result<f64, JsValue>;
+
+    /// Get the part of money stolen with a single thread.
+    /// @remarks
+    /// RAM cost: 1 GB
+    ///
+    /// Returns the part of the specified server’s money you will steal with a single thread hack.
+    ///
+    /// Like other basic hacking analysis functions, this calculation uses the current status of the player and server.
+    /// To calculate using hypothetical server or player status, obtain access to the Formulas API and use {@link HackingFormulas.hackPercent | formulas.hacking.hackPercent}.
+    ///
+    /// @example
+    /// ```js
+    /// //For example, assume the following returns 0.01:
+    /// const hackAmount = ns.hackAnalyze("foodnstuff");
+    /// //This means that if hack the foodnstuff server using a single thread, then you will steal 1%, or 0.01 of its total money. If you hack using N threads, then you will steal N*0.01 times its total money.
+    /// ```
+    /// @param host - Hostname of the target server.
+    /// @returns The part of money you will steal from the target server with a single thread hack.
+    #[wasm_bindgen(method, catch)]
+    pub fn hackAnalyze(this: &NS, host: &str) -> Result<f64, JsValue>;
+
+    /// Get the security increase for a number of threads.
+    /// @remarks
+    /// RAM cost: 1 GB
+    ///
+    /// Returns the security increase that would occur if a hack with this many threads happened.
+    ///
+    /// @param threads - Amount of threads that will be used.
+    /// @param hostname - Hostname of the target server. The number of threads is limited to the number needed to hack the server's maximum amount of money.
+    /// @returns The security increase.
+    #[wasm_bindgen(method, catch)]
+    pub fn hackAnalyzeSecurity(
+        this: &NS,
+        threads: f64,
+        hostname: Option<&str>,
+    ) -> Result<f64, JsValue>;
+
+    /// Get the chance of successfully hacking a server.
+    /// @remarks
+    /// RAM cost: 1 GB
+    ///
+    /// Returns the chance you have of successfully hacking the specified server.
+    ///
+    /// This returned value is in decimal form, not percentage.
+    ///
+    /// Like other basic hacking analysis functions, this calculation uses the current status of the player and server.
+    /// To calculate using hypothetical server or player status, obtain access to the Formulas API and use {@link HackingFormulas.hackChance | formulas.hacking.hackChance}.
+    ///
+    /// @param host - Hostname of the target server.
+    /// @returns The chance you have of successfully hacking the target server.
+    #[wasm_bindgen(method, catch)]
+    pub fn hackAnalyzeChance(this: &NS, host: &str) -> Result<f64, JsValue>;
+
+    /// Calculate the number of grow threads needed for a given multiplicative growth factor.
+    /// @remarks
+    /// RAM cost: 1 GB
+    ///
+    /// This function returns the total decimal number of {@link NS.grow | grow} threads needed in order to multiply the
+    /// money available on the specified server by a given multiplier, if all threads are executed at the server's current
+    /// security level, regardless of how many threads are assigned to each call.
+    ///
+    /// Note that there is also an additive factor that is applied before the multiplier. Each {@link NS.grow | grow} call
+    /// will add $1 to the host's money for each thread before applying the multiplier for its thread count. This means
+    /// that at extremely low starting money, fewer threads would be needed to apply the same effective multiplier than
+    /// what is calculated by growthAnalyze.
+    ///
+    /// Like other basic hacking analysis functions, this calculation uses the current status of the player and server.
+    /// To calculate using hypothetical server or player status, obtain access to the Formulas API and use {@link HackingFormulas.growThreads | formulas.hacking.growThreads}.
+    ///
+    /// @example
+    /// ```js
+    /// // calculate number of grow threads to apply 2x growth multiplier on n00dles (does not include the additive growth).
+    /// const growThreads = ns.growthAnalyze("n00dles", 2);
+    ///
+    /// // When using the thread count to launch a script, it needs to be converted to an integer.
+    /// ns.run("noodleGrow.js", Math.ceil(growThreads));
+    /// ```
+    /// @param host - Hostname of the target server.
+    /// @param multiplier - Multiplier that will be applied to a server's money after applying additive growth. Decimal form.
+    /// @param cores - Number of cores on the host running the grow function. Optional, defaults to 1.
+    /// @returns Decimal number of grow threads needed for the specified multiplicative growth factor (does not include additive growth).
+    #[wasm_bindgen(method, catch)]
+    pub fn growthAnalyze(
+        this: &NS,
+        host: &str,
+        multiplier: f64,
+        cores: Option<f64>,
+    ) -> Result<f64, JsValue>;
+
+    /// Calculate the security increase for a number of grow threads.
+    /// @remarks
+    /// RAM cost: 1 GB
+    ///
+    /// Returns the security increase that would occur if a grow with this many threads happened.
+    ///
+    /// @param threads - Amount of threads that will be used.
+    /// @param hostname - Optional. Hostname of the target server. If provided, security increase is limited by the number of threads needed to reach maximum money.
+    /// @param cores - Optional. The number of cores of the server that would run grow.
+    /// @returns The security increase.
+    #[wasm_bindgen(method, catch)]
+    pub fn growthAnalyzeSecurity(
+        this: &NS,
+        threads: f64,
+        hostname: Option<&str>,
+        cores: Option<f64>,
+    ) -> Result<f64, JsValue>;
+
+    /// Suspends the script for n milliseconds.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// @param millis - Number of milliseconds to sleep.
+    /// @example
+    /// ```js
+    /// // This will count from 1 to 10 in your terminal, with one number every 5 seconds
+    /// for (var i = 1; i <= 10; i++) {
+    ///   ns.tprint(i);
+    ///   await ns.sleep(5000);
+    /// }
+    /// ```
+    /// @returns A promise that resolves to true when the sleep is completed.
+    #[wasm_bindgen(method, catch)]
+    pub async fn sleep(this: &NS, millis: f64) -> Result<bool, JsValue>;
+
+    /// Suspends the script for n milliseconds. Doesn't block with concurrent calls.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// @param millis - Number of milliseconds to sleep.
+    /// @returns A promise that resolves to true when the sleep is completed.
+    #[wasm_bindgen(method, catch)]
+    pub async fn asleep(this: &NS, millis: f64) -> Result<bool, JsValue>;
+
+    /// Prints one or more values or variables to the script’s logs.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// If the argument is a string, you can color code your message by prefixing your
+    /// string with one of these strings:
+    ///
+    /// - `"ERROR"`: The whole string will be printed in red. Use this prefix to indicate
+    /// that an error has occurred.
+    ///
+    /// - `"SUCCESS"`: The whole string will be printed in green, similar to the default
+    /// theme of the Terminal. Use this prefix to indicate that something is correct.
+    ///
+    /// - `"WARN"`: The whole string will be printed in yellow. Use this prefix to
+    /// indicate that you or a user of your script should be careful of something.
+    ///
+    /// - `"INFO"`: The whole string will be printed in purplish blue. Use this prefix to
+    /// remind yourself or a user of your script of something. Think of this prefix as
+    /// indicating an FYI (for your information).
+    ///
+    /// For custom coloring, use ANSI escape sequences. The examples below use the Unicode
+    /// escape code `\u001b`. The color coding also works if `\u001b` is replaced with
+    /// the hexadecimal escape code `\x1b`. The Bash escape code `\e` is not supported.
+    /// The octal escape code `\033` is not allowed because the game runs JavaScript in
+    /// strict mode.
+    ///
+    /// @example
+    /// ```js
+    /// // Default color coding.
+    /// ns.print("ERROR means something's wrong.");
+    /// ns.print("SUCCESS means everything's OK.");
+    /// ns.print("WARN Tread with caution!");
+    /// ns.print("WARNING, warning, danger, danger!");
+    /// ns.print("WARNing! Here be dragons.");
+    /// ns.print("INFO for your I's only (FYI).");
+    /// ns.print("INFOrmation overload!");
+    /// // Custom color coding.
+    /// const cyan = "\u001b[36m";
+    /// const green = "\u001b[32m";
+    /// const red = "\u001b[31m";
+    /// const reset = "\u001b[0m";
+    /// ns.print(`${red}Ugh! What a mess.${reset}`);
+    /// ns.print(`${green}Well done!${reset}`);
+    /// ns.print(`${cyan}ERROR Should this be in red?${reset}`);
+    /// ns.tail();
+    /// ```
+    ///
+    /// @param args - Value(s) to be printed.
+    #[wasm_bindgen(method, catch, variadic)]
+    pub fn print(this: &NS, args: &[JsValue]) -> Result<(), JsValue>;
+
+    /// Prints a ReactNode to the script logs.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// See {@link ReactNode} type for the acceptable values.
+    ///
+    /// @param node - The React node to be printed.
+    #[wasm_bindgen(method, catch)]
+    pub fn printRaw(this: &NS, node: &JsValue) -> Result<(), JsValue>;
+
+    /// Prints a formatted string to the script’s logs.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// - See {@link NS.print | print} for how to add color to your printed strings.
+    ///
+    /// - For more detail, see: https://github.com/alexei/sprintf.js
+    ///
+    /// @example
+    /// ```js
+    /// const name = "Bit";
+    /// const age = 4;
+    /// ns.printf("My name is %s.", name);
+    /// ns.printf("I'm %d seconds old.", age);
+    /// ns.printf("My age in binary is %b.", age);
+    /// ns.printf("My age in scientific notation is %e.", age);
+    /// ns.printf("In %d seconds, I'll be %s.", 6, "Byte");
+    /// ns.printf("Am I a nibble? %t", (4 == age));
+    /// ns.tail();
+    /// ```
+    ///
+    /// @param format - Format of the message.
+    /// @param args - Value(s) to be printed.
+    #[wasm_bindgen(method, catch, variadic)]
+    pub fn printf(this: &NS, format: &str, args: &[JsValue]) -> Result<(), JsValue>;
+
+    /// Prints one or more values or variables to the Terminal.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// See {@link NS.print | print} for how to add color to your printed strings.
+    ///
+    /// @param args - Value(s) to be printed.
+    #[wasm_bindgen(method, catch, variadic)]
+    pub fn tprint(this: &NS, args: &[JsValue]) -> Result<(), JsValue>;
+
+    /// Prints a ReactNode to the terminal.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// See {@link ReactNode} type for the acceptable values.
+    ///
+    /// @param node - The React node to be printed.
+    #[wasm_bindgen(method, catch)]
+    pub fn tprintRaw(this: &NS, node: &JsValue) -> Result<(), JsValue>;
+
+    /// Prints a raw value or a variable to the Terminal.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// - See {@link NS.print | print} for how to add color to your printed strings.
+    ///
+    /// - See {@link NS.printf | printf} for examples on formatted strings.
+    ///
+    /// - For more detail, see: https://github.com/alexei/sprintf.js
+    ///
+    /// @param format - Format of the message.
+    /// @param values - Value(s) to be printed.
+    #[wasm_bindgen(method, catch, variadic)]
+    pub fn tprintf(this: &NS, format: &str, values: &[JsValue]) -> Result<(), JsValue>;
+
+    /// Clears the script’s logs.
+    /// @remarks
+    /// RAM cost: 0 GB
+    #[wasm_bindgen(method, catch)]
+    pub fn clearLog(this: &NS) -> Result<(), JsValue>;
+
+    /// Disables logging for the given function.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// Logging can be disabled for all functions by passing `ALL` as the argument.
+    ///
+    /// @param fn - Name of function for which to disable logging.
+    #[wasm_bindgen(method, catch)]
+    pub fn disableLog(this: &NS, r#fn: &str) -> Result<(), JsValue>;
+
+    /// Enable logging for a certain function.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// Re-enables logging for the given function. If `ALL` is passed into this
+    /// function as an argument, then it will revert the effects of disableLog(`ALL`).
+    ///
+    /// @param fn - Name of function for which to enable logging.
+    #[wasm_bindgen(method, catch)]
+    pub fn enableLog(this: &NS, r#fn: &str) -> Result<(), JsValue>;
+
+    /// Checks the status of the logging for the given function.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// @param fn - Name of function to check.
+    /// @returns Returns a boolean indicating whether or not logging is enabled for that function (or `ALL`).
+    #[wasm_bindgen(method, catch)]
+    pub fn isLogEnabled(this: &NS, r#fn: &str) -> Result<bool, JsValue>;
+
+    /// Get all the logs of a script.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// Returns a script’s logs. The logs are returned as an array, where each line is an element in the array.
+    /// The most recently logged line is at the end of the array.
+    /// Note that there is a maximum number of lines that a script stores in its logs. This is configurable in the game’s options.
+    /// If the function is called with no arguments, it will return the current script’s logs.
+    ///
+    /// Otherwise, the PID or filename, hostname/ip, and args… arguments can be used to get logs from another script.
+    /// Remember that scripts are uniquely identified by both their names and arguments.
+    ///
+    /// @example
+    /// ```js
+    /// //Get logs from foo.js on the current server that was run with no args
+    /// ns.getScriptLogs("foo.js");
+    ///
+    /// //Open logs from foo.js on the foodnstuff server that was run with no args
+    /// ns.getScriptLogs("foo.js", "foodnstuff");
+    ///
+    /// //Open logs from foo.js on the foodnstuff server that was run with the arguments [1, "test"]
+    /// ns.getScriptLogs("foo.js", "foodnstuff", 1, "test");
+    /// ```
+    /// @param fn - Optional. Filename or PID of script to get logs from.
+    /// @param host - Optional. Hostname of the server that the script is on.
+    /// @param args - Arguments to identify which scripts to get logs for.
+    /// @returns Returns a string array, where each line is an element in the array. The most recently logged line is at the end of the array.
+    #[wasm_bindgen(method, catch, variadic)]
+    pub fn getScriptLogs(
+        this: &NS,
+        r#fn: Option<&JsValue>,
+        host: Option<&str>,
+        args: &[JsValue],
+    ) -> Result<Vec<String>, JsValue>;
+
+    /// Get an array of recently killed scripts across all servers.
+    /// @remarks
+    /// RAM cost: 0.2 GB
+    ///
+    /// The most recently killed script is the first element in the array.
+    /// Note that there is a maximum number of recently killed scripts which are tracked.
+    /// This is configurable in the game's options as `Recently killed scripts size`.
+    ///
+    /// @example
+    /// ```ts
+    /// let recentScripts = ns.getRecentScripts();
+    /// let mostRecent = recentScripts.shift()
+    /// if (mostRecent)
+    ///   ns.tprint(mostRecent.logs.join('\n'))
+    /// ```
+    ///
+    /// @returns Array with information about previously killed scripts.
+    #[wasm_bindgen(method, catch)]
+    pub fn getRecentScripts(this: &NS) -> Result<Vec<RecentScript>, JsValue>;
+
+    /// Open the tail window of a script.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// Opens a script’s logs. This is functionally the same as the tail Terminal command.
+    ///
+    /// If the function is called with no arguments, it will open the current script’s logs.
+    ///
+    /// Otherwise, the PID or filename, hostname/ip, and args… arguments can be used to get the logs from another script.
+    /// Remember that scripts are uniquely identified by both their names and arguments.
+    ///
+    /// @example
+    /// ```js
+    /// //Open logs from foo.js on the current server that was run with no args
+    /// ns.tail("foo.js");
+    ///
+    /// //Get logs from foo.js on the foodnstuff server that was run with no args
+    /// ns.tail("foo.js", "foodnstuff");
+    ///
+    /// //Get logs from foo.js on the foodnstuff server that was run with the arguments [1, "test"]
+    /// ns.tail("foo.js", "foodnstuff", 1, "test");
+    /// ```
+    /// @param fn - Optional. Filename or PID of the script being tailed. If omitted, the current script is tailed.
+    /// @param host - Optional. Hostname of the script being tailed. Defaults to the server this script is running on. If args are specified, this is not optional.
+    /// @param args - Arguments for the script being tailed.
+    #[wasm_bindgen(method, catch, variadic)]
+    pub fn tail(
+        this: &NS,
+        r#fn: Option<&JsValue>,
+        host: Option<&str>,
+        args: &[JsValue],
+    ) -> Result<(), JsValue>;
+
+    /// Move a tail window.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// Moves a tail window. Coordinates are in screenspace pixels (top left is 0,0).
+    ///
+    /// @param x - x coordinate.
+    /// @param y - y coordinate.
+    /// @param pid - Optional. PID of the script having its tail moved. If omitted, the current script is used.
+    #[wasm_bindgen(method, catch)]
+    pub fn moveTail(this: &NS, x: f64, y: f64, pid: Option<f64>) -> Result<(), JsValue>;
+
+    /// Resize a tail window.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// Resize a tail window. Size are in pixel.
+    ///
+    /// @param width - Width of the window.
+    /// @param height - Height of the window.
+    /// @param pid - Optional. PID of the script having its tail resized. If omitted, the current script is used.
+    #[wasm_bindgen(method, catch)]
+    pub fn resizeTail(this: &NS, width: f64, height: f64, pid: Option<f64>) -> Result<(), JsValue>;
+
+    /// Close the tail window of a script.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// Closes a script’s logs. This is functionally the same as pressing the "Close" button on the tail window.
+    ///
+    /// If the function is called with no arguments, it will close the current script’s logs.
+    ///
+    /// Otherwise, the pid argument can be used to close the logs from another script.
+    ///
+    /// @param pid - Optional. PID of the script having its tail closed. If omitted, the current script is used.
+    #[wasm_bindgen(method, catch)]
+    pub fn closeTail(this: &NS, pid: Option<f64>) -> Result<(), JsValue>;
+
+    /// Set the title of the tail window of a script.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// This sets the title to the given string, and also forces an update of the
+    /// tail window's contents.
+    ///
+    /// The title is saved across restarts, but only if it is a simple string.
+    ///
+    /// If the pid is unspecified, it will modify the current script’s logs.
+    ///
+    /// Otherwise, the pid argument can be used to change the logs from another script.
+    ///
+    /// It is possible to pass any React Node instead of a string.
+    /// See {@link ReactElement} and {@link ReactNode} types for additional info.
+    ///
+    /// @param title - The new title for the tail window.
+    /// @param pid - Optional. PID of the script having its tail closed. If omitted, the current script is used.
+    #[wasm_bindgen(method, catch)]
+    pub fn setTitle(this: &NS, title: &JsValue, pid: Option<f64>) -> Result<(), JsValue>;
+
+    /// Get the list of servers connected to a server.
+    /// @remarks
+    /// RAM cost: 0.2 GB
+    ///
+    /// Returns an array containing the hostnames of all servers that are one
+    /// node way from the specified target server. The hostnames in the returned
+    /// array are strings.
+    ///
+    /// @example
+    /// ```js
+    /// // All servers that are one hop from the current server.
+    /// ns.tprint("Neighbors of current server.");
+    /// let neighbor = ns.scan();
+    /// for (let i = 0; i < neighbor.length; i++) {
+    ///     ns.tprint(neighbor[i]);
+    /// }
+    /// // All neighbors of n00dles.
+    /// const target = "n00dles";
+    /// neighbor = ns.scan(target);
+    /// ns.tprintf("Neighbors of %s.", target);
+    /// for (let i = 0; i < neighbor.length; i++) {
+    ///     ns.tprint(neighbor[i]);
+    /// }
+    /// ```
+    ///
+    /// @param host - Optional. Hostname of the server to scan, default to current server.
+    /// @returns Returns an array of hostnames.
+    #[wasm_bindgen(method, catch)]
+    pub fn scan(this: &NS, host: Option<&str>) -> Result<Vec<String>, JsValue>;
+
+    /// Returns whether the player has access to the darkweb.
+    /// @remarks
+    /// RAM cost: 0.05GB
+    ///
+    /// @example
+    /// ```js
+    /// if (ns.hasTorRouter()) ns.tprint("TOR router detected.");
+    /// ```
+    ///
+    /// @returns Whether player has access to the dark web.
+    #[wasm_bindgen(method, catch)]
+    pub fn hasTorRouter(this: &NS) -> Result<bool, JsValue>;
+
+    /// Runs NUKE.exe on a server.
+    /// @remarks
+    /// RAM cost: 0.05 GB
+    ///
+    /// Running NUKE.exe on a target server gives you root access which means you can execute scripts on said server. NUKE.exe must exist on your home computer.
+    ///
+    /// @example
+    /// ```js
+    /// ns.nuke("foodnstuff");
+    /// ```
+    /// @param host - Hostname of the target server.
+    #[wasm_bindgen(method, catch)]
+    pub fn nuke(this: &NS, host: &str) -> Result<(), JsValue>;
+
+    /// Runs BruteSSH.exe on a server.
+    /// @remarks
+    /// RAM cost: 0.05 GB
+    ///
+    /// Runs the BruteSSH.exe program on the target server. BruteSSH.exe must exist on your home computer.
+    ///
+    /// @example
+    /// ```js
+    /// ns.brutessh("foodnstuff");
+    /// ```
+    /// @param host - Hostname of the target server.
+    #[wasm_bindgen(method, catch)]
+    pub fn brutessh(this: &NS, host: &str) -> Result<(), JsValue>;
+
+    /// Runs FTPCrack.exe on a server.
+    /// @remarks
+    /// RAM cost: 0.05 GB
+    ///
+    /// Runs the FTPCrack.exe program on the target server. FTPCrack.exe must exist on your home computer.
+    ///
+    /// @example
+    /// ```js
+    /// ns.ftpcrack("foodnstuff");
+    /// ```
+    /// @param host - Hostname of the target server.
+    #[wasm_bindgen(method, catch)]
+    pub fn ftpcrack(this: &NS, host: &str) -> Result<(), JsValue>;
+
+    /// Runs relaySMTP.exe on a server.
+    /// @remarks
+    /// RAM cost: 0.05 GB
+    ///
+    /// Runs the relaySMTP.exe program on the target server. relaySMTP.exe must exist on your home computer.
+    ///
+    /// @example
+    /// ```js
+    /// ns.relaysmtp("foodnstuff");
+    /// ```
+    /// @param host - Hostname of the target server.
+    #[wasm_bindgen(method, catch)]
+    pub fn relaysmtp(this: &NS, host: &str) -> Result<(), JsValue>;
+
+    /// Runs HTTPWorm.exe on a server.
+    /// @remarks
+    /// RAM cost: 0.05 GB
+    ///
+    /// Runs the HTTPWorm.exe program on the target server. HTTPWorm.exe must exist on your home computer.
+    ///
+    /// @example
+    /// ```js
+    /// ns.httpworm("foodnstuff");
+    /// ```
+    /// @param host - Hostname of the target server.
+    #[wasm_bindgen(method, catch)]
+    pub fn httpworm(this: &NS, host: &str) -> Result<(), JsValue>;
+
+    /// Runs SQLInject.exe on a server.
+    /// @remarks
+    /// RAM cost: 0.05 GB
+    ///
+    /// Runs the SQLInject.exe program on the target server. SQLInject.exe must exist on your home computer.
+    ///
+    /// @example
+    /// ```js
+    /// ns.sqlinject("foodnstuff");
+    /// ```
+    /// @remarks RAM cost: 0.05 GB
+    /// @param host - Hostname of the target server.
+    #[wasm_bindgen(method, catch)]
+    pub fn sqlinject(this: &NS, host: &str) -> Result<(), JsValue>;
+
+    /// Start another script on the current server.
+    /// @remarks
+    /// RAM cost: 1 GB
+    ///
+    /// Run a script as a separate process. This function can only be used to run scripts located on the
+    /// current server (the server running the script that calls this function). Requires a significant
+    /// amount of RAM to run this command.
+    ///
+    /// The second argument is either a thread count, or a {@link RunOptions} object that can also
+    /// specify the number of threads (among other things).
+    ///
+    /// If the script was successfully started, then this functions returns the PID of that script.
+    /// Otherwise, it returns 0.
+    ///
+    /// PID stands for Process ID. The PID is a unique identifier for each script.
+    /// The PID will always be a positive integer.
+    ///
+    /// Running this function with 0 or fewer threads will cause a runtime error.
+    ///
+    /// @example
+    /// ```js
+    /// //The simplest way to use the run command is to call it with just the script name. The following example will run ‘foo.js’ single-threaded with no arguments:
+    /// ns.run("foo.js");
+    ///
+    /// //The following example will run ‘foo.js’ but with 5 threads instead of single-threaded:
+    /// ns.run("foo.js", {threads: 5});
+    ///
+    /// //This next example will run ‘foo.js’ single-threaded, and will pass the string ‘foodnstuff’ into the script as an argument:
+    /// ns.run("foo.js", 1, 'foodnstuff');
+    /// ```
+    /// @param script - Filename of script to run.
+    /// @param threadOrOptions - Either an integer number of threads for new script, or a {@link RunOptions} object. Threads defaults to 1.
+    /// @param args - Additional arguments to pass into the new script that is being run. Note that if any arguments are being passed into the new script, then the second argument threadOrOptions must be filled in with a value.
+    /// @returns Returns the PID of a successfully started script, and 0 otherwise.
+    #[wasm_bindgen(method, catch, variadic)]
+    pub fn run(
+        this: &NS,
+        script: &str,
+        threadOrOptions: Option<&JsValue>,
+        args: &[JsValue],
+    ) -> Result<f64, JsValue>;
+
+    /// Start another script on any server.
+    /// @remarks
+    /// RAM cost: 1.3 GB
+    ///
+    /// Run a script as a separate process on a specified server. This is similar to the function {@link NS.run | run}
+    /// except that it can be used to run a script that already exists on any server, instead of just the current server.
+    ///
+    /// If the script was successfully started, then this function returns the PID of that script.
+    /// Otherwise, it returns 0.
+    ///
+    /// PID stands for Process ID. The PID is a unique identifier for each script.
+    /// The PID will always be a positive integer.
+    ///
+    /// Running this function with 0 or fewer threads will cause a runtime error.
+    ///
+    /// @example
+    /// ```js
+    /// // The simplest way to use the exec command is to call it with just the script name
+    /// // and the target server. The following example will try to run generic-hack.js
+    /// // on the foodnstuff server.
+    /// ns.exec("generic-hack.js", "foodnstuff");
+    ///
+    /// // The following example will try to run the script generic-hack.js on the
+    /// // joesguns server with 10 threads.
+    /// ns.exec("generic-hack.js", "joesguns", {threads: 10});
+    ///
+    /// // This last example will try to run the script foo.js on the foodnstuff server
+    /// // with 5 threads. It will also pass the number 1 and the string “test” in as
+    /// // arguments to the script.
+    /// ns.exec("foo.js", "foodnstuff", 5, 1, "test");
+    /// ```
+    /// @param script - Filename of script to execute. This file must already exist on the target server.
+    /// @param hostname - Hostname of the `target server` on which to execute the script.
+    /// @param threadOrOptions - Either an integer number of threads for new script, or a {@link RunOptions} object. Threads defaults to 1.
+    /// @param args - Additional arguments to pass into the new script that is being run. Note that if any arguments are being passed into the new script, then the third argument threadOrOptions must be filled in with a value.
+    /// @returns Returns the PID of a successfully started script, and 0 otherwise.
+    #[wasm_bindgen(method, catch, variadic)]
+    pub fn exec(
+        this: &NS,
+        script: &str,
+        hostname: &str,
+        threadOrOptions: Option<&JsValue>,
+        args: &[JsValue],
+    ) -> Result<f64, JsValue>;
+
+    /// Terminate current script and start another in a defined number of milliseconds.
+    /// @remarks
+    /// RAM cost: 2 GB
+    ///
+    /// Terminates the current script, and then after a defined delay it will execute the
+    /// newly-specified script. The purpose of this function is to execute a new script without being
+    /// constrained by the RAM usage of the current one. This function can only be used to run scripts
+    /// on the local server.
+    ///
+    /// Because this function immediately terminates the script, it does not have a return value.
+    ///
+    /// Running this function with 0 or fewer threads will cause a runtime error.
+    ///
+    /// @example
+    /// ```js
+    /// //The following example will execute the script ‘foo.js’ with 10 threads, in 500 milliseconds and the arguments ‘foodnstuff’ and 90:
+    /// ns.spawn('foo.js', 10, 500, 'foodnstuff', 90);
+    /// ```
+    /// @param script - Filename of script to execute.
+    /// @param threadOrOptions - Either an integer number of threads for new script, or a {@link SpawnOptions} object. Threads defaults to 1.
+    /// @param args - Additional arguments to pass into the new script that is being run.
+    #[wasm_bindgen(method, catch, variadic)]
+    pub fn spawn(
+        this: &NS,
+        script: &str,
+        threadOrOptions: Option<&JsValue>,
+        args: &[JsValue],
+    ) -> Result<(), JsValue>;
+
+    /// Terminate the script with the provided PID.
+    /// @remarks
+    /// RAM cost: 0.5 GB
+    ///
+    /// Kills the script with the provided PID.
+    /// To instead kill a script using its filename, hostname, and args, see {@link NS.(kill:2) | the other ns.kill entry}.
+    ///
+    /// @example
+    /// ```js
+    /// // kills the script with PID 20:
+    /// ns.kill(20);
+    /// ```
+    ///
+    /// @param pid - The PID of the script to kill.
+    /// @returns True if the script is successfully killed, and false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn kill(this: &NS, pid: f64) -> Result<bool, JsValue>;
+
+    /// Terminate the script(s) with the provided filename, hostname, and script arguments.
+    /// @remarks
+    /// RAM cost: 0.5 GB
+    ///
+    /// Kills the script(s) with the provided filename, running on the specified host with the specified args.
+    /// To instead kill a script using its PID, see {@link NS.(kill:1) | the other ns.kill entry}.
+    ///
+    /// @example
+    /// ```js
+    /// // kill the script "foo.js" on the same server the current script is running from, with no arguments
+    /// ns.kill("foo.js");
+    ///
+    /// // kill the script "foo.js" on the "n00dles" server with no arguments.
+    /// ns.kill("foo.js", "n00dles");
+    ///
+    /// // kill the script foo.js on the current server that was run with the arguments [1, “foodnstuff”, false]:
+    /// ns.kill("foo.js", ns.getHostname(), 1, "foodnstuff", false);
+    /// ```
+    /// @param filename - Filename of the script to kill.
+    /// @param hostname - Hostname where the script to kill is running. Defaults to the current server.
+    /// @param args - Arguments of the script to kill.
+    /// @returns True if the scripts were successfully killed, and false otherwise.
+    #[wasm_bindgen(method, catch, variadic, js_name = "kill")]
+    pub fn kill_1(
+        this: &NS,
+        filename: &str,
+        hostname: Option<&str>,
+        args: &[JsValue],
+    ) -> Result<bool, JsValue>;
+
+    /// Terminate all scripts on a server.
+    /// @remarks
+    /// RAM cost: 0.5 GB
+    ///
+    /// Kills all running scripts on the specified server. This function returns true
+    /// if any scripts were killed, and false otherwise. In other words, it will return
+    /// true if there are any scripts running on the target server.
+    /// If no host is defined, it will kill all scripts, where the script is running.
+    ///
+    /// @param host - IP or hostname of the server on which to kill all scripts.
+    /// @param safetyguard - Skips the script that calls this function
+    /// @returns True if any scripts were killed, and false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn killall(
+        this: &NS,
+        host: Option<&str>,
+        safetyguard: Option<bool>,
+    ) -> Result<bool, JsValue>;
+
+    /// Terminates the current script immediately.
+    /// @remarks
+    /// RAM cost: 0 GB
+    #[wasm_bindgen(method, catch)]
+    pub fn exit(this: &NS) -> Result<(), JsValue>;
+
+    /// Copy file between servers.
+    /// @remarks
+    /// RAM cost: 0.6 GB
+    ///
+    /// Copies a script or literature (.lit) file(s) to another server. The files argument can be either a string
+    /// specifying a single file to copy, or an array of strings specifying multiple files to copy.
+    ///
+    /// @example
+    /// ```js
+    /// //Copies foo.lit from the helios server to the home computer:
+    /// ns.scp("foo.lit", "home", "helios" );
+    ///
+    /// //Tries to copy three files from rothman-uni to home computer:
+    /// const files = ["foo1.lit", "foo2.txt", "foo3.js"];
+    /// ns.scp(files, "home", "rothman-uni");
+    /// ```
+    /// @example
+    /// ```js
+    /// const server = ns.args[0];
+    /// const files = ["hack.js", "weaken.js", "grow.js"];
+    /// ns.scp(files, server, "home");
+    /// ```
+    /// @param files - Filename or an array of filenames of script/literature files to copy. Note that if a file is located in a subdirectory, the filename must include the leading `/`.
+    /// @param destination - Hostname of the destination server, which is the server to which the file will be copied.
+    /// @param source - Hostname of the source server, which is the server from which the file will be copied. This argument is optional and if it’s omitted the source will be the current server.
+    /// @returns True if the file is successfully copied over and false otherwise. If the files argument is an array then this function will return false if any of the operations failed.
+    #[wasm_bindgen(method, catch)]
+    pub fn scp(
+        this: &NS,
+        files: &JsValue,
+        destination: &str,
+        source: Option<&str>,
+    ) -> Result<bool, JsValue>;
+
+    /// List files on a server.
+    /// @remarks
+    /// RAM cost: 0.2 GB
+    ///
+    /// Returns an array with the filenames of all files on the specified server
+    /// (as strings). The returned array is sorted in alphabetic order.
+    ///
+    /// @param host - Hostname of the target server.
+    /// @param substring - A substring to search for in the filename.
+    /// @returns Array with the filenames of all files on the specified server.
+    #[wasm_bindgen(method, catch)]
+    pub fn ls(this: &NS, host: &str, substring: Option<&str>) -> Result<Vec<String>, JsValue>;
+
+    /// List running scripts on a server.
+    /// @remarks
+    /// RAM cost: 0.2 GB
+    ///
+    /// Returns an array with general information about all scripts running on the specified target server.
+    ///
+    /// @example
+    /// ```js
+    /// const ps = ns.ps("home");
+    /// for (let script of ps) {
+    ///   ns.tprint(`${script.filename} ${script.threads}`);
+    ///   ns.tprint(script.args);
+    /// }
+    /// ```
+    /// @param host - Host address of the target server. If not specified, it will be the current server’s IP by default.
+    /// @returns Array with general information about all scripts running on the specified target server.
+    #[wasm_bindgen(method, catch)]
+    pub fn ps(this: &NS, host: Option<&str>) -> Result<Vec<ProcessInfo>, JsValue>;
+
+    /// Check if you have root access on a server.
+    /// @remarks
+    /// RAM cost: 0.05 GB
+    ///
+    /// Returns a boolean indicating whether or not the player has root access to the specified target server.
+    ///
+    /// @example
+    /// ```js
+    /// if (!ns.hasRootAccess("foodnstuff")) {
+    ///   ns.nuke("foodnstuff");
+    /// }
+    /// ```
+    /// @param host - Hostname of the target server.
+    /// @returns True if player has root access to the specified target server, and false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn hasRootAccess(this: &NS, host: &str) -> Result<bool, JsValue>;
+
+    /// Returns a string with the hostname of the server that the script is running on.
+    ///
+    /// @remarks
+    /// RAM cost: 0.05 GB
+    /// @returns Hostname of the server that the script runs on.
+    #[wasm_bindgen(method, catch)]
+    pub fn getHostname(this: &NS) -> Result<String, JsValue>;
+
+    /// Returns the player’s current hacking level.
+    ///
+    /// @remarks
+    /// RAM cost: 0.05 GB
+    /// @returns Player’s current hacking level
+    #[wasm_bindgen(method, catch)]
+    pub fn getHackingLevel(this: &NS) -> Result<f64, JsValue>;
+
+    /// Get hacking related multipliers.
+    /// @remarks
+    /// RAM cost: 0.25 GB
+    ///
+    /// Returns an object containing the Player’s hacking related multipliers.
+    /// These multipliers are returned in fractional forms, not percentages
+    /// (e.g. 1.5 instead of 150%).
+    ///
+    /// @example
+    /// ```js
+    /// const mults = ns.getHackingMultipliers();
+    /// print(`chance: ${mults.chance}`);
+    /// print(`growthL ${mults.growth}`);
+    /// ```
+    /// @returns Object containing the Player’s hacking related multipliers.
+    #[wasm_bindgen(method, catch)]
+    pub fn getHackingMultipliers(this: &NS) -> Result<HackingMultipliers, JsValue>;
+
+    /// Get hacknet related multipliers.
+    /// @remarks
+    /// RAM cost: 0.25 GB
+    ///
+    /// Returns an object containing the Player’s hacknet related multipliers.
+    /// These multipliers are returned in fractional forms, not percentages
+    /// (e.g. 1.5 instead of 150%).
+    ///
+    /// @example
+    /// ```js
+    /// const mults = ns.getHacknetMultipliers();
+    /// ns.tprint(`production: ${mults.production}`);
+    /// ns.tprint(`purchaseCost: ${mults.purchaseCost}`);
+    /// ```
+    /// @returns Object containing the Player’s hacknet related multipliers.
+    #[wasm_bindgen(method, catch)]
+    pub fn getHacknetMultipliers(this: &NS) -> Result<HacknetMultipliers, JsValue>;
+
+    /// Returns a server object for the given server. Defaults to the running script's server if host is not specified.
+    ///
+    /// @remarks
+    /// RAM cost: 2 GB
+    /// @param host - Optional. Hostname for the requested server object.
+    /// @returns The requested server object.
+    #[wasm_bindgen(method, catch)]
+    pub fn getServer(this: &NS, host: Option<&str>) -> Result<Server, JsValue>;
+
+    /// Get money available on a server.
+    /// @remarks
+    /// RAM cost: 0.1 GB
+    ///
+    /// Returns the amount of money available on a server.
+    /// Running this function on the home computer will return the player’s money.
+    ///
+    /// @example
+    /// ```js
+    /// ns.getServerMoneyAvailable("foodnstuff");
+    /// ns.getServerMoneyAvailable("home"); // Returns player's money
+    /// ```
+    /// @param host - Hostname of target server.
+    /// @returns Amount of money available on the server.
+    #[wasm_bindgen(method, catch)]
+    pub fn getServerMoneyAvailable(this: &NS, host: &str) -> Result<f64, JsValue>;
+
+    /// Get the maximum money available on a server.
+    /// @remarks
+    /// RAM cost: 0.1 GB
+    ///
+    /// Returns the maximum amount of money that can be available on a server.
+    ///
+    /// @param host - Hostname of target server.
+    /// @returns Maximum amount of money available on the server.
+    #[wasm_bindgen(method, catch)]
+    pub fn getServerMaxMoney(this: &NS, host: &str) -> Result<f64, JsValue>;
+
+    /// Get a server growth parameter.
+    /// @remarks
+    /// RAM cost: 0.1 GB
+    ///
+    /// Returns the server’s intrinsic “growth parameter”. This growth
+    /// parameter is a number typically between 0 and 100 that represents
+    /// how quickly the server’s money grows. This parameter affects the
+    /// percentage by which the server’s money is increased when using the
+    /// grow function. A higher growth parameter will result in a
+    /// higher percentage increase from grow.
+    ///
+    /// @param host - Hostname of target server.
+    /// @returns Parameter that affects the percentage by which the server’s money is increased when using the grow function.
+    #[wasm_bindgen(method, catch)]
+    pub fn getServerGrowth(this: &NS, host: &str) -> Result<f64, JsValue>;
+
+    /// Get server security level.
+    /// @remarks
+    /// RAM cost: 0.1 GB
+    ///
+    /// Returns the security level of the target server. A server’s security
+    /// level is denoted by a number, typically between 1 and 100
+    /// (but it can go above 100).
+    ///
+    /// @param host - Hostname of target server.
+    /// @returns Security level of the target server.
+    #[wasm_bindgen(method, catch)]
+    pub fn getServerSecurityLevel(this: &NS, host: &str) -> Result<f64, JsValue>;
+
+    /// Returns the minimum security level of the target server.
+    ///
+    /// @remarks RAM cost: 0.1 GB
+    /// @param host - Hostname of target server.
+    /// @returns Minimum security level of the target server.
+    #[wasm_bindgen(method, catch)]
+    pub fn getServerMinSecurityLevel(this: &NS, host: &str) -> Result<f64, JsValue>;
+
+    /// Get the base security level of a server.
+    /// @remarks
+    /// RAM cost: 0.1 GB
+    /// Returns the base security level of the target server.
+    /// For the server's actual security level, use {@link NS.getServerSecurityLevel | ns.getServerSecurityLevel}.
+    ///
+    /// @param host - Host of target server.
+    /// @returns Base security level of the target server.
+    #[wasm_bindgen(method, catch)]
+    pub fn getServerBaseSecurityLevel(this: &NS, host: &str) -> Result<f64, JsValue>;
+
+    /// Get the maximum amount of RAM on a server.
+    /// @remarks
+    /// RAM cost: 0.05 GB
+    ///
+    /// @param host - Hostname of the target server.
+    /// @returns The maximum amount of RAM (GB) a server can have.
+    #[wasm_bindgen(method, catch)]
+    pub fn getServerMaxRam(this: &NS, host: &str) -> Result<f64, JsValue>;
+
+    /// Get the used RAM on a server.
+    /// @remarks
+    /// RAM cost: 0.05 GB
+    ///
+    /// @param host - Hostname of the target server.
+    /// @returns The amount of used RAM (GB) on the specified server.
+    #[wasm_bindgen(method, catch)]
+    pub fn getServerUsedRam(this: &NS, host: &str) -> Result<f64, JsValue>;
+
+    /// Returns the required hacking level of the target server.
+    ///
+    /// @remarks RAM cost: 0.1 GB
+    /// @param host - Hostname of target server.
+    /// @returns The required hacking level of the target server.
+    #[wasm_bindgen(method, catch)]
+    pub fn getServerRequiredHackingLevel(this: &NS, host: &str) -> Result<f64, JsValue>;
+
+    /// Returns the number of open ports required to successfully run NUKE.exe on the specified server.
+    ///
+    /// @remarks RAM cost: 0.1 GB
+    /// @param host - Hostname of target server.
+    /// @returns The number of open ports required to successfully run NUKE.exe on the specified server.
+    #[wasm_bindgen(method, catch)]
+    pub fn getServerNumPortsRequired(this: &NS, host: &str) -> Result<f64, JsValue>;
+
+    /// Returns a boolean denoting whether or not the specified server exists.
+    ///
+    /// @remarks RAM cost: 0.1 GB
+    /// @param host - Hostname of target server.
+    /// @returns True if the specified server exists, and false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn serverExists(this: &NS, host: &str) -> Result<bool, JsValue>;
+
+    /// Check if a file exists.
+    /// @remarks
+    /// RAM cost: 0.1 GB
+    ///
+    /// Returns a boolean indicating whether the specified file exists on the target server.
+    /// The filename for programs is case-insensitive, other file types are case-sensitive.
+    /// For example, fileExists(“brutessh.exe”) will work fine, even though the actual program
+    /// is named 'BruteSSH.exe'.
+    ///
+    /// @example
+    /// ```js
+    /// // The function call will return true if the script named foo.js exists on the foodnstuff server, and false otherwise.
+    /// ns.fileExists("foo.js", "foodnstuff");
+    ///
+    /// // The function call will return true if the current server contains the FTPCrack.exe program, and false otherwise.
+    /// ns.fileExists("ftpcrack.exe");
+    /// ```
+    /// @param filename - Filename of file to check.
+    /// @param host - Host of target server. Optional, defaults to the server the script is running on.
+    /// @returns True if specified file exists, and false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn fileExists(this: &NS, filename: &str, host: Option<&str>) -> Result<bool, JsValue>;
+
+    /// Check if a script is running.
+    /// @remarks
+    /// RAM cost: 0.1 GB
+    ///
+    /// Returns a boolean indicating whether the specified script is running on the target server.
+    /// If you use a PID instead of a filename, the hostname and args parameters are unnecessary.
+    /// If hostname is omitted while filename is used as the first parameter, hostname defaults to the server the calling script is running on.
+    /// Remember that a script is semi-uniquely identified by both its name and its arguments.
+    /// (You can run multiple copies of scripts with the same arguments, but for the purposes of
+    /// functions like this that check based on filename, the filename plus arguments forms the key.)
+    ///
+    /// @example
+    /// ```js
+    /// //The function call will return true if there is a script named foo.js with no arguments running on the foodnstuff server, and false otherwise:
+    /// ns.isRunning("foo.js", "foodnstuff");
+    ///
+    /// //The function call will return true if there is a script named foo.js with no arguments running on the current server, and false otherwise:
+    /// ns.isRunning("foo.js", ns.getHostname());
+    ///
+    /// //The function call will return true if there is a script named foo.js running with the arguments 1, 5, and “test” (in that order) on the joesguns server, and false otherwise:
+    /// ns.isRunning("foo.js", "joesguns", 1, 5, "test");
+    /// ```
+    /// @param script - Filename or PID of script to check. This is case-sensitive.
+    /// @param host - Hostname of target server. Optional, defaults to the server the calling script is running on.
+    /// @param args - Arguments to specify/identify the script. Optional, when looking for scripts run without arguments.
+    /// @returns True if the specified script is running on the target server, and false otherwise.
+    #[wasm_bindgen(method, catch, variadic)]
+    pub fn isRunning(
+        this: &NS,
+        script: &JsValue,
+        host: Option<&str>,
+        args: &[JsValue],
+    ) -> Result<bool, JsValue>;
+
+    /// Get general info about a running script.
+    /// @remarks
+    /// RAM cost: 0.3 GB
+    ///
+    /// Running with no args returns current script.
+    /// If you use a PID as the first parameter, the hostname and args parameters are unnecessary.
+    /// If hostname is omitted while filename is used as the first parameter, hostname defaults to the server the calling script is running on.
+    /// Remember that a script is semi-uniquely identified by both its name and its arguments.
+    /// (You can run multiple copies of scripts with the same arguments, but for the purposes of
+    /// functions like this that check based on filename, the filename plus arguments forms the key.)
+    ///
+    /// @param filename - Optional. Filename or PID of the script.
+    /// @param hostname - Hostname of target server. Optional, defaults to the server the calling script is running on.
+    /// @param args  - Arguments to specify/identify the script. Optional, when looking for scripts run without arguments.
+    /// @returns The info about the running script if found, and null otherwise.
+    #[wasm_bindgen(method, catch, variadic)]
+    pub fn getRunningScript(
+        this: &NS,
+        filename: Option<&JsValue>,
+        hostname: Option<&str>,
+        args: &[JsValue],
+    ) -> Result<Option<RunningScript>, JsValue>;
+
+    /// Get cost of purchasing a server.
+    /// @remarks
+    /// RAM cost: 0.25 GB
+    ///
+    /// Returns the cost to purchase a server with the specified amount of ram.
+    ///
+    /// @example
+    /// ```js
+    /// const ram = 2 ** 20;
+    /// const cost = ns.getPurchasedServerCost(ram);
+    /// ns.tprint(`A purchased server with ${ns.formatRam(ram)} costs ${ns.formatMoney(cost)}`);
+    /// ```
+    /// @param ram - Amount of RAM of a potential purchased server, in GB. Must be a power of 2 (2, 4, 8, 16, etc.). Maximum value of 1048576 (2^20).
+    /// @returns The cost to purchase a server with the specified amount of ram.
+    #[wasm_bindgen(method, catch)]
+    pub fn getPurchasedServerCost(this: &NS, ram: f64) -> Result<f64, JsValue>;
+
+    /// Purchase a server.
+    /// @remarks
+    /// 2.25 GB
+    ///
+    /// Purchase a server with the specified hostname and amount of RAM.
+    ///
+    /// The hostname argument can be any data type, but it will be converted to a string
+    /// and have whitespace removed. Anything that resolves to an empty string will cause
+    /// the function to fail. If there is already a server with the specified hostname,
+    /// then the function will automatically append a number at the end of the hostname
+    /// argument value until it finds a unique hostname. For example, if the script calls
+    /// `purchaseServer(“foo”, 4)` but a server named “foo” already exists, then it will
+    /// automatically change the hostname to `foo-0`. If there is already a server with the
+    /// hostname `foo-0`, then it will change the hostname to `foo-1`, and so on.
+    ///
+    /// Note that there is a maximum limit to the amount of servers you can purchase.
+    ///
+    /// Returns the hostname of the newly purchased server as a string. If the function
+    /// fails to purchase a server, then it will return an empty string. The function will
+    /// fail if the arguments passed in are invalid, if the player does not have enough
+    /// money to purchase the specified server, or if the player has exceeded the maximum
+    /// amount of servers.
+    ///
+    /// @example
+    /// ```js
+    /// // Attempt to purchase 5 servers with 64GB of ram each
+    /// const ram = 64;
+    /// const prefix = "pserv-";
+    /// for (i = 0; i < 5; ++i) {
+    ///    ns.purchaseServer(prefix + i, ram);
+    /// }
+    /// ```
+    /// @param hostname - Hostname of the purchased server.
+    /// @param ram - Amount of RAM of the purchased server, in GB. Must be a power of 2 (2, 4, 8, 16, etc.). Maximum value of 1048576 (2^20).
+    /// @returns The hostname of the newly purchased server.
+    #[wasm_bindgen(method, catch)]
+    pub fn purchaseServer(this: &NS, hostname: &str, ram: f64) -> Result<String, JsValue>;
+
+    /// Get cost of upgrading a purchased server to the given ram.
+    /// @remarks
+    /// RAM cost: 0.1 GB
+    ///
+    /// @param hostname - Hostname of the server to upgrade.
+    /// @param ram - Amount of RAM of the purchased server, in GB. Must be a power of 2 (2, 4, 8, 16, etc.). Maximum value of 1048576 (2^20).
+    /// @returns The price to upgrade.
+    #[wasm_bindgen(method, catch)]
+    pub fn getPurchasedServerUpgradeCost(
+        this: &NS,
+        hostname: &str,
+        ram: f64,
+    ) -> Result<f64, JsValue>;
+
+    /// Upgrade a purchased server's RAM.
+    /// @remarks
+    /// RAM cost: 0.25 GB
+    ///
+    /// @param hostname - Hostname of the server to upgrade.
+    /// @param ram - Amount of RAM of the purchased server, in GB. Must be a power of 2 (2, 4, 8, 16, etc.). Maximum value of 1048576 (2^20).
+    /// @returns True if the upgrade succeeded, and false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn upgradePurchasedServer(this: &NS, hostname: &str, ram: f64) -> Result<bool, JsValue>;
+
+    /// Rename a purchased server.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// @param hostname - Current server hostname.
+    /// @param newName - New server hostname.
+    /// @returns True if successful, and false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn renamePurchasedServer(this: &NS, hostname: &str, newName: &str)
+        -> Result<bool, JsValue>;
+
+    /// Delete a purchased server.
+    /// @remarks
+    /// 2.25 GB
+    ///
+    /// Deletes one of your purchased servers, which is specified by its hostname.
+    ///
+    /// The hostname argument can be any data type, but it will be converted to a string.
+    /// Whitespace is automatically removed from the string. This function will not delete a
+    /// server that still has scripts running on it.
+    ///
+    /// @param host - Hostname of the server to delete.
+    /// @returns True if successful, and false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn deleteServer(this: &NS, host: &str) -> Result<bool, JsValue>;
+
+    /// Returns an array with the hostnames of all of the servers you have purchased.
+    ///
+    /// @remarks 2.25 GB
+    /// @returns Returns an array with the hostnames of all of the servers you have purchased.
+    #[wasm_bindgen(method, catch)]
+    pub fn getPurchasedServers(this: &NS) -> Result<Vec<String>, JsValue>;
+
+    /// Returns the maximum number of servers you can purchase.
+    ///
+    /// @remarks RAM cost: 0.05 GB
+    /// @returns Returns the maximum number of servers you can purchase.
+    #[wasm_bindgen(method, catch)]
+    pub fn getPurchasedServerLimit(this: &NS) -> Result<f64, JsValue>;
+
+    /// Returns the maximum RAM that a purchased server can have.
+    ///
+    /// @remarks RAM cost: 0.05 GB
+    /// @returns Returns the maximum RAM (in GB) that a purchased server can have.
+    #[wasm_bindgen(method, catch)]
+    pub fn getPurchasedServerMaxRam(this: &NS) -> Result<f64, JsValue>;
+
+    /// Write data to a file.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// This function can be used to write data to a text file (.txt) or a script (.js or .script).
+    ///
+    /// This function will write data to that file. If the specified file does not exist,
+    /// then it will be created. The third argument mode defines how the data will be written to
+    /// the file. If mode is set to “w”, then the data is written in “write” mode which means
+    /// that it will overwrite all existing data on the file. If mode is set to any other value
+    /// then the data will be written in “append” mode which means that the data will be added at the
+    /// end of the file.
+    ///
+    /// @param filename - Name of the file to be written to.
+    /// @param data - Data to write.
+    /// @param mode - Defines the write mode.
+    #[wasm_bindgen(method, catch)]
+    pub fn write(
+        this: &NS,
+        filename: &str,
+        data: Option<&str>,
+        mode: Option<&str>,
+    ) -> Result<(), JsValue>;
+
+    /// Attempt to write to a port.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// Attempts to write data to the specified Netscript port.
+    /// If the port is full, the data will not be written.
+    /// Otherwise, the data will be written normally.
+    ///
+    /// @param portNumber - Port to attempt to write to. Must be a positive integer.
+    /// @param data - Data to write.
+    /// @returns True if the data is successfully written to the port, and false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn tryWritePort(this: &NS, portNumber: f64, data: &JsValue) -> Result<bool, JsValue>;
+
+    /// Read content of a file.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// This function is used to read data from a text file (.txt) or script (.js or .script).
+    ///
+    /// This function will return the data in the specified file.
+    /// If the file does not exist, an empty string will be returned.
+    ///
+    /// @param filename - Name of the file to be read.
+    /// @returns Data in the specified text file.
+    #[wasm_bindgen(method, catch)]
+    pub fn read(this: &NS, filename: &str) -> Result<String, JsValue>;
+
+    /// Get a copy of the data from a port without popping it.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// This function is used to peek at the data from a port. It returns the
+    /// first element in the specified port without removing that element. If
+    /// the port is empty, the string “NULL PORT DATA” will be returned.
+    ///
+    /// @param portNumber - Port to peek. Must be a positive integer.
+    /// @returns Data in the specified port.
+    #[wasm_bindgen(method, catch)]
+    pub fn peek(this: &NS, portNumber: f64) -> Result<JsValue, JsValue>;
+
+    /// Clear data from a file.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// Delete all data from that text file.
+    ///
+    /// @param handle - Text file to clear.
+    #[wasm_bindgen(method, catch)]
+    pub fn clear(this: &NS, handle: &str) -> Result<(), JsValue>;
+
+    /// Clear data from a port.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// Delete all data from the underlying queue.
+    ///
+    /// @param portNumber - Port to clear data from. Must be a positive integer.
+    #[wasm_bindgen(method, catch)]
+    pub fn clearPort(this: &NS, portNumber: f64) -> Result<(), JsValue>;
+
+    /// Write data to a port.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// Write data to the given Netscript port.
+    /// @param portNumber - Port to write to. Must be a positive integer.
+    /// @param data - Data to write.
+    /// @returns The data popped off the queue if it was full, or null if it was not full.
+    #[wasm_bindgen(method, catch)]
+    pub fn writePort(this: &NS, portNumber: f64, data: &JsValue) -> Result<JsValue, JsValue>;
+
+    /// Read data from a port.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// Read data from that port. A port is a serialized queue.
+    /// This function will remove the first element from that queue and return it.
+    /// If the queue is empty, then the string “NULL PORT DATA” will be returned.
+    /// @param portNumber - Port to read from. Must be a positive integer.
+    /// @returns The data read.
+    #[wasm_bindgen(method, catch)]
+    pub fn readPort(this: &NS, portNumber: f64) -> Result<JsValue, JsValue>;
+
+    /// Get all data on a port.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// Get a handle to a Netscript Port.
+    ///
+    /// WARNING: Port Handles only work in NetscriptJS (Netscript 2.0). They will not work in Netscript 1.0.
+    ///
+    /// @param portNumber - Port number. Must be a positive integer.
+    #[wasm_bindgen(method, catch)]
+    pub fn getPortHandle(this: &NS, portNumber: f64) -> Result<NetscriptPort, JsValue>;
+
+    /// Delete a file.
+    /// @remarks
+    /// RAM cost: 1 GB
+    ///
+    /// Removes the specified file from the current server. This function works for every file
+    /// type except message (.msg) files.
+    ///
+    /// @param name - Filename of file to remove. Must include the extension.
+    /// @param host - Hostname of the server on which to delete the file. Optional. Defaults to current server.
+    /// @returns True if it successfully deletes the file, and false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn rm(this: &NS, name: &str, host: Option<&str>) -> Result<bool, JsValue>;
+
+    /// Check if any script with a filename is running.
+    /// @remarks
+    /// RAM cost: 1 GB
+    ///
+    /// Returns a boolean indicating whether any instance of the specified script is running
+    /// on the target server, regardless of its arguments.
+    ///
+    /// This is different than the {@link NS.isRunning | isRunning} function because it does not try to
+    /// identify a specific instance of a running script by its arguments.
+    ///
+    /// @example
+    /// ```js
+    /// //The function call will return true if there is any script named foo.js running on the foodnstuff server, and false otherwise:
+    /// ns.scriptRunning("foo.js", "foodnstuff");
+    ///
+    /// //The function call will return true if there is any script named “foo.js” running on the current server, and false otherwise:
+    /// ns.scriptRunning("foo.js", ns.getHostname());
+    /// ```
+    /// @param script - Filename of script to check. This is case-sensitive.
+    /// @param host - Hostname of target server.
+    /// @returns True if the specified script is running, and false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub fn scriptRunning(this: &NS, script: &str, host: &str) -> Result<bool, JsValue>;
+
+    /// Kill all scripts with a filename.
+    /// @remarks
+    /// RAM cost: 1 GB
+    ///
+    /// Kills all scripts with the specified filename on the target server specified by hostname,
+    /// regardless of arguments.
+    ///
+    /// @param script - Filename of script to kill. This is case-sensitive.
+    /// @param host - Hostname of target server.
+    /// @returns True if one or more scripts were successfully killed, and false if none were.
+    #[wasm_bindgen(method, catch)]
+    pub fn scriptKill(this: &NS, script: &str, host: &str) -> Result<bool, JsValue>;
+
+    /// Returns the current script name.
+    ///
+    /// @remarks RAM cost: 0 GB
+    /// @returns Current script name.
+    #[wasm_bindgen(method, catch)]
+    pub fn getScriptName(this: &NS) -> Result<String, JsValue>;
+
+    /// Get the ram cost of a script.
+    /// @remarks
+    /// RAM cost: 0.1 GB
+    ///
+    /// Returns the amount of RAM required to run the specified script on the target server.
+    /// Returns 0 if the script does not exist.
+    ///
+    /// @param script - Filename of script. This is case-sensitive.
+    /// @param host - Hostname of target server the script is located on. This is optional. If it is not specified then the function will use the current server as the target server.
+    /// @returns Amount of RAM (in GB) required to run the specified script on the target server, and 0 if the script does not exist.
+    #[wasm_bindgen(method, catch)]
+    pub fn getScriptRam(this: &NS, script: &str, host: Option<&str>) -> Result<f64, JsValue>;
+
+    /// Get the execution time of a hack() call.
+    /// @remarks
+    /// RAM cost: 0.05 GB
+    ///
+    /// When `hack` completes an amount of money is stolen depending on the player's skills.
+    /// Returns the amount of time in milliseconds it takes to execute the {@link NS.hack | hack} Netscript function on the target server.
+    /// The required time is increased by the security level of the target server and decreased by the player's hacking level.
+    ///
+    /// @param host - Hostname of target server.
+    /// @returns Returns the amount of time in milliseconds it takes to execute the {@link NS.hack | hack} Netscript function.
+    #[wasm_bindgen(method, catch)]
+    pub fn getHackTime(this: &NS, host: &str) -> Result<f64, JsValue>;
+
+    /// Get the execution time of a grow() call.
+    /// @remarks
+    /// RAM cost: 0.05 GB
+    ///
+    /// Returns the amount of time in milliseconds it takes to execute the grow Netscript function on the target server.
+    /// The required time is increased by the security level of the target server and decreased by the player's hacking level.
+    ///
+    /// @param host - Hostname of target server.
+    /// @returns Returns the amount of time in milliseconds it takes to execute the grow Netscript function.
+    #[wasm_bindgen(method, catch)]
+    pub fn getGrowTime(this: &NS, host: &str) -> Result<f64, JsValue>;
+
+    /// Get the execution time of a weaken() call.
+    /// @remarks
+    /// RAM cost: 0.05 GB
+    ///
+    /// Returns the amount of time in milliseconds it takes to execute the {@link NS.weaken | weaken} Netscript function on the target server.
+    /// The required time is increased by the security level of the target server and decreased by the player's hacking level.
+    ///
+    /// @param host - Hostname of target server.
+    /// @returns Returns the amount of time in milliseconds it takes to execute the {@link NS.weaken | weaken} Netscript function.
+    #[wasm_bindgen(method, catch)]
+    pub fn getWeakenTime(this: &NS, host: &str) -> Result<f64, JsValue>;
+
+    /// Get the income of all scripts.
+    /// @remarks
+    /// RAM cost: 0.1 GB
+    ///
+    /// @returns An array of two values.
+    /// The first value is the total income (dollar / second) of all of your active scripts
+    /// (scripts that are currently running on any server).
+    /// The second value is the total income (dollar / second) that you’ve earned from scripts
+    /// since you last installed Augmentations.
+    #[wasm_bindgen(method, catch)]
+    pub fn getTotalScriptIncome(this: &NS) -> Result<Vec<f64>, JsValue>;
+
+    /// Get the income of a script.
+    /// @remarks
+    /// RAM cost: 0.1 GB
+    ///
+    /// Returns the amount of income the specified script generates while online
+    /// (when the game is open, does not apply for offline income). Remember that
+    /// a script is uniquely identified by both its name and its arguments. So for
+    /// example if you ran a script with the arguments “foodnstuff” and “5” then
+    /// in order to use this function to get that script’s income you must specify
+    /// those same arguments in the same order in this function call.
+    ///
+    /// @param script - Filename of script.
+    /// @param host - Server on which script is running.
+    /// @param args - Arguments that the script is running with.
+    /// @returns Amount of income the specified script generates while online.
+    #[wasm_bindgen(method, catch, variadic)]
+    pub fn getScriptIncome(
+        this: &NS,
+        script: &str,
+        host: &str,
+        args: &[JsValue],
+    ) -> Result<f64, JsValue>;
+
+    /// Get the exp gain of all scripts.
+    /// @remarks
+    /// RAM cost: 0.1 GB
+    ///
+    /// @returns Total experience gain rate of all of your active scripts.
+    #[wasm_bindgen(method, catch)]
+    pub fn getTotalScriptExpGain(this: &NS) -> Result<f64, JsValue>;
+
+    /// Get the exp gain of a script.
+    /// @remarks
+    /// RAM cost: 0.1 GB
+    ///
+    /// Returns the amount of hacking experience the specified script generates while online
+    /// (when the game is open, does not apply for offline experience gains). Remember that a
+    /// script is uniquely identified by both its name and its arguments.
+    ///
+    /// This function can also return the total experience gain rate of all of your active
+    /// scripts by running the function with no arguments.
+    ///
+    /// @param script - Filename of script.
+    /// @param host - Server on which script is running.
+    /// @param args - Arguments that the script is running with.
+    /// @returns Amount of hacking experience the specified script generates while online.
+    #[wasm_bindgen(method, catch, variadic)]
+    pub fn getScriptExpGain(
+        this: &NS,
+        script: &str,
+        host: &str,
+        args: &[JsValue],
+    ) -> Result<f64, JsValue>;
+
+    /// Returns the amount of time in milliseconds that have passed since you last installed Augmentations.
+    ///
+    /// @remarks RAM cost: 0.05 GB
+    /// @returns Time in milliseconds that have passed since you last installed Augmentations.
+    #[wasm_bindgen(method, catch)]
+    pub fn getTimeSinceLastAug(this: &NS) -> Result<f64, JsValue>;
+
+    /// Format a string.
+    ///
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// see: https://github.com/alexei/sprintf.js
+    /// @param format - String to format.
+    /// @param args - Formatting arguments.
+    /// @returns Formatted text.
+    #[wasm_bindgen(method, catch, variadic)]
+    pub fn sprintf(this: &NS, format: &str, args: &[JsValue]) -> Result<String, JsValue>;
+
+    /// Format a string with an array of arguments.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// see: https://github.com/alexei/sprintf.js
+    /// @param format - String to format.
+    /// @param args - Formatting arguments.
+    /// @returns Formatted text.
+    #[wasm_bindgen(method, catch)]
+    pub fn vsprintf(this: &NS, format: &str, args: Vec<JsValue>) -> Result<String, JsValue>;
+
+    /// Format a number.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// Converts a number into a numeric string with the specified format options.
+    /// This is the same function that the game itself uses to display numbers. The format also depends on the Numeric
+    /// Display settings (all options on the "Numeric Display" options page)
+    /// To format ram or percentages, see {@link NS.formatRam | formatRam} and {@link NS.formatPercent | formatPercent}
+    ///
+    /// @param n - Number to format.
+    /// @param fractionalDigits - Number of digits to show in the fractional part of the decimal number. Optional, defaults to 3.
+    /// @param suffixStart - How high a number must be before a suffix will be added. Optional, defaults to 1000.
+    /// @param isInteger - Whether the number represents an integer. Integers do not display fractional digits until a suffix is present. Optional, defaults to false.
+    /// @returns Formatted number.
+    #[wasm_bindgen(method, catch)]
+    pub fn formatNumber(
+        this: &NS,
+        n: f64,
+        fractionalDigits: Option<f64>,
+        suffixStart: Option<f64>,
+        isInteger: Option<bool>,
+    ) -> Result<String, JsValue>;
+
+    /// Format a number as an amount of ram.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// Converts a number into a ram string with the specified number of fractional digits.
+    /// This is the same function that the game itself uses to display ram. The format also depends on the Numeric Display
+    /// settings (all options on the "Numeric Display" options page)
+    /// To format plain numbers or percentages, see {@link NS.formatNumber | formatNumber} and {@link NS.formatPercent | formatPercent}
+    ///
+    /// @param n - Number to format as an amount of ram, in base units of GB (or GiB if that Numeric Display option is set).
+    /// @param fractionalDigits - Number of digits to show in the fractional part of the decimal number. Optional, defaults to 2.
+    /// @returns Formatted ram amount.
+    #[wasm_bindgen(method, catch)]
+    pub fn formatRam(this: &NS, n: f64, fractionalDigits: Option<f64>) -> Result<String, JsValue>;
+
+    /// Format a number as a percentage.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// Converts a number into a percentage string with the specified number of fractional digits.
+    /// This is the same function that the game itself uses to display percentages. The format also depends on the Numeric
+    /// Display settings (all options on the "Numeric Display" options page)
+    /// To format plain numbers or ram, see {@link NS.formatNumber | formatNumber} and {@link NS.formatRam | formatRam}
+    ///
+    /// @param n - Number to format as a percentage.
+    /// @param fractionalDigits - Number of digits to show in the fractional part of the decimal number. Optional, defaults to 2.
+    /// @param suffixStart - When to switch the percentage to a multiplier. Default is 1e6 or x1.00m.
+    /// @returns Formatted percentage.
+    #[wasm_bindgen(method, catch)]
+    pub fn formatPercent(
+        this: &NS,
+        n: f64,
+        fractionalDigits: Option<f64>,
+        suffixStart: Option<f64>,
+    ) -> Result<String, JsValue>;
+
+    /// Format a number using the numeral library. This function is deprecated and will be removed in 2.4.
+    /// @deprecated Use ns.formatNumber, formatRam, or formatPercent instead. Will be removed in 2.4.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// Converts a number into a string with the specified format options.
+    /// See http://numeraljs.com/#format for documentation on format strings supported.
+    ///
+    /// This function is deprecated and will be removed in 2.3.
+    ///
+    /// @param n - Number to format.
+    /// @param format - Formatting options. See http://numeraljs.com/#format for valid formats.
+    /// @returns Formatted number.
+    #[wasm_bindgen(method, catch)]
+    pub fn nFormat(this: &NS, n: f64, format: &str) -> Result<String, JsValue>;
+
+    /// Format time to a readable string.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// @param milliseconds - Number of millisecond to format.
+    /// @param milliPrecision - Format time with subsecond precision. Defaults to false.
+    /// @returns The formatted time.
+    #[wasm_bindgen(method, catch)]
+    pub fn tFormat(
+        this: &NS,
+        milliseconds: f64,
+        milliPrecision: Option<bool>,
+    ) -> Result<String, JsValue>;
+
+    /// Prompt the player with an input modal.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// Prompts the player with a dialog box. Here is an explanation of the various options.
+    ///
+    /// - `options.type` is not provided to the function. If `options.type` is left out and
+    /// only a string is passed to the function, then the default behavior is to create a
+    /// boolean dialog box.
+    ///
+    /// - `options.type` has value `undefined` or `"boolean"`. A boolean dialog box is
+    /// created. The player is shown "Yes" and "No" prompts, which return true and false
+    /// respectively. The script's execution is halted until the player presses either the
+    /// "Yes" or "No" button.
+    ///
+    /// - `options.type` has value `"text"`. The player is given a text field to enter
+    /// free-form text. The script's execution is halted until the player enters some text
+    /// and/or presses the "Confirm" button.
+    ///
+    /// - `options.type` has value `"select"`. The player is shown a drop-down field.
+    /// Choosing type `"select"` will require an array to be passed via the
+    /// `options.choices` property. The array can be an array of strings, an array of
+    /// numbers (not BigInt numbers), or a mixture of both numbers and strings. Any other
+    /// types of array elements will result in an error or an undefined/unexpected
+    /// behavior. The `options.choices` property will be ignored if `options.type` has a
+    /// value other than `"select"`. The script's execution is halted until the player
+    /// chooses one of the provided options and presses the "Confirm" button.
+    ///
+    /// @example
+    /// ```js
+    /// // A Yes/No question. The default is to create a boolean dialog box.
+    /// const queryA = "Do you enjoy Bitburner?";
+    /// const resultA = await ns.prompt(queryA);
+    /// ns.tprint(`${queryA} ${resultA}`);
+    ///
+    /// // Another Yes/No question. Can also create a boolean dialog box by explicitly
+    /// // passing the option {"type": "boolean"}.
+    /// const queryB = "Is programming fun?";
+    /// const resultB = await ns.prompt(queryB, { type: "boolean" });
+    /// ns.tprint(`${queryB} ${resultB}`);
+    ///
+    /// // Free-form text box.
+    /// const resultC = await ns.prompt("Please enter your name.", { type: "text" });
+    /// ns.tprint(`Hello, ${resultC}.`);
+    ///
+    /// // A drop-down list.
+    /// const resultD = await ns.prompt("Please select your favorite fruit.", {
+    ///   type: "select",
+    ///   choices: ["Apple", "Banana", "Orange", "Pear", "Strawberry"]
+    /// });
+    /// ns.tprint(`Your favorite fruit is ${resultD.toLowerCase()}.`);
+    /// ```
+    ///
+    /// @param txt - Text to appear in the prompt dialog box.
+    /// @param options - Options to modify the prompt the player is shown.
+    /// @returns True if the player clicks “Yes”; false if the player clicks “No”; or the value entered by the player.
+    #[wasm_bindgen(method, catch)]
+    pub async fn prompt(
+        this: &NS,
+        txt: &str,
+        options: Option<&JsValue>,
+    ) -> Result<JsValue, JsValue>;
+
+    /// Open up a message box.
+    /// @param msg - Message to alert.
+    #[wasm_bindgen(method, catch)]
+    pub fn alert(this: &NS, msg: &str) -> Result<(), JsValue>;
+
+    /// Queue a toast (bottom-right notification).
+    /// @param msg - Message in the toast.
+    /// @param variant - Type of toast. Must be one of success, info, warning, error. Defaults to success.
+    /// @param duration - Duration of toast in ms. Can also be `null` to create a persistent toast. Defaults to 2000.
+    #[wasm_bindgen(method, catch)]
+    pub fn toast(
+        this: &NS,
+        msg: &str,
+        variant: Option<ToastVariant>,
+        duration: Option<f64>,
+    ) -> Result<(), JsValue>;
+
+    /// Download a file from the internet.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// Retrieves data from a URL and downloads it to a file on the specified server.
+    /// The data can only be downloaded to a script (.js or .script) or a text file (.txt).
+    /// If the file already exists, it will be overwritten by this command.
+    /// Note that it will not be possible to download data from many websites because they
+    /// do not allow cross-origin resource sharing (CORS).
+    ///
+    /// IMPORTANT: This is an asynchronous function that returns a Promise.
+    /// The Promise’s resolved value will be a boolean indicating whether or not the data was
+    /// successfully retrieved from the URL. Because the function is async and returns a Promise,
+    /// it is recommended you use wget in NetscriptJS (Netscript 2.0).
+    ///
+    /// In NetscriptJS, you must preface any call to wget with the await keyword (like you would {@link NS.hack | hack} or {@link NS.sleep | sleep}).
+    /// wget will still work in Netscript 1.0, but the function's execution will not be synchronous
+    /// (i.e. it may not execute when you expect/want it to).
+    /// Furthermore, since Promises are not supported in ES5,
+    /// you will not be able to process the returned value of wget in Netscript 1.0.
+    ///
+    /// @example
+    /// ```js
+    /// await ns.wget("https://raw.githubusercontent.com/bitburner-official/bitburner-src/master/README.md", "game_readme.txt");
+    /// ```
+    /// @param url - URL to pull data from.
+    /// @param target - Filename to write data to. Must be script or text file.
+    /// @param host - Optional hostname/ip of server for target file.
+    /// @returns True if the data was successfully retrieved from the URL, false otherwise.
+    #[wasm_bindgen(method, catch)]
+    pub async fn wget(
+        this: &NS,
+        url: &str,
+        target: &str,
+        host: Option<&str>,
+    ) -> Result<bool, JsValue>;
+
+    /// Returns the amount of Faction favor required to be able to donate to a faction.
+    ///
+    /// @remarks RAM cost: 0.1 GB
+    /// @returns Amount of Faction favor required to be able to donate to a faction.
+    #[wasm_bindgen(method, catch)]
+    pub fn getFavorToDonate(this: &NS) -> Result<f64, JsValue>;
+
+    /// Get the current Bitnode multipliers.
+    /// @remarks
+    /// RAM cost: 4 GB
+    ///
+    /// Returns an object containing the current (or supplied) BitNode multipliers.
+    /// This function requires you to be in Bitnode 5 or have Source-File 5 in order to run.
+    /// The multipliers are returned in decimal forms (e.g. 1.5 instead of 150%).
+    /// The multipliers represent the difference between the current BitNode and
+    /// the original BitNode (BitNode-1).
+    ///
+    /// For example, if the CrimeMoney multiplier has a value of 0.1, then that means
+    /// that committing crimes in the current BitNode will only give 10% of the money
+    /// you would have received in BitNode-1.
+    ///
+    /// @example
+    /// ```js
+    /// const mults = ns.getBitNodeMultipliers();
+    /// ns.tprint(`ServerMaxMoney: ${mults.ServerMaxMoney}`);
+    /// ns.tprint(`HackExpGain: ${mults.HackExpGain}`);
+    /// ```
+    /// @returns Object containing the current BitNode multipliers.
+    #[wasm_bindgen(method, catch)]
+    pub fn getBitNodeMultipliers(
+        this: &NS,
+        n: Option<f64>,
+        lvl: Option<f64>,
+    ) -> Result<BitNodeMultipliers, JsValue>;
+
+    /// Get information about the player.
+    /// @remarks
+    /// RAM cost: 0.5 GB
+    ///
+    /// Returns an object with information on the current player.
+    ///
+    /// @returns Player info
+    #[wasm_bindgen(method, catch)]
+    pub fn getPlayer(this: &NS) -> Result<Player, JsValue>;
+
+    /// Get information about the sources of income for this run.
+    /// @remarks
+    /// RAM cost: 1.0 GB
+    ///
+    /// Returns an object with information on the income sources for this run
+    ///
+    /// @returns Money sources
+    #[wasm_bindgen(method, catch)]
+    pub fn getMoneySources(this: &NS) -> Result<MoneySources, JsValue>;
+
+    /// Add callback function when the script dies
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// NS2 exclusive
+    ///
+    /// Add callback to be executed when the script dies.
+    #[wasm_bindgen(method, catch)]
+    pub fn atExit(this: &NS, f: &js_sys::Function) -> Result<(), JsValue>;
+
+    /// Move a file on the target server.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// Move the source file to the specified destination on the target server.
+    ///
+    /// This command only works for scripts and text files (.txt). It cannot, however,  be used
+    /// to convert from script to text file, or vice versa.
+    ///
+    /// This function can also be used to rename files.
+    ///
+    /// @param host - Hostname of target server.
+    /// @param source - Filename of the source file.
+    /// @param destination - Filename of the destination file.
+    #[wasm_bindgen(method, catch)]
+    pub fn mv(this: &NS, host: &str, source: &str, destination: &str) -> Result<(), JsValue>;
+
+    /// Get information about resets.
+    /// @remarks
+    /// RAM cost: 1 GB
+    ///
+    /// @example
+    /// ```js
+    /// const resetInfo = ns.getResetInfo();
+    /// const lastAugReset = resetInfo.lastAugReset;
+    /// ns.tprint(`The last augmentation reset was: ${new Date(lastAugReset)}`);
+    /// ns.tprint(`It has been ${Date.now() - lastAugReset}ms since the last augmentation reset.`);
+    /// ```
+    #[wasm_bindgen(method, catch)]
+    pub fn getResetInfo(this: &NS) -> Result<ResetInfo, JsValue>;
+
+    /// Get the ram cost of a netscript function.
+    ///
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// @param name - The fully-qualified function name, without the leading `ns`. Example inputs: `hack`, `tprint`, `stock.getPosition`.
+    #[wasm_bindgen(method, catch)]
+    pub fn getFunctionRamCost(this: &NS, name: &str) -> Result<f64, JsValue>;
+
+    /// Parse command line flags.
+    /// @remarks
+    /// RAM cost: 0 GB
+    ///
+    /// Allows Unix-like flag parsing.
+    /// @example
+    /// ```js
+    /// export async function main(ns) {
+    ///   const data = ns.flags([
+    ///     ['delay', 0], // a default number means this flag is a number
+    ///     ['server', 'foodnstuff'], //  a default string means this flag is a string
+    ///     ['exclude', []], // a default array means this flag is a default array of string
+    ///     ['help', false], // a default boolean means this flag is a boolean
+    ///   ]);
+    ///   ns.tprint(data);
+    /// }
+    ///
+    /// // [home ~/]> run example.js
+    /// // {"_":[],"delay":0,"server":"foodnstuff","exclude":[],"help":false}
+    /// // [home ~/]> run example.js --delay 3000
+    /// // {"_":[],"server":"foodnstuff","exclude":[],"help":false,"delay":3000}
+    /// // [home ~/]> run example.js --delay 3000 --server harakiri-sushi
+    /// // {"_":[],"exclude":[],"help":false,"delay":3000,"server":"harakiri-sushi"}
+    /// // [home ~/]> run example.js --delay 3000 --server harakiri-sushi hello world
+    /// // {"_":["hello","world"],"exclude":[],"help":false,"delay":3000,"server":"harakiri-sushi"}
+    /// // [home ~/]> run example.js --delay 3000 --server harakiri-sushi hello world --exclude a --exclude b
+    /// // {"_":["hello","world"],"help":false,"delay":3000,"server":"harakiri-sushi","exclude":["a","b"]}
+    /// // [home ~/]> run example.script --help
+    /// // {"_":[],"delay":0,"server":"foodnstuff","exclude":[],"help":true}
+    /// ```
+    #[wasm_bindgen(method, catch)]
+    pub fn flags(this: &NS, schema: Vec<JsValue>) -> Result<JsValue, JsValue>;
+
+    /// Share the server's ram with your factions.
+    /// @remarks
+    /// RAM cost: 2.4 GB
+    ///
+    /// Increases rep/second for all faction work while share is running. Each cycle of ns.share() is 10 seconds.
+    /// Scales with thread count, but at a sharply decreasing rate.
+    #[wasm_bindgen(method, catch)]
+    pub async fn share(this: &NS) -> Result<(), JsValue>;
+
+    /// Share Power has a multiplicative effect on rep/second while doing work for a faction.
+    /// Share Power increases incrementally for every thread of share running on your server network, but at a sharply decreasing rate.
+    /// @remarks
+    /// RAM cost: 0.2 GB
+    #[wasm_bindgen(method, catch)]
+    pub fn getSharePower(this: &NS) -> Result<f64, JsValue>;
+
+    #[wasm_bindgen(method, getter)]
+    pub fn enums(this: &NS) -> JsValue;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_enums(this: &NS, value: &JsValue);
+}
+
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ToastVariant {
+    Success = "success",
+    Warning = "warning",
+    Error = "error",
+    Info = "info",
+}
+
+impl ToastVariant {
+    /// All the variants in declaration order
+    pub const ALL: [ToastVariant; 4] = [
+        ToastVariant::Success,
+        ToastVariant::Warning,
+        ToastVariant::Error,
+        ToastVariant::Info,
+    ];
+
+    /// The value of this variant in JavaScript
+    pub fn as_str(&self) -> &'static str {
+        self.to_str()
+    }
+}
+
+string_enum!(ToastVariant);
+
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CrimeType {
+    Shoplift = "Shoplift",
+    RobStore = "Rob Store",
+    Mug = "Mug",
+    Larceny = "Larceny",
+    DealDrugs = "Deal Drugs",
+    BondForgery = "Bond Forgery",
+    TraffickArms = "Traffick Arms",
+    Homicide = "Homicide",
+    GrandTheftAuto = "Grand Theft Auto",
+    Kidnap = "Kidnap",
+    Assassination = "Assassination",
+    Heist = "Heist",
+}
+
+impl CrimeType {
+    /// All the variants in declaration order
+    pub const ALL: [CrimeType; 12] = [
+        CrimeType::Shoplift,
+        CrimeType::RobStore,
+        CrimeType::Mug,
+        CrimeType::Larceny,
+        CrimeType::DealDrugs,
+        CrimeType::BondForgery,
+        CrimeType::TraffickArms,
+        CrimeType::Homicide,
+        CrimeType::GrandTheftAuto,
+        CrimeType::Kidnap,
+        CrimeType::Assassination,
+        CrimeType::Heist,
+    ];
+
+    /// The value of this variant in JavaScript
+    pub fn as_str(&self) -> &'static str {
+        self.to_str()
+    }
+}
+
+string_enum!(CrimeType);
+
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FactionWorkType {
+    Hacking = "hacking",
+    Field = "field",
+    Security = "security",
+}
+
+impl FactionWorkType {
+    /// All the variants in declaration order
+    pub const ALL: [FactionWorkType; 3] = [
+        FactionWorkType::Hacking,
+        FactionWorkType::Field,
+        FactionWorkType::Security,
+    ];
+
+    /// The value of this variant in JavaScript
+    pub fn as_str(&self) -> &'static str {
+        self.to_str()
+    }
+}
+
+string_enum!(FactionWorkType);
+
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UniversityClassType {
+    ComputerScience = "Computer Science",
+    DataStructures = "Data Structures",
+    Networks = "Networks",
+    Algorithms = "Algorithms",
+    Management = "Management",
+    Leadership = "Leadership",
+}
+
+impl UniversityClassType {
+    /// All the variants in declaration order
+    pub const ALL: [UniversityClassType; 6] = [
+        UniversityClassType::ComputerScience,
+        UniversityClassType::DataStructures,
+        UniversityClassType::Networks,
+        UniversityClassType::Algorithms,
+        UniversityClassType::Management,
+        UniversityClassType::Leadership,
+    ];
+
+    /// The value of this variant in JavaScript
+    pub fn as_str(&self) -> &'static str {
+        self.to_str()
+    }
+}
+
+string_enum!(UniversityClassType);
+
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GymType {
+    Strength = "str",
+    Defense = "def",
+    Dexterity = "dex",
+    Agility = "agi",
+}
+
+impl GymType {
+    /// All the variants in declaration order
+    pub const ALL: [GymType; 4] = [
+        GymType::Strength,
+        GymType::Defense,
+        GymType::Dexterity,
+        GymType::Agility,
+    ];
+
+    /// The value of this variant in JavaScript
+    pub fn as_str(&self) -> &'static str {
+        self.to_str()
+    }
+}
+
+string_enum!(GymType);
+
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JobName {
+    Software0 = "Software Engineering Intern",
+    Software1 = "Junior Software Engineer",
+    Software2 = "Senior Software Engineer",
+    Software3 = "Lead Software Developer",
+    Software4 = "Head of Software",
+    Software5 = "Head of Engineering",
+    Software6 = "Vice President of Technology",
+    Software7 = "Chief Technology Officer",
+    It0 = "IT Intern",
+    It1 = "IT Analyst",
+    It2 = "IT Manager",
+    It3 = "Systems Administrator",
+    SecurityEng = "Security Engineer",
+    NetworkEng0 = "Network Engineer",
+    NetworkEng1 = "Network Administrator",
+    Business0 = "Business Intern",
+    Business1 = "Business Analyst",
+    Business2 = "Business Manager",
+    Business3 = "Operations Manager",
+    Business4 = "Chief Financial Officer",
+    Business5 = "Chief Executive Officer",
+    Security0 = "Security Guard",
+    Security1 = "Security Officer",
+    Security2 = "Security Supervisor",
+    Security3 = "Head of Security",
+    Agent0 = "Field Agent",
+    Agent1 = "Secret Agent",
+    Agent2 = "Special Operative",
+    Waiter = "Waiter",
+    Employee = "Employee",
+    SoftwareConsult0 = "Software Consultant",
+    SoftwareConsult1 = "Senior Software Consultant",
+    BusinessConsult0 = "Business Consultant",
+    BusinessConsult1 = "Senior Business Consultant",
+    WaiterPT = "Part-time Waiter",
+    EmployeePT = "Part-time Employee",
+}
+
+impl JobName {
+    /// All the variants in declaration order
+    pub const ALL: [JobName; 36] = [
+        JobName::Software0,
+        JobName::Software1,
+        JobName::Software2,
+        JobName::Software3,
+        JobName::Software4,
+        JobName::Software5,
+        JobName::Software6,
+        JobName::Software7,
+        JobName::It0,
+        JobName::It1,
+        JobName::It2,
+        JobName::It3,
+        JobName::SecurityEng,
+        JobName::NetworkEng0,
+        JobName::NetworkEng1,
+        JobName::Business0,
+        JobName::Business1,
+        JobName::Business2,
+        JobName::Business3,
+        JobName::Business4,
+        JobName::Business5,
+        JobName::Security0,
+        JobName::Security1,
+        JobName::Security2,
+        JobName::Security3,
+        JobName::Agent0,
+        JobName::Agent1,
+        JobName::Agent2,
+        JobName::Waiter,
+        JobName::Employee,
+        JobName::SoftwareConsult0,
+        JobName::SoftwareConsult1,
+        JobName::BusinessConsult0,
+        JobName::BusinessConsult1,
+        JobName::WaiterPT,
+        JobName::EmployeePT,
+    ];
+
+    /// The value of this variant in JavaScript
+    pub fn as_str(&self) -> &'static str {
+        self.to_str()
+    }
+}
+
+string_enum!(JobName);
+
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JobField {
+    Software = "Software",
+    SoftwareConsultant = "Software Consultant",
+    It = "IT",
+    SecurityEngineer = "Security Engineer",
+    NetworkEngineer = "Network Engineer",
+    Business = "Business",
+    BusinessConsultant = "Business Consultant",
+    Security = "Security",
+    Agent = "Agent",
+    Employee = "Employee",
+    PartTimeEmployee = "Part-time Employee",
+    Waiter = "Waiter",
+    PartTimeWaiter = "Part-time Waiter",
+}
+
+impl JobField {
+    /// All the variants in declaration order
+    pub const ALL: [JobField; 13] = [
+        JobField::Software,
+        JobField::SoftwareConsultant,
+        JobField::It,
+        JobField::SecurityEngineer,
+        JobField::NetworkEngineer,
+        JobField::Business,
+        JobField::BusinessConsultant,
+        JobField::Security,
+        JobField::Agent,
+        JobField::Employee,
+        JobField::PartTimeEmployee,
+        JobField::Waiter,
+        JobField::PartTimeWaiter,
+    ];
+
+    /// The value of this variant in JavaScript
+    pub fn as_str(&self) -> &'static str {
+        self.to_str()
+    }
+}
+
+string_enum!(JobField);
+
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CorpEmployeePosition {
+    Operations = "Operations",
+    Engineer = "Engineer",
+    Business = "Business",
+    Management = "Management",
+    ResearchDevelopment = "Research & Development",
+    Intern = "Intern",
+    Unassigned = "Unassigned",
+}
+
+impl CorpEmployeePosition {
+    /// All the variants in declaration order
+    pub const ALL: [CorpEmployeePosition; 7] = [
+        CorpEmployeePosition::Operations,
+        CorpEmployeePosition::Engineer,
+        CorpEmployeePosition::Business,
+        CorpEmployeePosition::Management,
+        CorpEmployeePosition::ResearchDevelopment,
+        CorpEmployeePosition::Intern,
+        CorpEmployeePosition::Unassigned,
+    ];
+
+    /// The value of this variant in JavaScript
+    pub fn as_str(&self) -> &'static str {
+        self.to_str()
+    }
+}
+
+string_enum!(CorpEmployeePosition);
+
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CorpIndustryName {
+    SpringWater = "Spring Water",
+    WaterUtilities = "Water Utilities",
+    Agriculture = "Agriculture",
+    Fishing = "Fishing",
+    Mining = "Mining",
+    Refinery = "Refinery",
+    Restaurant = "Restaurant",
+    Tobacco = "Tobacco",
+    Chemical = "Chemical",
+    Pharmaceutical = "Pharmaceutical",
+    ComputerHardware = "Computer Hardware",
+    Robotics = "Robotics",
+    Software = "Software",
+    Healthcare = "Healthcare",
+    RealEstate = "Real Estate",
+}
+
+impl CorpIndustryName {
+    /// All the variants in declaration order
+    pub const ALL: [CorpIndustryName; 15] = [
+        CorpIndustryName::SpringWater,
+        CorpIndustryName::WaterUtilities,
+        CorpIndustryName::Agriculture,
+        CorpIndustryName::Fishing,
+        CorpIndustryName::Mining,
+        CorpIndustryName::Refinery,
+        CorpIndustryName::Restaurant,
+        CorpIndustryName::Tobacco,
+        CorpIndustryName::Chemical,
+        CorpIndustryName::Pharmaceutical,
+        CorpIndustryName::ComputerHardware,
+        CorpIndustryName::Robotics,
+        CorpIndustryName::Software,
+        CorpIndustryName::Healthcare,
+        CorpIndustryName::RealEstate,
+    ];
+
+    /// The value of this variant in JavaScript
+    pub fn as_str(&self) -> &'static str {
+        self.to_str()
+    }
+}
+
+string_enum!(CorpIndustryName);
+
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CorpSmartSupplyOption {
+    Leftovers = "leftovers",
+    Imports = "imports",
+    None = "none",
+}
+
+impl CorpSmartSupplyOption {
+    /// All the variants in declaration order
+    pub const ALL: [CorpSmartSupplyOption; 3] = [
+        CorpSmartSupplyOption::Leftovers,
+        CorpSmartSupplyOption::Imports,
+        CorpSmartSupplyOption::None,
+    ];
+
+    /// The value of this variant in JavaScript
+    pub fn as_str(&self) -> &'static str {
+        self.to_str()
+    }
+}
+
+string_enum!(CorpSmartSupplyOption);
+
+/// Names of all cities
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CityName {
+    Aevum = "Aevum",
+    Chongqing = "Chongqing",
+    Sector12 = "Sector-12",
+    NewTokyo = "New Tokyo",
+    Ishima = "Ishima",
+    Volhaven = "Volhaven",
+}
+
+impl CityName {
+    /// All the variants in declaration order
+    pub const ALL: [CityName; 6] = [
+        CityName::Aevum,
+        CityName::Chongqing,
+        CityName::Sector12,
+        CityName::NewTokyo,
+        CityName::Ishima,
+        CityName::Volhaven,
+    ];
+
+    /// The value of this variant in JavaScript
+    pub fn as_str(&self) -> &'static str {
+        self.to_str()
+    }
+}
+
+string_enum!(CityName);
+
+/// Names of all locations
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LocationName {
+    AevumAeroCorp = "AeroCorp",
+    AevumBachmanAndAssociates = "Bachman & Associates",
+    AevumClarkeIncorporated = "Clarke Incorporated",
+    AevumCrushFitnessGym = "Crush Fitness Gym",
+    AevumECorp = "ECorp",
+    AevumFulcrumTechnologies = "Fulcrum Technologies",
+    AevumGalacticCybersystems = "Galactic Cybersystems",
+    AevumNetLinkTechnologies = "NetLink Technologies",
+    AevumPolice = "Aevum Police Headquarters",
+    AevumRhoConstruction = "Rho Construction",
+    AevumSnapFitnessGym = "Snap Fitness Gym",
+    AevumSummitUniversity = "Summit University",
+    AevumWatchdogSecurity = "Watchdog Security",
+    AevumCasino = "Iker Molina Casino",
+    ChongqingKuaiGongInternational = "KuaiGong International",
+    ChongqingSolarisSpaceSystems = "Solaris Space Systems",
+    ChongqingChurchOfTheMachineGod = "Church of the Machine God",
+    Sector12AlphaEnterprises = "Alpha Enterprises",
+    Sector12BladeIndustries = "Blade Industries",
+    Sector12CIA = "Central Intelligence Agency",
+    Sector12CarmichaelSecurity = "Carmichael Security",
+    Sector12CityHall = "Sector-12 City Hall",
+    Sector12DeltaOne = "DeltaOne",
+    Sector12FoodNStuff = "FoodNStuff",
+    Sector12FourSigma = "Four Sigma",
+    Sector12IcarusMicrosystems = "Icarus Microsystems",
+    Sector12IronGym = "Iron Gym",
+    Sector12JoesGuns = "Joe's Guns",
+    Sector12MegaCorp = "MegaCorp",
+    Sector12NSA = "National Security Agency",
+    Sector12PowerhouseGym = "Powerhouse Gym",
+    Sector12RothmanUniversity = "Rothman University",
+    Sector12UniversalEnergy = "Universal Energy",
+    NewTokyoDefComm = "DefComm",
+    NewTokyoGlobalPharmaceuticals = "Global Pharmaceuticals",
+    NewTokyoNoodleBar = "Noodle Bar",
+    NewTokyoVitaLife = "VitaLife",
+    NewTokyoArcade = "Arcade",
+    IshimaNovaMedical = "Nova Medical",
+    IshimaOmegaSoftware = "Omega Software",
+    IshimaStormTechnologies = "Storm Technologies",
+    IshimaGlitch = "0x6C1",
+    VolhavenCompuTek = "CompuTek",
+    VolhavenHeliosLabs = "Helios Labs",
+    VolhavenLexoCorp = "LexoCorp",
+    VolhavenMilleniumFitnessGym = "Millenium Fitness Gym",
+    VolhavenNWO = "NWO",
+    VolhavenOmniTekIncorporated = "OmniTek Incorporated",
+    VolhavenOmniaCybersystems = "Omnia Cybersystems",
+    VolhavenSysCoreSecurities = "SysCore Securities",
+    VolhavenZBInstituteOfTechnology = "ZB Institute of Technology",
+    Hospital = "Hospital",
+    Slums = "The Slums",
+    TravelAgency = "Travel Agency",
+    WorldStockExchange = "World Stock Exchange",
+    Void = "The Void",
+}
+
+impl LocationName {
+    /// All the variants in declaration order
+    pub const ALL: [LocationName; 56] = [
+        LocationName::AevumAeroCorp,
+        LocationName::AevumBachmanAndAssociates,
+        LocationName::AevumClarkeIncorporated,
+        LocationName::AevumCrushFitnessGym,
+        LocationName::AevumECorp,
+        LocationName::AevumFulcrumTechnologies,
+        LocationName::AevumGalacticCybersystems,
+        LocationName::AevumNetLinkTechnologies,
+        LocationName::AevumPolice,
+        LocationName::AevumRhoConstruction,
+        LocationName::AevumSnapFitnessGym,
+        LocationName::AevumSummitUniversity,
+        LocationName::AevumWatchdogSecurity,
+        LocationName::AevumCasino,
+        LocationName::ChongqingKuaiGongInternational,
+        LocationName::ChongqingSolarisSpaceSystems,
+        LocationName::ChongqingChurchOfTheMachineGod,
+        LocationName::Sector12AlphaEnterprises,
+        LocationName::Sector12BladeIndustries,
+        LocationName::Sector12CIA,
+        LocationName::Sector12CarmichaelSecurity,
+        LocationName::Sector12CityHall,
+        LocationName::Sector12DeltaOne,
+        LocationName::Sector12FoodNStuff,
+        LocationName::Sector12FourSigma,
+        LocationName::Sector12IcarusMicrosystems,
+        LocationName::Sector12IronGym,
+        LocationName::Sector12JoesGuns,
+        LocationName::Sector12MegaCorp,
+        LocationName::Sector12NSA,
+        LocationName::Sector12PowerhouseGym,
+        LocationName::Sector12RothmanUniversity,
+        LocationName::Sector12UniversalEnergy,
+        LocationName::NewTokyoDefComm,
+        LocationName::NewTokyoGlobalPharmaceuticals,
+        LocationName::NewTokyoNoodleBar,
+        LocationName::NewTokyoVitaLife,
+        LocationName::NewTokyoArcade,
+        LocationName::IshimaNovaMedical,
+        LocationName::IshimaOmegaSoftware,
+        LocationName::IshimaStormTechnologies,
+        LocationName::IshimaGlitch,
+        LocationName::VolhavenCompuTek,
+        LocationName::VolhavenHeliosLabs,
+        LocationName::VolhavenLexoCorp,
+        LocationName::VolhavenMilleniumFitnessGym,
+        LocationName::VolhavenNWO,
+        LocationName::VolhavenOmniTekIncorporated,
+        LocationName::VolhavenOmniaCybersystems,
+        LocationName::VolhavenSysCoreSecurities,
+        LocationName::VolhavenZBInstituteOfTechnology,
+        LocationName::Hospital,
+        LocationName::Slums,
+        LocationName::TravelAgency,
+        LocationName::WorldStockExchange,
+        LocationName::Void,
+    ];
+
+    /// The value of this variant in JavaScript
+    pub fn as_str(&self) -> &'static str {
+        self.to_str()
+    }
+}
+
+string_enum!(LocationName);
+
+/// Names of all companies
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CompanyName {
+    ECorp = "ECorp",
+    MegaCorp = "MegaCorp",
+    BachmanAndAssociates = "Bachman & Associates",
+    BladeIndustries = "Blade Industries",
+    Nwo = "NWO",
+    ClarkeIncorporated = "Clarke Incorporated",
+    OmniTekIncorporated = "OmniTek Incorporated",
+    FourSigma = "Four Sigma",
+    KuaiGongInternational = "KuaiGong International",
+    FulcrumTechnologies = "Fulcrum Technologies",
+    StormTechnologies = "Storm Technologies",
+    DefComm = "DefComm",
+    HeliosLabs = "Helios Labs",
+    VitaLife = "VitaLife",
+    IcarusMicrosystems = "Icarus Microsystems",
+    UniversalEnergy = "Universal Energy",
+    GalacticCybersystems = "Galactic Cybersystems",
+    AeroCorp = "AeroCorp",
+    OmniaCybersystems = "Omnia Cybersystems",
+    SolarisSpaceSystems = "Solaris Space Systems",
+    DeltaOne = "DeltaOne",
+    GlobalPharmaceuticals = "Global Pharmaceuticals",
+    NovaMedical = "Nova Medical",
+    Cia = "Central Intelligence Agency",
+    Nsa = "National Security Agency",
+    WatchdogSecurity = "Watchdog Security",
+    LexoCorp = "LexoCorp",
+    RhoConstruction = "Rho Construction",
+    AlphaEnterprises = "Alpha Enterprises",
+    Police = "Aevum Police Headquarters",
+    SysCoreSecurities = "SysCore Securities",
+    CompuTek = "CompuTek",
+    NetLinkTechnologies = "NetLink Technologies",
+    CarmichaelSecurity = "Carmichael Security",
+    FoodNStuff = "FoodNStuff",
+    JoesGuns = "Joe's Guns",
+    OmegaSoftware = "Omega Software",
+    NoodleBar = "Noodle Bar",
+}
+
+impl CompanyName {
+    /// All the variants in declaration order
+    pub const ALL: [CompanyName; 38] = [
+        CompanyName::ECorp,
+        CompanyName::MegaCorp,
+        CompanyName::BachmanAndAssociates,
+        CompanyName::BladeIndustries,
+        CompanyName::Nwo,
+        CompanyName::ClarkeIncorporated,
+        CompanyName::OmniTekIncorporated,
+        CompanyName::FourSigma,
+        CompanyName::KuaiGongInternational,
+        CompanyName::FulcrumTechnologies,
+        CompanyName::StormTechnologies,
+        CompanyName::DefComm,
+        CompanyName::HeliosLabs,
+        CompanyName::VitaLife,
+        CompanyName::IcarusMicrosystems,
+        CompanyName::UniversalEnergy,
+        CompanyName::GalacticCybersystems,
+        CompanyName::AeroCorp,
+        CompanyName::OmniaCybersystems,
+        CompanyName::SolarisSpaceSystems,
+        CompanyName::DeltaOne,
+        CompanyName::GlobalPharmaceuticals,
+        CompanyName::NovaMedical,
+        CompanyName::Cia,
+        CompanyName::Nsa,
+        CompanyName::WatchdogSecurity,
+        CompanyName::LexoCorp,
+        CompanyName::RhoConstruction,
+        CompanyName::AlphaEnterprises,
+        CompanyName::Police,
+        CompanyName::SysCoreSecurities,
+        CompanyName::CompuTek,
+        CompanyName::NetLinkTechnologies,
+        CompanyName::CarmichaelSecurity,
+        CompanyName::FoodNStuff,
+        CompanyName::JoesGuns,
+        CompanyName::OmegaSoftware,
+        CompanyName::NoodleBar,
+    ];
+
+    /// The value of this variant in JavaScript
+    pub fn as_str(&self) -> &'static str {
+        self.to_str()
+    }
+}
+
+string_enum!(CompanyName);
+
+#[wasm_bindgen]
+extern "C" {
+    /// Corporation Office API
+    /// @remarks
+    /// requires the Office API upgrade from your corporation.
+    #[derive(Debug, Clone, PartialEq)]
+    pub type OfficeAPI;
+
+    /// Hire an employee.
+    /// @param divisionName - Name of the division
+    /// @param city - Name of the city
+    /// @param employeePosition - Position to place into. Defaults to "Unassigned".
+    /// @returns True if an employee was hired, false otherwise
+    #[wasm_bindgen(method, catch)]
+    pub fn hireEmployee(
+        this: &OfficeAPI,
+        divisionName: &str,
+        city: CityName,
+        employeePosition: Option<CorpEmployeePosition>,
+    ) -> Result<bool, JsValue>;
+
+    /// Upgrade office size.
+    /// @param divisionName - Name of the division
+    /// @param city - Name of the city
+    /// @param size - Amount of positions to open
+    #[wasm_bindgen(method, catch)]
+    pub fn upgradeOfficeSize(
+        this: &OfficeAPI,
+        divisionName: &str,
+        city: CityName,
+        size: f64,
+    ) -> Result<(), JsValue>;
+
+    /// Throw a party for your employees
+    /// @param divisionName - Name of the division
+    /// @param city - Name of the city
+    /// @param costPerEmployee - Amount to spend per employee.
+    /// @returns Multiplier for morale, or zero on failure
+    #[wasm_bindgen(method, catch)]
+    pub fn throwParty(
+        this: &OfficeAPI,
+        divisionName: &str,
+        city: CityName,
+        costPerEmployee: f64,
+    ) -> Result<f64, JsValue>;
+
+    /// Buy tea for your employees
+    /// @param divisionName - Name of the division
+    /// @param city - Name of the city
+    /// @returns true if buying tea was successful, false otherwise
+    #[wasm_bindgen(method, catch)]
+    pub fn buyTea(this: &OfficeAPI, divisionName: &str, city: CityName) -> Result<bool, JsValue>;
+
+    /// Hire AdVert.
+    /// @param divisionName - Name of the division
+    #[wasm_bindgen(method, catch)]
+    pub fn hireAdVert(this: &OfficeAPI, divisionName: &str) -> Result<(), JsValue>;
+
+    /// Purchase a research
+    /// @param divisionName - Name of the division
+    /// @param researchName - Name of the research
+    #[wasm_bindgen(method, catch)]
+    pub fn research(
+        this: &OfficeAPI,
+        divisionName: &str,
+        researchName: &str,
+    ) -> Result<(), JsValue>;
+
+    /// Get data about an office
+    /// @param divisionName - Name of the division
+    /// @param city - Name of the city
+    /// @returns Office data
+    #[wasm_bindgen(method, catch)]
+    pub fn getOffice(
+        this: &OfficeAPI,
+        divisionName: &str,
+        city: CityName,
+    ) -> Result<Office, JsValue>;
+
+    /// Get the cost to hire AdVert.
+    /// @param divisionName - Name of the division.
+    /// @returns The cost to hire AdVert.
+    #[wasm_bindgen(method, catch)]
+    pub fn getHireAdVertCost(this: &OfficeAPI, divisionName: &str) -> Result<f64, JsValue>;
+
+    /// Get the number of times you have hired AdVert.
+    /// @param divisionName - Name of the division.
+    /// @returns Number of times you have hired AdVert.
+    #[wasm_bindgen(method, catch)]
+    pub fn getHireAdVertCount(this: &OfficeAPI, divisionName: &str) -> Result<f64, JsValue>;
+
+    /// Get the cost to unlock research
+    /// @param divisionName - Name of the division
+    /// @param researchName - Name of the research
+    /// @returns cost
+    #[wasm_bindgen(method, catch)]
+    pub fn getResearchCost(
+        this: &OfficeAPI,
+        divisionName: &str,
+        researchName: &str,
+    ) -> Result<f64, JsValue>;
+
+    /// Gets if you have unlocked a research
+    /// @param divisionName - Name of the division
+    /// @param researchName - Name of the research
+    /// @returns true is unlocked, false if not
+    #[wasm_bindgen(method, catch)]
+    pub fn hasResearched(
+        this: &OfficeAPI,
+        divisionName: &str,
+        researchName: &str,
+    ) -> Result<bool, JsValue>;
+
+    /// Set the auto job assignment for a job
+    /// @param divisionName - Name of the division
+    /// @param city - Name of the city
+    /// @param job - Name of the job
+    /// @param amount - Number of employees to assign to that job
+    /// @returns true if the employee count reached the target amount, false if not
+    #[wasm_bindgen(method, catch)]
+    pub fn setAutoJobAssignment(
+        this: &OfficeAPI,
+        divisionName: &str,
+        city: CityName,
+        job: &str,
+        amount: f64,
+    ) -> Result<bool, JsValue>;
+
+    /// Cost to Upgrade office size.
+    /// @param divisionName - Name of the division
+    /// @param city - Name of the city
+    /// @param size - Amount of positions to open
+    /// @returns Cost of upgrading the office
+    #[wasm_bindgen(method, catch)]
+    pub fn getOfficeSizeUpgradeCost(
+        this: &OfficeAPI,
+        divisionName: &str,
+        city: CityName,
+        size: f64,
+    ) -> Result<f64, JsValue>;
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// Corporation Warehouse API
+    /// @remarks
+    /// Requires the Warehouse API upgrade from your corporation.
+    #[derive(Debug, Clone, PartialEq)]
+    pub type WarehouseAPI;
+
+    /// Set material sell data.
+    /// @param divisionName - Name of the division
+    /// @param city - Name of the city
+    /// @param materialName - Name of the material
+    /// @param amt - Amount to sell, can be "MAX"
+    /// @param price - Price to sell, can be "MP"
+    #[wasm_bindgen(method, catch)]
+    pub fn sellMaterial(
+        this: &WarehouseAPI,
+        divisionName: &str,
+        city: CityName,
+        materialName: &str,
+        amt: &str,
+        price: &str,
+    ) -> Result<(), JsValue>;
+
+    /// Set product sell data.
+    /// @param divisionName - Name of the division
+    /// @param city - Name of the city
+    /// @param productName - Name of the product
+    /// @param amt - Amount to sell, can be "MAX"
+    /// @param price - Price to sell, can be "MP"
+    /// @param all - Set sell amount and price in all cities
+    #[wasm_bindgen(method, catch)]
+    pub fn sellProduct(
+        this: &WarehouseAPI,
+        divisionName: &str,
+        city: CityName,
+        productName: &str,
+        amt: &str,
+        price: &str,
+        all: bool,
+    ) -> Result<(), JsValue>;
+
+    /// Discontinue a product.
+    /// @param divisionName - Name of the division
+    /// @param productName - Name of the product
+    #[wasm_bindgen(method, catch)]
+    pub fn discontinueProduct(
+        this: &WarehouseAPI,
+        divisionName: &str,
+        productName: &str,
+    ) -> Result<(), JsValue>;
+
+    /// Set smart supply
+    /// @param divisionName - Name of the division
+    /// @param city - Name of the city
+    /// @param enabled - smart supply enabled
+    #[wasm_bindgen(method, catch)]
+    pub fn setSmartSupply(
+        this: &WarehouseAPI,
+        divisionName: &str,
+        city: CityName,
+        enabled: bool,
+    ) -> Result<(), JsValue>;
+
+    /// Set whether smart supply uses leftovers before buying
+    /// @param divisionName - Name of the division
+    /// @param city - Name of the city
+    /// @param materialName - Name of the material
+    /// @param option - smart supply option, "leftovers" to use leftovers, "imports" to use only imported materials, "none" to not use materials from store
+    #[wasm_bindgen(method, catch)]
+    pub fn setSmartSupplyOption(
+        this: &WarehouseAPI,
+        divisionName: &str,
+        city: CityName,
+        materialName: &str,
+        option: CorpSmartSupplyOption,
+    ) -> Result<(), JsValue>;
+
+    /// Set material buy data
+    /// @param divisionName - Name of the division
+    /// @param city - Name of the city
+    /// @param materialName - Name of the material
+    /// @param amt - Amount of material to buy
+    #[wasm_bindgen(method, catch)]
+    pub fn buyMaterial(
+        this: &WarehouseAPI,
+        divisionName: &str,
+        city: CityName,
+        materialName: &str,
+        amt: f64,
+    ) -> Result<(), JsValue>;
+
+    /// Set material to bulk buy
+    /// @param divisionName - Name of the division
+    /// @param city - Name of the city
+    /// @param materialName - Name of the material
+    /// @param amt - Amount of material to buy
+    #[wasm_bindgen(method, catch)]
+    pub fn bulkPurchase(
+        this: &WarehouseAPI,
+        divisionName: &str,
+        city: CityName,
+        materialName: &str,
+        amt: f64,
+    ) -> Result<(), JsValue>;
+
+    /// Get warehouse data
+    /// @param divisionName - Name of the division
+    /// @param city - Name of the city
+    /// @returns warehouse data
+    #[wasm_bindgen(method, catch)]
+    pub fn getWarehouse(
+        this: &WarehouseAPI,
+        divisionName: &str,
+        city: CityName,
+    ) -> Result<Warehouse, JsValue>;
+
+    /// Get product data
+    /// @param divisionName - Name of the division
+    /// @param cityName - Name of the city
+    /// @param productName - Name of the product
+    /// @returns product data
+    #[wasm_bindgen(method, catch)]
+    pub fn getProduct(
+        this: &WarehouseAPI,
+        divisionName: &str,
+        cityName: CityName,
+        productName: &str,
+    ) -> Result<Product, JsValue>;
+
+    /// Get material data
+    /// @param divisionName - Name of the division
+    /// @param city - Name of the city
+    /// @param materialName - Name of the material
+    /// @returns material data
+    #[wasm_bindgen(method, catch)]
+    pub fn getMaterial(
+        this: &WarehouseAPI,
+        divisionName: &str,
+        city: CityName,
+        materialName: &str,
+    ) -> Result<Material, JsValue>;
+
+    /// Set market TA 1 for a material.
+    /// @param divisionName - Name of the division
+    /// @param city - Name of the city
+    /// @param materialName - Name of the material
+    /// @param on - market ta enabled
+    #[wasm_bindgen(method, catch)]
+    pub fn setMaterialMarketTA1(
+        this: &WarehouseAPI,
+        divisionName: &str,
+        city: CityName,
+        materialName: &str,
+        on: bool,
+    ) -> Result<(), JsValue>;
+
+    /// Set market TA 2 for a material.
+    /// @param divisionName - Name of the division
+    /// @param city - Name of the city
+    /// @param materialName - Name of the material
+    /// @param on - market ta enabled
+    #[wasm_bindgen(method, catch)]
+    pub fn setMaterialMarketTA2(
+        this: &WarehouseAPI,
+        divisionName: &str,
+        city: CityName,
+        materialName: &str,
+        on: bool,
+    ) -> Result<(), JsValue>;
+
+    /// Set market TA 1 for a product.
+    /// @param divisionName - Name of the division
+    /// @param productName - Name of the product
+    /// @param on - market ta enabled
+    #[wasm_bindgen(method, catch)]
+    pub fn setProductMarketTA1(
+        this: &WarehouseAPI,
+        divisionName: &str,
+        productName: &str,
+        on: bool,
+    ) -> Result<(), JsValue>;
+
+    /// Set market TA 2 for a product.
+    /// @param divisionName - Name of the division
+    /// @param productName - Name of the product
+    /// @param on - market ta enabled
+    #[wasm_bindgen(method, catch)]
+    pub fn setProductMarketTA2(
+        this: &WarehouseAPI,
+        divisionName: &str,
+        productName: &str,
+        on: bool,
+    ) -> Result<(), JsValue>;
+
+    /// Set material export data
+    /// @param sourceDivision - Source division
+    /// @param sourceCity - Source city
+    /// @param targetDivision - Target division
+    /// @param targetCity - Target city
+    /// @param materialName - Name of the material
+    /// @param amt - Amount of material to export.
+    #[wasm_bindgen(method, catch)]
+    pub fn exportMaterial(
+        this: &WarehouseAPI,
+        sourceDivision: &str,
+        sourceCity: CityName,
+        targetDivision: &str,
+        targetCity: CityName,
+        materialName: &str,
+        amt: &JsValue,
+    ) -> Result<(), JsValue>;
+
+    /// Cancel material export
+    /// @param sourceDivision - Source division
+    /// @param sourceCity - Source city
+    /// @param targetDivision - Target division
+    /// @param targetCity - Target city
+    /// @param materialName - Name of the material
+    #[wasm_bindgen(method, catch)]
+    pub fn cancelExportMaterial(
+        this: &WarehouseAPI,
+        sourceDivision: &str,
+        sourceCity: CityName,
+        targetDivision: &str,
+        targetCity: CityName,
+        materialName: &str,
+    ) -> Result<(), JsValue>;
+
+    /// Purchase warehouse for a new city
+    /// @param divisionName - Name of the division
+    /// @param city - Name of the city
+    #[wasm_bindgen(method, catch)]
+    pub fn purchaseWarehouse(
+        this: &WarehouseAPI,
+        divisionName: &str,
+        city: CityName,
+    ) -> Result<(), JsValue>;
+
+    /// Upgrade warehouse
+    /// @param divisionName - Name of the division
+    /// @param city - Name of the city
+    /// @param amt - amount of upgrades defaults to 1
+    #[wasm_bindgen(method, catch)]
+    pub fn upgradeWarehouse(
+        this: &WarehouseAPI,
+        divisionName: &str,
+        city: CityName,
+        amt: Option<f64>,
+    ) -> Result<(), JsValue>;
+
+    /// Create a new product
+    /// @param divisionName - Name of the division
+    /// @param city - Name of the city
+    /// @param productName - Name of the product
+    /// @param designInvest - Amount to invest for the design of the product.
+    /// @param marketingInvest - Amount to invest for the marketing of the product.
+    #[wasm_bindgen(method, catch)]
+    pub fn makeProduct(
+        this: &WarehouseAPI,
+        divisionName: &str,
+        city: CityName,
+        productName: &str,
+        designInvest: f64,
+        marketingInvest: f64,
+    ) -> Result<(), JsValue>;
+
+    /// Limit Material Production.
+    /// @param divisionName - Name of the division.
+    /// @param city - Name of the city.
+    /// @param materialName - Name of the material.
+    /// @param qty - Amount to limit to. Pass a negative value to remove the limit instead.
+    #[wasm_bindgen(method, catch)]
+    pub fn limitMaterialProduction(
+        this: &WarehouseAPI,
+        divisionName: &str,
+        city: CityName,
+        materialName: &str,
+        qty: f64,
+    ) -> Result<(), JsValue>;
+
+    /// Limit Product Production.
+    /// @param divisionName - Name of the division.
+    /// @param city - Name of the city.
+    /// @param productName - Name of the product.
+    /// @param qty - Amount to limit to. Pass a negative value to remove the limit instead.
+    #[wasm_bindgen(method, catch)]
+    pub fn limitProductProduction(
+        this: &WarehouseAPI,
+        divisionName: &str,
+        city: CityName,
+        productName: &str,
+        qty: f64,
+    ) -> Result<(), JsValue>;
+
+    /// Gets the cost to upgrade a warehouse to the next level
+    /// @param divisionName - Name of the division
+    /// @param city - Name of the city
+    /// @param amt - amount of upgrades. Optional, defaults to 1
+    /// @returns cost to upgrade
+    #[wasm_bindgen(method, catch)]
+    pub fn getUpgradeWarehouseCost(
+        this: &WarehouseAPI,
+        divisionName: &str,
+        city: CityName,
+        amt: Option<f64>,
+    ) -> Result<f64, JsValue>;
+
+    /// Check if you have a warehouse in city
+    /// @returns true if warehouse is present, false if not
+    #[wasm_bindgen(method, catch)]
+    pub fn hasWarehouse(
+        this: &WarehouseAPI,
+        divisionName: &str,
+        city: CityName,
+    ) -> Result<bool, JsValue>;
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// Corporation API
+    #[wasm_bindgen(extends = WarehouseAPI, extends = OfficeAPI)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub type Corporation;
+
+    /// Returns whether the player has a corporation. Does not require API access.
+    /// @returns whether the player has a corporation
+    #[wasm_bindgen(method, catch)]
+    pub fn hasCorporation(this: &Corporation) -> Result<bool, JsValue>;
+
+    /// Create a Corporation
+    /// @param corporationName - Name of the corporation
+    /// @param selfFund - If you should self fund, defaults to true, false will only work on Bitnode 3
+    /// @returns true if created and false if not
+    #[wasm_bindgen(method, catch)]
+    pub fn createCorporation(
+        this: &Corporation,
+        corporationName: &str,
+        selfFund: bool,
+    ) -> Result<bool, JsValue>;
+
+    /// Check if you have a one time unlockable upgrade
+    /// @param upgradeName - Name of the upgrade
+    /// @returns true if unlocked and false if not
+    #[wasm_bindgen(method, catch)]
+    pub fn hasUnlock(this: &Corporation, upgradeName: &str) -> Result<bool, JsValue>;
+
+    /// Gets the cost to unlock a one time unlockable upgrade
+    /// @param upgradeName - Name of the upgrade
+    /// @returns cost of the upgrade
+    #[wasm_bindgen(method, catch)]
+    pub fn getUnlockCost(this: &Corporation, upgradeName: &str) -> Result<f64, JsValue>;
+
+    /// Get the level of a levelable upgrade
+    /// @param upgradeName - Name of the upgrade
+    /// @returns the level of the upgrade
+    #[wasm_bindgen(method, catch)]
+    pub fn getUpgradeLevel(this: &Corporation, upgradeName: &str) -> Result<f64, JsValue>;
+
+    /// Gets the cost to unlock the next level of a levelable upgrade
+    /// @param upgradeName - Name of the upgrade
+    /// @returns cost of the upgrade
+    #[wasm_bindgen(method, catch)]
+    pub fn getUpgradeLevelCost(this: &Corporation, upgradeName: &str) -> Result<f64, JsValue>;
+
+    /// Get an offer for investment based on you companies current valuation
+    /// @returns An offer of investment
+    #[wasm_bindgen(method, catch)]
+    pub fn getInvestmentOffer(this: &Corporation) -> Result<InvestmentOffer, JsValue>;
+
+    /// Get corporation related constants
+    /// @returns corporation related constants
+    #[wasm_bindgen(method, catch)]
+    pub fn getConstants(this: &Corporation) -> Result<CorpConstants, JsValue>;
+
+    /// Get constant industry definition data for a specific industry
+    #[wasm_bindgen(method, catch)]
+    pub fn getIndustryData(
+        this: &Corporation,
+        industryName: CorpIndustryName,
+    ) -> Result<CorpIndustryData, JsValue>;
+
+    /// Get constant data for a specific material
+    #[wasm_bindgen(method, catch)]
+    pub fn getMaterialData(
+        this: &Corporation,
+        materialName: CorpMaterialName,
+    ) -> Result<CorpMaterialConstantData, JsValue>;
+
+    /// Accept investment based on you companies current valuation
+    /// @remarks
+    /// Is based on current valuation and will not honer a specific Offer
+    /// @returns An offer of investment
+    #[wasm_bindgen(method, catch)]
+    pub fn acceptInvestmentOffer(this: &Corporation) -> Result<bool, JsValue>;
+
+    /// Go public
+    /// @param numShares - number of shares you would like to issue for your IPO
+    /// @returns true if you successfully go public, false if not
+    #[wasm_bindgen(method, catch)]
+    pub fn goPublic(this: &Corporation, numShares: f64) -> Result<bool, JsValue>;
+
+    /// Bribe a faction
+    /// @param factionName - Faction name
+    /// @param amountCash - Amount of money to bribe
+    /// @returns True if successful, false if not
+    #[wasm_bindgen(method, catch)]
+    pub fn bribe(this: &Corporation, factionName: &str, amountCash: f64) -> Result<bool, JsValue>;
+
+    /// Get corporation data
+    /// @returns Corporation data
+    #[wasm_bindgen(method, catch)]
+    pub fn getCorporation(this: &Corporation) -> Result<CorporationInfo, JsValue>;
+
+    /// Get division data
+    /// @param divisionName - Name of the division
+    /// @returns Division data
+    #[wasm_bindgen(method, catch)]
+    pub fn getDivision(this: &Corporation, divisionName: &str) -> Result<Division, JsValue>;
+
+    /// Expand to a new industry
+    /// @param industryType - Name of the industry
+    /// @param divisionName - Name of the division
+    #[wasm_bindgen(method, catch)]
+    pub fn expandIndustry(
+        this: &Corporation,
+        industryType: CorpIndustryName,
+        divisionName: &str,
+    ) -> Result<(), JsValue>;
+
+    /// Expand to a new city
+    /// @param divisionName - Name of the division
+    /// @param city - Name of the city
+    #[wasm_bindgen(method, catch)]
+    pub fn expandCity(
+        this: &Corporation,
+        divisionName: &str,
+        city: CityName,
+    ) -> Result<(), JsValue>;
+
+    /// Unlock an upgrade
+    /// @param upgradeName - Name of the upgrade
+    #[wasm_bindgen(method, catch)]
+    pub fn purchaseUnlock(this: &Corporation, upgradeName: &str) -> Result<(), JsValue>;
+
+    /// Level an upgrade.
+    /// @param upgradeName - Name of the upgrade
+    #[wasm_bindgen(method, catch)]
+    pub fn levelUpgrade(this: &Corporation, upgradeName: &str) -> Result<(), JsValue>;
+
+    /// Issue dividends
+    /// @param rate - Fraction of profit to issue as dividends.
+    #[wasm_bindgen(method, catch)]
+    pub fn issueDividends(this: &Corporation, rate: f64) -> Result<(), JsValue>;
+
+    /// Issue new shares
+    /// @param amount - Number of new shares to issue, will be rounded to nearest 10m. Defaults to max amount.
+    /// @returns Amount of funds generated for the corporation.
+    #[wasm_bindgen(method, catch)]
+    pub fn issueNewShares(this: &Corporation, amount: Option<f64>) -> Result<f64, JsValue>;
+
+    /// Buyback Shares.
+    /// Spend money from the player's wallet to transfer shares from public traders to the CEO.
+    /// @param amount - Amount of shares to buy back, must be integer and larger than 0
+    #[wasm_bindgen(method, catch)]
+    pub fn buyBackShares(this: &Corporation, amount: f64) -> Result<(), JsValue>;
+
+    /// Sell Shares.
+    /// Transfer shares from the CEO to public traders to receive money in the player's wallet.
+    /// @param amount -  Amount of shares to sell, must be integer between 1 and 100t
+    #[wasm_bindgen(method, catch)]
+    pub fn sellShares(this: &Corporation, amount: f64) -> Result<(), JsValue>;
+
+    /// Get bonus time.
+    /// “Bonus time” is accumulated when the game is offline or if the game is inactive in the browser.
+    /// “Bonus time” makes the game progress faster.
+    /// @returns Bonus time for the Corporation mechanic in milliseconds.
+    #[wasm_bindgen(method, catch)]
+    pub fn getBonusTime(this: &Corporation) -> Result<f64, JsValue>;
+
+    /// Sleep until the next Corporation update has happened.
+    /// @remarks
+    /// RAM cost: 1 GB
+    ///
+    /// The amount of real time spent asleep between updates can vary due to "bonus time"
+    /// (usually 200 milliseconds - 2 seconds).
+    ///
+    /// @returns Promise that resolves to the name of the state that was just processed.
+    ///
+    /// I.e. when the state is PURCHASE, it means purchasing has just happened.
+    /// Note that this is the state just before `getCorporation().state`.
+    ///
+    /// Possible states are START, PURCHASE, PRODUCTION, EXPORT, SALE.
+    ///
+    /// @example
+    /// ```js
+    /// while (true) {
+    ///   const prevState = await ns.corporation.nextUpdate();
+    ///   const nextState = ns.corporation.getCorporation().state;
+    ///   ns.print(`Corporation finished with ${prevState}, next will be ${nextState}.`);
+    ///   // Manage the Corporation
+    /// }
+    /// ```
+    #[wasm_bindgen(method, catch)]
+    pub async fn nextUpdate(this: &Corporation) -> Result<CorpStateName, JsValue>;
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// Product rating information
+    #[derive(Debug, Clone, PartialEq)]
+    pub type CorpProductData;
+
+    /// Name of the product
+    #[wasm_bindgen(method, getter)]
+    pub fn name(this: &CorpProductData) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_name(this: &CorpProductData, value: &str);
+
+    /// Verb used to describe creation of the product
+    #[wasm_bindgen(method, getter)]
+    pub fn verb(this: &CorpProductData) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_verb(this: &CorpProductData, value: &str);
+
+    /// Description of product creation
+    #[wasm_bindgen(method, getter)]
+    pub fn desc(this: &CorpProductData) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_desc(this: &CorpProductData, value: &str);
+
+    /// Weighting factors for product
+    #[wasm_bindgen(method, getter)]
+    pub fn ratingWeights(this: &CorpProductData) -> JsValue;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_ratingWeights(this: &CorpProductData, value: &JsValue);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// Data for an individual industry
+    #[derive(Debug, Clone, PartialEq)]
+    pub type CorpIndustryData;
+
+    #[wasm_bindgen(method, getter)]
+    pub fn startingCost(this: &CorpIndustryData) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_startingCost(this: &CorpIndustryData, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn description(this: &CorpIndustryData) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_description(this: &CorpIndustryData, value: &str);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn product(this: &CorpIndustryData) -> Option<CorpProductData>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_product(this: &CorpIndustryData, value: Option<&CorpProductData>);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn recommendStarting(this: &CorpIndustryData) -> bool;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_recommendStarting(this: &CorpIndustryData, value: bool);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn requiredMaterials(this: &CorpIndustryData) -> JsValue;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_requiredMaterials(this: &CorpIndustryData, value: &JsValue);
+
+    /// Real estate factor
+    #[wasm_bindgen(method, getter)]
+    pub fn realEstateFactor(this: &CorpIndustryData) -> Option<f64>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_realEstateFactor(this: &CorpIndustryData, value: Option<f64>);
+
+    /// Scientific research factor (affects quality)
+    #[wasm_bindgen(method, getter)]
+    pub fn scienceFactor(this: &CorpIndustryData) -> Option<f64>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_scienceFactor(this: &CorpIndustryData, value: Option<f64>);
+
+    /// Hardware factor
+    #[wasm_bindgen(method, getter)]
+    pub fn hardwareFactor(this: &CorpIndustryData) -> Option<f64>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_hardwareFactor(this: &CorpIndustryData, value: Option<f64>);
+
+    /// Robots factor
+    #[wasm_bindgen(method, getter)]
+    pub fn robotFactor(this: &CorpIndustryData) -> Option<f64>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_robotFactor(this: &CorpIndustryData, value: Option<f64>);
+
+    /// AI Cores factor
+    #[wasm_bindgen(method, getter)]
+    pub fn aiCoreFactor(this: &CorpIndustryData) -> Option<f64>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_aiCoreFactor(this: &CorpIndustryData, value: Option<f64>);
+
+    /// Advertising factor (affects sales)
+    #[wasm_bindgen(method, getter)]
+    pub fn advertisingFactor(this: &CorpIndustryData) -> Option<f64>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_advertisingFactor(this: &CorpIndustryData, value: Option<f64>);
+
+    /// Array of Materials produced
+    #[wasm_bindgen(method, getter)]
+    pub fn producedMaterials(this: &CorpIndustryData) -> JsValue;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_producedMaterials(this: &CorpIndustryData, value: Option<Vec<String>>);
+
+    /// Whether the industry of this division is capable of producing materials
+    #[wasm_bindgen(method, getter)]
+    pub fn makesMaterials(this: &CorpIndustryData) -> bool;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_makesMaterials(this: &CorpIndustryData, value: bool);
+
+    /// Whether the industry of this division is capable of developing and producing products
+    #[wasm_bindgen(method, getter)]
+    pub fn makesProducts(this: &CorpIndustryData) -> bool;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_makesProducts(this: &CorpIndustryData, value: bool);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// General info about a corporation
+    #[derive(Debug, Clone, PartialEq)]
+    pub type CorporationInfo;
+
+    /// Name of the corporation
+    #[wasm_bindgen(method, getter)]
+    pub fn name(this: &CorporationInfo) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_name(this: &CorporationInfo, value: &str);
+
+    /// Funds available
+    #[wasm_bindgen(method, getter)]
+    pub fn funds(this: &CorporationInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_funds(this: &CorporationInfo, value: f64);
+
+    /// Revenue per second this cycle
+    #[wasm_bindgen(method, getter)]
+    pub fn revenue(this: &CorporationInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_revenue(this: &CorporationInfo, value: f64);
+
+    /// Expenses per second this cycle
+    #[wasm_bindgen(method, getter)]
+    pub fn expenses(this: &CorporationInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_expenses(this: &CorporationInfo, value: f64);
+
+    /// Indicating if the company is public
+    #[wasm_bindgen(method, getter)]
+    pub fn public(this: &CorporationInfo) -> bool;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_public(this: &CorporationInfo, value: bool);
+
+    /// Total number of shares issued by this corporation.
+    #[wasm_bindgen(method, getter)]
+    pub fn totalShares(this: &CorporationInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_totalShares(this: &CorporationInfo, value: f64);
+
+    /// Amount of shares owned by the CEO.
+    #[wasm_bindgen(method, getter)]
+    pub fn numShares(this: &CorporationInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_numShares(this: &CorporationInfo, value: f64);
+
+    /// Cooldown until shares can be sold again
+    #[wasm_bindgen(method, getter)]
+    pub fn shareSaleCooldown(this: &CorporationInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_shareSaleCooldown(this: &CorporationInfo, value: f64);
+
+    /// Amount of shares owned by private investors. Not available for public sale or CEO buyback.
+    #[wasm_bindgen(method, getter)]
+    pub fn investorShares(this: &CorporationInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_investorShares(this: &CorporationInfo, value: f64);
+
+    /// Amount of shares owned by public traders. Available for CEO buyback.
+    #[wasm_bindgen(method, getter)]
+    pub fn issuedShares(this: &CorporationInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_issuedShares(this: &CorporationInfo, value: f64);
+
+    /// Cooldown until new shares can be issued
+    #[wasm_bindgen(method, getter)]
+    pub fn issueNewSharesCooldown(this: &CorporationInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_issueNewSharesCooldown(this: &CorporationInfo, value: f64);
+
+    /// Price of the shares
+    #[wasm_bindgen(method, getter)]
+    pub fn sharePrice(this: &CorporationInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_sharePrice(this: &CorporationInfo, value: f64);
+
+    /// Fraction of profits issued as dividends
+    #[wasm_bindgen(method, getter)]
+    pub fn dividendRate(this: &CorporationInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_dividendRate(this: &CorporationInfo, value: f64);
+
+    /// Tax applied on your earnings as a shareholder
+    #[wasm_bindgen(method, getter)]
+    pub fn dividendTax(this: &CorporationInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_dividendTax(this: &CorporationInfo, value: f64);
+
+    /// Your earnings as a shareholder per second this cycle
+    #[wasm_bindgen(method, getter)]
+    pub fn dividendEarnings(this: &CorporationInfo) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_dividendEarnings(this: &CorporationInfo, value: f64);
+
+    /// The next state to be processed.
+    ///
+    /// I.e. when the state is PURCHASE, it means purchasing will occur during the next state transition.
+    ///
+    /// Possible states are START, PURCHASE, PRODUCTION, EXPORT, SALE.
+    #[wasm_bindgen(method, getter)]
+    pub fn nextState(this: &CorporationInfo) -> CorpStateName;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_nextState(this: &CorporationInfo, value: CorpStateName);
+
+    /// The last state that got processed.
+    ///
+    /// I.e. when that state is PURCHASE, it means purchasing just happened.
+    ///
+    /// Possible states are START, PURCHASE, PRODUCTION, EXPORT, SALE.
+    #[wasm_bindgen(method, getter)]
+    pub fn prevState(this: &CorporationInfo) -> CorpStateName;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_prevState(this: &CorporationInfo, value: CorpStateName);
+
+    /// Array of all division names
+    #[wasm_bindgen(method, getter)]
+    pub fn divisions(this: &CorporationInfo) -> Vec<String>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_divisions(this: &CorporationInfo, value: Vec<String>);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// Corporation related constants
+    #[derive(Debug, Clone, PartialEq)]
+    pub type CorpConstants;
+
+    /// Names of all corporation game states
+    #[wasm_bindgen(method, getter)]
+    pub fn stateNames(this: &CorpConstants) -> Vec<String>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_stateNames(this: &CorpConstants, value: Vec<String>);
+
+    /// Names of all employee positions
+    #[wasm_bindgen(method, getter)]
+    pub fn employeePositions(this: &CorpConstants) -> Vec<String>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_employeePositions(this: &CorpConstants, value: Vec<String>);
+
+    /// Names of all industries
+    #[wasm_bindgen(method, getter)]
+    pub fn industryNames(this: &CorpConstants) -> Vec<String>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_industryNames(this: &CorpConstants, value: Vec<String>);
+
+    /// Names of all materials
+    #[wasm_bindgen(method, getter)]
+    pub fn materialNames(this: &CorpConstants) -> Vec<String>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_materialNames(this: &CorpConstants, value: Vec<String>);
+
+    /// Names of all one-time corporation-wide unlocks
+    #[wasm_bindgen(method, getter)]
+    pub fn unlockNames(this: &CorpConstants) -> Vec<String>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_unlockNames(this: &CorpConstants, value: Vec<String>);
+
+    /// Names of all corporation-wide upgrades
+    #[wasm_bindgen(method, getter)]
+    pub fn upgradeNames(this: &CorpConstants) -> Vec<String>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_upgradeNames(this: &CorpConstants, value: Vec<String>);
+
+    /// Names of all researches common to all industries
+    #[wasm_bindgen(method, getter)]
+    pub fn researchNamesBase(this: &CorpConstants) -> Vec<String>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_researchNamesBase(this: &CorpConstants, value: Vec<String>);
+
+    /// Names of all researches only available to product industries
+    #[wasm_bindgen(method, getter)]
+    pub fn researchNamesProductOnly(this: &CorpConstants) -> Vec<String>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_researchNamesProductOnly(this: &CorpConstants, value: Vec<String>);
+
+    /// Names of all researches
+    #[wasm_bindgen(method, getter)]
+    pub fn researchNames(this: &CorpConstants) -> Vec<String>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_researchNames(this: &CorpConstants, value: Vec<String>);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn initialShares(this: &CorpConstants) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_initialShares(this: &CorpConstants, value: f64);
+
+    /// When selling large number of shares, price is dynamically updated for every batch of this amount
+    #[wasm_bindgen(method, getter)]
+    pub fn sharesPerPriceUpdate(this: &CorpConstants) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_sharesPerPriceUpdate(this: &CorpConstants, value: f64);
+
+    /// Cooldown for issue new shares cooldown in game cycles (1 game cycle = 200ms)
+    #[wasm_bindgen(method, getter)]
+    pub fn issueNewSharesCooldown(this: &CorpConstants) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_issueNewSharesCooldown(this: &CorpConstants, value: f64);
+
+    /// Cooldown for selling shares in game cycles (1 game cycle = 200ms)
+    #[wasm_bindgen(method, getter)]
+    pub fn sellSharesCooldown(this: &CorpConstants) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_sellSharesCooldown(this: &CorpConstants, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn teaCostPerEmployee(this: &CorpConstants) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_teaCostPerEmployee(this: &CorpConstants, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn gameCyclesPerMarketCycle(this: &CorpConstants) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_gameCyclesPerMarketCycle(this: &CorpConstants, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn gameCyclesPerCorpStateCycle(this: &CorpConstants) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_gameCyclesPerCorpStateCycle(this: &CorpConstants, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn secondsPerMarketCycle(this: &CorpConstants) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_secondsPerMarketCycle(this: &CorpConstants, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn warehouseInitialCost(this: &CorpConstants) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_warehouseInitialCost(this: &CorpConstants, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn warehouseInitialSize(this: &CorpConstants) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_warehouseInitialSize(this: &CorpConstants, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn warehouseSizeUpgradeCostBase(this: &CorpConstants) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_warehouseSizeUpgradeCostBase(this: &CorpConstants, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn officeInitialCost(this: &CorpConstants) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_officeInitialCost(this: &CorpConstants, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn officeInitialSize(this: &CorpConstants) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_officeInitialSize(this: &CorpConstants, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn officeSizeUpgradeCostBase(this: &CorpConstants) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_officeSizeUpgradeCostBase(this: &CorpConstants, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn bribeThreshold(this: &CorpConstants) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_bribeThreshold(this: &CorpConstants, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn bribeAmountPerReputation(this: &CorpConstants) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_bribeAmountPerReputation(this: &CorpConstants, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn baseProductProfitMult(this: &CorpConstants) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_baseProductProfitMult(this: &CorpConstants, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn dividendMaxRate(this: &CorpConstants) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_dividendMaxRate(this: &CorpConstants, value: f64);
+
+    /// Conversion factor for employee stats to initial salary
+    #[wasm_bindgen(method, getter)]
+    pub fn employeeSalaryMultiplier(this: &CorpConstants) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_employeeSalaryMultiplier(this: &CorpConstants, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn marketCyclesPerEmployeeRaise(this: &CorpConstants) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_marketCyclesPerEmployeeRaise(this: &CorpConstants, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn employeeRaiseAmount(this: &CorpConstants) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_employeeRaiseAmount(this: &CorpConstants, value: f64);
+
+    /// Max products for a division without upgrades
+    #[wasm_bindgen(method, getter)]
+    pub fn maxProductsBase(this: &CorpConstants) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_maxProductsBase(this: &CorpConstants, value: f64);
+
+    /// The minimum decay value for morale/energy
+    #[wasm_bindgen(method, getter)]
+    pub fn minEmployeeDecay(this: &CorpConstants) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_minEmployeeDecay(this: &CorpConstants, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn smartSupplyOptions(this: &CorpConstants) -> Vec<String>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_smartSupplyOptions(this: &CorpConstants, value: Vec<String>);
+}
+
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CorpStateName {
+    Start = "START",
+    Purchase = "PURCHASE",
+    Production = "PRODUCTION",
+    Export = "EXPORT",
+    Sale = "SALE",
+}
+
+impl CorpStateName {
+    /// All the variants in declaration order
+    pub const ALL: [CorpStateName; 5] = [
+        CorpStateName::Start,
+        CorpStateName::Purchase,
+        CorpStateName::Production,
+        CorpStateName::Export,
+        CorpStateName::Sale,
+    ];
+
+    /// The value of this variant in JavaScript
+    pub fn as_str(&self) -> &'static str {
+        self.to_str()
+    }
+}
+
+string_enum!(CorpStateName);
+
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CorpMaterialName {
+    Minerals = "Minerals",
+    Ore = "Ore",
+    Water = "Water",
+    Food = "Food",
+    Plants = "Plants",
+    Metal = "Metal",
+    Hardware = "Hardware",
+    Chemicals = "Chemicals",
+    Drugs = "Drugs",
+    Robots = "Robots",
+    AICores = "AI Cores",
+    RealEstate = "Real Estate",
+}
+
+impl CorpMaterialName {
+    /// All the variants in declaration order
+    pub const ALL: [CorpMaterialName; 12] = [
+        CorpMaterialName::Minerals,
+        CorpMaterialName::Ore,
+        CorpMaterialName::Water,
+        CorpMaterialName::Food,
+        CorpMaterialName::Plants,
+        CorpMaterialName::Metal,
+        CorpMaterialName::Hardware,
+        CorpMaterialName::Chemicals,
+        CorpMaterialName::Drugs,
+        CorpMaterialName::Robots,
+        CorpMaterialName::AICores,
+        CorpMaterialName::RealEstate,
+    ];
+
+    /// The value of this variant in JavaScript
+    pub fn as_str(&self) -> &'static str {
+        self.to_str()
+    }
+}
+
+string_enum!(CorpMaterialName);
+
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CorpUnlockName {
+    Export = "Export",
+    SmartSupply = "Smart Supply",
+    MarketResearchDemand = "Market Research - Demand",
+    MarketDataCompetition = "Market Data - Competition",
+    VeChain = "VeChain",
+    ShadyAccounting = "Shady Accounting",
+    GovernmentPartnership = "Government Partnership",
+    WarehouseAPI = "Warehouse API",
+    OfficeAPI = "Office API",
+}
+
+impl CorpUnlockName {
+    /// All the variants in declaration order
+    pub const ALL: [CorpUnlockName; 9] = [
+        CorpUnlockName::Export,
+        CorpUnlockName::SmartSupply,
+        CorpUnlockName::MarketResearchDemand,
+        CorpUnlockName::MarketDataCompetition,
+        CorpUnlockName::VeChain,
+        CorpUnlockName::ShadyAccounting,
+        CorpUnlockName::GovernmentPartnership,
+        CorpUnlockName::WarehouseAPI,
+        CorpUnlockName::OfficeAPI,
+    ];
+
+    /// The value of this variant in JavaScript
+    pub fn as_str(&self) -> &'static str {
+        self.to_str()
+    }
+}
+
+string_enum!(CorpUnlockName);
+
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CorpUpgradeName {
+    SmartFactories = "Smart Factories",
+    SmartStorage = "Smart Storage",
+    DreamSense = "DreamSense",
+    WilsonAnalytics = "Wilson Analytics",
+    NuoptimalNootropicInjectorImplants = "Nuoptimal Nootropic Injector Implants",
+    SpeechProcessorImplants = "Speech Processor Implants",
+    NeuralAccelerators = "Neural Accelerators",
+    FocusWires = "FocusWires",
+    ABCSalesBots = "ABC SalesBots",
+    ProjectInsight = "Project Insight",
+}
+
+impl CorpUpgradeName {
+    /// All the variants in declaration order
+    pub const ALL: [CorpUpgradeName; 10] = [
+        CorpUpgradeName::SmartFactories,
+        CorpUpgradeName::SmartStorage,
+        CorpUpgradeName::DreamSense,
+        CorpUpgradeName::WilsonAnalytics,
+        CorpUpgradeName::NuoptimalNootropicInjectorImplants,
+        CorpUpgradeName::SpeechProcessorImplants,
+        CorpUpgradeName::NeuralAccelerators,
+        CorpUpgradeName::FocusWires,
+        CorpUpgradeName::ABCSalesBots,
+        CorpUpgradeName::ProjectInsight,
+    ];
+
+    /// The value of this variant in JavaScript
+    pub fn as_str(&self) -> &'static str {
+        self.to_str()
+    }
+}
+
+string_enum!(CorpUpgradeName);
+
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CorpResearchName {
+    HiTechRDLaboratory = "Hi-Tech R&D Laboratory",
+    AutoBrew = "AutoBrew",
+    AutoPartyManager = "AutoPartyManager",
+    AutomaticDrugAdministration = "Automatic Drug Administration",
+    CPH4Injections = "CPH4 Injections",
+    Drones = "Drones",
+    DronesAssembly = "Drones - Assembly",
+    DronesTransport = "Drones - Transport",
+    GoJuice = "Go-Juice",
+    HRBuddyRecruitment = "HRBuddy-Recruitment",
+    HRBuddyTraining = "HRBuddy-Training",
+    MarketTAI = "Market-TA.I",
+    MarketTAII = "Market-TA.II",
+    Overclock = "Overclock",
+    SelfCorrectingAssemblers = "Self-Correcting Assemblers",
+    StiMu = "Sti.mu",
+    UPgradeCapacityI = "uPgrade: Capacity.I",
+    UPgradeCapacityII = "uPgrade: Capacity.II",
+    UPgradeDashboard = "uPgrade: Dashboard",
+    UPgradeFulcrum = "uPgrade: Fulcrum",
+    SudoAssist = "sudo.Assist",
+}
+
+impl CorpResearchName {
+    /// All the variants in declaration order
+    pub const ALL: [CorpResearchName; 21] = [
+        CorpResearchName::HiTechRDLaboratory,
+        CorpResearchName::AutoBrew,
+        CorpResearchName::AutoPartyManager,
+        CorpResearchName::AutomaticDrugAdministration,
+        CorpResearchName::CPH4Injections,
+        CorpResearchName::Drones,
+        CorpResearchName::DronesAssembly,
+        CorpResearchName::DronesTransport,
+        CorpResearchName::GoJuice,
+        CorpResearchName::HRBuddyRecruitment,
+        CorpResearchName::HRBuddyTraining,
+        CorpResearchName::MarketTAI,
+        CorpResearchName::MarketTAII,
+        CorpResearchName::Overclock,
+        CorpResearchName::SelfCorrectingAssemblers,
+        CorpResearchName::StiMu,
+        CorpResearchName::UPgradeCapacityI,
+        CorpResearchName::UPgradeCapacityII,
+        CorpResearchName::UPgradeDashboard,
+        CorpResearchName::UPgradeFulcrum,
+        CorpResearchName::SudoAssist,
+    ];
+
+    /// The value of this variant in JavaScript
+    pub fn as_str(&self) -> &'static str {
+        self.to_str()
+    }
+}
+
+string_enum!(CorpResearchName);
+
+#[wasm_bindgen]
+extern "C" {
+    /// Corporation material information
+    #[derive(Debug, Clone, PartialEq)]
+    pub type CorpMaterialConstantData;
+
+    /// Name of the material
+    #[wasm_bindgen(method, getter)]
+    pub fn name(this: &CorpMaterialConstantData) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_name(this: &CorpMaterialConstantData, value: &str);
+
+    /// Size of the material
+    #[wasm_bindgen(method, getter)]
+    pub fn size(this: &CorpMaterialConstantData) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_size(this: &CorpMaterialConstantData, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn demandBase(this: &CorpMaterialConstantData) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_demandBase(this: &CorpMaterialConstantData, value: f64);
+
+    /// Min and max demand
+    #[wasm_bindgen(method, getter)]
+    pub fn demandRange(this: &CorpMaterialConstantData) -> Vec<f64>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_demandRange(this: &CorpMaterialConstantData, value: &[f64]);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn competitionBase(this: &CorpMaterialConstantData) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_competitionBase(this: &CorpMaterialConstantData, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn competitionRange(this: &CorpMaterialConstantData) -> Vec<f64>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_competitionRange(this: &CorpMaterialConstantData, value: &[f64]);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn baseCost(this: &CorpMaterialConstantData) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_baseCost(this: &CorpMaterialConstantData, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn maxVolatility(this: &CorpMaterialConstantData) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_maxVolatility(this: &CorpMaterialConstantData, value: f64);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn baseMarkup(this: &CorpMaterialConstantData) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_baseMarkup(this: &CorpMaterialConstantData, value: f64);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// Corporation industry information
+    #[derive(Debug, Clone, PartialEq)]
+    pub type IndustryData;
+
+    /// Industry type
+    #[wasm_bindgen(method, getter = "type")]
+    pub fn r#type(this: &IndustryData) -> CorpIndustryName;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_type(this: &IndustryData, value: CorpIndustryName);
+
+    /// Cost to make a new division of this industry type
+    #[wasm_bindgen(method, getter)]
+    pub fn cost(this: &IndustryData) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_cost(this: &IndustryData, value: f64);
+
+    /// Materials required for production and their amounts
+    #[wasm_bindgen(method, getter)]
+    pub fn requiredMaterials(this: &IndustryData) -> JsValue;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_requiredMaterials(this: &IndustryData, value: &JsValue);
+
+    /// Materials produced
+    #[wasm_bindgen(method, getter)]
+    pub fn producedMaterials(this: &IndustryData) -> JsValue;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_producedMaterials(this: &IndustryData, value: Option<Vec<String>>);
+
+    /// Whether the division makes materials
+    #[wasm_bindgen(method, getter)]
+    pub fn makesMaterials(this: &IndustryData) -> bool;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_makesMaterials(this: &IndustryData, value: bool);
+
+    /// Whether the division makes products
+    #[wasm_bindgen(method, getter)]
+    pub fn makesProducts(this: &IndustryData) -> bool;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_makesProducts(this: &IndustryData, value: bool);
+
+    /// Product type
+    #[wasm_bindgen(method, getter)]
+    pub fn productType(this: &IndustryData) -> Option<String>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_productType(this: &IndustryData, value: Option<&str>);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// Product in a warehouse
+    #[derive(Debug, Clone, PartialEq)]
+    pub type Product;
+
+    /// Name of the product
+    #[wasm_bindgen(method, getter)]
+    pub fn name(this: &Product) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_name(this: &Product, value: &str);
+
+    /// Demand for the product, only present if "Market Research - Demand" unlocked
+    #[wasm_bindgen(method, getter)]
+    pub fn demand(this: &Product) -> Option<f64>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_demand(this: &Product, value: Option<f64>);
+
+    /// Competition for the product, only present if "Market Research - Competition" unlocked
+    #[wasm_bindgen(method, getter)]
+    pub fn competition(this: &Product) -> Option<f64>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_competition(this: &Product, value: Option<f64>);
+
+    /// Rating based on stats
+    #[wasm_bindgen(method, getter)]
+    pub fn rating(this: &Product) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_rating(this: &Product, value: f64);
+
+    /// Effective rating in the specific city
+    #[wasm_bindgen(method, getter)]
+    pub fn effectiveRating(this: &Product) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_effectiveRating(this: &Product, value: f64);
+
+    /// Product stats
+    #[wasm_bindgen(method, getter)]
+    pub fn stats(this: &Product) -> JsValue;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_stats(this: &Product, value: &JsValue);
+
+    /// Production cost
+    #[wasm_bindgen(method, getter)]
+    pub fn productionCost(this: &Product) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_productionCost(this: &Product, value: f64);
+
+    /// Desired sell price, can be "MP+5"
+    #[wasm_bindgen(method, getter)]
+    pub fn desiredSellPrice(this: &Product) -> JsValue;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_desiredSellPrice(this: &Product, value: &JsValue);
+
+    /// Desired sell amount, e.g. "PROD/2"
+    #[wasm_bindgen(method, getter)]
+    pub fn desiredSellAmount(this: &Product) -> JsValue;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_desiredSellAmount(this: &Product, value: &JsValue);
+
+    /// Amount of product stored in warehouse
+    #[wasm_bindgen(method, getter)]
+    pub fn stored(this: &Product) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_stored(this: &Product, value: f64);
+
+    /// Amount of product produced last cycle
+    #[wasm_bindgen(method, getter)]
+    pub fn productionAmount(this: &Product) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_productionAmount(this: &Product, value: f64);
+
+    /// Amount of product sold last cycle
+    #[wasm_bindgen(method, getter)]
+    pub fn actualSellAmount(this: &Product) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_actualSellAmount(this: &Product, value: f64);
+
+    /// A number between 0-100 representing percentage completion
+    #[wasm_bindgen(method, getter)]
+    pub fn developmentProgress(this: &Product) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_developmentProgress(this: &Product, value: f64);
+
+    /// Funds that were spent on advertising the product
+    #[wasm_bindgen(method, getter)]
+    pub fn advertisingInvestment(this: &Product) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_advertisingInvestment(this: &Product, value: f64);
+
+    /// Funds that were spent on designing the product
+    #[wasm_bindgen(method, getter)]
+    pub fn designInvestment(this: &Product) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_designInvestment(this: &Product, value: f64);
+
+    /// How much warehouse space is occupied per unit of this product
+    #[wasm_bindgen(method, getter)]
+    pub fn size(this: &Product) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_size(this: &Product, value: f64);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// Material in a warehouse
+    #[derive(Debug, Clone, PartialEq)]
+    pub type Material;
+
+    /// Name of the material
+    #[wasm_bindgen(method, getter)]
+    pub fn name(this: &Material) -> CorpMaterialName;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_name(this: &Material, value: CorpMaterialName);
+
+    /// Amount of material
+    #[wasm_bindgen(method, getter)]
+    pub fn stored(this: &Material) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_stored(this: &Material, value: f64);
+
+    /// Quality of the material
+    #[wasm_bindgen(method, getter)]
+    pub fn quality(this: &Material) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_quality(this: &Material, value: f64);
+
+    /// Demand for the material, only present if "Market Research - Demand" unlocked
+    #[wasm_bindgen(method, getter)]
+    pub fn demand(this: &Material) -> Option<f64>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_demand(this: &Material, value: Option<f64>);
+
+    /// Competition for the material, only present if "Market Research - Competition" unlocked
+    #[wasm_bindgen(method, getter)]
+    pub fn competition(this: &Material) -> Option<f64>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_competition(this: &Material, value: Option<f64>);
+
+    /// Amount of material produced last cycle
+    #[wasm_bindgen(method, getter)]
+    pub fn productionAmount(this: &Material) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_productionAmount(this: &Material, value: f64);
+
+    /// Amount of material sold last cycle
+    #[wasm_bindgen(method, getter)]
+    pub fn actualSellAmount(this: &Material) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_actualSellAmount(this: &Material, value: f64);
+
+    /// Cost to buy material
+    #[wasm_bindgen(method, getter)]
+    pub fn marketPrice(this: &Material) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_marketPrice(this: &Material, value: f64);
+
+    /// Sell cost, can be "MP+5"
+    #[wasm_bindgen(method, getter)]
+    pub fn desiredSellPrice(this: &Material) -> JsValue;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_desiredSellPrice(this: &Material, value: &JsValue);
+
+    /// Sell amount, can be "PROD/2"
+    #[wasm_bindgen(method, getter)]
+    pub fn desiredSellAmount(this: &Material) -> JsValue;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_desiredSellAmount(this: &Material, value: &JsValue);
+
+    /// Export orders
+    #[wasm_bindgen(method, getter)]
+    pub fn exports(this: &Material) -> Vec<Export>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_exports(this: &Material, value: Vec<Export>);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// Export order for a material
+    #[derive(Debug, Clone, PartialEq)]
+    pub type Export;
+
+    /// Division the material is being exported to
+    #[wasm_bindgen(method, getter)]
+    pub fn division(this: &Export) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_division(this: &Export, value: &str);
+
+    /// City the material is being exported to
+    #[wasm_bindgen(method, getter)]
+    pub fn city(this: &Export) -> CityName;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_city(this: &Export, value: CityName);
+
+    /// Amount of material exported
+    #[wasm_bindgen(method, getter)]
+    pub fn amount(this: &Export) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_amount(this: &Export, value: &str);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// Warehouse for a division in a city
+    #[derive(Debug, Clone, PartialEq)]
+    pub type Warehouse;
+
+    /// Amount of size upgrade bought
+    #[wasm_bindgen(method, getter)]
+    pub fn level(this: &Warehouse) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_level(this: &Warehouse, value: f64);
+
+    /// City in which the warehouse is located
+    #[wasm_bindgen(method, getter)]
+    pub fn city(this: &Warehouse) -> CityName;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_city(this: &Warehouse, value: CityName);
+
+    /// Total space in the warehouse
+    #[wasm_bindgen(method, getter)]
+    pub fn size(this: &Warehouse) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_size(this: &Warehouse, value: f64);
+
+    /// Used space in the warehouse
+    #[wasm_bindgen(method, getter)]
+    pub fn sizeUsed(this: &Warehouse) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_sizeUsed(this: &Warehouse, value: f64);
+
+    /// Smart Supply status in the warehouse
+    #[wasm_bindgen(method, getter)]
+    pub fn smartSupplyEnabled(this: &Warehouse) -> bool;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_smartSupplyEnabled(this: &Warehouse, value: bool);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// Office for a division in a city.
+    #[derive(Debug, Clone, PartialEq)]
+    pub type Office;
+
+    /// City of the office
+    #[wasm_bindgen(method, getter)]
+    pub fn city(this: &Office) -> CityName;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_city(this: &Office, value: CityName);
+
+    /// Maximum number of employee
+    #[wasm_bindgen(method, getter)]
+    pub fn size(this: &Office) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_size(this: &Office, value: f64);
+
+    /// Maximum amount of energy of the employees
+    #[wasm_bindgen(method, getter)]
+    pub fn maxEnergy(this: &Office) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_maxEnergy(this: &Office, value: f64);
+
+    /// Maximum morale of the employees
+    #[wasm_bindgen(method, getter)]
+    pub fn maxMorale(this: &Office) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_maxMorale(this: &Office, value: f64);
+
+    /// Amount of employees
+    #[wasm_bindgen(method, getter)]
+    pub fn numEmployees(this: &Office) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_numEmployees(this: &Office, value: f64);
+
+    /// Average energy of the employees
+    #[wasm_bindgen(method, getter)]
+    pub fn avgEnergy(this: &Office) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_avgEnergy(this: &Office, value: f64);
+
+    /// Average morale of the employees
+    #[wasm_bindgen(method, getter)]
+    pub fn avgMorale(this: &Office) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_avgMorale(this: &Office, value: f64);
+
+    /// Total experience of all employees
+    #[wasm_bindgen(method, getter)]
+    pub fn totalExperience(this: &Office) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_totalExperience(this: &Office, value: f64);
+
+    /// Production of the employees
+    #[wasm_bindgen(method, getter)]
+    pub fn employeeProductionByJob(this: &Office) -> JsValue;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_employeeProductionByJob(this: &Office, value: &JsValue);
+
+    /// Positions of the employees
+    #[wasm_bindgen(method, getter)]
+    pub fn employeeJobs(this: &Office) -> JsValue;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_employeeJobs(this: &Office, value: &JsValue);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// Corporation division
+    #[derive(Debug, Clone, PartialEq)]
+    pub type Division;
+
+    /// Name of the division
+    #[wasm_bindgen(method, getter)]
+    pub fn name(this: &Division) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_name(this: &Division, value: &str);
+
+    /// Type of division, like Agriculture
+    #[wasm_bindgen(method, getter = "type")]
+    pub fn r#type(this: &Division) -> CorpIndustryName;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_type(this: &Division, value: CorpIndustryName);
+
+    /// Awareness of the division
+    #[wasm_bindgen(method, getter)]
+    pub fn awareness(this: &Division) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_awareness(this: &Division, value: f64);
+
+    /// Popularity of the division
+    #[wasm_bindgen(method, getter)]
+    pub fn popularity(this: &Division) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_popularity(this: &Division, value: f64);
+
+    /// Production multiplier
+    #[wasm_bindgen(method, getter)]
+    pub fn productionMult(this: &Division) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_productionMult(this: &Division, value: f64);
+
+    /// Amount of research in that division
+    #[wasm_bindgen(method, getter)]
+    pub fn researchPoints(this: &Division) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_researchPoints(this: &Division, value: f64);
+
+    /// Revenue last cycle
+    #[wasm_bindgen(method, getter)]
+    pub fn lastCycleRevenue(this: &Division) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_lastCycleRevenue(this: &Division, value: f64);
+
+    /// Expenses last cycle
+    #[wasm_bindgen(method, getter)]
+    pub fn lastCycleExpenses(this: &Division) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_lastCycleExpenses(this: &Division, value: f64);
+
+    /// Revenue this cycle
+    #[wasm_bindgen(method, getter)]
+    pub fn thisCycleRevenue(this: &Division) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_thisCycleRevenue(this: &Division, value: f64);
+
+    /// Expenses this cycle
+    #[wasm_bindgen(method, getter)]
+    pub fn thisCycleExpenses(this: &Division) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_thisCycleExpenses(this: &Division, value: f64);
+
+    /// Number of times AdVert has been bought
+    #[wasm_bindgen(method, getter)]
+    pub fn numAdVerts(this: &Division) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_numAdVerts(this: &Division, value: f64);
+
+    /// Cities in which this division has expanded
+    #[wasm_bindgen(method, getter)]
+    pub fn cities(this: &Division) -> Vec<String>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_cities(this: &Division, value: Vec<String>);
+
+    /// Names of Products developed by this division
+    #[wasm_bindgen(method, getter)]
+    pub fn products(this: &Division) -> Vec<String>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_products(this: &Division, value: Vec<String>);
+
+    /// Whether the industry of this division is capable of developing and producing products
+    #[wasm_bindgen(method, getter)]
+    pub fn makesProducts(this: &Division) -> bool;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_makesProducts(this: &Division, value: bool);
+
+    /// How many products this division can support
+    #[wasm_bindgen(method, getter)]
+    pub fn maxProducts(this: &Division) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_maxProducts(this: &Division, value: f64);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// Corporation investment offer
+    #[derive(Debug, Clone, PartialEq)]
+    pub type InvestmentOffer;
+
+    /// Amount of funds you will get from this investment
+    #[wasm_bindgen(method, getter)]
+    pub fn funds(this: &InvestmentOffer) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_funds(this: &InvestmentOffer, value: f64);
+
+    /// Amount of share you will give in exchange for this investment
+    #[wasm_bindgen(method, getter)]
+    pub fn shares(this: &InvestmentOffer) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_shares(this: &InvestmentOffer, value: f64);
+
+    /// Current round of funding (max 4)
+    #[wasm_bindgen(method, getter)]
+    pub fn round(this: &InvestmentOffer) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_round(this: &InvestmentOffer, value: f64);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// Interface Theme
+    #[derive(Debug, Clone, PartialEq)]
+    pub type UserInterfaceTheme;
+
+    #[wasm_bindgen(method, getter)]
+    pub fn primarylight(this: &UserInterfaceTheme) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_primarylight(this: &UserInterfaceTheme, value: &str);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn primary(this: &UserInterfaceTheme) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_primary(this: &UserInterfaceTheme, value: &str);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn primarydark(this: &UserInterfaceTheme) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_primarydark(this: &UserInterfaceTheme, value: &str);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn successlight(this: &UserInterfaceTheme) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_successlight(this: &UserInterfaceTheme, value: &str);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn success(this: &UserInterfaceTheme) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_success(this: &UserInterfaceTheme, value: &str);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn successdark(this: &UserInterfaceTheme) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_successdark(this: &UserInterfaceTheme, value: &str);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn errorlight(this: &UserInterfaceTheme) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_errorlight(this: &UserInterfaceTheme, value: &str);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn error(this: &UserInterfaceTheme) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_error(this: &UserInterfaceTheme, value: &str);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn errordark(this: &UserInterfaceTheme) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_errordark(this: &UserInterfaceTheme, value: &str);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn secondarylight(this: &UserInterfaceTheme) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_secondarylight(this: &UserInterfaceTheme, value: &str);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn secondary(this: &UserInterfaceTheme) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_secondary(this: &UserInterfaceTheme, value: &str);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn secondarydark(this: &UserInterfaceTheme) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_secondarydark(this: &UserInterfaceTheme, value: &str);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn warninglight(this: &UserInterfaceTheme) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_warninglight(this: &UserInterfaceTheme, value: &str);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn warning(this: &UserInterfaceTheme) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_warning(this: &UserInterfaceTheme, value: &str);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn warningdark(this: &UserInterfaceTheme) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_warningdark(this: &UserInterfaceTheme, value: &str);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn infolight(this: &UserInterfaceTheme) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_infolight(this: &UserInterfaceTheme, value: &str);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn info(this: &UserInterfaceTheme) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_info(this: &UserInterfaceTheme, value: &str);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn infodark(this: &UserInterfaceTheme) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_infodark(this: &UserInterfaceTheme, value: &str);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn welllight(this: &UserInterfaceTheme) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_welllight(this: &UserInterfaceTheme, value: &str);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn well(this: &UserInterfaceTheme) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_well(this: &UserInterfaceTheme, value: &str);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn white(this: &UserInterfaceTheme) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_white(this: &UserInterfaceTheme, value: &str);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn black(this: &UserInterfaceTheme) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_black(this: &UserInterfaceTheme, value: &str);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn hp(this: &UserInterfaceTheme) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_hp(this: &UserInterfaceTheme, value: &str);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn money(this: &UserInterfaceTheme) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_money(this: &UserInterfaceTheme, value: &str);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn hack(this: &UserInterfaceTheme) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_hack(this: &UserInterfaceTheme, value: &str);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn combat(this: &UserInterfaceTheme) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_combat(this: &UserInterfaceTheme, value: &str);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn cha(this: &UserInterfaceTheme) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_cha(this: &UserInterfaceTheme, value: &str);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn int(this: &UserInterfaceTheme) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_int(this: &UserInterfaceTheme, value: &str);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn rep(this: &UserInterfaceTheme) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_rep(this: &UserInterfaceTheme, value: &str);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn disabled(this: &UserInterfaceTheme) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_disabled(this: &UserInterfaceTheme, value: &str);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn backgroundprimary(this: &UserInterfaceTheme) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_backgroundprimary(this: &UserInterfaceTheme, value: &str);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn backgroundsecondary(this: &UserInterfaceTheme) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_backgroundsecondary(this: &UserInterfaceTheme, value: &str);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn button(this: &UserInterfaceTheme) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_button(this: &UserInterfaceTheme, value: &str);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// Interface Styles
+    #[derive(Debug, Clone, PartialEq)]
+    pub type IStyleSettings;
+
+    #[wasm_bindgen(method, getter)]
+    pub fn fontFamily(this: &IStyleSettings) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_fontFamily(this: &IStyleSettings, value: &str);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn lineHeight(this: &IStyleSettings) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_lineHeight(this: &IStyleSettings, value: f64);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// Game Information
+    #[derive(Debug, Clone, PartialEq)]
+    pub type GameInfo;
+
+    #[wasm_bindgen(method, getter)]
+    pub fn version(this: &GameInfo) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_version(this: &GameInfo, value: &str);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn commit(this: &GameInfo) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_commit(this: &GameInfo, value: &str);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn platform(this: &GameInfo) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_platform(this: &GameInfo, value: &str);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// Used for autocompletion
+    #[derive(Debug, Clone, PartialEq)]
+    pub type AutocompleteData;
+
+    #[wasm_bindgen(method, getter)]
+    pub fn servers(this: &AutocompleteData) -> Vec<String>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_servers(this: &AutocompleteData, value: Vec<String>);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn scripts(this: &AutocompleteData) -> Vec<String>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_scripts(this: &AutocompleteData, value: Vec<String>);
+
+    #[wasm_bindgen(method, getter)]
+    pub fn txts(this: &AutocompleteData) -> Vec<String>;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_txts(this: &AutocompleteData, value: Vec<String>);
+
+    #[wasm_bindgen(method, catch)]
+    pub fn flags(this: &AutocompleteData, schema: Vec<JsValue>) -> Result<JsValue, JsValue>;
+}
@@ -0,0 +1,798 @@
+//! Typed access to the Bladeburner API at `ns.bladeburner()`, and an
+//! [`ActionSelector`] picking what to do next.
+//!
+//! The definitions type actions and skills as strings, this module has enums
+//! for them instead. Black operations keep their names as strings since
+//! there is one per rank step and they only run once.
+//!
+//! The selector works on a [`BladeburnerState`] snapshot so it can be tested
+//! natively:
+//!
+//! ```
+//! use bitburner_api::bladeburner::{
+//!     ActionCandidate, ActionSelector, BladeburnerAction, BladeburnerState, ContractName,
+//!     GeneralAction, Stamina, SuccessChance,
+//! };
+//!
+//! let tracking = ActionCandidate {
+//!     action: BladeburnerAction::Contract(ContractName::Tracking),
+//!     success: SuccessChance { min: 0.9, max: 0.9 },
+//!     time: 5000.0,
+//!     rep_gain: 4.0,
+//!     count_remaining: 12.0,
+//! };
+//! let mut state = BladeburnerState {
+//!     stamina: Stamina { current: 50.0, max: 100.0 },
+//!     actions: vec![tracking.clone()],
+//!     ..BladeburnerState::default()
+//! };
+//!
+//! let selector = ActionSelector::default();
+//! assert_eq!(selector.select(&state), tracking.action);
+//!
+//! // too tired to take contracts
+//! state.stamina.current = 20.0;
+//! assert_eq!(
+//!     selector.select(&state),
+//!     BladeburnerAction::General(GeneralAction::HyperbolicRegenerationChamber)
+//! );
+//! ```
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsValue;
+
+use crate::{Bladeburner, CityName};
+
+/// A kind of Bladeburner action.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BladeburnerActionType {
+    General,
+    Contract,
+    Operation,
+    BlackOp,
+}
+
+impl BladeburnerActionType {
+    /// All the variants in declaration order
+    pub const ALL: [BladeburnerActionType; 4] = [
+        BladeburnerActionType::General,
+        BladeburnerActionType::Contract,
+        BladeburnerActionType::Operation,
+        BladeburnerActionType::BlackOp,
+    ];
+
+    /// The value of this variant in JavaScript
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BladeburnerActionType::General => "General",
+            BladeburnerActionType::Contract => "Contracts",
+            BladeburnerActionType::Operation => "Operations",
+            BladeburnerActionType::BlackOp => "Black Operations",
+        }
+    }
+}
+
+string_enum!(BladeburnerActionType);
+
+/// A general action, always available.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GeneralAction {
+    Training,
+    FieldAnalysis,
+    Recruitment,
+    Diplomacy,
+    HyperbolicRegenerationChamber,
+    InciteViolence,
+}
+
+impl GeneralAction {
+    /// All the variants in declaration order
+    pub const ALL: [GeneralAction; 6] = [
+        GeneralAction::Training,
+        GeneralAction::FieldAnalysis,
+        GeneralAction::Recruitment,
+        GeneralAction::Diplomacy,
+        GeneralAction::HyperbolicRegenerationChamber,
+        GeneralAction::InciteViolence,
+    ];
+
+    /// The value of this variant in JavaScript
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GeneralAction::Training => "Training",
+            GeneralAction::FieldAnalysis => "Field Analysis",
+            GeneralAction::Recruitment => "Recruitment",
+            GeneralAction::Diplomacy => "Diplomacy",
+            GeneralAction::HyperbolicRegenerationChamber => "Hyperbolic Regeneration Chamber",
+            GeneralAction::InciteViolence => "Incite Violence",
+        }
+    }
+}
+
+string_enum!(GeneralAction);
+
+/// A contract, available a limited number of times.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ContractName {
+    Tracking,
+    BountyHunter,
+    Retirement,
+}
+
+impl ContractName {
+    /// All the variants in declaration order
+    pub const ALL: [ContractName; 3] = [
+        ContractName::Tracking,
+        ContractName::BountyHunter,
+        ContractName::Retirement,
+    ];
+
+    /// The value of this variant in JavaScript
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ContractName::Tracking => "Tracking",
+            ContractName::BountyHunter => "Bounty Hunter",
+            ContractName::Retirement => "Retirement",
+        }
+    }
+}
+
+string_enum!(ContractName);
+
+/// An operation, available a limited number of times.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OperationName {
+    Investigation,
+    UndercoverOperation,
+    StingOperation,
+    Raid,
+    StealthRetirementOperation,
+    Assassination,
+}
+
+impl OperationName {
+    /// All the variants in declaration order
+    pub const ALL: [OperationName; 6] = [
+        OperationName::Investigation,
+        OperationName::UndercoverOperation,
+        OperationName::StingOperation,
+        OperationName::Raid,
+        OperationName::StealthRetirementOperation,
+        OperationName::Assassination,
+    ];
+
+    /// The value of this variant in JavaScript
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OperationName::Investigation => "Investigation",
+            OperationName::UndercoverOperation => "Undercover Operation",
+            OperationName::StingOperation => "Sting Operation",
+            OperationName::Raid => "Raid",
+            OperationName::StealthRetirementOperation => "Stealth Retirement Operation",
+            OperationName::Assassination => "Assassination",
+        }
+    }
+}
+
+string_enum!(OperationName);
+
+/// A Bladeburner skill.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BladeburnerSkill {
+    BladesIntuition,
+    Cloak,
+    ShortCircuit,
+    DigitalObserver,
+    Tracer,
+    Overclock,
+    Reaper,
+    EvasiveSystem,
+    Datamancer,
+    CybersEdge,
+    HandsOfMidas,
+    Hyperdrive,
+}
+
+impl BladeburnerSkill {
+    /// All the variants in declaration order
+    pub const ALL: [BladeburnerSkill; 12] = [
+        BladeburnerSkill::BladesIntuition,
+        BladeburnerSkill::Cloak,
+        BladeburnerSkill::ShortCircuit,
+        BladeburnerSkill::DigitalObserver,
+        BladeburnerSkill::Tracer,
+        BladeburnerSkill::Overclock,
+        BladeburnerSkill::Reaper,
+        BladeburnerSkill::EvasiveSystem,
+        BladeburnerSkill::Datamancer,
+        BladeburnerSkill::CybersEdge,
+        BladeburnerSkill::HandsOfMidas,
+        BladeburnerSkill::Hyperdrive,
+    ];
+
+    /// The value of this variant in JavaScript
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BladeburnerSkill::BladesIntuition => "Blade's Intuition",
+            BladeburnerSkill::Cloak => "Cloak",
+            BladeburnerSkill::ShortCircuit => "Short-Circuit",
+            BladeburnerSkill::DigitalObserver => "Digital Observer",
+            BladeburnerSkill::Tracer => "Tracer",
+            BladeburnerSkill::Overclock => "Overclock",
+            BladeburnerSkill::Reaper => "Reaper",
+            BladeburnerSkill::EvasiveSystem => "Evasive System",
+            BladeburnerSkill::Datamancer => "Datamancer",
+            BladeburnerSkill::CybersEdge => "Cyber's Edge",
+            BladeburnerSkill::HandsOfMidas => "Hands of Midas",
+            BladeburnerSkill::Hyperdrive => "Hyperdrive",
+        }
+    }
+}
+
+string_enum!(BladeburnerSkill);
+
+/// An action to start.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum BladeburnerAction {
+    General(GeneralAction),
+    Contract(ContractName),
+    Operation(OperationName),
+    /// A black operation, by name
+    BlackOp(String),
+}
+
+impl BladeburnerAction {
+    pub fn action_type(&self) -> BladeburnerActionType {
+        match self {
+            BladeburnerAction::General(_) => BladeburnerActionType::General,
+            BladeburnerAction::Contract(_) => BladeburnerActionType::Contract,
+            BladeburnerAction::Operation(_) => BladeburnerActionType::Operation,
+            BladeburnerAction::BlackOp(_) => BladeburnerActionType::BlackOp,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            BladeburnerAction::General(action) => action.as_str(),
+            BladeburnerAction::Contract(contract) => contract.as_str(),
+            BladeburnerAction::Operation(operation) => operation.as_str(),
+            BladeburnerAction::BlackOp(name) => name,
+        }
+    }
+
+    /// Reads the type and name of `getCurrentAction`, `None` when idle or
+    /// when the action is unknown.
+    ///
+    /// Versions of the game disagree on the type strings, so any spelling of
+    /// the types is accepted, and general actions may have their own name as
+    /// type.
+    pub fn from_type_and_name(action_type: &str, name: &str) -> Option<Self> {
+        let normalized: String = action_type
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .flat_map(char::to_lowercase)
+            .collect();
+        match normalized.trim_end_matches('s') {
+            "idle" => None,
+            "contract" => name.parse().ok().map(BladeburnerAction::Contract),
+            "operation" | "op" => name.parse().ok().map(BladeburnerAction::Operation),
+            "blackop" | "blackoperation" => Some(BladeburnerAction::BlackOp(name.to_owned())),
+            _ => name.parse().ok().map(BladeburnerAction::General),
+        }
+    }
+}
+
+impl fmt::Display for BladeburnerAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.action_type(), self.name())
+    }
+}
+
+impl From<GeneralAction> for BladeburnerAction {
+    fn from(action: GeneralAction) -> Self {
+        BladeburnerAction::General(action)
+    }
+}
+
+impl From<ContractName> for BladeburnerAction {
+    fn from(contract: ContractName) -> Self {
+        BladeburnerAction::Contract(contract)
+    }
+}
+
+impl From<OperationName> for BladeburnerAction {
+    fn from(operation: OperationName) -> Self {
+        BladeburnerAction::Operation(operation)
+    }
+}
+
+/// Estimated chance of success of an action, the range narrowing with
+/// field analysis.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SuccessChance {
+    pub min: f64,
+    pub max: f64,
+}
+
+impl SuccessChance {
+    pub fn average(&self) -> f64 {
+        (self.min + self.max) / 2.0
+    }
+
+    /// Width of the estimate.
+    pub fn uncertainty(&self) -> f64 {
+        self.max - self.min
+    }
+}
+
+/// Current and maximum stamina.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Stamina {
+    pub current: f64,
+    pub max: f64,
+}
+
+impl Stamina {
+    /// Share of the maximum stamina left.
+    pub fn ratio(&self) -> f64 {
+        if self.max > 0.0 {
+            self.current / self.max
+        } else {
+            0.0
+        }
+    }
+}
+
+/// The next black operation, as returned by `getNextBlackOp`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NextBlackOp {
+    pub name: String,
+    /// Rank required to start it
+    pub rank: f64,
+}
+
+fn pair(values: Vec<f64>, what: &str) -> Result<(f64, f64), JsValue> {
+    match values[..] {
+        [first, second] => Ok((first, second)),
+        _ => Err(JsValue::from_str(&format!(
+            "expected 2 numbers in {what}, got {values:?}"
+        ))),
+    }
+}
+
+impl Bladeburner {
+    /// Starts `action`, returns whether it started.
+    pub fn start_action(&self, action: &BladeburnerAction) -> Result<bool, JsValue> {
+        self.startAction(action.action_type().as_str(), action.name())
+    }
+
+    /// The action being performed, `None` when idle.
+    pub fn get_current_action(&self) -> Result<Option<BladeburnerAction>, JsValue> {
+        let current = self.getCurrentAction()?;
+        Ok(BladeburnerAction::from_type_and_name(
+            &current.r#type(),
+            &current.name(),
+        ))
+    }
+
+    /// Milliseconds `action` takes.
+    pub fn get_action_time(&self, action: &BladeburnerAction) -> Result<f64, JsValue> {
+        self.getActionTime(action.action_type().as_str(), action.name())
+    }
+
+    pub fn get_success_chance(&self, action: &BladeburnerAction) -> Result<SuccessChance, JsValue> {
+        let chance =
+            self.getActionEstimatedSuccessChance(action.action_type().as_str(), action.name())?;
+        let (min, max) = pair(chance, "a success chance")?;
+        Ok(SuccessChance { min, max })
+    }
+
+    /// Reputation gained by succeeding at `action` at its current level.
+    pub fn get_action_rep_gain(&self, action: &BladeburnerAction) -> Result<f64, JsValue> {
+        let (action_type, name) = (action.action_type().as_str(), action.name());
+        let level = self.getActionCurrentLevel(action_type, name)?;
+        self.getActionRepGain(action_type, name, level)
+    }
+
+    /// How many more times `action` can be done, infinite for general
+    /// actions.
+    pub fn get_action_count_remaining(&self, action: &BladeburnerAction) -> Result<f64, JsValue> {
+        self.getActionCountRemaining(action.action_type().as_str(), action.name())
+    }
+
+    pub fn get_stamina(&self) -> Result<Stamina, JsValue> {
+        let (current, max) = pair(self.getStamina()?, "the stamina")?;
+        Ok(Stamina { current, max })
+    }
+
+    /// The next black operation, `None` once they are all done.
+    pub fn get_next_black_op(&self) -> Result<Option<NextBlackOp>, JsValue> {
+        Ok(serde_wasm_bindgen::from_value(self.getNextBlackOp()?)?)
+    }
+
+    pub fn get_skill_level(&self, skill: BladeburnerSkill) -> Result<f64, JsValue> {
+        self.getSkillLevel(skill.as_str())
+    }
+
+    /// Skill points needed to upgrade `skill` `count` times.
+    pub fn get_skill_upgrade_cost(
+        &self,
+        skill: BladeburnerSkill,
+        count: Option<u32>,
+    ) -> Result<f64, JsValue> {
+        self.getSkillUpgradeCost(skill.as_str(), count.map(f64::from))
+    }
+
+    /// Upgrades `skill` `count` times, returns whether it was upgraded.
+    pub fn upgrade_skill(
+        &self,
+        skill: BladeburnerSkill,
+        count: Option<u32>,
+    ) -> Result<bool, JsValue> {
+        self.upgradeSkill(skill.as_str(), count.map(f64::from))
+    }
+
+    /// What the selector needs to know about `action`.
+    pub fn get_candidate(&self, action: BladeburnerAction) -> Result<ActionCandidate, JsValue> {
+        Ok(ActionCandidate {
+            success: self.get_success_chance(&action)?,
+            time: self.get_action_time(&action)?,
+            rep_gain: self.get_action_rep_gain(&action)?,
+            count_remaining: self.get_action_count_remaining(&action)?,
+            action,
+        })
+    }
+
+    /// Reads everything [`ActionSelector::select`] needs, about the current
+    /// city.
+    pub fn get_state(&self) -> Result<BladeburnerState, JsValue> {
+        let mut actions = vec![];
+        for contract in ContractName::ALL {
+            actions.push(self.get_candidate(contract.into())?);
+        }
+        for operation in OperationName::ALL {
+            actions.push(self.get_candidate(operation.into())?);
+        }
+        let next_black_op = self.get_next_black_op()?;
+        if let Some(black_op) = &next_black_op {
+            actions.push(self.get_candidate(BladeburnerAction::BlackOp(black_op.name.clone()))?);
+        }
+        let city: CityName = self.getCity()?;
+        Ok(BladeburnerState {
+            rank: self.getRank()?,
+            stamina: self.get_stamina()?,
+            city_chaos: self.getCityChaos(city)?,
+            actions,
+            next_black_op,
+        })
+    }
+}
+
+/// A contract, operation or black operation the selector can pick.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ActionCandidate {
+    pub action: BladeburnerAction,
+    pub success: SuccessChance,
+    /// Milliseconds the action takes
+    pub time: f64,
+    /// Reputation gained on success
+    pub rep_gain: f64,
+    pub count_remaining: f64,
+}
+
+impl ActionCandidate {
+    /// Expected reputation per second.
+    pub fn rep_per_second(&self) -> f64 {
+        self.rep_gain * self.success.average() / (self.time / 1000.0)
+    }
+}
+
+/// Everything the selector needs to know about the Bladeburner division.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BladeburnerState {
+    pub rank: f64,
+    pub stamina: Stamina,
+    /// Chaos of the current city
+    pub city_chaos: f64,
+    /// Contracts, operations and the next black operation
+    pub actions: Vec<ActionCandidate>,
+    pub next_black_op: Option<NextBlackOp>,
+}
+
+/// Picks the next Bladeburner action.
+///
+/// In order of priority:
+/// 1. rest in the regeneration chamber when stamina is below
+///    [`min_stamina`](Self::min_stamina) of the maximum,
+/// 2. calm the city with diplomacy when its chaos is above
+///    [`max_chaos`](Self::max_chaos),
+/// 3. the next black operation if the rank allows it and its success chance
+///    is at least [`black_op_success`](Self::black_op_success),
+/// 4. the contract or operation with the best expected reputation per
+///    second, among those with a success chance of at least
+///    [`min_success`](Self::min_success),
+/// 5. field analysis if some estimate is wider than
+///    [`max_uncertainty`](Self::max_uncertainty), training otherwise.
+///
+/// Success chances are compared by their lower bound.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ActionSelector {
+    min_stamina: f64,
+    max_chaos: f64,
+    min_success: f64,
+    black_op_success: f64,
+    max_uncertainty: f64,
+}
+
+impl Default for ActionSelector {
+    fn default() -> Self {
+        ActionSelector {
+            min_stamina: 0.5,
+            max_chaos: 50.0,
+            min_success: 0.8,
+            black_op_success: 0.95,
+            max_uncertainty: 0.05,
+        }
+    }
+}
+
+impl ActionSelector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Share of the maximum stamina under which to rest, 0.5 by default.
+    /// Success chances drop when stamina is below half.
+    pub fn min_stamina(mut self, ratio: f64) -> Self {
+        self.min_stamina = ratio;
+        self
+    }
+
+    /// City chaos above which to use diplomacy, 50 by default.
+    pub fn max_chaos(mut self, chaos: f64) -> Self {
+        self.max_chaos = chaos;
+        self
+    }
+
+    /// Success chance required for contracts and operations, 0.8 by default.
+    pub fn min_success(mut self, chance: f64) -> Self {
+        self.min_success = chance;
+        self
+    }
+
+    /// Success chance required for black operations, 0.95 by default.
+    pub fn black_op_success(mut self, chance: f64) -> Self {
+        self.black_op_success = chance;
+        self
+    }
+
+    /// Width of success estimates above which to do field analysis, 0.05 by
+    /// default.
+    pub fn max_uncertainty(mut self, width: f64) -> Self {
+        self.max_uncertainty = width;
+        self
+    }
+
+    pub fn select(&self, state: &BladeburnerState) -> BladeburnerAction {
+        if state.stamina.ratio() < self.min_stamina {
+            return GeneralAction::HyperbolicRegenerationChamber.into();
+        }
+        if state.city_chaos > self.max_chaos {
+            return GeneralAction::Diplomacy.into();
+        }
+
+        let available = |candidate: &&ActionCandidate| candidate.count_remaining >= 1.0;
+        if let Some(black_op) = &state.next_black_op {
+            let ready = state.actions.iter().filter(available).find(|candidate| {
+                candidate.action == BladeburnerAction::BlackOp(black_op.name.clone())
+                    && black_op.rank <= state.rank
+                    && candidate.success.min >= self.black_op_success
+            });
+            if let Some(candidate) = ready {
+                return candidate.action.clone();
+            }
+        }
+
+        let best = state
+            .actions
+            .iter()
+            .filter(available)
+            .filter(|candidate| candidate.action.action_type() != BladeburnerActionType::BlackOp)
+            .filter(|candidate| candidate.success.min >= self.min_success)
+            .max_by(|a, b| a.rep_per_second().total_cmp(&b.rep_per_second()));
+        if let Some(candidate) = best {
+            return candidate.action.clone();
+        }
+
+        let uncertain = state
+            .actions
+            .iter()
+            .any(|candidate| candidate.success.uncertainty() > self.max_uncertainty);
+        if uncertain {
+            GeneralAction::FieldAnalysis.into()
+        } else {
+            GeneralAction::Training.into()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BLACK_OP: &str = "Operation Typhoon";
+
+    fn candidate(action: impl Into<BladeburnerAction>, min: f64, rep_gain: f64) -> ActionCandidate {
+        ActionCandidate {
+            action: action.into(),
+            success: SuccessChance { min, max: min },
+            time: 10_000.0,
+            rep_gain,
+            count_remaining: 10.0,
+        }
+    }
+
+    /// Rested, in a calm city, with a tracking contract and a black operation
+    /// that are both sure to succeed.
+    fn state() -> BladeburnerState {
+        BladeburnerState {
+            rank: 2500.0,
+            stamina: Stamina {
+                current: 100.0,
+                max: 100.0,
+            },
+            city_chaos: 0.0,
+            actions: vec![
+                candidate(ContractName::Tracking, 1.0, 4.0),
+                candidate(BladeburnerAction::BlackOp(BLACK_OP.to_owned()), 1.0, 0.0),
+            ],
+            next_black_op: Some(NextBlackOp {
+                name: BLACK_OP.to_owned(),
+                rank: 2500.0,
+            }),
+        }
+    }
+
+    fn select(state: &BladeburnerState) -> BladeburnerAction {
+        ActionSelector::default().select(state)
+    }
+
+    #[test]
+    fn rests_first() {
+        let mut state = state();
+        state.stamina.current = 49.0;
+        state.city_chaos = 1000.0;
+        assert_eq!(
+            select(&state),
+            GeneralAction::HyperbolicRegenerationChamber.into()
+        );
+
+        let selector = ActionSelector::default().min_stamina(0.4);
+        state.city_chaos = 0.0;
+        assert_eq!(
+            selector.select(&state),
+            BladeburnerAction::BlackOp(BLACK_OP.to_owned())
+        );
+    }
+
+    #[test]
+    fn calms_chaos_before_acting() {
+        let mut state = state();
+        state.city_chaos = 51.0;
+        assert_eq!(select(&state), GeneralAction::Diplomacy.into());
+        state.city_chaos = 50.0;
+        assert_ne!(select(&state), GeneralAction::Diplomacy.into());
+    }
+
+    #[test]
+    fn black_op_when_ready() {
+        let mut state = state();
+        assert_eq!(
+            select(&state),
+            BladeburnerAction::BlackOp(BLACK_OP.to_owned())
+        );
+
+        // not enough rank, not sure enough or already done
+        state.rank = 2000.0;
+        assert_eq!(select(&state), ContractName::Tracking.into());
+        state.rank = 2500.0;
+        state.actions[1].success.min = 0.9;
+        assert_eq!(select(&state), ContractName::Tracking.into());
+        state.actions[1].success.min = 1.0;
+        state.actions[1].count_remaining = 0.0;
+        assert_eq!(select(&state), ContractName::Tracking.into());
+    }
+
+    #[test]
+    fn best_reputation_per_second() {
+        let mut state = state();
+        state.next_black_op = None;
+        state.actions = vec![
+            candidate(ContractName::Tracking, 0.9, 4.0),
+            candidate(ContractName::BountyHunter, 0.9, 8.0),
+            // better but too risky
+            candidate(OperationName::Assassination, 0.5, 100.0),
+        ];
+        assert_eq!(select(&state), ContractName::BountyHunter.into());
+
+        state.actions[1].count_remaining = 0.5;
+        assert_eq!(select(&state), ContractName::Tracking.into());
+        let selector = ActionSelector::default().min_success(0.5);
+        assert_eq!(selector.select(&state), OperationName::Assassination.into());
+    }
+
+    #[test]
+    fn analyzes_or_trains_when_nothing_is_safe() {
+        let mut state = state();
+        state.next_black_op = None;
+        state.actions = vec![candidate(OperationName::Investigation, 0.5, 10.0)];
+        assert_eq!(select(&state), GeneralAction::Training.into());
+
+        state.actions[0].success.max = 0.9;
+        assert_eq!(select(&state), GeneralAction::FieldAnalysis.into());
+        let selector = ActionSelector::default().max_uncertainty(0.5);
+        assert_eq!(selector.select(&state), GeneralAction::Training.into());
+    }
+
+    #[test]
+    fn type_spellings() {
+        let black_op = Some(BladeburnerAction::BlackOp(BLACK_OP.to_owned()));
+        for spelling in ["Black Operations", "Black Operation", "blackop", "BlackOps"] {
+            assert_eq!(
+                BladeburnerAction::from_type_and_name(spelling, BLACK_OP),
+                black_op,
+                "{spelling}"
+            );
+        }
+
+        let investigation = Some(OperationName::Investigation.into());
+        for spelling in ["Operations", "Operation", "operations", "Op"] {
+            assert_eq!(
+                BladeburnerAction::from_type_and_name(spelling, "Investigation"),
+                investigation,
+                "{spelling}"
+            );
+        }
+
+        let tracking = Some(ContractName::Tracking.into());
+        for spelling in ["Contracts", "Contract", "contracts"] {
+            assert_eq!(
+                BladeburnerAction::from_type_and_name(spelling, "Tracking"),
+                tracking,
+                "{spelling}"
+            );
+        }
+
+        let training = Some(GeneralAction::Training.into());
+        for spelling in ["General", "General Action", "Training"] {
+            assert_eq!(
+                BladeburnerAction::from_type_and_name(spelling, "Training"),
+                training,
+                "{spelling}"
+            );
+        }
+    }
+
+    #[test]
+    fn idle_and_unknown_actions() {
+        assert_eq!(BladeburnerAction::from_type_and_name("Idle", "Idle"), None);
+        assert_eq!(
+            BladeburnerAction::from_type_and_name("Contracts", "Unknown"),
+            None
+        );
+        assert_eq!(
+            BladeburnerAction::from_type_and_name("Operations", "Tracking"),
+            None
+        );
+    }
+
+    #[test]
+    fn type_names_round_trip() {
+        for action_type in BladeburnerActionType::ALL {
+            let parsed: BladeburnerActionType = action_type.as_str().parse().unwrap();
+            assert_eq!(parsed, action_type);
+        }
+    }
+}
@@ -0,0 +1,485 @@
+//! Typed access to the coding contract API at `ns.codingcontract()`,
+//! [`solvers`] for every contract type and a crawler submitting their
+//! answers.
+//!
+//! The definitions type contract data as `any`, here it is a
+//! [`ContractData`] with a variant per [`ContractType`], and answers are a
+//! [`ContractAnswer`]. Parsing and solving do not need the game:
+//!
+//! ```
+//! use bitburner_api::contracts::{ContractAnswer, ContractData, ContractType};
+//! use bitburner_api::{mock, MockNs};
+//!
+//! let data = serde_json::json!([3, [[0, 1], [1, 2]]]);
+//! let data = ContractData::parse(ContractType::ProperTwoColoring, data).unwrap();
+//! assert_eq!(data.solve(), Some(ContractAnswer::Numbers(vec![0, 1, 0])));
+//!
+//! let mut ns = MockNs::new();
+//! ns.add_server(mock::server("n00dles"), &["home"]);
+//! ns.add_file("n00dles", "contract-1234.cct");
+//! ns.add_file("n00dles", "notes.txt");
+//! let contracts = bitburner_api::contracts::find_contracts(&ns).unwrap();
+//! assert_eq!(contracts.len(), 1);
+//! assert_eq!(contracts[0].host, "n00dles");
+//! ```
+
+pub mod solvers;
+
+use serde::{Deserialize, Deserializer, Serialize};
+use wasm_bindgen::JsValue;
+
+use crate::{network::Network, CodingContract, NetscriptApi, NS};
+
+/// The problems coding contracts pose.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ContractType {
+    FindLargestPrimeFactor,
+    SubarrayWithMaximumSum,
+    TotalWaysToSum,
+    TotalWaysToSumII,
+    SpiralizeMatrix,
+    ArrayJumpingGame,
+    ArrayJumpingGameII,
+    MergeOverlappingIntervals,
+    GenerateIpAddresses,
+    StockTraderI,
+    StockTraderII,
+    StockTraderIII,
+    StockTraderIV,
+    MinimumPathSumInATriangle,
+    UniquePathsInAGridI,
+    UniquePathsInAGridII,
+    ShortestPathInAGrid,
+    SanitizeParentheses,
+    FindAllValidMathExpressions,
+    HammingEncode,
+    HammingDecode,
+    ProperTwoColoring,
+    RleCompression,
+    LzDecompression,
+    LzCompression,
+    CaesarCipher,
+    VigenereCipher,
+}
+
+impl ContractType {
+    pub const ALL: [ContractType; 27] = [
+        ContractType::FindLargestPrimeFactor,
+        ContractType::SubarrayWithMaximumSum,
+        ContractType::TotalWaysToSum,
+        ContractType::TotalWaysToSumII,
+        ContractType::SpiralizeMatrix,
+        ContractType::ArrayJumpingGame,
+        ContractType::ArrayJumpingGameII,
+        ContractType::MergeOverlappingIntervals,
+        ContractType::GenerateIpAddresses,
+        ContractType::StockTraderI,
+        ContractType::StockTraderII,
+        ContractType::StockTraderIII,
+        ContractType::StockTraderIV,
+        ContractType::MinimumPathSumInATriangle,
+        ContractType::UniquePathsInAGridI,
+        ContractType::UniquePathsInAGridII,
+        ContractType::ShortestPathInAGrid,
+        ContractType::SanitizeParentheses,
+        ContractType::FindAllValidMathExpressions,
+        ContractType::HammingEncode,
+        ContractType::HammingDecode,
+        ContractType::ProperTwoColoring,
+        ContractType::RleCompression,
+        ContractType::LzDecompression,
+        ContractType::LzCompression,
+        ContractType::CaesarCipher,
+        ContractType::VigenereCipher,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ContractType::FindLargestPrimeFactor => "Find Largest Prime Factor",
+            ContractType::SubarrayWithMaximumSum => "Subarray with Maximum Sum",
+            ContractType::TotalWaysToSum => "Total Ways to Sum",
+            ContractType::TotalWaysToSumII => "Total Ways to Sum II",
+            ContractType::SpiralizeMatrix => "Spiralize Matrix",
+            ContractType::ArrayJumpingGame => "Array Jumping Game",
+            ContractType::ArrayJumpingGameII => "Array Jumping Game II",
+            ContractType::MergeOverlappingIntervals => "Merge Overlapping Intervals",
+            ContractType::GenerateIpAddresses => "Generate IP Addresses",
+            ContractType::StockTraderI => "Algorithmic Stock Trader I",
+            ContractType::StockTraderII => "Algorithmic Stock Trader II",
+            ContractType::StockTraderIII => "Algorithmic Stock Trader III",
+            ContractType::StockTraderIV => "Algorithmic Stock Trader IV",
+            ContractType::MinimumPathSumInATriangle => "Minimum Path Sum in a Triangle",
+            ContractType::UniquePathsInAGridI => "Unique Paths in a Grid I",
+            ContractType::UniquePathsInAGridII => "Unique Paths in a Grid II",
+            ContractType::ShortestPathInAGrid => "Shortest Path in a Grid",
+            ContractType::SanitizeParentheses => "Sanitize Parentheses in Expression",
+            ContractType::FindAllValidMathExpressions => "Find All Valid Math Expressions",
+            ContractType::HammingEncode => "HammingCodes: Integer to Encoded Binary",
+            ContractType::HammingDecode => "HammingCodes: Encoded Binary to Integer",
+            ContractType::ProperTwoColoring => "Proper 2-Coloring of a Graph",
+            ContractType::RleCompression => "Compression I: RLE Compression",
+            ContractType::LzDecompression => "Compression II: LZ Decompression",
+            ContractType::LzCompression => "Compression III: LZ Compression",
+            ContractType::CaesarCipher => "Encryption I: Caesar Cipher",
+            ContractType::VigenereCipher => "Encryption II: Vigenère Cipher",
+        }
+    }
+}
+
+string_enum!(ContractType);
+
+/// The data of a contract, in the shape its type gives it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ContractData {
+    FindLargestPrimeFactor(i64),
+    SubarrayWithMaximumSum(Vec<i64>),
+    TotalWaysToSum(i64),
+    /// The number to sum up to and the numbers the sums may use
+    TotalWaysToSumII(i64, Vec<i64>),
+    SpiralizeMatrix(Vec<Vec<i64>>),
+    ArrayJumpingGame(Vec<i64>),
+    ArrayJumpingGameII(Vec<i64>),
+    MergeOverlappingIntervals(Vec<[i64; 2]>),
+    GenerateIpAddresses(String),
+    StockTraderI(Vec<i64>),
+    StockTraderII(Vec<i64>),
+    StockTraderIII(Vec<i64>),
+    /// The most transactions allowed and the prices
+    StockTraderIV(i64, Vec<i64>),
+    MinimumPathSumInATriangle(Vec<Vec<i64>>),
+    /// Rows and columns of the grid
+    UniquePathsInAGridI(i64, i64),
+    UniquePathsInAGridII(Vec<Vec<i64>>),
+    ShortestPathInAGrid(Vec<Vec<i64>>),
+    SanitizeParentheses(String),
+    /// The digits and the value the expressions must have
+    FindAllValidMathExpressions(String, i64),
+    HammingEncode(i64),
+    HammingDecode(String),
+    /// The number of vertices and the edges
+    ProperTwoColoring(usize, Vec<[usize; 2]>),
+    RleCompression(String),
+    LzDecompression(String),
+    LzCompression(String),
+    /// The plaintext and the left shift
+    CaesarCipher(String, u32),
+    /// The plaintext and the keyword
+    VigenereCipher(String, String),
+}
+
+impl ContractData {
+    /// Reads the data of a contract of type `contract_type`, e.g. from a
+    /// `serde_json::Value` or a `serde_wasm_bindgen::Deserializer`.
+    pub fn parse<'de, D: Deserializer<'de>>(
+        contract_type: ContractType,
+        data: D,
+    ) -> Result<ContractData, D::Error> {
+        use ContractData as Data;
+        use ContractType as Type;
+
+        Ok(match contract_type {
+            Type::FindLargestPrimeFactor => {
+                Data::FindLargestPrimeFactor(Deserialize::deserialize(data)?)
+            }
+            Type::SubarrayWithMaximumSum => {
+                Data::SubarrayWithMaximumSum(Deserialize::deserialize(data)?)
+            }
+            Type::TotalWaysToSum => Data::TotalWaysToSum(Deserialize::deserialize(data)?),
+            Type::TotalWaysToSumII => {
+                let (n, set) = Deserialize::deserialize(data)?;
+                Data::TotalWaysToSumII(n, set)
+            }
+            Type::SpiralizeMatrix => Data::SpiralizeMatrix(Deserialize::deserialize(data)?),
+            Type::ArrayJumpingGame => Data::ArrayJumpingGame(Deserialize::deserialize(data)?),
+            Type::ArrayJumpingGameII => Data::ArrayJumpingGameII(Deserialize::deserialize(data)?),
+            Type::MergeOverlappingIntervals => {
+                Data::MergeOverlappingIntervals(Deserialize::deserialize(data)?)
+            }
+            Type::GenerateIpAddresses => Data::GenerateIpAddresses(Deserialize::deserialize(data)?),
+            Type::StockTraderI => Data::StockTraderI(Deserialize::deserialize(data)?),
+            Type::StockTraderII => Data::StockTraderII(Deserialize::deserialize(data)?),
+            Type::StockTraderIII => Data::StockTraderIII(Deserialize::deserialize(data)?),
+            Type::StockTraderIV => {
+                let (transactions, prices) = Deserialize::deserialize(data)?;
+                Data::StockTraderIV(transactions, prices)
+            }
+            Type::MinimumPathSumInATriangle => {
+                Data::MinimumPathSumInATriangle(Deserialize::deserialize(data)?)
+            }
+            Type::UniquePathsInAGridI => {
+                let (rows, columns) = Deserialize::deserialize(data)?;
+                Data::UniquePathsInAGridI(rows, columns)
+            }
+            Type::UniquePathsInAGridII => {
+                Data::UniquePathsInAGridII(Deserialize::deserialize(data)?)
+            }
+            Type::ShortestPathInAGrid => Data::ShortestPathInAGrid(Deserialize::deserialize(data)?),
+            Type::SanitizeParentheses => Data::SanitizeParentheses(Deserialize::deserialize(data)?),
+            Type::FindAllValidMathExpressions => {
+                let (digits, target) = Deserialize::deserialize(data)?;
+                Data::FindAllValidMathExpressions(digits, target)
+            }
+            Type::HammingEncode => Data::HammingEncode(Deserialize::deserialize(data)?),
+            Type::HammingDecode => Data::HammingDecode(Deserialize::deserialize(data)?),
+            Type::ProperTwoColoring => {
+                let (vertices, edges) = Deserialize::deserialize(data)?;
+                Data::ProperTwoColoring(vertices, edges)
+            }
+            Type::RleCompression => Data::RleCompression(Deserialize::deserialize(data)?),
+            Type::LzDecompression => Data::LzDecompression(Deserialize::deserialize(data)?),
+            Type::LzCompression => Data::LzCompression(Deserialize::deserialize(data)?),
+            Type::CaesarCipher => {
+                let (plaintext, shift) = Deserialize::deserialize(data)?;
+                Data::CaesarCipher(plaintext, shift)
+            }
+            Type::VigenereCipher => {
+                let (plaintext, keyword) = Deserialize::deserialize(data)?;
+                Data::VigenereCipher(plaintext, keyword)
+            }
+        })
+    }
+
+    pub fn contract_type(&self) -> ContractType {
+        use ContractData as Data;
+        use ContractType as Type;
+
+        match self {
+            Data::FindLargestPrimeFactor(..) => Type::FindLargestPrimeFactor,
+            Data::SubarrayWithMaximumSum(..) => Type::SubarrayWithMaximumSum,
+            Data::TotalWaysToSum(..) => Type::TotalWaysToSum,
+            Data::TotalWaysToSumII(..) => Type::TotalWaysToSumII,
+            Data::SpiralizeMatrix(..) => Type::SpiralizeMatrix,
+            Data::ArrayJumpingGame(..) => Type::ArrayJumpingGame,
+            Data::ArrayJumpingGameII(..) => Type::ArrayJumpingGameII,
+            Data::MergeOverlappingIntervals(..) => Type::MergeOverlappingIntervals,
+            Data::GenerateIpAddresses(..) => Type::GenerateIpAddresses,
+            Data::StockTraderI(..) => Type::StockTraderI,
+            Data::StockTraderII(..) => Type::StockTraderII,
+            Data::StockTraderIII(..) => Type::StockTraderIII,
+            Data::StockTraderIV(..) => Type::StockTraderIV,
+            Data::MinimumPathSumInATriangle(..) => Type::MinimumPathSumInATriangle,
+            Data::UniquePathsInAGridI(..) => Type::UniquePathsInAGridI,
+            Data::UniquePathsInAGridII(..) => Type::UniquePathsInAGridII,
+            Data::ShortestPathInAGrid(..) => Type::ShortestPathInAGrid,
+            Data::SanitizeParentheses(..) => Type::SanitizeParentheses,
+            Data::FindAllValidMathExpressions(..) => Type::FindAllValidMathExpressions,
+            Data::HammingEncode(..) => Type::HammingEncode,
+            Data::HammingDecode(..) => Type::HammingDecode,
+            Data::ProperTwoColoring(..) => Type::ProperTwoColoring,
+            Data::RleCompression(..) => Type::RleCompression,
+            Data::LzDecompression(..) => Type::LzDecompression,
+            Data::LzCompression(..) => Type::LzCompression,
+            Data::CaesarCipher(..) => Type::CaesarCipher,
+            Data::VigenereCipher(..) => Type::VigenereCipher,
+        }
+    }
+
+    /// The answer the contract expects, `None` if the data has no answer,
+    /// e.g. an LZ back reference before the start of the text.
+    pub fn solve(&self) -> Option<ContractAnswer> {
+        use ContractAnswer as Answer;
+        use ContractData as Data;
+
+        let answer = match self {
+            Data::FindLargestPrimeFactor(n) => Answer::Number(solvers::largest_prime_factor(*n)),
+            Data::SubarrayWithMaximumSum(numbers) => {
+                Answer::Number(solvers::max_subarray_sum(numbers))
+            }
+            Data::TotalWaysToSum(n) => Answer::Number(solvers::total_ways_to_sum(*n)),
+            Data::TotalWaysToSumII(n, set) => {
+                Answer::Number(solvers::total_ways_to_sum_ii(*n, set))
+            }
+            Data::SpiralizeMatrix(matrix) => Answer::Numbers(solvers::spiralize_matrix(matrix)),
+            Data::ArrayJumpingGame(jumps) => {
+                Answer::Number(solvers::array_jumping_game(jumps).into())
+            }
+            Data::ArrayJumpingGameII(jumps) => {
+                Answer::Number(solvers::array_jumping_game_ii(jumps))
+            }
+            Data::MergeOverlappingIntervals(intervals) => {
+                Answer::Intervals(solvers::merge_overlapping_intervals(intervals))
+            }
+            Data::GenerateIpAddresses(digits) => {
+                Answer::Texts(solvers::generate_ip_addresses(digits))
+            }
+            Data::StockTraderI(prices) => Answer::Number(solvers::stock_trader(1, prices)),
+            Data::StockTraderII(prices) => {
+                Answer::Number(solvers::stock_trader(usize::MAX, prices))
+            }
+            Data::StockTraderIII(prices) => Answer::Number(solvers::stock_trader(2, prices)),
+            Data::StockTraderIV(transactions, prices) => Answer::Number(solvers::stock_trader(
+                (*transactions).max(0) as usize,
+                prices,
+            )),
+            Data::MinimumPathSumInATriangle(triangle) => {
+                Answer::Number(solvers::minimum_path_sum_in_a_triangle(triangle))
+            }
+            Data::UniquePathsInAGridI(rows, columns) => {
+                Answer::Number(solvers::unique_paths_in_a_grid_i(*rows, *columns))
+            }
+            Data::UniquePathsInAGridII(grid) => {
+                Answer::Number(solvers::unique_paths_in_a_grid_ii(grid))
+            }
+            Data::ShortestPathInAGrid(grid) => Answer::Text(solvers::shortest_path_in_a_grid(grid)),
+            Data::SanitizeParentheses(expression) => {
+                Answer::Texts(solvers::sanitize_parentheses(expression))
+            }
+            Data::FindAllValidMathExpressions(digits, target) => {
+                Answer::Texts(solvers::find_all_valid_math_expressions(digits, *target))
+            }
+            Data::HammingEncode(value) => Answer::Text(solvers::hamming_encode(*value)),
+            Data::HammingDecode(encoded) => Answer::Number(solvers::hamming_decode(encoded)),
+            Data::ProperTwoColoring(vertices, edges) => {
+                Answer::Numbers(solvers::proper_two_coloring(*vertices, edges))
+            }
+            Data::RleCompression(text) => Answer::Text(solvers::rle_compression(text)),
+            Data::LzDecompression(compressed) => {
+                Answer::Text(solvers::lz_decompression(compressed)?)
+            }
+            Data::LzCompression(plain) => Answer::Text(solvers::lz_compression(plain)),
+            Data::CaesarCipher(plaintext, shift) => {
+                Answer::Text(solvers::caesar_cipher(plaintext, *shift))
+            }
+            Data::VigenereCipher(plaintext, keyword) => {
+                Answer::Text(solvers::vigenere_cipher(plaintext, keyword)?)
+            }
+        };
+        Some(answer)
+    }
+}
+
+/// An answer to a contract, serialized the way `attempt` takes it. Yes or
+/// no answers are the numbers 1 and 0.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(untagged)]
+pub enum ContractAnswer {
+    Number(i64),
+    Numbers(Vec<i64>),
+    Intervals(Vec<[i64; 2]>),
+    Text(String),
+    Texts(Vec<String>),
+}
+
+impl CodingContract {
+    pub fn get_contract_type(&self, filename: &str, host: &str) -> Result<ContractType, JsValue> {
+        self.getContractType(filename, Some(host))?
+            .parse()
+            .map_err(|err: crate::ParseEnumError| JsValue::from_str(&err.to_string()))
+    }
+
+    pub fn get_data(&self, filename: &str, host: &str) -> Result<ContractData, JsValue> {
+        let contract_type = self.get_contract_type(filename, host)?;
+        self.get_data_of(contract_type, filename, host)
+    }
+
+    /// The data of a contract whose type is already known, without asking
+    /// the game for it again.
+    pub fn get_data_of(
+        &self,
+        contract_type: ContractType,
+        filename: &str,
+        host: &str,
+    ) -> Result<ContractData, JsValue> {
+        let data = self.getData(filename, Some(host))?;
+        Ok(ContractData::parse(
+            contract_type,
+            serde_wasm_bindgen::Deserializer::from(data),
+        )?)
+    }
+
+    /// Submits `answer`, returns the reward or `None` if it was wrong.
+    pub fn attempt_answer(
+        &self,
+        answer: &ContractAnswer,
+        filename: &str,
+        host: &str,
+    ) -> Result<Option<String>, JsValue> {
+        let answer = serde_wasm_bindgen::to_value(answer)?;
+        let reward = self.attempt(&answer, filename, Some(host))?;
+        Ok(Some(reward).filter(|reward| !reward.is_empty()))
+    }
+
+    pub fn get_num_tries_remaining(&self, filename: &str, host: &str) -> Result<u32, JsValue> {
+        Ok(self.getNumTriesRemaining(filename, Some(host))? as u32)
+    }
+
+    /// Creates a contract of `contract_type` on `home`, without reward.
+    pub fn create_dummy_contract(&self, contract_type: ContractType) -> Result<(), JsValue> {
+        self.createDummyContract(contract_type.as_str())
+    }
+}
+
+/// A contract file somewhere in the network.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContractFile {
+    pub host: String,
+    pub filename: String,
+}
+
+/// What became of a contract given to [`solve_contracts`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ContractOutcome {
+    /// Solved, with the reward the game described
+    Solved(String),
+    /// The answer was wrong
+    Failed { tries_remaining: u32 },
+    /// The type has no solver, e.g. one added to the game after this crate
+    Unsupported(String),
+    /// The data has no answer, the contract was left untouched
+    Unsolvable,
+}
+
+/// Every `.cct` file on the servers reachable from `home`.
+pub fn find_contracts<N: NetscriptApi>(ns: &N) -> Result<Vec<ContractFile>, String> {
+    let mut contracts = vec![];
+    for host in Network::crawl(ns)?.hostnames() {
+        for filename in ns.ls(host, Some(".cct"))? {
+            contracts.push(ContractFile {
+                host: host.to_owned(),
+                filename,
+            });
+        }
+    }
+    Ok(contracts)
+}
+
+/// Finds every contract in the network and submits the answer of its
+/// solver.
+pub fn solve_contracts(ns: &NS) -> Result<Vec<(ContractFile, ContractOutcome)>, JsValue> {
+    let api = ns.codingcontract();
+    let mut outcomes = vec![];
+    for contract in find_contracts(ns)? {
+        let ContractFile { host, filename } = &contract;
+        let contract_type = api.getContractType(filename, Some(host))?;
+        let outcome = match contract_type.parse::<ContractType>() {
+            Err(_) => ContractOutcome::Unsupported(contract_type.clone()),
+            Ok(parsed) => match api.get_data_of(parsed, filename, host)?.solve() {
+                None => ContractOutcome::Unsolvable,
+                Some(answer) => match api.attempt_answer(&answer, filename, host)? {
+                    Some(reward) => ContractOutcome::Solved(reward),
+                    // the contract is gone once out of tries
+                    None => ContractOutcome::Failed {
+                        tries_remaining: api.get_num_tries_remaining(filename, host).unwrap_or(0),
+                    },
+                },
+            },
+        };
+        log::info!("{host}/{filename} ({contract_type}): {outcome:?}");
+        outcomes.push((contract, outcome));
+    }
+    Ok(outcomes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn unsolvable_data_has_no_answer() {
+        let data = ContractData::parse(ContractType::LzDecompression, json!("1a12")).unwrap();
+        assert_eq!(data.solve(), None);
+        let data = ContractData::parse(ContractType::VigenereCipher, json!(["ABC", ""])).unwrap();
+        assert_eq!(data.solve(), None);
+    }
+}
@@ -0,0 +1,855 @@
+//! Solvers of every coding contract type, on plain Rust values.
+//!
+//! ```
+//! use bitburner_api::contracts::solvers;
+//!
+//! assert_eq!(solvers::largest_prime_factor(13195), 29);
+//! assert_eq!(solvers::max_subarray_sum(&[-2, 1, -3, 4, -1, 2, 1, -5, 4]), 6);
+//! assert_eq!(solvers::total_ways_to_sum(5), 6);
+//! assert_eq!(solvers::total_ways_to_sum_ii(5, &[1, 2, 5]), 4);
+//! assert_eq!(
+//!     solvers::spiralize_matrix(&[vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]),
+//!     [1, 2, 3, 6, 9, 8, 7, 4, 5]
+//! );
+//! assert!(solvers::array_jumping_game(&[2, 3, 1, 1, 4]));
+//! assert!(!solvers::array_jumping_game(&[3, 2, 1, 0, 4]));
+//! assert_eq!(solvers::array_jumping_game_ii(&[2, 3, 1, 1, 4]), 2);
+//! assert_eq!(
+//!     solvers::merge_overlapping_intervals(&[[1, 3], [8, 10], [2, 6], [10, 16]]),
+//!     [[1, 6], [8, 16]]
+//! );
+//! assert_eq!(
+//!     solvers::generate_ip_addresses("25525511135"),
+//!     ["255.255.11.135", "255.255.111.35"]
+//! );
+//! assert_eq!(solvers::stock_trader(1, &[7, 1, 5, 3, 6, 4]), 5);
+//! assert_eq!(solvers::stock_trader(2, &[3, 3, 5, 0, 0, 3, 1, 4]), 6);
+//! assert_eq!(
+//!     solvers::minimum_path_sum_in_a_triangle(&[vec![2], vec![3, 4], vec![6, 5, 7], vec![4, 1, 8, 3]]),
+//!     11
+//! );
+//! assert_eq!(solvers::unique_paths_in_a_grid_i(3, 7), 28);
+//! assert_eq!(
+//!     solvers::unique_paths_in_a_grid_ii(&[vec![0, 0, 0], vec![0, 1, 0], vec![0, 0, 0]]),
+//!     2
+//! );
+//! assert_eq!(
+//!     solvers::shortest_path_in_a_grid(&[vec![0, 1], vec![0, 0]]),
+//!     "DR"
+//! );
+//! let mut sanitized = solvers::sanitize_parentheses("()())()");
+//! sanitized.sort();
+//! assert_eq!(sanitized, ["(())()", "()()()"]);
+//! let mut expressions = solvers::find_all_valid_math_expressions("123", 6);
+//! expressions.sort();
+//! assert_eq!(expressions, ["1*2*3", "1+2+3"]);
+//! assert_eq!(solvers::hamming_encode(8), "11110000");
+//! assert_eq!(solvers::hamming_decode("11110000"), 8);
+//! // a flipped bit is corrected
+//! assert_eq!(solvers::hamming_decode("11110100"), 8);
+//! assert_eq!(solvers::proper_two_coloring(4, &[[0, 2], [0, 3], [1, 2], [1, 3]]), [0, 0, 1, 1]);
+//! assert!(solvers::proper_two_coloring(3, &[[0, 1], [1, 2], [2, 0]]).is_empty());
+//! assert_eq!(solvers::rle_compression("aaaaaaaaaaaaabbbbcd"), "9a4a4b1c1d");
+//! assert_eq!(
+//!     solvers::lz_decompression("5aaabb450723abb").as_deref(),
+//!     Some("aaabbaaababababaabb")
+//! );
+//! assert_eq!(solvers::lz_compression("abracadabra"), "7abracad47");
+//! assert_eq!(solvers::caesar_cipher("DEBUG MODEM", 3), "ABYRD JLABJ");
+//! assert_eq!(
+//!     solvers::vigenere_cipher("DASHBOARD", "LINUX").as_deref(),
+//!     Some("OIFBYZIEX")
+//! );
+//! ```
+
+use std::collections::{HashSet, VecDeque};
+
+pub fn largest_prime_factor(mut n: i64) -> i64 {
+    let mut factor = 2;
+    while factor * factor <= n {
+        while n % factor == 0 && n > factor {
+            n /= factor;
+        }
+        factor += 1;
+    }
+    n
+}
+
+/// Largest sum of a non-empty contiguous subarray, 0 for an empty array.
+pub fn max_subarray_sum(numbers: &[i64]) -> i64 {
+    if numbers.is_empty() {
+        return 0;
+    }
+    let mut best = i64::MIN;
+    let mut current = 0;
+    for &number in numbers {
+        current = number.max(current + number);
+        best = best.max(current);
+    }
+    best
+}
+
+/// Ways to write `n` as a sum of at least two positive integers.
+pub fn total_ways_to_sum(n: i64) -> i64 {
+    let parts: Vec<i64> = (1..n).collect();
+    total_ways_to_sum_ii(n, &parts)
+}
+
+/// Ways to write `n` as a sum of the numbers of `set`, each usable any
+/// number of times.
+pub fn total_ways_to_sum_ii(n: i64, set: &[i64]) -> i64 {
+    let n = n.max(0) as usize;
+    let mut ways = vec![0; n + 1];
+    ways[0] = 1;
+    for &part in set.iter().filter(|&&part| part > 0) {
+        let part = part as usize;
+        for total in part..=n {
+            ways[total] += ways[total - part];
+        }
+    }
+    ways[n]
+}
+
+/// The elements of a matrix in clockwise spiral order.
+pub fn spiralize_matrix(matrix: &[Vec<i64>]) -> Vec<i64> {
+    let mut spiral = vec![];
+    if matrix.is_empty() {
+        return spiral;
+    }
+    let (mut top, mut bottom) = (0, matrix.len() as isize - 1);
+    let (mut left, mut right) = (0, matrix[0].len() as isize - 1);
+    while top <= bottom && left <= right {
+        for column in left..=right {
+            spiral.push(matrix[top as usize][column as usize]);
+        }
+        for row in top + 1..=bottom {
+            spiral.push(matrix[row as usize][right as usize]);
+        }
+        if top < bottom {
+            for column in (left..right).rev() {
+                spiral.push(matrix[bottom as usize][column as usize]);
+            }
+        }
+        if left < right {
+            for row in (top + 1..bottom).rev() {
+                spiral.push(matrix[row as usize][left as usize]);
+            }
+        }
+        top += 1;
+        bottom -= 1;
+        left += 1;
+        right -= 1;
+    }
+    spiral
+}
+
+/// Whether the last cell can be reached, each cell holding the longest jump
+/// from it.
+pub fn array_jumping_game(jumps: &[i64]) -> bool {
+    let mut reach = 0;
+    for (index, &jump) in jumps.iter().enumerate() {
+        if index > reach {
+            return false;
+        }
+        reach = reach.max(index + jump.max(0) as usize);
+    }
+    true
+}
+
+/// Fewest jumps reaching the last cell, 0 if it cannot be reached.
+pub fn array_jumping_game_ii(jumps: &[i64]) -> i64 {
+    let last = jumps.len().saturating_sub(1);
+    let (mut count, mut current_end, mut farthest) = (0, 0, 0);
+    for (index, &jump) in jumps.iter().enumerate().take(last) {
+        farthest = farthest.max(index + jump.max(0) as usize);
+        if index == current_end {
+            if farthest <= index {
+                return 0;
+            }
+            count += 1;
+            current_end = farthest;
+            if current_end >= last {
+                break;
+            }
+        }
+    }
+    count
+}
+
+/// Intervals merged where they overlap, sorted.
+pub fn merge_overlapping_intervals(intervals: &[[i64; 2]]) -> Vec<[i64; 2]> {
+    let mut sorted = intervals.to_vec();
+    sorted.sort();
+    let mut merged: Vec<[i64; 2]> = vec![];
+    for [start, end] in sorted {
+        match merged.last_mut() {
+            Some(last) if start <= last[1] => last[1] = last[1].max(end),
+            _ => merged.push([start, end]),
+        }
+    }
+    merged
+}
+
+/// Every IPv4 address the digits can be split into.
+pub fn generate_ip_addresses(digits: &str) -> Vec<String> {
+    fn is_octet(part: &str) -> bool {
+        !part.is_empty()
+            && part.len() <= 3
+            && (part == "0" || !part.starts_with('0'))
+            && part.parse::<u32>().is_ok_and(|octet| octet <= 255)
+    }
+
+    let mut addresses = vec![];
+    let n = digits.len();
+    for a in 1..=3.min(n) {
+        for b in a + 1..=(a + 3).min(n) {
+            for c in b + 1..=(b + 3).min(n) {
+                let parts = [&digits[..a], &digits[a..b], &digits[b..c], &digits[c..]];
+                if parts.iter().all(|part| is_octet(part)) {
+                    addresses.push(parts.join("."));
+                }
+            }
+        }
+    }
+    addresses
+}
+
+/// Largest profit of at most `transactions` buys each followed by a sell.
+/// Stock Trader I allows 1, II any number, III 2 and IV gives the number.
+pub fn stock_trader(transactions: usize, prices: &[i64]) -> i64 {
+    let transactions = transactions.min(prices.len() / 2);
+    let mut holding = vec![i64::MIN; transactions + 1];
+    let mut free = vec![0; transactions + 1];
+    for &price in prices {
+        for done in 1..=transactions {
+            holding[done] = holding[done].max(free[done - 1] - price);
+            free[done] = free[done].max(holding[done].saturating_add(price));
+        }
+    }
+    free[transactions]
+}
+
+/// Smallest sum of a path from the top to the bottom of a triangle, moving
+/// to an adjacent number of the row below.
+pub fn minimum_path_sum_in_a_triangle(triangle: &[Vec<i64>]) -> i64 {
+    let mut sums = triangle.last().cloned().unwrap_or_default();
+    for row in triangle.iter().rev().skip(1) {
+        for (index, &number) in row.iter().enumerate() {
+            sums[index] = number + sums[index].min(sums[index + 1]);
+        }
+    }
+    sums.first().copied().unwrap_or(0)
+}
+
+/// Paths from the top left to the bottom right of a grid, moving right or
+/// down.
+pub fn unique_paths_in_a_grid_i(rows: i64, columns: i64) -> i64 {
+    let grid = vec![vec![0; columns.max(0) as usize]; rows.max(0) as usize];
+    unique_paths_in_a_grid_ii(&grid)
+}
+
+/// Paths from the top left to the bottom right of a grid, moving right or
+/// down and avoiding the obstacles marked 1.
+pub fn unique_paths_in_a_grid_ii(grid: &[Vec<i64>]) -> i64 {
+    let columns = grid.first().map_or(0, Vec::len);
+    let mut paths = vec![0; columns];
+    if columns > 0 {
+        paths[0] = 1;
+    }
+    for row in grid {
+        for (column, &cell) in row.iter().enumerate() {
+            if cell == 1 {
+                paths[column] = 0;
+            } else if column > 0 {
+                paths[column] += paths[column - 1];
+            }
+        }
+    }
+    paths.last().copied().unwrap_or(0)
+}
+
+/// A shortest path as `U`, `D`, `L` and `R` moves from the top left to the
+/// bottom right of a grid avoiding the walls marked 1, empty if there is
+/// none.
+pub fn shortest_path_in_a_grid(grid: &[Vec<i64>]) -> String {
+    let rows = grid.len();
+    let columns = grid.first().map_or(0, Vec::len);
+    if rows == 0 || columns == 0 || grid[0][0] == 1 {
+        return String::new();
+    }
+    let mut previous = vec![vec![None; columns]; rows];
+    let mut queue = VecDeque::from([(0, 0)]);
+    previous[0][0] = Some(((0, 0), ' '));
+    while let Some((row, column)) = queue.pop_front() {
+        if (row, column) == (rows - 1, columns - 1) {
+            let mut path = vec![];
+            let mut cell = (row, column);
+            while cell != (0, 0) {
+                let (from, step) = previous[cell.0][cell.1].unwrap();
+                path.push(step);
+                cell = from;
+            }
+            return path.iter().rev().collect();
+        }
+        let moves = [
+            (row.wrapping_sub(1), column, 'U'),
+            (row + 1, column, 'D'),
+            (row, column.wrapping_sub(1), 'L'),
+            (row, column + 1, 'R'),
+        ];
+        for (next_row, next_column, step) in moves {
+            if next_row < rows
+                && next_column < columns
+                && grid[next_row][next_column] != 1
+                && previous[next_row][next_column].is_none()
+            {
+                previous[next_row][next_column] = Some(((row, column), step));
+                queue.push_back((next_row, next_column));
+            }
+        }
+    }
+    String::new()
+}
+
+/// Every valid expression left by removing the fewest parentheses.
+pub fn sanitize_parentheses(expression: &str) -> Vec<String> {
+    fn is_valid(expression: &str) -> bool {
+        let mut depth = 0;
+        for c in expression.chars() {
+            match c {
+                '(' => depth += 1,
+                ')' if depth == 0 => return false,
+                ')' => depth -= 1,
+                _ => {}
+            }
+        }
+        depth == 0
+    }
+
+    let mut level = HashSet::from([expression.to_owned()]);
+    loop {
+        let valid: Vec<String> = level
+            .iter()
+            .filter(|candidate| is_valid(candidate))
+            .cloned()
+            .collect();
+        if !valid.is_empty() {
+            return valid;
+        }
+        level = level
+            .iter()
+            .flat_map(|candidate| {
+                candidate
+                    .char_indices()
+                    .filter(|(_, c)| matches!(c, '(' | ')'))
+                    .map(|(index, _)| {
+                        let mut shorter = candidate.clone();
+                        shorter.remove(index);
+                        shorter
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+    }
+}
+
+/// Every way of inserting `+`, `-` and `*` between the digits so that the
+/// expression evaluates to `target`.
+pub fn find_all_valid_math_expressions(digits: &str, target: i64) -> Vec<String> {
+    fn search(
+        digits: &str,
+        target: i64,
+        expression: &mut String,
+        value: i64,
+        last: i64,
+        found: &mut Vec<String>,
+    ) {
+        if digits.is_empty() {
+            if value == target {
+                found.push(expression.clone());
+            }
+            return;
+        }
+        for length in 1..=digits.len() {
+            let operand = &digits[..length];
+            if length > 1 && operand.starts_with('0') {
+                break;
+            }
+            let Ok(number) = operand.parse::<i64>() else {
+                break;
+            };
+            let rest = &digits[length..];
+            let start = expression.len();
+            if start == 0 {
+                expression.push_str(operand);
+                search(rest, target, expression, number, number, found);
+            } else {
+                for (operator, value, last) in [
+                    ('+', value + number, number),
+                    ('-', value - number, -number),
+                    ('*', value - last + last * number, last * number),
+                ] {
+                    expression.push(operator);
+                    expression.push_str(operand);
+                    search(rest, target, expression, value, last, found);
+                    expression.truncate(start);
+                }
+            }
+            expression.truncate(start);
+        }
+    }
+
+    let mut found = vec![];
+    search(digits, target, &mut String::new(), 0, 0, &mut found);
+    found
+}
+
+/// Encodes a number as the game's extended Hamming code: the data bits most
+/// significant first at the positions that are not powers of two, the
+/// parity bits at the powers of two and the overall parity at 0.
+pub fn hamming_encode(value: i64) -> String {
+    let data: Vec<u8> = format!("{value:b}").bytes().map(|bit| bit - b'0').collect();
+    let mut encoded = vec![0u8];
+    let mut data = data.into_iter();
+    let mut remaining = data.len();
+    let mut position = 1usize;
+    while remaining > 0 {
+        if position.is_power_of_two() {
+            encoded.push(0);
+        } else {
+            encoded.push(data.next().unwrap());
+            remaining -= 1;
+        }
+        position += 1;
+    }
+
+    let parity = (0..encoded.len())
+        .filter(|&position| encoded[position] == 1)
+        .fold(0, |parity, position| parity ^ position);
+    let mut bit = 1;
+    while bit <= parity {
+        encoded[bit] = u8::from(parity & bit != 0);
+        bit <<= 1;
+    }
+    encoded[0] = encoded.iter().sum::<u8>() % 2;
+    encoded.iter().map(|bit| char::from(b'0' + bit)).collect()
+}
+
+/// Decodes the game's extended Hamming code, correcting a flipped bit.
+pub fn hamming_decode(encoded: &str) -> i64 {
+    let mut bits: Vec<u8> = encoded.bytes().map(|bit| u8::from(bit == b'1')).collect();
+    let error = (0..bits.len())
+        .filter(|&position| bits[position] == 1)
+        .fold(0, |error, position| error ^ position);
+    if error != 0 && error < bits.len() {
+        bits[error] ^= 1;
+    }
+    (1..bits.len())
+        .filter(|position| !position.is_power_of_two())
+        .fold(0, |value, position| value * 2 + i64::from(bits[position]))
+}
+
+/// Colors of the vertices with no edge between two vertices of the same
+/// color, empty if there is no such coloring.
+pub fn proper_two_coloring(vertices: usize, edges: &[[usize; 2]]) -> Vec<i64> {
+    if edges.iter().flatten().any(|&vertex| vertex >= vertices) {
+        return vec![];
+    }
+    let mut neighbours = vec![vec![]; vertices];
+    for &[a, b] in edges {
+        neighbours[a].push(b);
+        neighbours[b].push(a);
+    }
+    let mut colors: Vec<Option<i64>> = vec![None; vertices];
+    for start in 0..vertices {
+        if colors[start].is_some() {
+            continue;
+        }
+        colors[start] = Some(0);
+        let mut queue = VecDeque::from([start]);
+        while let Some(vertex) = queue.pop_front() {
+            let color = colors[vertex].unwrap();
+            for &neighbour in &neighbours[vertex] {
+                match colors[neighbour] {
+                    None => {
+                        colors[neighbour] = Some(1 - color);
+                        queue.push_back(neighbour);
+                    }
+                    Some(other) if other == color => return vec![],
+                    Some(_) => {}
+                }
+            }
+        }
+    }
+    colors.into_iter().flatten().collect()
+}
+
+/// Run-length encoding, with runs of at most 9 characters.
+pub fn rle_compression(text: &str) -> String {
+    let mut encoded = String::new();
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        let mut run = 1;
+        while run < 9 && chars.peek() == Some(&c) {
+            chars.next();
+            run += 1;
+        }
+        encoded.push(char::from(b'0' + run));
+        encoded.push(c);
+    }
+    encoded
+}
+
+/// Decodes the game's LZ variant, alternating literal chunks of a length
+/// and its characters with back references of a length and an offset.
+/// `None` if a length is not a digit or a back reference does not point
+/// into the text decoded so far.
+pub fn lz_decompression(compressed: &str) -> Option<String> {
+    fn digit(byte: u8) -> Option<usize> {
+        byte.is_ascii_digit().then(|| usize::from(byte - b'0'))
+    }
+
+    let bytes = compressed.as_bytes();
+    let mut plain: Vec<u8> = vec![];
+    let mut index = 0;
+    while index < bytes.len() {
+        let literal = digit(bytes[index])?;
+        plain.extend_from_slice(&bytes[index + 1..(index + 1 + literal).min(bytes.len())]);
+        index += 1 + literal;
+        if index >= bytes.len() {
+            break;
+        }
+        let length = digit(bytes[index])?;
+        if length == 0 {
+            index += 1;
+            continue;
+        }
+        let Some(&offset) = bytes.get(index + 1) else {
+            break;
+        };
+        let offset = digit(offset)?;
+        if offset == 0 || offset > plain.len() {
+            return None;
+        }
+        for _ in 0..length {
+            plain.push(plain[plain.len() - offset]);
+        }
+        index += 2;
+    }
+    Some(String::from_utf8_lossy(&plain).into_owned())
+}
+
+/// Shortest encoding of `plain` in the game's LZ variant.
+pub fn lz_compression(plain: &str) -> String {
+    // state[0][length] ends with a literal of that length, state[offset][length]
+    // with a back reference, each holding the encoding of what came before
+    type State = [[Option<String>; 10]; 10];
+    fn set(state: &mut State, i: usize, j: usize, encoded: String) {
+        if state[i][j]
+            .as_ref()
+            .is_none_or(|current| encoded.len() < current.len())
+        {
+            state[i][j] = Some(encoded);
+        }
+    }
+
+    if plain.is_empty() {
+        return String::new();
+    }
+    let plain = plain.as_bytes();
+    let text = |range: std::ops::Range<usize>| String::from_utf8_lossy(&plain[range]).into_owned();
+    let mut current: State = Default::default();
+    current[0][1] = Some(String::new());
+    for i in 1..plain.len() {
+        let mut next: State = Default::default();
+        let c = plain[i];
+
+        for (length, encoded) in current[0].iter().enumerate().skip(1) {
+            let Some(encoded) = encoded.clone() else {
+                continue;
+            };
+            if length < 9 {
+                set(&mut next, 0, length + 1, encoded.clone());
+            } else {
+                set(&mut next, 0, 1, format!("{encoded}9{}0", text(i - 9..i)));
+            }
+            for offset in 1..=9.min(i) {
+                if plain[i - offset] == c {
+                    set(
+                        &mut next,
+                        offset,
+                        1,
+                        format!("{encoded}{length}{}", text(i - length..i)),
+                    );
+                }
+            }
+        }
+
+        for (offset, row) in current.iter().enumerate().skip(1) {
+            for (length, encoded) in row.iter().enumerate().skip(1) {
+                let Some(encoded) = encoded.clone() else {
+                    continue;
+                };
+                if plain[i - offset] == c {
+                    if length < 9 {
+                        set(&mut next, offset, length + 1, encoded.clone());
+                    } else {
+                        set(&mut next, offset, 1, format!("{encoded}9{offset}0"));
+                    }
+                }
+                set(&mut next, 0, 1, format!("{encoded}{length}{offset}"));
+                for new_offset in 1..=9.min(i) {
+                    if plain[i - new_offset] == c {
+                        set(
+                            &mut next,
+                            new_offset,
+                            1,
+                            format!("{encoded}{length}{offset}0"),
+                        );
+                    }
+                }
+            }
+        }
+        current = next;
+    }
+
+    let mut best: Option<String> = None;
+    let mut consider = |encoded: String| {
+        if best.as_ref().is_none_or(|best| encoded.len() < best.len()) {
+            best = Some(encoded);
+        }
+    };
+    for (length, encoded) in current[0].iter().enumerate().skip(1) {
+        if let Some(encoded) = encoded {
+            let start = plain.len().saturating_sub(length);
+            consider(format!("{encoded}{length}{}", text(start..plain.len())));
+        }
+    }
+    for (offset, row) in current.iter().enumerate().skip(1) {
+        for (length, encoded) in row.iter().enumerate().skip(1) {
+            if let Some(encoded) = encoded {
+                consider(format!("{encoded}{length}{offset}"));
+            }
+        }
+    }
+    best.unwrap_or_default()
+}
+
+/// Shifts every letter `shift` places back in the alphabet, keeping spaces.
+pub fn caesar_cipher(plaintext: &str, shift: u32) -> String {
+    plaintext
+        .bytes()
+        .map(|c| match c {
+            b'A'..=b'Z' => char::from(b'A' + ((c - b'A') as u32 + 26 - shift % 26) as u8 % 26),
+            _ => char::from(c),
+        })
+        .collect()
+}
+
+/// Shifts every letter by the letter of the repeated `keyword` at the same
+/// position, keeping spaces. `None` if the keyword is empty or not only
+/// uppercase letters.
+pub fn vigenere_cipher(plaintext: &str, keyword: &str) -> Option<String> {
+    let keyword = keyword.as_bytes();
+    if keyword.is_empty() || !keyword.iter().all(u8::is_ascii_uppercase) {
+        return None;
+    }
+    let ciphertext = plaintext
+        .bytes()
+        .enumerate()
+        .map(|(index, c)| match c {
+            b'A'..=b'Z' => {
+                let key = keyword[index % keyword.len()] - b'A';
+                char::from(b'A' + (c - b'A' + key) % 26)
+            }
+            _ => char::from(c),
+        })
+        .collect();
+    Some(ciphertext)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prime_factors() {
+        assert_eq!(largest_prime_factor(2), 2);
+        assert_eq!(largest_prime_factor(97), 97);
+        assert_eq!(largest_prime_factor(1024), 2);
+    }
+
+    #[test]
+    fn subarrays() {
+        assert_eq!(max_subarray_sum(&[]), 0);
+        assert_eq!(max_subarray_sum(&[-5]), -5);
+        assert_eq!(max_subarray_sum(&[-3, -1, -2]), -1);
+    }
+
+    #[test]
+    fn ways_to_sum() {
+        assert_eq!(total_ways_to_sum(1), 0);
+        assert_eq!(total_ways_to_sum(2), 1);
+        assert_eq!(total_ways_to_sum_ii(0, &[1, 2]), 1);
+        assert_eq!(total_ways_to_sum_ii(3, &[2]), 0);
+        // a part of 0 would count the same sums again and again
+        assert_eq!(total_ways_to_sum_ii(5, &[0, 1]), 1);
+    }
+
+    #[test]
+    fn spirals() {
+        assert!(spiralize_matrix(&[]).is_empty());
+        assert!(spiralize_matrix(&[vec![]]).is_empty());
+        assert_eq!(spiralize_matrix(&[vec![5]]), [5]);
+        assert_eq!(spiralize_matrix(&[vec![1, 2, 3]]), [1, 2, 3]);
+        assert_eq!(spiralize_matrix(&[vec![1], vec![2], vec![3]]), [1, 2, 3]);
+        assert_eq!(
+            spiralize_matrix(&[vec![1, 2, 3], vec![4, 5, 6]]),
+            [1, 2, 3, 6, 5, 4]
+        );
+    }
+
+    #[test]
+    fn jumping_games() {
+        assert!(array_jumping_game(&[0]));
+        assert!(!array_jumping_game(&[0, 1]));
+        assert_eq!(array_jumping_game_ii(&[0]), 0);
+        assert_eq!(array_jumping_game_ii(&[1]), 0);
+        // unreachable ends are answered with 0
+        assert_eq!(array_jumping_game_ii(&[0, 1]), 0);
+        assert_eq!(array_jumping_game_ii(&[1, 0, 1]), 0);
+    }
+
+    #[test]
+    fn intervals() {
+        assert!(merge_overlapping_intervals(&[]).is_empty());
+        assert_eq!(merge_overlapping_intervals(&[[3, 4]]), [[3, 4]]);
+        assert_eq!(merge_overlapping_intervals(&[[2, 3], [1, 2]]), [[1, 3]]);
+    }
+
+    #[test]
+    fn ip_addresses() {
+        assert!(generate_ip_addresses("").is_empty());
+        assert!(generate_ip_addresses("123").is_empty());
+        assert!(generate_ip_addresses("1111111111111").is_empty());
+        assert_eq!(generate_ip_addresses("0000"), ["0.0.0.0"]);
+    }
+
+    #[test]
+    fn stock_traders() {
+        assert_eq!(stock_trader(1, &[]), 0);
+        assert_eq!(stock_trader(1, &[5]), 0);
+        assert_eq!(stock_trader(2, &[9, 7, 4, 1]), 0);
+        assert_eq!(stock_trader(0, &[1, 9]), 0);
+        assert_eq!(stock_trader(usize::MAX, &[1, 2, 1, 2]), 2);
+    }
+
+    #[test]
+    fn triangles_and_grids() {
+        assert_eq!(minimum_path_sum_in_a_triangle(&[]), 0);
+        assert_eq!(minimum_path_sum_in_a_triangle(&[vec![7]]), 7);
+
+        assert_eq!(unique_paths_in_a_grid_i(1, 1), 1);
+        assert_eq!(unique_paths_in_a_grid_i(1, 5), 1);
+        assert_eq!(unique_paths_in_a_grid_ii(&[vec![0]]), 1);
+        assert_eq!(unique_paths_in_a_grid_ii(&[vec![1, 0], vec![0, 0]]), 0);
+        assert_eq!(unique_paths_in_a_grid_ii(&[vec![0, 1], vec![1, 0]]), 0);
+
+        assert_eq!(shortest_path_in_a_grid(&[vec![0]]), "");
+        assert_eq!(shortest_path_in_a_grid(&[vec![1]]), "");
+        assert_eq!(shortest_path_in_a_grid(&[vec![0, 1], vec![1, 0]]), "");
+        assert_eq!(
+            shortest_path_in_a_grid(&[vec![0, 0, 0], vec![1, 1, 0], vec![0, 0, 0]]),
+            "RRDD"
+        );
+    }
+
+    #[test]
+    fn parentheses() {
+        assert_eq!(sanitize_parentheses(""), [""]);
+        assert_eq!(sanitize_parentheses(")("), [""]);
+        assert_eq!(sanitize_parentheses("a"), ["a"]);
+        assert_eq!(sanitize_parentheses("(a"), ["a"]);
+    }
+
+    #[test]
+    fn math_expressions() {
+        assert_eq!(find_all_valid_math_expressions("0", 0), ["0"]);
+        assert!(find_all_valid_math_expressions("12", 100).is_empty());
+        let mut expressions = find_all_valid_math_expressions("105", 5);
+        expressions.sort();
+        assert_eq!(expressions, ["1*0+5", "10-5"]);
+    }
+
+    #[test]
+    fn hamming_codes() {
+        assert_eq!(hamming_decode(""), 0);
+        for value in [0, 1, 2, 3, 8, 255, 1 << 20, 123_456_789] {
+            let encoded = hamming_encode(value);
+            assert_eq!(hamming_decode(&encoded), value, "{encoded}");
+            // flipping any single bit is corrected
+            for flipped in 0..encoded.len() {
+                let mut bits = encoded.clone().into_bytes();
+                bits[flipped] ^= b'0' ^ b'1';
+                let bits = String::from_utf8(bits).unwrap();
+                assert_eq!(hamming_decode(&bits), value, "{bits}");
+            }
+        }
+    }
+
+    #[test]
+    fn two_colorings() {
+        assert!(proper_two_coloring(0, &[]).is_empty());
+        assert_eq!(proper_two_coloring(1, &[]), [0]);
+        assert_eq!(proper_two_coloring(3, &[]), [0, 0, 0]);
+        assert_eq!(proper_two_coloring(2, &[[1, 0]]), [0, 1]);
+        assert!(proper_two_coloring(2, &[[0, 2]]).is_empty());
+        assert!(proper_two_coloring(1, &[[0, 0]]).is_empty());
+    }
+
+    #[test]
+    fn run_lengths() {
+        assert_eq!(rle_compression(""), "");
+        assert_eq!(rle_compression("a"), "1a");
+        assert_eq!(rle_compression("aaaaaaaaaa"), "9a1a");
+    }
+
+    #[test]
+    fn lz_decompression_edge_cases() {
+        assert_eq!(lz_decompression("").as_deref(), Some(""));
+        assert_eq!(lz_decompression("1a").as_deref(), Some("a"));
+        assert_eq!(lz_decompression("1a91").as_deref(), Some("aaaaaaaaaa"));
+        // back references of offset 0 or before the start
+        assert_eq!(lz_decompression("1a10"), None);
+        assert_eq!(lz_decompression("1a12"), None);
+        assert_eq!(lz_decompression("011"), None);
+        assert_eq!(lz_decompression("x"), None);
+    }
+
+    #[test]
+    fn lz_round_trips() {
+        assert_eq!(lz_compression(""), "");
+        assert_eq!(lz_compression("a"), "1a");
+        for plain in [
+            "aaaaaaaaaaaaaaaaaaaa",
+            "abcdefghijklmnopqrst",
+            "mississippi mississippi",
+            "aaabbaaababababaabb",
+        ] {
+            let compressed = lz_compression(plain);
+            assert_eq!(lz_decompression(&compressed).as_deref(), Some(plain));
+        }
+    }
+
+    #[test]
+    fn ciphers() {
+        assert_eq!(caesar_cipher("HELLO WORLD", 0), "HELLO WORLD");
+        assert_eq!(caesar_cipher("HELLO WORLD", 26), "HELLO WORLD");
+        assert_eq!(caesar_cipher("", 3), "");
+
+        assert_eq!(vigenere_cipher("", "KEY").as_deref(), Some(""));
+        assert_eq!(vigenere_cipher("ABC", "A").as_deref(), Some("ABC"));
+        assert_eq!(vigenere_cipher("ABC", ""), None);
+        assert_eq!(vigenere_cipher("ABC", "key"), None);
+    }
+}
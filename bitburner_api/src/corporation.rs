@@ -0,0 +1,646 @@
+//! Typed access to the corporation API at `ns.corporation()`.
+//!
+//! The names of industries, materials, positions, unlocks, upgrades,
+//! researches and states are enums, like [`CorpMaterialName`], and the
+//! structured return values are deserialized into the structs of this
+//! module. `nextUpdate` resolves with the state that was just processed, so
+//! a loop can act once per state cycle:
+//!
+//! ```ignore
+//! use bitburner_api::{CityName, CorpMaterialName, CorpStateName};
+//!
+//! let corp = ns.corporation();
+//! loop {
+//!     if corp.nextUpdate().await? == CorpStateName::Start {
+//!         for division in corp.get_corporation()?.divisions {
+//!             for city in corp.get_division(&division)?.cities {
+//!                 let plants = corp.get_material(&division, city, CorpMaterialName::Plants)?;
+//!                 if plants.stored > 0.0 {
+//!                     corp.sell_material(&division, city, CorpMaterialName::Plants, "MAX", "MP")?;
+//!                 }
+//!             }
+//!         }
+//!     }
+//! }
+//! ```
+
+use std::{collections::HashMap, fmt};
+
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsValue;
+
+use crate::{
+    CityName, CorpEmployeePosition, CorpIndustryName, CorpMaterialName, CorpResearchName,
+    CorpSmartSupplyOption, CorpStateName, CorpUnlockName, CorpUpgradeName, Corporation, OfficeAPI,
+};
+
+/// General info about the corporation, mirrors the `CorporationInfo`
+/// interface of the definitions.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CorporationInfo {
+    pub name: String,
+    pub funds: f64,
+    /// Revenue per second this cycle
+    pub revenue: f64,
+    /// Expenses per second this cycle
+    pub expenses: f64,
+    pub public: bool,
+    pub total_shares: f64,
+    /// Shares owned by the CEO
+    pub num_shares: f64,
+    pub share_sale_cooldown: f64,
+    /// Shares owned by private investors
+    pub investor_shares: f64,
+    /// Shares owned by public traders
+    pub issued_shares: f64,
+    pub issue_new_shares_cooldown: f64,
+    pub share_price: f64,
+    /// Fraction of profits issued as dividends
+    pub dividend_rate: f64,
+    pub dividend_tax: f64,
+    /// Earnings per second as a shareholder this cycle
+    pub dividend_earnings: f64,
+    /// The state processed at the next transition
+    pub next_state: CorpStateName,
+    /// The state processed at the last transition
+    pub prev_state: CorpStateName,
+    pub divisions: Vec<String>,
+}
+
+/// Constants of the corporation mechanics, mirrors the `CorpConstants`
+/// interface of the definitions.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CorpConstants {
+    pub state_names: Vec<CorpStateName>,
+    pub employee_positions: Vec<CorpEmployeePosition>,
+    pub industry_names: Vec<CorpIndustryName>,
+    pub material_names: Vec<CorpMaterialName>,
+    pub unlock_names: Vec<CorpUnlockName>,
+    pub upgrade_names: Vec<CorpUpgradeName>,
+    /// Researches common to all industries
+    pub research_names_base: Vec<CorpResearchName>,
+    /// Researches only available to product industries
+    pub research_names_product_only: Vec<CorpResearchName>,
+    pub research_names: Vec<CorpResearchName>,
+    pub initial_shares: f64,
+    /// Shares sold between two price updates
+    pub shares_per_price_update: f64,
+    /// In game cycles of 200ms
+    pub issue_new_shares_cooldown: f64,
+    /// In game cycles of 200ms
+    pub sell_shares_cooldown: f64,
+    pub tea_cost_per_employee: f64,
+    pub game_cycles_per_market_cycle: f64,
+    pub game_cycles_per_corp_state_cycle: f64,
+    pub seconds_per_market_cycle: f64,
+    pub warehouse_initial_cost: f64,
+    pub warehouse_initial_size: f64,
+    pub warehouse_size_upgrade_cost_base: f64,
+    pub office_initial_cost: f64,
+    pub office_initial_size: f64,
+    pub office_size_upgrade_cost_base: f64,
+    pub bribe_threshold: f64,
+    pub bribe_amount_per_reputation: f64,
+    pub base_product_profit_mult: f64,
+    pub dividend_max_rate: f64,
+    pub employee_salary_multiplier: f64,
+    pub market_cycles_per_employee_raise: f64,
+    pub employee_raise_amount: f64,
+    /// Products a division supports without upgrades
+    pub max_products_base: f64,
+    /// Minimum decay of morale and energy
+    pub min_employee_decay: f64,
+    pub smart_supply_options: Vec<CorpSmartSupplyOption>,
+}
+
+/// A division of the corporation, mirrors the `Division` interface of the
+/// definitions.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Division {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub industry: CorpIndustryName,
+    pub awareness: f64,
+    pub popularity: f64,
+    pub production_mult: f64,
+    pub research_points: f64,
+    pub last_cycle_revenue: f64,
+    pub last_cycle_expenses: f64,
+    pub this_cycle_revenue: f64,
+    pub this_cycle_expenses: f64,
+    /// Times AdVert was bought
+    pub num_ad_verts: f64,
+    /// Cities the division expanded to
+    pub cities: Vec<CityName>,
+    pub products: Vec<String>,
+    pub makes_products: bool,
+    pub max_products: f64,
+}
+
+/// A sell amount or price, either a number or an expression like `"MAX"`
+/// or `"MP+5"`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SellValue {
+    Number(f64),
+    Expression(String),
+}
+
+impl fmt::Display for SellValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SellValue::Number(number) => write!(f, "{number}"),
+            SellValue::Expression(expression) => f.write_str(expression),
+        }
+    }
+}
+
+/// Stats of a product.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ProductStats {
+    pub quality: f64,
+    pub performance: f64,
+    pub durability: f64,
+    pub reliability: f64,
+    pub aesthetics: f64,
+    pub features: f64,
+}
+
+/// A product of a division in a city, mirrors the `Product` interface of
+/// the definitions.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Product {
+    pub name: String,
+    /// Only known with the "Market Research - Demand" unlock
+    pub demand: Option<f64>,
+    /// Only known with the "Market Data - Competition" unlock
+    pub competition: Option<f64>,
+    pub rating: f64,
+    /// Rating in this city
+    pub effective_rating: f64,
+    pub stats: ProductStats,
+    pub production_cost: f64,
+    pub desired_sell_price: SellValue,
+    pub desired_sell_amount: SellValue,
+    pub stored: f64,
+    /// Produced last cycle
+    pub production_amount: f64,
+    /// Sold last cycle
+    pub actual_sell_amount: f64,
+    /// Percentage of completion, from 0 to 100
+    pub development_progress: f64,
+    pub advertising_investment: f64,
+    pub design_investment: f64,
+    /// Warehouse space taken by a unit
+    pub size: f64,
+}
+
+/// An export order of a material, mirrors the `Export` interface of the
+/// definitions.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Export {
+    pub division: String,
+    pub city: CityName,
+    /// Amount or expression like `"(IPROD+IINV/10)*-1"`
+    pub amount: String,
+}
+
+/// A material in a warehouse, mirrors the `Material` interface of the
+/// definitions.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Material {
+    pub name: CorpMaterialName,
+    pub stored: f64,
+    pub quality: f64,
+    /// Only known with the "Market Research - Demand" unlock
+    pub demand: Option<f64>,
+    /// Only known with the "Market Data - Competition" unlock
+    pub competition: Option<f64>,
+    /// Produced last cycle
+    pub production_amount: f64,
+    /// Sold last cycle
+    pub actual_sell_amount: f64,
+    pub market_price: f64,
+    pub desired_sell_price: SellValue,
+    pub desired_sell_amount: SellValue,
+    pub exports: Vec<Export>,
+}
+
+/// The warehouse of a division in a city, mirrors the `Warehouse` interface
+/// of the definitions.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Warehouse {
+    /// Size upgrades bought
+    pub level: f64,
+    pub city: CityName,
+    pub size: f64,
+    pub size_used: f64,
+    pub smart_supply_enabled: bool,
+}
+
+/// The office of a division in a city, mirrors the `Office` interface of
+/// the definitions.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Office {
+    pub city: CityName,
+    /// Maximum number of employees
+    pub size: f64,
+    pub max_energy: f64,
+    pub max_morale: f64,
+    pub num_employees: f64,
+    pub avg_energy: f64,
+    pub avg_morale: f64,
+    pub total_experience: f64,
+    pub employee_production_by_job: HashMap<CorpEmployeePosition, f64>,
+    /// Employees in each position
+    pub employee_jobs: HashMap<CorpEmployeePosition, f64>,
+}
+
+/// An investment offer, mirrors the `InvestmentOffer` interface of the
+/// definitions.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct InvestmentOffer {
+    pub funds: f64,
+    /// Shares given for the funds
+    pub shares: f64,
+    /// Funding round, up to 4
+    pub round: f64,
+}
+
+/// Weights of the product stats in its rating.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RatingWeights {
+    pub aesthetics: f64,
+    pub durability: f64,
+    pub features: f64,
+    pub quality: f64,
+    pub performance: f64,
+    pub reliability: f64,
+}
+
+/// The products of an industry, mirrors the `CorpProductData` interface of
+/// the definitions.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CorpProductData {
+    pub name: String,
+    /// Verb describing the creation of a product
+    pub verb: String,
+    pub desc: String,
+    pub rating_weights: RatingWeights,
+}
+
+/// Data of an industry, mirrors the `CorpIndustryData` interface of the
+/// definitions.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CorpIndustryData {
+    pub starting_cost: f64,
+    pub description: String,
+    pub product: Option<CorpProductData>,
+    pub recommend_starting: bool,
+    pub required_materials: HashMap<CorpMaterialName, f64>,
+    pub real_estate_factor: Option<f64>,
+    /// Affects quality
+    pub science_factor: Option<f64>,
+    pub hardware_factor: Option<f64>,
+    pub robot_factor: Option<f64>,
+    pub ai_core_factor: Option<f64>,
+    /// Affects sales
+    pub advertising_factor: Option<f64>,
+    #[serde(default)]
+    pub produced_materials: Vec<CorpMaterialName>,
+    pub makes_materials: bool,
+    pub makes_products: bool,
+}
+
+/// Data of a material, mirrors the `CorpMaterialConstantData` interface of
+/// the definitions.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CorpMaterialConstantData {
+    pub name: CorpMaterialName,
+    /// Warehouse space taken by a unit
+    pub size: f64,
+    pub demand_base: f64,
+    /// Minimum and maximum demand
+    pub demand_range: [f64; 2],
+    pub competition_base: f64,
+    /// Minimum and maximum competition
+    pub competition_range: [f64; 2],
+    pub base_cost: f64,
+    pub max_volatility: f64,
+    pub base_markup: f64,
+}
+
+impl_try_from_bindings!(
+    CorporationInfo,
+    CorpConstants,
+    Division,
+    Product,
+    Material,
+    Warehouse,
+    Office,
+    InvestmentOffer,
+    CorpIndustryData,
+    CorpMaterialConstantData,
+);
+
+impl Corporation {
+    /// The office functions, which the corporation inherits in the
+    /// definitions.
+    pub fn office(&self) -> &OfficeAPI {
+        self.as_ref()
+    }
+
+    pub fn get_corporation(&self) -> Result<CorporationInfo, JsValue> {
+        self.getCorporation()?.try_into()
+    }
+
+    pub fn get_constants(&self) -> Result<CorpConstants, JsValue> {
+        self.getConstants()?.try_into()
+    }
+
+    pub fn get_investment_offer(&self) -> Result<InvestmentOffer, JsValue> {
+        self.getInvestmentOffer()?.try_into()
+    }
+
+    pub fn get_industry_data(
+        &self,
+        industry: CorpIndustryName,
+    ) -> Result<CorpIndustryData, JsValue> {
+        self.getIndustryData(industry)?.try_into()
+    }
+
+    pub fn get_material_data(
+        &self,
+        material: CorpMaterialName,
+    ) -> Result<CorpMaterialConstantData, JsValue> {
+        self.getMaterialData(material)?.try_into()
+    }
+
+    pub fn get_division(&self, division: &str) -> Result<Division, JsValue> {
+        self.getDivision(division)?.try_into()
+    }
+
+    /// All the divisions of the corporation.
+    pub fn get_divisions(&self) -> Result<Vec<Division>, JsValue> {
+        self.get_corporation()?
+            .divisions
+            .iter()
+            .map(|division| self.get_division(division))
+            .collect()
+    }
+
+    pub fn has_unlock(&self, unlock: CorpUnlockName) -> Result<bool, JsValue> {
+        self.hasUnlock(unlock.as_str())
+    }
+
+    pub fn get_unlock_cost(&self, unlock: CorpUnlockName) -> Result<f64, JsValue> {
+        self.getUnlockCost(unlock.as_str())
+    }
+
+    pub fn purchase_unlock(&self, unlock: CorpUnlockName) -> Result<(), JsValue> {
+        self.purchaseUnlock(unlock.as_str())
+    }
+
+    pub fn get_upgrade_level(&self, upgrade: CorpUpgradeName) -> Result<u32, JsValue> {
+        Ok(self.getUpgradeLevel(upgrade.as_str())? as u32)
+    }
+
+    pub fn get_upgrade_level_cost(&self, upgrade: CorpUpgradeName) -> Result<f64, JsValue> {
+        self.getUpgradeLevelCost(upgrade.as_str())
+    }
+
+    pub fn level_upgrade(&self, upgrade: CorpUpgradeName) -> Result<(), JsValue> {
+        self.levelUpgrade(upgrade.as_str())
+    }
+
+    pub fn get_office(&self, division: &str, city: CityName) -> Result<Office, JsValue> {
+        self.office().getOffice(division, city)?.try_into()
+    }
+
+    pub fn hire_employee(
+        &self,
+        division: &str,
+        city: CityName,
+        position: CorpEmployeePosition,
+    ) -> Result<bool, JsValue> {
+        self.office().hireEmployee(division, city, Some(position))
+    }
+
+    /// Sets the number of employees automatically assigned to `position`.
+    pub fn set_auto_job_assignment(
+        &self,
+        division: &str,
+        city: CityName,
+        position: CorpEmployeePosition,
+        amount: u32,
+    ) -> Result<bool, JsValue> {
+        self.office()
+            .setAutoJobAssignment(division, city, position.as_str(), amount.into())
+    }
+
+    pub fn research(&self, division: &str, research: CorpResearchName) -> Result<(), JsValue> {
+        self.office().research(division, research.as_str())
+    }
+
+    pub fn has_researched(
+        &self,
+        division: &str,
+        research: CorpResearchName,
+    ) -> Result<bool, JsValue> {
+        self.office().hasResearched(division, research.as_str())
+    }
+
+    /// Cost of a research in research points.
+    pub fn get_research_cost(
+        &self,
+        division: &str,
+        research: CorpResearchName,
+    ) -> Result<f64, JsValue> {
+        self.office().getResearchCost(division, research.as_str())
+    }
+
+    pub fn get_warehouse(&self, division: &str, city: CityName) -> Result<Warehouse, JsValue> {
+        self.getWarehouse(division, city)?.try_into()
+    }
+
+    pub fn get_material(
+        &self,
+        division: &str,
+        city: CityName,
+        material: CorpMaterialName,
+    ) -> Result<Material, JsValue> {
+        self.getMaterial(division, city, material.as_str())?
+            .try_into()
+    }
+
+    pub fn get_product(
+        &self,
+        division: &str,
+        city: CityName,
+        product: &str,
+    ) -> Result<Product, JsValue> {
+        self.getProduct(division, city, product)?.try_into()
+    }
+
+    /// Buys `amount` units of a material per second.
+    pub fn buy_material(
+        &self,
+        division: &str,
+        city: CityName,
+        material: CorpMaterialName,
+        amount: f64,
+    ) -> Result<(), JsValue> {
+        self.buyMaterial(division, city, material.as_str(), amount)
+    }
+
+    /// Buys `amount` units of a material at once.
+    pub fn bulk_purchase(
+        &self,
+        division: &str,
+        city: CityName,
+        material: CorpMaterialName,
+        amount: f64,
+    ) -> Result<(), JsValue> {
+        self.bulkPurchase(division, city, material.as_str(), amount)
+    }
+
+    /// Sells a material, `amount` and `price` accept the expressions of the
+    /// game like `"MAX"` or `"MP*1.2"`.
+    pub fn sell_material(
+        &self,
+        division: &str,
+        city: CityName,
+        material: CorpMaterialName,
+        amount: &str,
+        price: &str,
+    ) -> Result<(), JsValue> {
+        self.sellMaterial(division, city, material.as_str(), amount, price)
+    }
+
+    pub fn limit_material_production(
+        &self,
+        division: &str,
+        city: CityName,
+        material: CorpMaterialName,
+        amount: f64,
+    ) -> Result<(), JsValue> {
+        self.limitMaterialProduction(division, city, material.as_str(), amount)
+    }
+
+    pub fn set_smart_supply_option(
+        &self,
+        division: &str,
+        city: CityName,
+        material: CorpMaterialName,
+        option: CorpSmartSupplyOption,
+    ) -> Result<(), JsValue> {
+        self.setSmartSupplyOption(division, city, material.as_str(), option)
+    }
+
+    pub fn set_material_market_ta1(
+        &self,
+        division: &str,
+        city: CityName,
+        material: CorpMaterialName,
+        on: bool,
+    ) -> Result<(), JsValue> {
+        self.setMaterialMarketTA1(division, city, material.as_str(), on)
+    }
+
+    pub fn set_material_market_ta2(
+        &self,
+        division: &str,
+        city: CityName,
+        material: CorpMaterialName,
+        on: bool,
+    ) -> Result<(), JsValue> {
+        self.setMaterialMarketTA2(division, city, material.as_str(), on)
+    }
+
+    /// Exports a material between two warehouses, `amount` accepts the
+    /// expressions of the game like `"(IPROD+IINV/10)*-1"`.
+    pub fn export_material(
+        &self,
+        from: (&str, CityName),
+        to: (&str, CityName),
+        material: CorpMaterialName,
+        amount: &str,
+    ) -> Result<(), JsValue> {
+        self.exportMaterial(
+            from.0,
+            from.1,
+            to.0,
+            to.1,
+            material.as_str(),
+            &amount.into(),
+        )
+    }
+
+    pub fn cancel_export_material(
+        &self,
+        from: (&str, CityName),
+        to: (&str, CityName),
+        material: CorpMaterialName,
+    ) -> Result<(), JsValue> {
+        self.cancelExportMaterial(from.0, from.1, to.0, to.1, material.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{enums::tests::round_trips, ParseEnumError};
+
+    #[test]
+    fn corp_name_enums_round_trip() {
+        round_trips(&CorpEmployeePosition::ALL);
+        round_trips(&CorpIndustryName::ALL);
+        round_trips(&CorpMaterialName::ALL);
+        round_trips(&CorpResearchName::ALL);
+        round_trips(&CorpSmartSupplyOption::ALL);
+        round_trips(&CorpStateName::ALL);
+        round_trips(&CorpUnlockName::ALL);
+        round_trips(&CorpUpgradeName::ALL);
+    }
+
+    #[test]
+    fn corp_names_match_the_game_strings() {
+        assert_eq!(
+            "Research & Development".parse(),
+            Ok(CorpEmployeePosition::ResearchDevelopment)
+        );
+        assert_eq!("START".parse(), Ok(CorpStateName::Start));
+        assert_eq!("none".parse(), Ok(CorpSmartSupplyOption::None));
+        assert_eq!(
+            serde_json::from_str::<CorpResearchName>(r#""Hi-Tech R&D Laboratory""#).unwrap(),
+            CorpResearchName::HiTechRDLaboratory
+        );
+        assert_eq!(
+            serde_json::to_string(&CorpUnlockName::MarketResearchDemand).unwrap(),
+            r#""Market Research - Demand""#
+        );
+    }
+
+    #[test]
+    fn unknown_corp_names_are_rejected() {
+        assert_eq!(
+            "Start".parse::<CorpStateName>(),
+            Err(ParseEnumError {
+                enum_name: "CorpStateName",
+                value: "Start".to_owned(),
+            })
+        );
+        assert!(serde_json::from_str::<CorpMaterialName>(r#""Gold""#).is_err());
+    }
+}
@@ -0,0 +1,153 @@
+use std::{error::Error, fmt};
+
+/// Error parsing a string that is not a value of the game enum `enum_name`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseEnumError {
+    pub enum_name: &'static str,
+    pub value: String,
+}
+
+impl fmt::Display for ParseEnumError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} is not a valid {}", self.value, self.enum_name)
+    }
+}
+
+impl Error for ParseEnumError {}
+
+/// Implements `Display`, `FromStr` and serde's traits for a wasm-bindgen
+/// string enum in terms of its `ALL` and `as_str` items, so that the enums
+/// read and write the same strings as the game.
+macro_rules! string_enum {
+    ($name:ident) => {
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl ::std::str::FromStr for $name {
+            type Err = $crate::ParseEnumError;
+
+            fn from_str(value: &str) -> Result<Self, Self::Err> {
+                $name::ALL
+                    .into_iter()
+                    .find(|variant| variant.as_str() == value)
+                    .ok_or_else(|| $crate::ParseEnumError {
+                        enum_name: stringify!($name),
+                        value: value.to_owned(),
+                    })
+            }
+        }
+
+        impl ::serde::Serialize for $name {
+            fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> ::serde::Deserialize<'de> for $name {
+            fn deserialize<D: ::serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Self, D::Error> {
+                let value = <::std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+                value.parse().map_err(::serde::de::Error::custom)
+            }
+        }
+    };
+}
+
+/// Implements `TryFrom` the opaque [`bindings`](crate::bindings) type of the
+/// same name for structs mirroring an interface of the definitions, reading
+/// its properties with serde-wasm-bindgen.
+macro_rules! impl_try_from_bindings {
+    ($($ty:ident),* $(,)?) => {$(
+        impl TryFrom<$crate::bindings::$ty> for $ty {
+            type Error = ::wasm_bindgen::JsValue;
+
+            fn try_from(value: $crate::bindings::$ty) -> Result<Self, Self::Error> {
+                Ok(::serde_wasm_bindgen::from_value(value.into())?)
+            }
+        }
+    )*};
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::{fmt::Debug, str::FromStr};
+
+    use serde::{de::DeserializeOwned, Serialize};
+
+    use super::*;
+    use crate::{
+        CityName, CompanyName, CrimeType, FactionWorkType, GymType, JobField, JobName,
+        LocationName, OrderType, PositionType, ToastVariant, UniversityClassType,
+    };
+
+    /// Checks that every variant reads back what it writes, as a string and
+    /// through serde.
+    pub(crate) fn round_trips<T>(all: &[T])
+    where
+        T: Debug + PartialEq + fmt::Display + FromStr<Err = ParseEnumError>,
+        T: Serialize + DeserializeOwned,
+    {
+        for variant in all {
+            assert_eq!(variant.to_string().parse::<T>().as_ref(), Ok(variant));
+            let json = serde_json::to_string(variant).unwrap();
+            assert_eq!(json, format!("{:?}", variant.to_string()));
+            assert_eq!(&serde_json::from_str::<T>(&json).unwrap(), variant);
+        }
+    }
+
+    #[test]
+    fn game_enums_round_trip() {
+        round_trips(&PositionType::ALL);
+        round_trips(&OrderType::ALL);
+        round_trips(&ToastVariant::ALL);
+        round_trips(&CrimeType::ALL);
+        round_trips(&FactionWorkType::ALL);
+        round_trips(&UniversityClassType::ALL);
+        round_trips(&GymType::ALL);
+        round_trips(&JobName::ALL);
+        round_trips(&JobField::ALL);
+        round_trips(&CityName::ALL);
+        round_trips(&LocationName::ALL);
+        round_trips(&CompanyName::ALL);
+    }
+
+    #[test]
+    fn variants_write_the_game_strings() {
+        assert_eq!(CityName::Sector12.to_string(), "Sector-12");
+        assert_eq!(GymType::Strength.to_string(), "str");
+        assert_eq!(PositionType::Long.to_string(), "L");
+        assert_eq!(
+            JobName::Software0.to_string(),
+            "Software Engineering Intern"
+        );
+        assert_eq!(
+            serde_json::to_string(&CompanyName::BachmanAndAssociates).unwrap(),
+            r#""Bachman & Associates""#
+        );
+    }
+
+    #[test]
+    fn unknown_values_are_rejected() {
+        let err = "Sector 12".parse::<CityName>().unwrap_err();
+        assert_eq!(
+            err,
+            ParseEnumError {
+                enum_name: "CityName",
+                value: "Sector 12".to_owned(),
+            }
+        );
+        assert_eq!(err.to_string(), r#""Sector 12" is not a valid CityName"#);
+        // values are case sensitive, like in the game
+        assert!("mug".parse::<CrimeType>().is_err());
+
+        let err = serde_json::from_str::<GymType>(r#""strength""#).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains(r#""strength" is not a valid GymType"#));
+        assert!(serde_json::from_str::<GymType>("1").is_err());
+    }
+}
@@ -0,0 +1,226 @@
+//! Typed access to Formulas.exe at `ns.formulas()`, and BitNode
+//! multipliers.
+//!
+//! The hacking formulas are also reimplemented in [`native`], which needs
+//! neither Formulas.exe nor its RAM. Both take the same typed structs, so the
+//! native version can be checked against the game:
+//!
+//! ```ignore
+//! use bitburner_api::formulas::native;
+//!
+//! let hacking = ns.formulas().hacking();
+//! let server = ns.get_server(Some("n00dles"))?;
+//! let player = ns.get_player()?;
+//! let bitnode = ns.get_bitnode_multipliers()?;
+//! let expected = hacking.hack_percent(&server, &player.person)?;
+//! let actual = native::hack_percent(&server, &player.person, &bitnode);
+//! assert!((expected - actual).abs() < 1e-9);
+//! ```
+
+pub mod native;
+
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsValue;
+
+use crate::{bindings, Formulas, HackingFormulas, Person, Player, Server, NS};
+
+/// Multipliers of the current BitNode relative to BitNode-1, mirrors the
+/// `BitNodeMultipliers` interface of the definitions. The default is
+/// BitNode-1.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct BitNodeMultipliers {
+    pub agility_level_multiplier: f64,
+    pub augmentation_money_cost: f64,
+    pub augmentation_rep_cost: f64,
+    pub bladeburner_rank: f64,
+    pub bladeburner_skill_cost: f64,
+    pub charisma_level_multiplier: f64,
+    pub class_gym_exp_gain: f64,
+    pub coding_contract_money: f64,
+    pub company_work_exp_gain: f64,
+    pub company_work_money: f64,
+    pub corporation_divisions: f64,
+    pub corporation_softcap: f64,
+    pub corporation_valuation: f64,
+    pub crime_exp_gain: f64,
+    pub crime_money: f64,
+    /// Augmentations needed to join Daedalus
+    pub daedalus_augs_requirement: f64,
+    pub defense_level_multiplier: f64,
+    pub dexterity_level_multiplier: f64,
+    pub faction_passive_rep_gain: f64,
+    pub faction_work_exp_gain: f64,
+    pub faction_work_rep_gain: f64,
+    pub four_sigma_market_data_api_cost: f64,
+    pub four_sigma_market_data_cost: f64,
+    pub gang_softcap: f64,
+    pub hack_exp_gain: f64,
+    pub hacking_level_multiplier: f64,
+    pub hacknet_node_money: f64,
+    pub home_computer_ram_cost: f64,
+    pub infiltration_money: f64,
+    pub infiltration_rep: f64,
+    pub manual_hack_money: f64,
+    pub purchased_server_cost: f64,
+    pub purchased_server_limit: f64,
+    pub purchased_server_max_ram: f64,
+    pub purchased_server_softcap: f64,
+    pub rep_to_donate_to_faction: f64,
+    /// Money stolen by scripts
+    pub script_hack_money: f64,
+    /// Money actually gained from the money stolen by scripts
+    pub script_hack_money_gain: f64,
+    pub server_growth_rate: f64,
+    pub server_max_money: f64,
+    pub server_starting_money: f64,
+    pub server_starting_security: f64,
+    pub server_weaken_rate: f64,
+    pub strength_level_multiplier: f64,
+    pub staneks_gift_power_multiplier: f64,
+    /// Added to the size of Stanek's Gift
+    pub staneks_gift_extra_size: f64,
+    pub world_daemon_difficulty: f64,
+}
+
+impl Default for BitNodeMultipliers {
+    fn default() -> Self {
+        BitNodeMultipliers {
+            agility_level_multiplier: 1.0,
+            augmentation_money_cost: 1.0,
+            augmentation_rep_cost: 1.0,
+            bladeburner_rank: 1.0,
+            bladeburner_skill_cost: 1.0,
+            charisma_level_multiplier: 1.0,
+            class_gym_exp_gain: 1.0,
+            coding_contract_money: 1.0,
+            company_work_exp_gain: 1.0,
+            company_work_money: 1.0,
+            corporation_divisions: 1.0,
+            corporation_softcap: 1.0,
+            corporation_valuation: 1.0,
+            crime_exp_gain: 1.0,
+            crime_money: 1.0,
+            daedalus_augs_requirement: 30.0,
+            defense_level_multiplier: 1.0,
+            dexterity_level_multiplier: 1.0,
+            faction_passive_rep_gain: 1.0,
+            faction_work_exp_gain: 1.0,
+            faction_work_rep_gain: 1.0,
+            four_sigma_market_data_api_cost: 1.0,
+            four_sigma_market_data_cost: 1.0,
+            gang_softcap: 1.0,
+            hack_exp_gain: 1.0,
+            hacking_level_multiplier: 1.0,
+            hacknet_node_money: 1.0,
+            home_computer_ram_cost: 1.0,
+            infiltration_money: 1.0,
+            infiltration_rep: 1.0,
+            manual_hack_money: 1.0,
+            purchased_server_cost: 1.0,
+            purchased_server_limit: 1.0,
+            purchased_server_max_ram: 1.0,
+            purchased_server_softcap: 1.0,
+            rep_to_donate_to_faction: 1.0,
+            script_hack_money: 1.0,
+            script_hack_money_gain: 1.0,
+            server_growth_rate: 1.0,
+            server_max_money: 1.0,
+            server_starting_money: 1.0,
+            server_starting_security: 1.0,
+            server_weaken_rate: 1.0,
+            strength_level_multiplier: 1.0,
+            staneks_gift_power_multiplier: 1.0,
+            staneks_gift_extra_size: 0.0,
+            world_daemon_difficulty: 1.0,
+        }
+    }
+}
+
+impl TryFrom<bindings::BitNodeMultipliers> for BitNodeMultipliers {
+    type Error = JsValue;
+
+    fn try_from(multipliers: bindings::BitNodeMultipliers) -> Result<Self, Self::Error> {
+        Ok(serde_wasm_bindgen::from_value(multipliers.into())?)
+    }
+}
+
+impl NS {
+    /// Multipliers of the current BitNode, needs to be in BitNode 5 or to
+    /// own its source file.
+    pub fn get_bitnode_multipliers(&self) -> Result<BitNodeMultipliers, JsValue> {
+        self.getBitNodeMultipliers(None, None)?.try_into()
+    }
+}
+
+impl Formulas {
+    pub fn mock_server(&self) -> Result<Server, JsValue> {
+        self.mockServer()?.try_into()
+    }
+
+    pub fn mock_player(&self) -> Result<Player, JsValue> {
+        self.mockPlayer()?.try_into()
+    }
+
+    pub fn mock_person(&self) -> Result<Person, JsValue> {
+        self.mockPerson()?.try_into()
+    }
+}
+
+/// The hacking formulas on the typed structs, see [`native`] for their
+/// reimplementation.
+impl HackingFormulas {
+    pub fn hack_chance(&self, server: &Server, person: &Person) -> Result<f64, JsValue> {
+        self.hackChance(&server.try_into()?, &person.try_into()?)
+    }
+
+    pub fn hack_exp(&self, server: &Server, person: &Person) -> Result<f64, JsValue> {
+        self.hackExp(&server.try_into()?, &person.try_into()?)
+    }
+
+    pub fn hack_percent(&self, server: &Server, person: &Person) -> Result<f64, JsValue> {
+        self.hackPercent(&server.try_into()?, &person.try_into()?)
+    }
+
+    pub fn grow_percent(
+        &self,
+        server: &Server,
+        threads: f64,
+        person: &Person,
+        cores: u32,
+    ) -> Result<f64, JsValue> {
+        self.growPercent(
+            &server.try_into()?,
+            threads,
+            &person.try_into()?,
+            Some(cores.into()),
+        )
+    }
+
+    pub fn grow_threads(
+        &self,
+        server: &Server,
+        person: &Person,
+        target_money: f64,
+        cores: u32,
+    ) -> Result<f64, JsValue> {
+        self.growThreads(
+            &server.try_into()?,
+            &person.try_into()?,
+            target_money,
+            Some(cores.into()),
+        )
+    }
+
+    pub fn hack_time(&self, server: &Server, person: &Person) -> Result<f64, JsValue> {
+        self.hackTime(&server.try_into()?, &person.try_into()?)
+    }
+
+    pub fn grow_time(&self, server: &Server, person: &Person) -> Result<f64, JsValue> {
+        self.growTime(&server.try_into()?, &person.try_into()?)
+    }
+
+    pub fn weaken_time(&self, server: &Server, person: &Person) -> Result<f64, JsValue> {
+        self.weakenTime(&server.try_into()?, &person.try_into()?)
+    }
+}
@@ -0,0 +1,318 @@
+//! The hacking formulas of the game reimplemented in Rust, so planners run
+//! natively and without Formulas.exe.
+//!
+//! They mirror `HackingFormulas`, taking the BitNode multipliers where the
+//! game applies them:
+//!
+//! ```
+//! use bitburner_api::formulas::{native, BitNodeMultipliers};
+//! use bitburner_api::{Person, Server};
+//!
+//! let server = Server {
+//!     hostname: "n00dles".to_owned(),
+//!     has_admin_rights: true,
+//!     base_difficulty: Some(1.0),
+//!     hack_difficulty: Some(1.0),
+//!     min_difficulty: Some(1.0),
+//!     required_hacking_skill: Some(1.0),
+//!     money_available: Some(70e3),
+//!     money_max: Some(1.75e6),
+//!     server_growth: Some(3000.0),
+//!     ..Server::default()
+//! };
+//! let person = Person::default();
+//! let bitnode = BitNodeMultipliers::default();
+//!
+//! let chance = native::hack_chance(&server, &person);
+//! assert!(chance > 0.4 && chance < 0.5);
+//! let hack_time = native::hack_time(&server, &person);
+//! assert_eq!(native::weaken_time(&server, &person), 4.0 * hack_time);
+//!
+//! // the fewest threads growing the server back to its maximum
+//! let threads = native::grow_threads(&server, &person, 1.75e6, 1, &bitnode);
+//! let grown = |threads: f64| {
+//!     (70e3 + threads) * native::grow_percent(&server, threads, &person, 1, &bitnode)
+//! };
+//! assert!(grown(threads) >= 1.75e6);
+//! assert!(grown(threads - 1.0) < 1.75e6);
+//! ```
+
+use crate::{formulas::BitNodeMultipliers, Person, Server};
+
+/// Growth rate of a thread on a server without security.
+const SERVER_BASE_GROWTH_INCR: f64 = 0.03;
+
+/// Upper bound of the growth rate of a thread, as a logarithm.
+fn server_max_growth_log() -> f64 {
+    0.0035f64.ln_1p()
+}
+
+/// Bonus intelligence gives to an action, `weight` being how much it counts.
+pub fn intelligence_bonus(intelligence: f64, weight: f64) -> f64 {
+    1.0 + weight * intelligence.powf(0.8) / 600.0
+}
+
+/// Chance of a hack succeeding, from 0 to 1.
+pub fn hack_chance(server: &Server, person: &Person) -> f64 {
+    let hack_difficulty = server.hack_difficulty.unwrap_or(100.0);
+    let required_skill = server.required_hacking_skill.unwrap_or(1e9);
+    if !server.has_admin_rights || hack_difficulty >= 100.0 {
+        return 0.0;
+    }
+    let difficulty_mult = (100.0 - hack_difficulty) / 100.0;
+    let skill_mult = (1.75 * person.skills.hacking).max(1.0);
+    let skill_chance = (skill_mult - required_skill) / skill_mult;
+    let chance = skill_chance
+        * difficulty_mult
+        * person.mults.hacking_chance
+        * intelligence_bonus(person.skills.intelligence, 1.0);
+    chance.clamp(0.0, 1.0)
+}
+
+/// Hacking experience gained by a thread of hack, grow or weaken.
+pub fn hack_exp(server: &Server, person: &Person, bitnode: &BitNodeMultipliers) -> f64 {
+    match server.base_difficulty {
+        Some(base_difficulty) if base_difficulty != 0.0 => {
+            (3.0 + base_difficulty * 0.3) * person.mults.hacking_exp * bitnode.hack_exp_gain
+        }
+        _ => 0.0,
+    }
+}
+
+/// Fraction of the server's money a thread of hack steals, from 0 to 1.
+pub fn hack_percent(server: &Server, person: &Person, bitnode: &BitNodeMultipliers) -> f64 {
+    let hack_difficulty = server.hack_difficulty.unwrap_or(100.0);
+    if hack_difficulty >= 100.0 {
+        return 0.0;
+    }
+    let required_skill = server.required_hacking_skill.unwrap_or(1e9);
+    let difficulty_mult = (100.0 - hack_difficulty) / 100.0;
+    let skill_mult = (person.skills.hacking - (required_skill - 1.0)) / person.skills.hacking;
+    let percent =
+        difficulty_mult * skill_mult * person.mults.hacking_money * bitnode.script_hack_money
+            / 240.0;
+    percent.clamp(0.0, 1.0)
+}
+
+/// Logarithm of the growth multiplier of `threads` threads of grow.
+fn grow_log(
+    server: &Server,
+    threads: f64,
+    person: &Person,
+    cores: u32,
+    bitnode: &BitNodeMultipliers,
+) -> f64 {
+    let Some(server_growth) = server.server_growth.filter(|&growth| growth != 0.0) else {
+        return f64::NEG_INFINITY;
+    };
+    let hack_difficulty = server.hack_difficulty.unwrap_or(100.0);
+    let growth_log = (SERVER_BASE_GROWTH_INCR / hack_difficulty)
+        .ln_1p()
+        .min(server_max_growth_log());
+    let core_bonus = 1.0 + (cores as f64 - 1.0) / 16.0;
+    growth_log * server_growth / 100.0
+        * bitnode.server_growth_rate
+        * person.mults.hacking_grow
+        * core_bonus
+        * threads.max(0.0)
+}
+
+/// Multiplier `threads` threads of grow apply to the server's money, which
+/// first gets a dollar per thread.
+pub fn grow_percent(
+    server: &Server,
+    threads: f64,
+    person: &Person,
+    cores: u32,
+    bitnode: &BitNodeMultipliers,
+) -> f64 {
+    if server.server_growth.is_none_or(|growth| growth == 0.0) {
+        return 0.0;
+    }
+    grow_log(server, threads, person, cores, bitnode).exp()
+}
+
+/// Threads of grow needed to bring the server's money up to `target_money`,
+/// capped at its maximum.
+pub fn grow_threads(
+    server: &Server,
+    person: &Person,
+    target_money: f64,
+    cores: u32,
+    bitnode: &BitNodeMultipliers,
+) -> f64 {
+    if server.server_growth.is_none_or(|growth| growth == 0.0) {
+        return f64::INFINITY;
+    }
+    let start_money = server.money_available.unwrap_or(0.0).max(0.0);
+    let target_money = target_money.min(server.money_max.unwrap_or(1.0));
+    if target_money <= start_money {
+        return 0.0;
+    }
+
+    // Newton's method on ln(start + x) + k * x = ln(target)
+    let k = grow_log(server, 1.0, person, cores, bitnode);
+    let mut x = (target_money - start_money)
+        / (1.0 + (target_money / 16.0 + start_money * 15.0 / 16.0) * k);
+    let mut diff;
+    loop {
+        let ox = start_money + x;
+        let next = (x - ox * (ox / target_money).ln()) / (1.0 + ox * k);
+        diff = next - x;
+        x = next;
+        if (-1.0..=1.0).contains(&diff) {
+            break;
+        }
+    }
+
+    let grows_enough = |threads: f64| target_money <= (start_money + threads) * (k * threads).exp();
+    let ceil = x.ceil();
+    if ceil - x > 0.999999 && grows_enough(ceil - 1.0) {
+        return ceil - 1.0;
+    }
+    if ceil >= x + diff.abs() + 0.000001 || grows_enough(ceil) {
+        return ceil;
+    }
+    ceil + 1.0
+}
+
+/// Milliseconds a hack takes.
+pub fn hack_time(server: &Server, person: &Person) -> f64 {
+    let (Some(hack_difficulty), Some(required_skill)) =
+        (server.hack_difficulty, server.required_hacking_skill)
+    else {
+        return f64::INFINITY;
+    };
+    let skill_factor =
+        (2.5 * required_skill * hack_difficulty + 500.0) / (person.skills.hacking + 50.0);
+    let speed = person.mults.hacking_speed * intelligence_bonus(person.skills.intelligence, 1.0);
+    5.0 * skill_factor / speed * 1000.0
+}
+
+/// Milliseconds a grow takes.
+pub fn grow_time(server: &Server, person: &Person) -> f64 {
+    3.2 * hack_time(server, person)
+}
+
+/// Milliseconds a weaken takes.
+pub fn weaken_time(server: &Server, person: &Person) -> f64 {
+    4.0 * hack_time(server, person)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn n00dles() -> Server {
+        Server {
+            hostname: "n00dles".to_owned(),
+            has_admin_rights: true,
+            base_difficulty: Some(1.0),
+            hack_difficulty: Some(1.0),
+            min_difficulty: Some(1.0),
+            required_hacking_skill: Some(1.0),
+            money_available: Some(70e3),
+            money_max: Some(1.75e6),
+            server_growth: Some(3000.0),
+            ..Server::default()
+        }
+    }
+
+    /// Money after `threads` threads of grow.
+    fn grown(server: &Server, threads: f64) -> f64 {
+        let start = server.money_available.unwrap_or(0.0);
+        let bitnode = BitNodeMultipliers::default();
+        (start + threads) * grow_percent(server, threads, &Person::default(), 1, &bitnode)
+    }
+
+    #[test]
+    fn grow_threads_from_no_money() {
+        let bitnode = BitNodeMultipliers::default();
+        for money in [Some(0.0), None] {
+            let server = Server {
+                money_available: money,
+                ..n00dles()
+            };
+            let threads = grow_threads(&server, &Person::default(), 1.75e6, 1, &bitnode);
+            assert!(threads.is_finite() && threads > 0.0, "{threads}");
+            assert!(grown(&server, threads) >= 1.75e6);
+            assert!(grown(&server, threads - 1.0) < 1.75e6);
+        }
+    }
+
+    #[test]
+    fn grow_threads_bounds() {
+        let bitnode = BitNodeMultipliers::default();
+        let person = Person::default();
+        let server = n00dles();
+        assert_eq!(grow_threads(&server, &person, 70e3, 1, &bitnode), 0.0);
+        assert_eq!(grow_threads(&server, &person, 1.0, 1, &bitnode), 0.0);
+        // targets past the maximum stop at the maximum
+        assert_eq!(
+            grow_threads(&server, &person, 1e12, 1, &bitnode),
+            grow_threads(&server, &person, 1.75e6, 1, &bitnode)
+        );
+
+        let barren = Server {
+            server_growth: Some(0.0),
+            ..n00dles()
+        };
+        assert_eq!(
+            grow_threads(&barren, &person, 1.75e6, 1, &bitnode),
+            f64::INFINITY
+        );
+        assert_eq!(grow_percent(&barren, 100.0, &person, 1, &bitnode), 0.0);
+    }
+
+    #[test]
+    fn more_cores_need_fewer_threads() {
+        let bitnode = BitNodeMultipliers::default();
+        let person = Person::default();
+        let one = grow_threads(&n00dles(), &person, 1.75e6, 1, &bitnode);
+        let eight = grow_threads(&n00dles(), &person, 1.75e6, 8, &bitnode);
+        assert!(eight < one, "{eight} >= {one}");
+    }
+
+    #[test]
+    fn hack_percent_is_clamped() {
+        let bitnode = BitNodeMultipliers::default();
+        let mut person = Person::default();
+        person.mults.hacking_money = 1e9;
+        assert_eq!(hack_percent(&n00dles(), &person, &bitnode), 1.0);
+
+        // a skill below the requirement does not steal negative money
+        let guarded = Server {
+            required_hacking_skill: Some(100.0),
+            ..n00dles()
+        };
+        assert_eq!(hack_percent(&guarded, &Person::default(), &bitnode), 0.0);
+
+        let secured = Server {
+            hack_difficulty: Some(100.0),
+            ..n00dles()
+        };
+        assert_eq!(hack_percent(&secured, &Person::default(), &bitnode), 0.0);
+
+        let percent = hack_percent(&n00dles(), &Person::default(), &bitnode);
+        assert!(percent > 0.0 && percent < 1.0, "{percent}");
+    }
+
+    #[test]
+    fn hack_chance_is_clamped() {
+        let mut person = Person::default();
+        person.mults.hacking_chance = 1e9;
+        assert_eq!(hack_chance(&n00dles(), &person), 1.0);
+
+        let guarded = Server {
+            required_hacking_skill: Some(100.0),
+            ..n00dles()
+        };
+        assert_eq!(hack_chance(&guarded, &Person::default()), 0.0);
+
+        let locked = Server {
+            has_admin_rights: false,
+            ..n00dles()
+        };
+        assert_eq!(hack_chance(&locked, &Person::default()), 0.0);
+    }
+}
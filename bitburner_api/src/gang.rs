@@ -0,0 +1,1044 @@
+//! Typed access to the gang API at `ns.gang()`, and a [`GangManager`] that
+//! plans ascensions, tasks, equipment and territory warfare.
+//!
+//! The manager works on a [`GangState`] snapshot so it can be tested
+//! natively, the gain formulas of `GangFormulas` being reimplemented here:
+//!
+//! ```
+//! use bitburner_api::gang::{
+//!     GangGenInfo, GangGoal, GangManager, GangMemberInfo, GangState, GangTaskStats,
+//!     GangTerritory,
+//! };
+//!
+//! let territory = GangTerritory {
+//!     money: 1.0,
+//!     respect: 1.0,
+//!     wanted: 1.0,
+//! };
+//! let task = |name: &str, base_money, base_wanted| GangTaskStats {
+//!     name: name.to_owned(),
+//!     is_combat: true,
+//!     base_money,
+//!     base_wanted,
+//!     str_weight: 100.0,
+//!     difficulty: 1.0,
+//!     territory,
+//!     ..GangTaskStats::default()
+//! };
+//! let state = GangState {
+//!     gang: GangGenInfo {
+//!         respect: 100.0,
+//!         wanted_level: 1.0,
+//!         territory: 0.15,
+//!         ..GangGenInfo::default()
+//!     },
+//!     members: vec![GangMemberInfo {
+//!         name: "Alice".to_owned(),
+//!         str: 500.0,
+//!         ..GangMemberInfo::default()
+//!     }],
+//!     tasks: vec![
+//!         task("Mug People", 3.6, 0.0),
+//!         task("Human Trafficking", 120.0, 1.25),
+//!         task("Vigilante Justice", 0.0, -0.001),
+//!     ],
+//!     ..GangState::default()
+//! };
+//!
+//! let plan = GangManager::new(GangGoal::Money).plan(&state, 0.0);
+//! assert_eq!(plan.tasks, [("Alice".to_owned(), "Human Trafficking".to_owned())]);
+//! ```
+//!
+//! In the game, a loop reads the state, applies the plan and waits for the
+//! next update:
+//!
+//! ```ignore
+//! let gang = ns.gang();
+//! let manager = GangManager::new(GangGoal::Respect).equipment_share(0.1);
+//! loop {
+//!     let state = gang.get_state()?;
+//!     let money = ns.getServerMoneyAvailable("home")?;
+//!     gang.apply(&manager.plan(&state, money))?;
+//!     gang.nextUpdate().await?;
+//! }
+//! ```
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsValue;
+
+use crate::Gang;
+
+/// Task training combat stats.
+pub const TRAIN_COMBAT: &str = "Train Combat";
+/// Task training hacking.
+pub const TRAIN_HACKING: &str = "Train Hacking";
+
+/// General state of the gang, mirrors the `GangGenInfo` interface of the
+/// definitions.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GangGenInfo {
+    /// Faction the gang belongs to
+    pub faction: String,
+    pub is_hacking: bool,
+    /// Money earned per game cycle
+    pub money_gain_rate: f64,
+    /// Power for territory warfare
+    pub power: f64,
+    pub respect: f64,
+    /// Respect earned per game cycle
+    pub respect_gain_rate: f64,
+    pub respect_for_next_recruit: f64,
+    /// Share of the territory held, from 0 to 1
+    pub territory: f64,
+    pub territory_clash_chance: f64,
+    pub wanted_level: f64,
+    /// Wanted level gained per game cycle, negative when it decreases
+    pub wanted_level_gain_rate: f64,
+    pub territory_warfare_engaged: bool,
+    /// Multiplier of the gains, lowered by the wanted level
+    pub wanted_penalty: f64,
+}
+
+/// Power and territory of a gang, mirrors the `GangOtherInfoObject`
+/// interface of the definitions.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct GangOtherInfoObject {
+    pub power: f64,
+    /// Share of the territory held, from 0 to 1
+    pub territory: f64,
+}
+
+/// Power and territory by gang name, the player's gang included, mirrors the
+/// `GangOtherInfo` interface of the definitions.
+pub type GangOtherInfo = HashMap<String, GangOtherInfoObject>;
+
+/// How territory affects a task, mirrors the `GangTerritory` interface of the
+/// definitions.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct GangTerritory {
+    pub money: f64,
+    pub respect: f64,
+    pub wanted: f64,
+}
+
+/// A task of gang members, mirrors the `GangTaskStats` interface of the
+/// definitions. Weights are percentages of the stats that count.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GangTaskStats {
+    pub name: String,
+    pub desc: String,
+    pub is_hacking: bool,
+    pub is_combat: bool,
+    pub base_respect: f64,
+    pub base_wanted: f64,
+    pub base_money: f64,
+    pub hack_weight: f64,
+    pub str_weight: f64,
+    pub def_weight: f64,
+    pub dex_weight: f64,
+    pub agi_weight: f64,
+    pub cha_weight: f64,
+    pub difficulty: f64,
+    pub territory: GangTerritory,
+}
+
+/// Stat multipliers of gang equipment, mirrors the `EquipmentStats`
+/// interface of the definitions.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct EquipmentStats {
+    pub str: Option<f64>,
+    pub def: Option<f64>,
+    pub dex: Option<f64>,
+    pub agi: Option<f64>,
+    pub cha: Option<f64>,
+    pub hack: Option<f64>,
+}
+
+impl EquipmentStats {
+    /// Whether the equipment raises a stat that matters to a hacking or a
+    /// combat gang.
+    pub fn helps(&self, is_hacking: bool) -> bool {
+        let raises = |mult: Option<f64>| mult.is_some_and(|mult| mult > 1.0);
+        if is_hacking {
+            raises(self.hack) || raises(self.cha)
+        } else {
+            raises(self.str) || raises(self.def) || raises(self.dex) || raises(self.agi)
+        }
+    }
+}
+
+/// A gang member, mirrors the `GangMemberInfo` interface of the definitions.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GangMemberInfo {
+    pub name: String,
+    /// Currently assigned task
+    pub task: String,
+    /// Respect earned since the last ascension
+    #[serde(rename = "earnedRespect")]
+    pub earned_respect: f64,
+
+    pub hack: f64,
+    pub str: f64,
+    pub def: f64,
+    pub dex: f64,
+    pub agi: f64,
+    pub cha: f64,
+
+    pub hack_exp: f64,
+    pub str_exp: f64,
+    pub def_exp: f64,
+    pub dex_exp: f64,
+    pub agi_exp: f64,
+    pub cha_exp: f64,
+
+    pub hack_mult: f64,
+    pub str_mult: f64,
+    pub def_mult: f64,
+    pub dex_mult: f64,
+    pub agi_mult: f64,
+    pub cha_mult: f64,
+
+    pub hack_asc_mult: f64,
+    pub str_asc_mult: f64,
+    pub def_asc_mult: f64,
+    pub dex_asc_mult: f64,
+    pub agi_asc_mult: f64,
+    pub cha_asc_mult: f64,
+
+    pub hack_asc_points: f64,
+    pub str_asc_points: f64,
+    pub def_asc_points: f64,
+    pub dex_asc_points: f64,
+    pub agi_asc_points: f64,
+    pub cha_asc_points: f64,
+
+    /// Equipment owned, augmentations excluded
+    pub upgrades: Vec<String>,
+    pub augmentations: Vec<String>,
+
+    /// Respect earned per game cycle
+    #[serde(rename = "respectGain")]
+    pub respect_gain: f64,
+    /// Wanted level gained per game cycle
+    #[serde(rename = "wantedLevelGain")]
+    pub wanted_level_gain: f64,
+    /// Money earned per game cycle
+    #[serde(rename = "moneyGain")]
+    pub money_gain: f64,
+}
+
+impl GangMemberInfo {
+    /// Whether the member owns `equipment`, as an upgrade or augmentation.
+    pub fn owns(&self, equipment: &str) -> bool {
+        self.upgrades.iter().any(|owned| owned == equipment)
+            || self.augmentations.iter().any(|owned| owned == equipment)
+    }
+
+    /// Level of the stats that matter to a hacking or a combat gang.
+    pub fn main_stat(&self, is_hacking: bool) -> f64 {
+        if is_hacking {
+            self.hack
+        } else {
+            (self.str + self.def + self.dex + self.agi) / 4.0
+        }
+    }
+
+    /// Ascension multiplier of the stats that matter to a hacking or a
+    /// combat gang.
+    pub fn main_asc_mult(&self, is_hacking: bool) -> f64 {
+        if is_hacking {
+            self.hack_asc_mult
+        } else {
+            (self.str_asc_mult + self.def_asc_mult + self.dex_asc_mult + self.agi_asc_mult) / 4.0
+        }
+    }
+}
+
+/// Outcome of an ascension, mirrors the `GangMemberAscension` interface of
+/// the definitions. Stats are the factors their ascension multipliers grow
+/// by.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct GangMemberAscension {
+    /// Respect lost by ascending
+    pub respect: f64,
+    pub hack: f64,
+    pub str: f64,
+    pub def: f64,
+    pub dex: f64,
+    pub agi: f64,
+    pub cha: f64,
+}
+
+impl GangMemberAscension {
+    /// Growth of the multipliers that matter to a hacking or a combat gang.
+    pub fn main_factor(&self, is_hacking: bool) -> f64 {
+        if is_hacking {
+            self.hack
+        } else {
+            (self.str + self.def + self.dex + self.agi) / 4.0
+        }
+    }
+}
+
+/// An equipment on sale, with what `getEquipmentType`, `getEquipmentCost`
+/// and `getEquipmentStats` say about it.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Equipment {
+    pub name: String,
+    /// "Weapon", "Armor", "Vehicle", "Rootkit" or "Augmentation"
+    pub kind: String,
+    pub cost: f64,
+    pub stats: EquipmentStats,
+}
+
+impl_try_from_bindings!(
+    GangGenInfo,
+    GangTaskStats,
+    EquipmentStats,
+    GangMemberInfo,
+    GangMemberAscension,
+);
+
+// the `GangFormulas` formulas, for the BitNodes without a gang softcap
+
+fn stat_weight(member: &GangMemberInfo, task: &GangTaskStats) -> f64 {
+    (task.hack_weight * member.hack
+        + task.str_weight * member.str
+        + task.def_weight * member.def
+        + task.dex_weight * member.dex
+        + task.agi_weight * member.agi
+        + task.cha_weight * member.cha)
+        / 100.0
+}
+
+fn territory_mult(gang: &GangGenInfo, exponent: f64) -> f64 {
+    ((gang.territory * 100.0).powf(exponent) / 100.0).max(0.005)
+}
+
+/// Multiplier of the gains of `gang`, lowered by its wanted level.
+pub fn wanted_penalty(gang: &GangGenInfo) -> f64 {
+    gang.respect / (gang.respect + gang.wanted_level)
+}
+
+/// Respect `member` earns per game cycle doing `task`.
+pub fn respect_gain(gang: &GangGenInfo, member: &GangMemberInfo, task: &GangTaskStats) -> f64 {
+    if task.base_respect == 0.0 {
+        return 0.0;
+    }
+    let weight = stat_weight(member, task) - 4.0 * task.difficulty;
+    if weight <= 0.0 {
+        return 0.0;
+    }
+    let territory = territory_mult(gang, task.territory.respect);
+    let territory_penalty = 0.2 * gang.territory + 0.8;
+    (11.0 * task.base_respect * weight * territory * wanted_penalty(gang)).powf(territory_penalty)
+}
+
+/// Wanted level `member` gains per game cycle doing `task`, negative when it
+/// decreases.
+pub fn wanted_level_gain(gang: &GangGenInfo, member: &GangMemberInfo, task: &GangTaskStats) -> f64 {
+    if task.base_wanted == 0.0 {
+        return 0.0;
+    }
+    let weight = stat_weight(member, task) - 3.5 * task.difficulty;
+    if weight <= 0.0 {
+        return 0.0;
+    }
+    let territory = territory_mult(gang, task.territory.wanted);
+    if task.base_wanted < 0.0 {
+        return 0.4 * task.base_wanted * weight * territory;
+    }
+    (7.0 * task.base_wanted / (3.0 * weight * territory).powf(0.8)).min(100.0)
+}
+
+/// Money `member` earns per game cycle doing `task`.
+pub fn money_gain(gang: &GangGenInfo, member: &GangMemberInfo, task: &GangTaskStats) -> f64 {
+    if task.base_money == 0.0 {
+        return 0.0;
+    }
+    let weight = stat_weight(member, task) - 3.2 * task.difficulty;
+    if weight <= 0.0 {
+        return 0.0;
+    }
+    let territory = territory_mult(gang, task.territory.money);
+    let territory_penalty = 0.2 * gang.territory + 0.8;
+    (5.0 * task.base_money * weight * territory * wanted_penalty(gang)).powf(territory_penalty)
+}
+
+/// What the manager assigns tasks for.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum GangGoal {
+    #[default]
+    Money,
+    Respect,
+    /// Lowering the wanted level
+    Wanted,
+}
+
+/// Everything the manager needs to know about the gang.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GangState {
+    pub gang: GangGenInfo,
+    pub members: Vec<GangMemberInfo>,
+    pub tasks: Vec<GangTaskStats>,
+    pub equipment: Vec<Equipment>,
+    /// The other gangs, the player's gang may be included
+    pub others: GangOtherInfo,
+    /// Chances to win a clash, by gang name
+    pub clash_chances: HashMap<String, f64>,
+    /// Results of ascending now, by member name, for those who can
+    pub ascensions: HashMap<String, GangMemberAscension>,
+}
+
+/// What the manager decided, see [`Gang::apply`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GangPlan {
+    /// Members to ascend
+    pub ascend: Vec<String>,
+    /// Task of each member, as member and task names
+    pub tasks: Vec<(String, String)>,
+    /// Equipment to buy, as member and equipment names
+    pub purchases: Vec<(String, String)>,
+    /// Whether territory warfare should be engaged
+    pub warfare: bool,
+}
+
+/// Decides what the gang does, toward a [`GangGoal`].
+///
+/// - Members ascend when the multipliers that matter grow by at least
+///   [`ascension_threshold`](Self::ascension_threshold), a factor that goes
+///   down as their ascension multiplier goes up.
+/// - Members train until their main stats reach
+///   [`train_below`](Self::train_below), or while no task of the goal gains
+///   them anything.
+/// - When the wanted penalty falls below
+///   [`min_wanted_penalty`](Self::min_wanted_penalty), members switch to
+///   lowering the wanted level until it stops growing.
+/// - Equipment is bought cheapest first, with at most
+///   [`equipment_share`](Self::equipment_share) of the money.
+/// - Territory warfare is engaged when the chance to win against every gang
+///   that still holds territory reaches
+///   [`clash_threshold`](Self::clash_threshold).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GangManager {
+    goal: GangGoal,
+    ascension_threshold: f64,
+    train_below: f64,
+    min_wanted_penalty: f64,
+    equipment_share: f64,
+    clash_threshold: f64,
+}
+
+impl Default for GangManager {
+    fn default() -> Self {
+        GangManager::new(GangGoal::default())
+    }
+}
+
+impl GangManager {
+    pub fn new(goal: GangGoal) -> Self {
+        GangManager {
+            goal,
+            ascension_threshold: 1.6,
+            train_below: 50.0,
+            min_wanted_penalty: 0.95,
+            equipment_share: 0.0,
+            clash_threshold: 0.55,
+        }
+    }
+
+    pub fn goal(mut self, goal: GangGoal) -> Self {
+        self.goal = goal;
+        self
+    }
+
+    /// Growth factor required to ascend a member who never ascended, 1.6 by
+    /// default. It goes down with the square root of the ascension
+    /// multiplier, to 1.1 at the lowest.
+    pub fn ascension_threshold(mut self, factor: f64) -> Self {
+        self.ascension_threshold = factor;
+        self
+    }
+
+    /// Main stat level under which members train, 50 by default.
+    pub fn train_below(mut self, level: f64) -> Self {
+        self.train_below = level;
+        self
+    }
+
+    /// Wanted penalty under which members lower the wanted level, 0.95 by
+    /// default.
+    pub fn min_wanted_penalty(mut self, penalty: f64) -> Self {
+        self.min_wanted_penalty = penalty;
+        self
+    }
+
+    /// Share of the money spent on equipment, nothing by default.
+    pub fn equipment_share(mut self, share: f64) -> Self {
+        self.equipment_share = share;
+        self
+    }
+
+    /// Chance to win clashes required to engage territory warfare, 0.55 by
+    /// default.
+    pub fn clash_threshold(mut self, chance: f64) -> Self {
+        self.clash_threshold = chance;
+        self
+    }
+
+    /// Growth factor required to ascend a member with ascension multiplier
+    /// `asc_mult`.
+    pub fn ascension_threshold_for(&self, asc_mult: f64) -> f64 {
+        let threshold = 1.0 + (self.ascension_threshold - 1.0) / asc_mult.max(1.0).sqrt();
+        threshold.max(1.1)
+    }
+
+    /// Plans the next moves of the gang, with `money` to spend.
+    pub fn plan(&self, state: &GangState, money: f64) -> GangPlan {
+        GangPlan {
+            ascend: self.ascensions(state),
+            tasks: self.tasks(state),
+            purchases: self.purchases(state, money * self.equipment_share),
+            warfare: self.warfare(state),
+        }
+    }
+
+    /// Members that should ascend.
+    pub fn ascensions(&self, state: &GangState) -> Vec<String> {
+        let is_hacking = state.gang.is_hacking;
+        state
+            .members
+            .iter()
+            .filter(|member| {
+                state.ascensions.get(&member.name).is_some_and(|ascension| {
+                    let threshold = self.ascension_threshold_for(member.main_asc_mult(is_hacking));
+                    ascension.main_factor(is_hacking) >= threshold
+                })
+            })
+            .map(|member| member.name.clone())
+            .collect()
+    }
+
+    /// Task of each member.
+    pub fn tasks(&self, state: &GangState) -> Vec<(String, String)> {
+        let gang = &state.gang;
+        let training = if gang.is_hacking {
+            TRAIN_HACKING
+        } else {
+            TRAIN_COMBAT
+        };
+        let tasks: Vec<&GangTaskStats> = state
+            .tasks
+            .iter()
+            .filter(|task| {
+                if gang.is_hacking {
+                    task.is_hacking
+                } else {
+                    task.is_combat
+                }
+            })
+            .collect();
+
+        // best task of the goal and the wanted level it gains, `None` to train
+        let mut assigned: Vec<(&GangMemberInfo, Option<(&GangTaskStats, f64)>)> = state
+            .members
+            .iter()
+            .map(|member| {
+                if member.main_stat(gang.is_hacking) < self.train_below {
+                    return (member, None);
+                }
+                let score = |task: &GangTaskStats| match self.goal {
+                    GangGoal::Money => money_gain(gang, member, task),
+                    GangGoal::Respect => respect_gain(gang, member, task),
+                    GangGoal::Wanted => -wanted_level_gain(gang, member, task),
+                };
+                let best = tasks
+                    .iter()
+                    .map(|task| (*task, score(task)))
+                    .filter(|(_, score)| *score > 0.0)
+                    .max_by(|(_, a), (_, b)| a.total_cmp(b))
+                    .map(|(task, _)| (task, wanted_level_gain(gang, member, task)));
+                (member, best)
+            })
+            .collect();
+
+        if self.goal != GangGoal::Wanted && wanted_penalty(gang) < self.min_wanted_penalty {
+            let lowest = |member: &GangMemberInfo| {
+                tasks
+                    .iter()
+                    .map(|task| (*task, wanted_level_gain(gang, member, task)))
+                    .filter(|(_, gain)| *gain < 0.0)
+                    .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            };
+            let mut wanted: f64 = assigned
+                .iter()
+                .filter_map(|(_, best)| best.map(|b| b.1))
+                .sum();
+            // the members lowering the wanted level the most switch first
+            let mut switches: Vec<(usize, &GangTaskStats, f64)> = assigned
+                .iter()
+                .enumerate()
+                .filter(|(_, (_, best))| best.is_some())
+                .filter_map(|(i, (member, _))| lowest(member).map(|(task, gain)| (i, task, gain)))
+                .collect();
+            switches.sort_by(|a, b| a.2.total_cmp(&b.2));
+            for (i, task, gain) in switches {
+                if wanted <= 0.0 {
+                    break;
+                }
+                let previous = assigned[i].1.map_or(0.0, |best| best.1);
+                wanted += gain - previous;
+                assigned[i].1 = Some((task, gain));
+            }
+        }
+
+        assigned
+            .into_iter()
+            .map(|(member, best)| {
+                let task = best.map_or(training, |(task, _)| task.name.as_str());
+                (member.name.clone(), task.to_owned())
+            })
+            .collect()
+    }
+
+    /// Equipment to buy within `budget`, cheapest first.
+    pub fn purchases(&self, state: &GangState, budget: f64) -> Vec<(String, String)> {
+        let mut offers: Vec<(&GangMemberInfo, &Equipment)> = state
+            .equipment
+            .iter()
+            .filter(|equipment| equipment.stats.helps(state.gang.is_hacking))
+            .flat_map(|equipment| {
+                state
+                    .members
+                    .iter()
+                    .filter(|member| !member.owns(&equipment.name))
+                    .map(move |member| (member, equipment))
+            })
+            .collect();
+        offers.sort_by(|a, b| a.1.cost.total_cmp(&b.1.cost));
+
+        let mut spent = 0.0;
+        let mut purchases = vec![];
+        for (member, equipment) in offers {
+            if spent + equipment.cost > budget {
+                break;
+            }
+            spent += equipment.cost;
+            purchases.push((member.name.clone(), equipment.name.clone()));
+        }
+        purchases
+    }
+
+    /// Whether territory warfare should be engaged.
+    pub fn warfare(&self, state: &GangState) -> bool {
+        if state.gang.territory >= 1.0 {
+            return false;
+        }
+        state
+            .others
+            .iter()
+            .filter(|(name, other)| **name != state.gang.faction && other.territory > 0.0)
+            .all(|(name, _)| {
+                state
+                    .clash_chances
+                    .get(name)
+                    .is_some_and(|chance| *chance >= self.clash_threshold)
+            })
+    }
+}
+
+impl Gang {
+    pub fn get_gang_information(&self) -> Result<GangGenInfo, JsValue> {
+        self.getGangInformation()?.try_into()
+    }
+
+    pub fn get_other_gang_information(&self) -> Result<GangOtherInfo, JsValue> {
+        Ok(serde_wasm_bindgen::from_value(
+            self.getOtherGangInformation()?.into(),
+        )?)
+    }
+
+    pub fn get_member_information(&self, name: &str) -> Result<GangMemberInfo, JsValue> {
+        self.getMemberInformation(name)?.try_into()
+    }
+
+    /// Information about all the members.
+    pub fn get_members(&self) -> Result<Vec<GangMemberInfo>, JsValue> {
+        self.getMemberNames()?
+            .iter()
+            .map(|name| self.get_member_information(name))
+            .collect()
+    }
+
+    pub fn get_task_stats(&self, name: &str) -> Result<GangTaskStats, JsValue> {
+        self.getTaskStats(name)?.try_into()
+    }
+
+    /// Stats of all the tasks.
+    pub fn get_tasks(&self) -> Result<Vec<GangTaskStats>, JsValue> {
+        self.getTaskNames()?
+            .iter()
+            .map(|name| self.get_task_stats(name))
+            .collect()
+    }
+
+    pub fn get_equipment_stats(&self, name: &str) -> Result<EquipmentStats, JsValue> {
+        self.getEquipmentStats(name)?.try_into()
+    }
+
+    /// All the equipment on sale.
+    pub fn get_equipment(&self) -> Result<Vec<Equipment>, JsValue> {
+        self.getEquipmentNames()?
+            .into_iter()
+            .map(|name| {
+                Ok(Equipment {
+                    kind: self.getEquipmentType(&name)?,
+                    cost: self.getEquipmentCost(&name)?,
+                    stats: self.get_equipment_stats(&name)?,
+                    name,
+                })
+            })
+            .collect()
+    }
+
+    /// Ascends `member`, `None` if it could not.
+    pub fn ascend_member(&self, member: &str) -> Result<Option<GangMemberAscension>, JsValue> {
+        self.ascendMember(member)?
+            .map(GangMemberAscension::try_from)
+            .transpose()
+    }
+
+    /// Result of ascending `member` now, `None` if it cannot.
+    pub fn get_ascension_result(
+        &self,
+        member: &str,
+    ) -> Result<Option<GangMemberAscension>, JsValue> {
+        self.getAscensionResult(member)?
+            .map(GangMemberAscension::try_from)
+            .transpose()
+    }
+
+    /// Reads everything [`GangManager::plan`] needs.
+    pub fn get_state(&self) -> Result<GangState, JsValue> {
+        let gang = self.get_gang_information()?;
+        let members = self.get_members()?;
+        let others = self.get_other_gang_information()?;
+
+        let mut clash_chances = HashMap::new();
+        for name in others.keys().filter(|name| **name != gang.faction) {
+            clash_chances.insert(name.clone(), self.getChanceToWinClash(name)?);
+        }
+        let mut ascensions = HashMap::new();
+        for member in &members {
+            if let Some(ascension) = self.get_ascension_result(&member.name)? {
+                ascensions.insert(member.name.clone(), ascension);
+            }
+        }
+
+        Ok(GangState {
+            gang,
+            members,
+            tasks: self.get_tasks()?,
+            equipment: self.get_equipment()?,
+            others,
+            clash_chances,
+            ascensions,
+        })
+    }
+
+    /// Carries out `plan`, ascensions first.
+    pub fn apply(&self, plan: &GangPlan) -> Result<(), JsValue> {
+        for member in &plan.ascend {
+            self.ascendMember(member)?;
+        }
+        for (member, task) in &plan.tasks {
+            self.setMemberTask(member, task)?;
+        }
+        for (member, equipment) in &plan.purchases {
+            self.purchaseEquipment(member, equipment)?;
+        }
+        self.setTerritoryWarfare(plan.warfare)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn member(name: &str, stat: f64) -> GangMemberInfo {
+        GangMemberInfo {
+            name: name.to_owned(),
+            str: stat,
+            def: stat,
+            dex: stat,
+            agi: stat,
+            str_asc_mult: 1.0,
+            def_asc_mult: 1.0,
+            dex_asc_mult: 1.0,
+            agi_asc_mult: 1.0,
+            ..GangMemberInfo::default()
+        }
+    }
+
+    fn task(name: &str, base_money: f64, base_wanted: f64) -> GangTaskStats {
+        GangTaskStats {
+            name: name.to_owned(),
+            is_combat: true,
+            base_money,
+            base_wanted,
+            str_weight: 100.0,
+            difficulty: 1.0,
+            territory: GangTerritory {
+                money: 1.0,
+                respect: 1.0,
+                wanted: 1.0,
+            },
+            ..GangTaskStats::default()
+        }
+    }
+
+    /// A combat gang with `members`, a penalty of `respect / (respect +
+    /// wanted_level)`, a task gaining money and wanted level and one
+    /// lowering the wanted level.
+    fn state(members: Vec<GangMemberInfo>, respect: f64, wanted_level: f64) -> GangState {
+        GangState {
+            gang: GangGenInfo {
+                faction: "Slum Snakes".to_owned(),
+                respect,
+                wanted_level,
+                territory: 0.15,
+                ..GangGenInfo::default()
+            },
+            members,
+            tasks: vec![
+                task("Human Trafficking", 120.0, 1.25),
+                task("Vigilante Justice", 0.0, -0.001),
+            ],
+            ..GangState::default()
+        }
+    }
+
+    fn ascension(factor: f64) -> GangMemberAscension {
+        GangMemberAscension {
+            str: factor,
+            def: factor,
+            dex: factor,
+            agi: factor,
+            ..GangMemberAscension::default()
+        }
+    }
+
+    #[test]
+    fn ascends_from_the_threshold() {
+        let manager = GangManager::new(GangGoal::Money);
+        let mut state = state(
+            vec![
+                member("Alice", 100.0),
+                member("Bob", 100.0),
+                member("Carol", 100.0),
+            ],
+            100.0,
+            1.0,
+        );
+        let threshold = manager.ascension_threshold_for(1.0);
+        assert_eq!(threshold, 1.6);
+        state
+            .ascensions
+            .insert("Alice".to_owned(), ascension(threshold));
+        state
+            .ascensions
+            .insert("Bob".to_owned(), ascension(threshold - 0.01));
+        // Carol cannot ascend yet
+
+        assert_eq!(manager.ascensions(&state), ["Alice"]);
+    }
+
+    #[test]
+    fn ascension_threshold_goes_down_with_the_multiplier() {
+        let manager = GangManager::new(GangGoal::Money);
+        let mut veteran = member("Alice", 100.0);
+        veteran.str_asc_mult = 4.0;
+        veteran.def_asc_mult = 4.0;
+        veteran.dex_asc_mult = 4.0;
+        veteran.agi_asc_mult = 4.0;
+        let threshold = manager.ascension_threshold_for(4.0);
+        assert!((threshold - 1.3).abs() < 1e-9);
+
+        let mut state = state(vec![veteran], 100.0, 1.0);
+        state
+            .ascensions
+            .insert("Alice".to_owned(), ascension(threshold - 0.01));
+        assert!(manager.ascensions(&state).is_empty());
+        state
+            .ascensions
+            .insert("Alice".to_owned(), ascension(threshold));
+        assert_eq!(manager.ascensions(&state), ["Alice"]);
+
+        assert_eq!(manager.ascension_threshold_for(10_000.0), 1.1);
+    }
+
+    #[test]
+    fn keeps_the_goal_while_the_wanted_penalty_is_low_enough() {
+        let manager = GangManager::new(GangGoal::Money);
+        // a penalty of exactly 0.95, the default minimum
+        let state = state(vec![member("Alice", 500.0)], 95.0, 5.0);
+        assert_eq!(wanted_penalty(&state.gang), 0.95);
+        assert_eq!(
+            manager.tasks(&state),
+            [("Alice".to_owned(), "Human Trafficking".to_owned())]
+        );
+    }
+
+    #[test]
+    fn lowers_the_wanted_level_below_the_minimum_penalty() {
+        let manager = GangManager::new(GangGoal::Money);
+        let state = state(
+            vec![member("Alice", 500.0), member("Bob", 10.0)],
+            100.0,
+            10.0,
+        );
+        assert!(wanted_penalty(&state.gang) < 0.95);
+        // members still training keep training
+        assert_eq!(
+            manager.tasks(&state),
+            [
+                ("Alice".to_owned(), "Vigilante Justice".to_owned()),
+                ("Bob".to_owned(), TRAIN_COMBAT.to_owned()),
+            ]
+        );
+    }
+
+    #[test]
+    fn switches_only_until_the_wanted_level_stops_growing() {
+        let manager = GangManager::new(GangGoal::Money);
+        let state = state(
+            vec![member("Alice", 500.0), member("Bob", 2000.0)],
+            100.0,
+            10.0,
+        );
+        let gang = &state.gang;
+        let [trafficking, vigilante] = [&state.tasks[0], &state.tasks[1]];
+        let [alice, bob] = [&state.members[0], &state.members[1]];
+        // Bob lowers the wanted level the most, enough to make up for Alice
+        let after_bob =
+            wanted_level_gain(gang, alice, trafficking) + wanted_level_gain(gang, bob, vigilante);
+        assert!(after_bob <= 0.0);
+
+        assert_eq!(
+            manager.tasks(&state),
+            [
+                ("Alice".to_owned(), "Human Trafficking".to_owned()),
+                ("Bob".to_owned(), "Vigilante Justice".to_owned()),
+            ]
+        );
+        let wanted = GangManager::new(GangGoal::Wanted).tasks(&state);
+        assert!(wanted.iter().all(|(_, task)| task == "Vigilante Justice"));
+    }
+
+    fn shop() -> GangState {
+        let weapon = |name: &str, cost| Equipment {
+            name: name.to_owned(),
+            kind: "Weapon".to_owned(),
+            cost,
+            stats: EquipmentStats {
+                str: Some(1.1),
+                ..EquipmentStats::default()
+            },
+        };
+        let mut alice = member("Alice", 100.0);
+        alice.upgrades.push("Baseball Bat".to_owned());
+        let mut state = state(vec![alice, member("Bob", 100.0)], 100.0, 1.0);
+        state.equipment = vec![
+            weapon("Katana", 5e6),
+            weapon("Baseball Bat", 1e6),
+            Equipment {
+                name: "NUKE Rootkit".to_owned(),
+                kind: "Rootkit".to_owned(),
+                cost: 1.0,
+                stats: EquipmentStats {
+                    hack: Some(1.05),
+                    ..EquipmentStats::default()
+                },
+            },
+        ];
+        state
+    }
+
+    #[test]
+    fn buys_the_cheapest_equipment_within_the_budget() {
+        let manager = GangManager::new(GangGoal::Money);
+        let state = shop();
+        let purchase = |member: &str, equipment: &str| (member.to_owned(), equipment.to_owned());
+
+        assert_eq!(
+            manager.purchases(&state, 6e6),
+            [purchase("Bob", "Baseball Bat"), purchase("Alice", "Katana")]
+        );
+        // a cent short of the katana
+        assert_eq!(
+            manager.purchases(&state, 6e6 - 0.01),
+            [purchase("Bob", "Baseball Bat")]
+        );
+        assert_eq!(manager.purchases(&state, 1e6 - 0.01), []);
+        // hacking equipment does not help a combat gang
+        assert_eq!(manager.purchases(&state, 1e9).len(), 3);
+    }
+
+    #[test]
+    fn plan_spends_the_equipment_share() {
+        let state = shop();
+        let plan = GangManager::new(GangGoal::Money).plan(&state, 1e9);
+        assert!(plan.purchases.is_empty());
+
+        let manager = GangManager::new(GangGoal::Money).equipment_share(0.1);
+        assert_eq!(manager.plan(&state, 6e7).purchases.len(), 2);
+    }
+
+    fn rivals(chances: &[(&str, f64, f64)]) -> GangState {
+        let mut state = state(vec![member("Alice", 100.0)], 100.0, 1.0);
+        let own = GangOtherInfoObject {
+            power: 1.0,
+            territory: 0.15,
+        };
+        state.others.insert("Slum Snakes".to_owned(), own);
+        for &(name, territory, chance) in chances {
+            let other = GangOtherInfoObject {
+                power: 1.0,
+                territory,
+            };
+            state.others.insert(name.to_owned(), other);
+            state.clash_chances.insert(name.to_owned(), chance);
+        }
+        state
+    }
+
+    #[test]
+    fn engages_warfare_from_the_clash_threshold() {
+        let manager = GangManager::new(GangGoal::Money);
+        let at_cutoff = rivals(&[("Tetrads", 0.2, 0.55), ("The Syndicate", 0.1, 0.9)]);
+        assert!(manager.warfare(&at_cutoff));
+
+        let below = rivals(&[("Tetrads", 0.2, 0.549), ("The Syndicate", 0.1, 0.9)]);
+        assert!(!manager.warfare(&below));
+        assert!(manager.clash_threshold(0.5).warfare(&below));
+    }
+
+    #[test]
+    fn ignores_gangs_without_territory() {
+        let manager = GangManager::new(GangGoal::Money);
+        let state = rivals(&[("Tetrads", 0.0, 0.1), ("The Syndicate", 0.1, 0.9)]);
+        assert!(manager.warfare(&state));
+
+        let mut all_territory = state.clone();
+        all_territory.gang.territory = 1.0;
+        assert!(!manager.warfare(&all_territory));
+
+        let mut unknown_chance = state;
+        unknown_chance.clash_chances.remove("The Syndicate");
+        assert!(!manager.warfare(&unknown_chance));
+    }
+}
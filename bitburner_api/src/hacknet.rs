@@ -0,0 +1,732 @@
+//! Typed access to Hacknet nodes and servers at `ns.hacknet()`, and a
+//! payback time optimizer for buying and upgrading them.
+//!
+//! The game's Hacknet formulas are reimplemented on the constants structs, so
+//! the optimizer runs without Formulas.exe and natively in tests:
+//!
+//! ```
+//! use bitburner_api::hacknet::{
+//!     HacknetAction, HacknetMultipliers, HacknetNodeConstants, NodeStats, PaybackOptimizer,
+//! };
+//!
+//! let optimizer =
+//!     PaybackOptimizer::nodes(HacknetNodeConstants::default(), HacknetMultipliers::default());
+//!
+//! // without nodes, the only option is to buy one
+//! let first = optimizer.best(&[]).unwrap();
+//! assert_eq!(first.action, HacknetAction::Purchase);
+//! assert_eq!(first.cost, 1000.0);
+//!
+//! // a fresh node earns back its first levels quickly
+//! let node = NodeStats {
+//!     level: 1.0,
+//!     ram: 1.0,
+//!     cores: 1.0,
+//!     ..NodeStats::default()
+//! };
+//! let next = optimizer.best(&[node]).unwrap();
+//! assert_eq!(next.action, HacknetAction::Level(0));
+//! ```
+//!
+//! In the game, the optimizer is fed with the real constants, multipliers
+//! and nodes:
+//!
+//! ```ignore
+//! let hacknet = ns.hacknet();
+//! let constants = ns.formulas().hacknetNodes().get_constants()?;
+//! let optimizer = PaybackOptimizer::nodes(constants, ns.get_hacknet_multipliers()?)
+//!     .max_nodes(hacknet.max_num_nodes()?);
+//! if let Some(best) = optimizer.best(&hacknet.get_nodes()?) {
+//!     if best.cost <= ns.getServerMoneyAvailable("home")? {
+//!         hacknet.apply(best.action)?;
+//!     }
+//! }
+//! ```
+
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsValue;
+
+use crate::{bindings, Hacknet, HacknetNodesFormulas, HacknetServersFormulas, NS};
+
+/// Money the "Sell for Money" hash upgrade gives per hash.
+pub const MONEY_PER_HASH: f64 = 1e6 / 4.0;
+
+/// A Hacknet node or server, mirrors the `NodeStats` interface of the
+/// definitions.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NodeStats {
+    pub name: String,
+    pub level: f64,
+    /// RAM in GB
+    pub ram: f64,
+    /// Used RAM in GB, only for Hacknet servers
+    pub ram_used: Option<f64>,
+    pub cores: f64,
+    /// Only for Hacknet servers
+    pub cache: Option<f64>,
+    /// Hashes the server can store, only for Hacknet servers
+    pub hash_capacity: Option<f64>,
+    /// Money per second for nodes, hashes per second for servers
+    pub production: f64,
+    /// Seconds since the node was bought
+    pub time_online: f64,
+    pub total_production: f64,
+}
+
+/// Hacknet multipliers of the player, mirrors the `HacknetMultipliers`
+/// interface of the definitions.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HacknetMultipliers {
+    pub production: f64,
+    pub purchase_cost: f64,
+    pub ram_cost: f64,
+    pub core_cost: f64,
+    pub level_cost: f64,
+}
+
+impl Default for HacknetMultipliers {
+    fn default() -> Self {
+        HacknetMultipliers {
+            production: 1.0,
+            purchase_cost: 1.0,
+            ram_cost: 1.0,
+            core_cost: 1.0,
+            level_cost: 1.0,
+        }
+    }
+}
+
+impl TryFrom<bindings::HacknetMultipliers> for HacknetMultipliers {
+    type Error = JsValue;
+
+    fn try_from(multipliers: bindings::HacknetMultipliers) -> Result<Self, Self::Error> {
+        Ok(serde_wasm_bindgen::from_value(multipliers.into())?)
+    }
+}
+
+/// Constants of Hacknet nodes, mirrors the `HacknetNodeConstants` interface
+/// of the definitions. The default is the game's values.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct HacknetNodeConstants {
+    pub money_gain_per_level: f64,
+    pub base_cost: f64,
+    pub level_base_cost: f64,
+    pub ram_base_cost: f64,
+    pub core_base_cost: f64,
+    pub purchase_next_mult: f64,
+    pub upgrade_level_mult: f64,
+    pub upgrade_ram_mult: f64,
+    pub upgrade_core_mult: f64,
+    pub max_level: f64,
+    pub max_ram: f64,
+    pub max_cores: f64,
+}
+
+impl Default for HacknetNodeConstants {
+    fn default() -> Self {
+        HacknetNodeConstants {
+            money_gain_per_level: 1.5,
+            base_cost: 1000.0,
+            level_base_cost: 1.0,
+            ram_base_cost: 30e3,
+            core_base_cost: 500e3,
+            purchase_next_mult: 1.85,
+            upgrade_level_mult: 1.04,
+            upgrade_ram_mult: 1.28,
+            upgrade_core_mult: 1.48,
+            max_level: 200.0,
+            max_ram: 64.0,
+            max_cores: 16.0,
+        }
+    }
+}
+
+impl TryFrom<bindings::HacknetNodeConstants> for HacknetNodeConstants {
+    type Error = JsValue;
+
+    fn try_from(constants: bindings::HacknetNodeConstants) -> Result<Self, Self::Error> {
+        Ok(serde_wasm_bindgen::from_value(constants.into())?)
+    }
+}
+
+/// The formulas of `HacknetNodesFormulas`. `mult` arguments are the player's
+/// multipliers, BitNode multipliers included.
+impl HacknetNodeConstants {
+    /// Money per second of a node.
+    pub fn money_gain_rate(&self, level: f64, ram: f64, cores: f64, mult: f64) -> f64 {
+        let level_mult = level * self.money_gain_per_level;
+        let ram_mult = 1.035f64.powf(ram - 1.0);
+        let cores_mult = (cores + 5.0) / 6.0;
+        level_mult * ram_mult * cores_mult * mult
+    }
+
+    /// Cost of `extra_levels` levels from `starting_level`, infinite past the
+    /// maximum.
+    pub fn level_upgrade_cost(
+        &self,
+        starting_level: f64,
+        extra_levels: u32,
+        cost_mult: f64,
+    ) -> f64 {
+        if starting_level + extra_levels as f64 > self.max_level {
+            return f64::INFINITY;
+        }
+        let total: f64 = (0..extra_levels)
+            .map(|i| self.level_base_cost * self.upgrade_level_mult.powf(starting_level + i as f64))
+            .sum();
+        self.base_cost / 2.0 * total * cost_mult
+    }
+
+    /// Cost of doubling the RAM `extra_levels` times from `starting_ram`,
+    /// infinite past the maximum.
+    pub fn ram_upgrade_cost(&self, starting_ram: f64, extra_levels: u32, cost_mult: f64) -> f64 {
+        ram_upgrade_cost(
+            starting_ram,
+            extra_levels,
+            self.max_ram,
+            self.ram_base_cost,
+            self.upgrade_ram_mult,
+        ) * cost_mult
+    }
+
+    /// Cost of `extra_cores` cores from `starting_cores`, infinite past the
+    /// maximum.
+    pub fn core_upgrade_cost(&self, starting_cores: f64, extra_cores: u32, cost_mult: f64) -> f64 {
+        step_cost(
+            starting_cores,
+            extra_cores,
+            self.max_cores,
+            self.core_base_cost,
+            self.upgrade_core_mult,
+        ) * cost_mult
+    }
+
+    /// Cost of the `n`th node, counting from 1.
+    pub fn node_cost(&self, n: u32, mult: f64) -> f64 {
+        if n == 0 {
+            return 0.0;
+        }
+        self.base_cost * self.purchase_next_mult.powi(n as i32 - 1) * mult
+    }
+}
+
+/// Constants of Hacknet servers, mirrors the `HacknetServerConstants`
+/// interface of the definitions. The default is the game's values.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct HacknetServerConstants {
+    pub hashes_per_level: f64,
+    pub base_cost: f64,
+    pub ram_base_cost: f64,
+    pub core_base_cost: f64,
+    pub cache_base_cost: f64,
+    pub purchase_mult: f64,
+    pub upgrade_level_mult: f64,
+    pub upgrade_ram_mult: f64,
+    pub upgrade_core_mult: f64,
+    pub upgrade_cache_mult: f64,
+    pub max_servers: f64,
+    pub max_level: f64,
+    pub max_ram: f64,
+    pub max_cores: f64,
+    pub max_cache: f64,
+}
+
+impl Default for HacknetServerConstants {
+    fn default() -> Self {
+        HacknetServerConstants {
+            hashes_per_level: 0.001,
+            base_cost: 50e3,
+            ram_base_cost: 200e3,
+            core_base_cost: 1e6,
+            cache_base_cost: 10e6,
+            purchase_mult: 3.2,
+            upgrade_level_mult: 1.1,
+            upgrade_ram_mult: 1.4,
+            upgrade_core_mult: 1.55,
+            upgrade_cache_mult: 1.85,
+            max_servers: 20.0,
+            max_level: 300.0,
+            max_ram: 8192.0,
+            max_cores: 128.0,
+            max_cache: 15.0,
+        }
+    }
+}
+
+impl TryFrom<bindings::HacknetServerConstants> for HacknetServerConstants {
+    type Error = JsValue;
+
+    fn try_from(constants: bindings::HacknetServerConstants) -> Result<Self, Self::Error> {
+        Ok(serde_wasm_bindgen::from_value(constants.into())?)
+    }
+}
+
+/// The formulas of `HacknetServersFormulas`. `mult` arguments are the
+/// player's multipliers, BitNode multipliers included.
+impl HacknetServerConstants {
+    /// Hashes per second of a server.
+    pub fn hash_gain_rate(
+        &self,
+        level: f64,
+        ram_used: f64,
+        max_ram: f64,
+        cores: f64,
+        mult: f64,
+    ) -> f64 {
+        let base_gain = self.hashes_per_level * level;
+        let ram_mult = 1.07f64.powf(max_ram.log2());
+        let cores_mult = 1.0 + (cores - 1.0) / 5.0;
+        let ram_ratio = 1.0 - ram_used / max_ram;
+        base_gain * ram_mult * cores_mult * ram_ratio * mult
+    }
+
+    /// Cost of `extra_levels` levels from `starting_level`, infinite past the
+    /// maximum.
+    pub fn level_upgrade_cost(
+        &self,
+        starting_level: f64,
+        extra_levels: u32,
+        cost_mult: f64,
+    ) -> f64 {
+        if starting_level + extra_levels as f64 > self.max_level {
+            return f64::INFINITY;
+        }
+        let total: f64 = (0..extra_levels)
+            .map(|i| self.upgrade_level_mult.powf(starting_level + i as f64))
+            .sum();
+        10.0 * total * cost_mult
+    }
+
+    /// Cost of doubling the RAM `extra_levels` times from `starting_ram`,
+    /// infinite past the maximum.
+    pub fn ram_upgrade_cost(&self, starting_ram: f64, extra_levels: u32, cost_mult: f64) -> f64 {
+        ram_upgrade_cost(
+            starting_ram,
+            extra_levels,
+            self.max_ram,
+            self.ram_base_cost,
+            self.upgrade_ram_mult,
+        ) * cost_mult
+    }
+
+    /// Cost of `extra_cores` cores from `starting_cores`, infinite past the
+    /// maximum.
+    pub fn core_upgrade_cost(&self, starting_cores: f64, extra_cores: u32, cost_mult: f64) -> f64 {
+        step_cost(
+            starting_cores,
+            extra_cores,
+            self.max_cores,
+            self.core_base_cost,
+            self.upgrade_core_mult,
+        ) * cost_mult
+    }
+
+    /// Cost of `extra_cache` cache levels from `starting_cache`, infinite
+    /// past the maximum.
+    pub fn cache_upgrade_cost(&self, starting_cache: f64, extra_cache: u32) -> f64 {
+        step_cost(
+            starting_cache,
+            extra_cache,
+            self.max_cache,
+            self.cache_base_cost,
+            self.upgrade_cache_mult,
+        )
+    }
+
+    /// Cost of the `n`th server, counting from 1, infinite past the maximum.
+    pub fn server_cost(&self, n: u32, mult: f64) -> f64 {
+        if n == 0 {
+            return 0.0;
+        }
+        if n as f64 > self.max_servers {
+            return f64::INFINITY;
+        }
+        self.base_cost * self.purchase_mult.powi(n as i32 - 1) * mult
+    }
+}
+
+fn ram_upgrade_cost(
+    starting_ram: f64,
+    extra_levels: u32,
+    max_ram: f64,
+    base_cost: f64,
+    upgrade_mult: f64,
+) -> f64 {
+    if starting_ram * 2f64.powi(extra_levels as i32) > max_ram {
+        return f64::INFINITY;
+    }
+    let upgrades = starting_ram.log2().round();
+    (0..extra_levels)
+        .map(|i| {
+            let ram = starting_ram * 2f64.powi(i as i32);
+            ram * base_cost * upgrade_mult.powf(upgrades + i as f64)
+        })
+        .sum()
+}
+
+/// Cost of upgrades whose price grows by `upgrade_mult` at each step from 1.
+fn step_cost(start: f64, extra: u32, max: f64, base_cost: f64, upgrade_mult: f64) -> f64 {
+    if start + extra as f64 > max {
+        return f64::INFINITY;
+    }
+    (0..extra)
+        .map(|i| base_cost * upgrade_mult.powf(start + i as f64 - 1.0))
+        .sum()
+}
+
+/// Something to spend money on, indices are the ones of `getNodeStats`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HacknetAction {
+    Purchase,
+    Level(u32),
+    Ram(u32),
+    Core(u32),
+    /// Only for Hacknet servers
+    Cache(u32),
+}
+
+/// A possible action of [`PaybackOptimizer`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Candidate {
+    pub action: HacknetAction,
+    pub cost: f64,
+    /// Production gained, in money per second
+    pub gain: f64,
+    /// Seconds until the gain pays for the cost
+    pub payback: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Model {
+    Nodes(HacknetNodeConstants),
+    Servers {
+        constants: HacknetServerConstants,
+        hash_value: f64,
+    },
+}
+
+/// Picks the Hacknet purchase or upgrade that pays for itself the fastest.
+///
+/// Hacknet servers produce hashes, which are valued at `hash_value` money
+/// each, like [`MONEY_PER_HASH`] when they are sold.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PaybackOptimizer {
+    model: Model,
+    multipliers: HacknetMultipliers,
+    max_nodes: Option<u32>,
+}
+
+impl PaybackOptimizer {
+    pub fn nodes(constants: HacknetNodeConstants, multipliers: HacknetMultipliers) -> Self {
+        PaybackOptimizer {
+            model: Model::Nodes(constants),
+            multipliers,
+            max_nodes: None,
+        }
+    }
+
+    pub fn servers(
+        constants: HacknetServerConstants,
+        multipliers: HacknetMultipliers,
+        hash_value: f64,
+    ) -> Self {
+        PaybackOptimizer {
+            model: Model::Servers {
+                constants,
+                hash_value,
+            },
+            multipliers,
+            max_nodes: None,
+        }
+    }
+
+    /// Stops proposing purchases at `max` nodes, like `maxNumNodes` says.
+    pub fn max_nodes(mut self, max: u32) -> Self {
+        self.max_nodes = Some(max);
+        self
+    }
+
+    /// Every possible action that increases production, from the fastest
+    /// payback to the slowest.
+    pub fn candidates(&self, nodes: &[NodeStats]) -> Vec<Candidate> {
+        let mut candidates = vec![];
+        let mut push = |action, cost: f64, gain: f64| {
+            if cost.is_finite() && gain > 0.0 {
+                candidates.push(Candidate {
+                    action,
+                    cost,
+                    gain,
+                    payback: cost / gain,
+                });
+            }
+        };
+
+        let count = nodes.len() as u32;
+        if self.max_nodes.is_none_or(|max| count < max) {
+            let fresh = NodeStats {
+                level: 1.0,
+                ram: 1.0,
+                cores: 1.0,
+                ..NodeStats::default()
+            };
+            push(
+                HacknetAction::Purchase,
+                self.purchase_cost(count + 1),
+                self.production(&fresh),
+            );
+        }
+
+        let mults = &self.multipliers;
+        for (index, node) in nodes.iter().enumerate() {
+            let index = index as u32;
+            let current = self.production(node);
+            let (level_cost, ram_cost, core_cost) = match &self.model {
+                Model::Nodes(constants) => (
+                    constants.level_upgrade_cost(node.level, 1, mults.level_cost),
+                    constants.ram_upgrade_cost(node.ram, 1, mults.ram_cost),
+                    constants.core_upgrade_cost(node.cores, 1, mults.core_cost),
+                ),
+                Model::Servers { constants, .. } => (
+                    constants.level_upgrade_cost(node.level, 1, mults.level_cost),
+                    constants.ram_upgrade_cost(node.ram, 1, mults.ram_cost),
+                    constants.core_upgrade_cost(node.cores, 1, mults.core_cost),
+                ),
+            };
+            let upgraded = |node: NodeStats| self.production(&node) - current;
+            push(
+                HacknetAction::Level(index),
+                level_cost,
+                upgraded(NodeStats {
+                    level: node.level + 1.0,
+                    ..node.clone()
+                }),
+            );
+            push(
+                HacknetAction::Ram(index),
+                ram_cost,
+                upgraded(NodeStats {
+                    ram: node.ram * 2.0,
+                    ..node.clone()
+                }),
+            );
+            push(
+                HacknetAction::Core(index),
+                core_cost,
+                upgraded(NodeStats {
+                    cores: node.cores + 1.0,
+                    ..node.clone()
+                }),
+            );
+        }
+
+        candidates.sort_by(|a, b| a.payback.total_cmp(&b.payback));
+        candidates
+    }
+
+    /// The action with the fastest payback, `None` if nothing increases
+    /// production anymore.
+    pub fn best(&self, nodes: &[NodeStats]) -> Option<Candidate> {
+        self.candidates(nodes).into_iter().next()
+    }
+
+    /// The action with the fastest payback among those costing at most
+    /// `budget`.
+    pub fn best_within(&self, nodes: &[NodeStats], budget: f64) -> Option<Candidate> {
+        self.candidates(nodes)
+            .into_iter()
+            .find(|candidate| candidate.cost <= budget)
+    }
+
+    /// Production of `node` in money per second.
+    fn production(&self, node: &NodeStats) -> f64 {
+        let mult = self.multipliers.production;
+        match &self.model {
+            Model::Nodes(constants) => {
+                constants.money_gain_rate(node.level, node.ram, node.cores, mult)
+            }
+            Model::Servers {
+                constants,
+                hash_value,
+            } => {
+                let ram_used = node.ram_used.unwrap_or(0.0);
+                constants.hash_gain_rate(node.level, ram_used, node.ram, node.cores, mult)
+                    * hash_value
+            }
+        }
+    }
+
+    fn purchase_cost(&self, n: u32) -> f64 {
+        let mult = self.multipliers.purchase_cost;
+        match &self.model {
+            Model::Nodes(constants) => constants.node_cost(n, mult),
+            Model::Servers { constants, .. } => constants.server_cost(n, mult),
+        }
+    }
+}
+
+impl Hacknet {
+    pub fn num_nodes(&self) -> Result<u32, JsValue> {
+        Ok(self.numNodes()? as u32)
+    }
+
+    /// Maximum number of nodes, `u32::MAX` when unlimited.
+    pub fn max_num_nodes(&self) -> Result<u32, JsValue> {
+        let max = self.maxNumNodes()?;
+        Ok(if max.is_finite() {
+            max as u32
+        } else {
+            u32::MAX
+        })
+    }
+
+    /// Buys a node, returns its index or `None` if it could not be bought.
+    pub fn purchase_node(&self) -> Result<Option<u32>, JsValue> {
+        let index = self.purchaseNode()?;
+        Ok((index >= 0.0).then_some(index as u32))
+    }
+
+    pub fn get_node_stats(&self, index: u32) -> Result<NodeStats, JsValue> {
+        let stats = self.getNodeStats(index.into())?;
+        Ok(serde_wasm_bindgen::from_value(stats.into())?)
+    }
+
+    /// Stats of all the nodes, by index.
+    pub fn get_nodes(&self) -> Result<Vec<NodeStats>, JsValue> {
+        (0..self.num_nodes()?)
+            .map(|index| self.get_node_stats(index))
+            .collect()
+    }
+
+    /// Cost of doing `action`, infinite when it is not possible.
+    pub fn action_cost(&self, action: HacknetAction) -> Result<f64, JsValue> {
+        match action {
+            HacknetAction::Purchase => self.getPurchaseNodeCost(),
+            HacknetAction::Level(index) => self.getLevelUpgradeCost(index.into(), None),
+            HacknetAction::Ram(index) => self.getRamUpgradeCost(index.into(), None),
+            HacknetAction::Core(index) => self.getCoreUpgradeCost(index.into(), None),
+            HacknetAction::Cache(index) => self.getCacheUpgradeCost(index.into(), None),
+        }
+    }
+
+    /// Does `action` once, returns whether it succeeded.
+    pub fn apply(&self, action: HacknetAction) -> Result<bool, JsValue> {
+        match action {
+            HacknetAction::Purchase => Ok(self.purchase_node()?.is_some()),
+            HacknetAction::Level(index) => self.upgradeLevel(index.into(), None),
+            HacknetAction::Ram(index) => self.upgradeRam(index.into(), None),
+            HacknetAction::Core(index) => self.upgradeCore(index.into(), None),
+            HacknetAction::Cache(index) => self.upgradeCache(index.into(), None),
+        }
+    }
+}
+
+impl HacknetNodesFormulas {
+    pub fn get_constants(&self) -> Result<HacknetNodeConstants, JsValue> {
+        self.constants()?.try_into()
+    }
+}
+
+impl HacknetServersFormulas {
+    pub fn get_constants(&self) -> Result<HacknetServerConstants, JsValue> {
+        self.constants()?.try_into()
+    }
+}
+
+impl NS {
+    pub fn get_hacknet_multipliers(&self) -> Result<HacknetMultipliers, JsValue> {
+        self.getHacknetMultipliers()?.try_into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(level: f64, ram: f64, cores: f64) -> NodeStats {
+        NodeStats {
+            level,
+            ram,
+            cores,
+            ..NodeStats::default()
+        }
+    }
+
+    fn optimizer() -> PaybackOptimizer {
+        PaybackOptimizer::nodes(
+            HacknetNodeConstants::default(),
+            HacknetMultipliers::default(),
+        )
+    }
+
+    #[test]
+    fn zero_income_proposes_nothing() {
+        let multipliers = HacknetMultipliers {
+            production: 0.0,
+            ..HacknetMultipliers::default()
+        };
+        let optimizer = PaybackOptimizer::nodes(HacknetNodeConstants::default(), multipliers);
+        assert!(optimizer.candidates(&[]).is_empty());
+        assert_eq!(optimizer.best(&[node(1.0, 1.0, 1.0)]), None);
+
+        // hashes worth nothing earn nothing either
+        let optimizer = PaybackOptimizer::servers(
+            HacknetServerConstants::default(),
+            HacknetMultipliers::default(),
+            0.0,
+        );
+        assert_eq!(optimizer.best(&[node(1.0, 1.0, 1.0)]), None);
+    }
+
+    #[test]
+    fn maxed_upgrades_are_never_proposed() {
+        let maxed = node(200.0, 64.0, 16.0);
+        let optimizer = optimizer().max_nodes(1);
+        // their cost is infinite, so would be their payback time
+        assert_eq!(optimizer.best(std::slice::from_ref(&maxed)), None);
+
+        let candidates = optimizer.candidates(&[maxed, node(199.0, 64.0, 16.0)]);
+        let actions: Vec<HacknetAction> = candidates.iter().map(|c| c.action).collect();
+        assert_eq!(actions, [HacknetAction::Level(1)]);
+    }
+
+    #[test]
+    fn payback_times_are_finite_and_sorted() {
+        let nodes = [node(1.0, 1.0, 1.0), node(50.0, 8.0, 4.0)];
+        let candidates = optimizer().candidates(&nodes);
+        assert_eq!(candidates.len(), 7);
+        for candidate in &candidates {
+            assert!(candidate.payback.is_finite(), "{candidate:?}");
+            assert_eq!(candidate.payback, candidate.cost / candidate.gain);
+        }
+        assert!(candidates
+            .windows(2)
+            .all(|pair| pair[0].payback <= pair[1].payback));
+    }
+
+    #[test]
+    fn max_nodes_stops_purchases() {
+        let nodes = [node(1.0, 1.0, 1.0)];
+        let purchases = |optimizer: PaybackOptimizer| {
+            optimizer
+                .candidates(&nodes)
+                .iter()
+                .filter(|candidate| candidate.action == HacknetAction::Purchase)
+                .count()
+        };
+        assert_eq!(purchases(optimizer()), 1);
+        assert_eq!(purchases(optimizer().max_nodes(1)), 0);
+        assert_eq!(optimizer().max_nodes(0).best(&[]), None);
+    }
+
+    #[test]
+    fn budget() {
+        let nodes = [node(1.0, 1.0, 1.0)];
+        assert_eq!(optimizer().best_within(&nodes, 0.0), None);
+        let best = optimizer().best_within(&nodes, 1e12).unwrap();
+        assert_eq!(Some(best), optimizer().best(&nodes));
+    }
+}
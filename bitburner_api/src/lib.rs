@@ -1,51 +1,97 @@
+//! Rust bindings for the Bitburner Netscript API.
+//!
+//! Scripts export a `main_rs` function taking the [`NS`] object. It may be
+//! `async` to await the Netscript functions returning promises, the generated
+//! JavaScript awaits it either way:
+//!
+//! ```ignore
+//! use bitburner_api::{wasm_bindgen, wasm_bindgen_futures, JsValue, NS};
+//!
+//! #[wasm_bindgen]
+//! pub async fn main_rs(ns: &NS) -> Result<(), JsValue> {
+//!     loop {
+//!         ns.weaken("n00dles", None).await?;
+//!     }
+//! }
+//! ```
+
 pub extern crate js_sys;
+pub extern crate log;
 pub extern crate wasm_bindgen;
+pub extern crate wasm_bindgen_futures;
 pub use wasm_bindgen::{prelude::*, JsValue};
 
-// thank you github.com/paulcdejean
-#[wasm_bindgen]
-extern "C" {
-    // Continue adding more imported structs from Bitburner and their associated
-    // methods in here.
-    //
-    // For object attributes, skip to after this `extern` block.
+use serde::{Deserialize, Serialize};
 
-    #[wasm_bindgen]
-    pub fn alert(msg: &str);
+#[macro_use]
+mod enums;
+pub use enums::ParseEnumError;
+
+pub mod bindings;
+pub use bindings::*;
+
+mod player;
+pub use player::{Multipliers, Person, Player, Skills, HP};
+
+mod server;
+pub use server::Server;
+
+mod scripts;
+pub use scripts::{FilenameOrPid, Pid, RunOptions, RunningScript, ScriptArg, SpawnOptions};
 
-    pub type NS;
+mod api;
+pub use api::NetscriptApi;
 
-    #[wasm_bindgen(method, getter)]
-    pub fn args(this: &NS) -> Vec<JsValue>;
+pub mod mock;
+pub use mock::MockNs;
 
-    #[wasm_bindgen(method)]
-    pub fn tprint(this: &NS, print: &str);
+pub mod batch;
+pub mod bladeburner;
+pub mod contracts;
+pub mod corporation;
+pub mod formulas;
+pub mod gang;
+pub mod hacknet;
+pub mod logger;
 
-    #[wasm_bindgen(method)]
-    pub fn scan(this: &NS, scan: Option<&str>) -> Vec<JsValue>;
+pub mod network;
 
-    #[wasm_bindgen(catch, method)]
-    pub fn nuke(this: &NS, host: &str) -> Result<(), JsValue>;
+pub mod ports;
 
-    #[wasm_bindgen(catch, method)]
-    pub fn brutessh(this: &NS, hostname: &str) -> Result<(), JsValue>;
+pub mod rooting;
 
-    #[wasm_bindgen(catch, method)]
-    pub fn ftpcrack(this: &NS, hostname: &str) -> Result<(), JsValue>;
+pub mod report;
 
-    #[wasm_bindgen(catch, method)]
-    pub fn relaysmtp(this: &NS, hostname: &str) -> Result<(), JsValue>;
+pub mod script_args;
 
-    #[wasm_bindgen(catch, method)]
-    pub fn httpworm(this: &NS, hostname: &str) -> Result<(), JsValue>;
+pub mod singularity;
 
-    #[wasm_bindgen(catch, method)]
-    pub fn sqlinject(this: &NS, hostname: &str) -> Result<(), JsValue>;
+pub mod sleeve;
 
-    #[wasm_bindgen(method)]
-    pub fn getServer(this: &NS, host: Option<&str>) -> Server;
+pub mod stanek;
+
+pub mod stock;
+
+pub mod ui;
+pub use bitburner_api_derive::ScriptArgs;
+pub use script_args::ScriptArgs;
+
+// thank you github.com/paulcdejean
+#[wasm_bindgen]
+extern "C" {
+    // The Netscript API itself is generated into the `bindings` module from
+    // `bitburner.d.ts` by `cargo xtask gen-bindings`, only imports that are
+    // not part of the definitions belong here.
+
+    #[wasm_bindgen]
+    pub fn alert(msg: &str);
+}
 
-    pub type Server;
+/// Serializes `value` into a plain object that the game accepts in place of
+/// its own interfaces, maps included.
+pub(crate) fn to_js_object<T: Serialize + ?Sized>(value: &T) -> Result<JsValue, JsValue> {
+    let serializer = serde_wasm_bindgen::Serializer::new().serialize_maps_as_objects(true);
+    Ok(value.serialize(&serializer)?)
 }
 
 pub fn get_attribute<T>(
@@ -56,7 +102,8 @@ pub fn get_attribute<T>(
     js_sys::Reflect::get(object, &JsValue::from_str(field_name)).map(|x| mapper(&x))
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
 pub enum Args {
     Bool(bool),
     F64(f64),
@@ -0,0 +1,236 @@
+//! A [`log`] backend writing to the game.
+//!
+//! ```ignore
+//! use bitburner_api::log::{self, Level};
+//! use bitburner_api::logger::{LogOutput, NsLogger};
+//!
+//! NsLogger::new()
+//!     .output(Level::Warn, LogOutput::Toast)
+//!     .init(ns)
+//!     .unwrap();
+//! log::info!("hacking {target}");
+//! ```
+//!
+//! By default debug and info records go to the script log with `ns.print`
+//! and warnings and errors to the terminal with `ns.tprint`. The game does
+//! not tell whether `ns.disableLog("ALL")` was called, so the logger has its
+//! own switch: [`NsLogger::disable`] drops the records meant for the script
+//! log until [`NsLogger::enable`].
+//!
+//! ```ignore
+//! ns.disableLog("ALL");
+//! NsLogger::disable();
+//! ```
+
+use std::cell::{Cell, RefCell};
+
+use log::{Level, LevelFilter, Log, Metadata, Record, SetLoggerError};
+
+use crate::{NetscriptApi, ToastVariant, NS};
+
+/// Where the records of a level are written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogOutput {
+    /// The script log, with `ns.print`
+    Print,
+    /// The terminal, with `ns.tprint`
+    Tprint,
+    /// A toast, with `ns.toast`
+    Toast,
+    Off,
+}
+
+/// Configuration of the logger, installed with [`NsLogger::init`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NsLogger {
+    max_level: LevelFilter,
+    /// Outputs indexed by level, from error to trace
+    outputs: [LogOutput; 5],
+    /// Whether records meant for the script log are printed
+    print: bool,
+}
+
+impl Default for NsLogger {
+    fn default() -> Self {
+        NsLogger {
+            max_level: LevelFilter::Info,
+            outputs: [
+                LogOutput::Tprint,
+                LogOutput::Tprint,
+                LogOutput::Print,
+                LogOutput::Print,
+                LogOutput::Print,
+            ],
+            print: true,
+        }
+    }
+}
+
+impl NsLogger {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records more verbose than `level` are ignored, `Info` by default.
+    pub fn max_level(mut self, level: LevelFilter) -> Self {
+        self.max_level = level;
+        self
+    }
+
+    /// Sends the records of `level` to `output`.
+    pub fn output(mut self, level: Level, output: LogOutput) -> Self {
+        self.outputs[level as usize - 1] = output;
+        self
+    }
+
+    /// Installs the logger, writing to `ns`. Later runs of the same script
+    /// share the logger, calling this again rebinds it to their `ns` and
+    /// configuration. Fails if another logger was installed first.
+    pub fn init(self, ns: &NS) -> Result<(), SetLoggerError> {
+        if !INSTALLED.with(Cell::get) {
+            log::set_logger(&NsLog)?;
+            INSTALLED.with(|installed| installed.set(true));
+        }
+        log::set_max_level(self.max_level);
+        STATE.with(|state| *state.borrow_mut() = Some((ns.clone(), self)));
+        Ok(())
+    }
+
+    /// Drops the records meant for the script log until [`NsLogger::enable`],
+    /// records for the terminal and toasts are still written. Does nothing
+    /// before [`NsLogger::init`], which enables the script log again.
+    pub fn disable() {
+        Self::set_print(false);
+    }
+
+    /// Prints the records meant for the script log again.
+    pub fn enable() {
+        Self::set_print(true);
+    }
+
+    fn set_print(print: bool) {
+        STATE.with(|state| {
+            if let Some((_, logger)) = &mut *state.borrow_mut() {
+                logger.print = print;
+            }
+        });
+    }
+}
+
+thread_local! {
+    static INSTALLED: Cell<bool> = const { Cell::new(false) };
+    static STATE: RefCell<Option<(NS, NsLogger)>> = const { RefCell::new(None) };
+}
+
+/// The installed logger, its state is kept per thread as `NS` cannot be
+/// shared across threads.
+struct NsLog;
+
+impl Log for NsLog {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        STATE.with(|state| match &*state.borrow() {
+            Some((_, logger)) => metadata.level() <= logger.max_level,
+            None => false,
+        })
+    }
+
+    fn log(&self, record: &Record) {
+        STATE.with(|state| {
+            if let Some((ns, logger)) = &*state.borrow() {
+                write(ns, logger, record);
+            }
+        });
+    }
+
+    fn flush(&self) {}
+}
+
+/// Writes `record` where `logger` sends its level.
+fn write<N: NetscriptApi>(ns: &N, logger: &NsLogger, record: &Record) {
+    if record.level() > logger.max_level {
+        return;
+    }
+    // the game colors lines starting with the level
+    let message = format!("{} {}", record.level(), record.args());
+    let output = logger.outputs[record.level() as usize - 1];
+    // a logger has nowhere to report its own failures to
+    let _ = match output {
+        LogOutput::Print if logger.print => ns.print(&message),
+        LogOutput::Print | LogOutput::Off => Ok(()),
+        LogOutput::Tprint => ns.tprint(&message),
+        LogOutput::Toast => {
+            let variant = match record.level() {
+                Level::Error => ToastVariant::Error,
+                Level::Warn => ToastVariant::Warning,
+                _ => ToastVariant::Info,
+            };
+            ns.toast(&message, variant)
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MockNs;
+
+    fn write_at(ns: &MockNs, logger: &NsLogger, level: Level, message: &str) {
+        let args = format_args!("{message}");
+        let record = Record::builder().level(level).args(args).build();
+        write(ns, logger, &record);
+    }
+
+    #[test]
+    fn default_outputs() {
+        let ns = MockNs::new();
+        let logger = NsLogger::new();
+        write_at(&ns, &logger, Level::Info, "hacking n00dles");
+        write_at(&ns, &logger, Level::Error, "out of RAM");
+        write_at(&ns, &logger, Level::Debug, "too verbose");
+        assert_eq!(ns.logs(), ["INFO hacking n00dles"]);
+        assert_eq!(ns.terminal(), ["ERROR out of RAM"]);
+    }
+
+    fn disabled() -> NsLogger {
+        NsLogger {
+            print: false,
+            ..NsLogger::new()
+        }
+    }
+
+    #[test]
+    fn disabled_logger_prints_nothing() {
+        let ns = MockNs::new();
+        write_at(&ns, &disabled(), Level::Info, "hacking n00dles");
+        assert!(ns.logs().is_empty());
+
+        write_at(&ns, &NsLogger::new(), Level::Info, "hacking n00dles");
+        assert_eq!(ns.logs(), ["INFO hacking n00dles"]);
+    }
+
+    #[test]
+    fn function_logs_do_not_silence_the_logger() {
+        let ns = MockNs::new();
+        ns.disable_log("nuke").unwrap();
+        write_at(&ns, &NsLogger::new(), Level::Info, "rooted n00dles");
+        ns.disable_log("ALL").unwrap();
+        write_at(&ns, &NsLogger::new(), Level::Info, "hacking n00dles");
+        assert_eq!(ns.logs(), ["INFO rooted n00dles", "INFO hacking n00dles"]);
+    }
+
+    #[test]
+    fn disabled_logger_still_reaches_terminal_and_toasts() {
+        let ns = MockNs::new();
+        let logger = NsLogger {
+            print: false,
+            ..NsLogger::new().output(Level::Warn, LogOutput::Toast)
+        };
+        write_at(&ns, &logger, Level::Warn, "low money");
+        write_at(&ns, &logger, Level::Error, "out of RAM");
+        assert_eq!(
+            ns.toasts(),
+            [("WARN low money".to_owned(), ToastVariant::Warning)]
+        );
+        assert_eq!(ns.terminal(), ["ERROR out of RAM"]);
+    }
+}
@@ -0,0 +1,955 @@
+//! An in-memory [`NetscriptApi`] for testing script logic natively.
+//!
+//! ```
+//! use bitburner_api::{mock, MockNs, NetscriptApi, Server};
+//!
+//! let mut ns = MockNs::new();
+//! ns.add_server(
+//!     Server {
+//!         has_admin_rights: true,
+//!         money_available: Some(1000.0),
+//!         money_max: Some(2000.0),
+//!         ..mock::server("n00dles")
+//!     },
+//!     &["home"],
+//! );
+//!
+//! assert_eq!(ns.scan(None).unwrap(), ["n00dles"]);
+//! let stolen = mock::block_on(ns.hack("n00dles")).unwrap();
+//! assert_eq!(ns.money(), stolen);
+//! ```
+//!
+//! Hacking follows a simplified model rather than the game's formulas: hack
+//! steals a share of the money that shrinks with the security level, grow
+//! multiplies the money by a factor that increases with the server growth,
+//! and weaken lowers the security towards its minimum. Hack and grow raise the
+//! security like a single thread would in the game. Every action advances a
+//! virtual clock by its duration instead of waiting. The analyze functions
+//! follow the same model and ignore cores.
+//!
+//! Scripts added with [`MockNs::add_script`] can be copied and executed, which
+//! takes their RAM on the server and records a [`MockProcess`] but runs
+//! nothing.
+//!
+//! Like in the game, the functions disabled with
+//! [`NetscriptApi::disable_log`] stop writing to [`MockNs::logs`], and asking
+//! whether `"ALL"` is enabled is an error.
+
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, BTreeSet},
+    future::Future,
+    pin::pin,
+    task::{Context, Poll, Waker},
+};
+
+use crate::{NetscriptApi, Pid, ScriptArg, Server, ToastVariant};
+
+/// Share of the money a hack steals at zero security
+pub const HACK_FRACTION: f64 = 0.1;
+/// Security added by a hack
+pub const HACK_SECURITY: f64 = 0.002;
+/// Security added by a grow
+pub const GROW_SECURITY: f64 = 0.004;
+/// Security removed by a weaken
+pub const WEAKEN_SECURITY: f64 = 0.05;
+/// Milliseconds a hack takes per point of security
+pub const HACK_TIME_PER_SECURITY: f64 = 200.0;
+
+/// A server to add to a [`MockNs`], with no money, security 1 and no
+/// requirements to hack. Meant to be completed with struct update syntax.
+pub fn server(hostname: &str) -> Server {
+    Server {
+        hostname: hostname.to_owned(),
+        ip: String::new(),
+        cpu_cores: 1,
+        organization_name: hostname.to_owned(),
+        backdoor_installed: Some(false),
+        base_difficulty: Some(1.0),
+        hack_difficulty: Some(1.0),
+        min_difficulty: Some(1.0),
+        money_available: Some(0.0),
+        money_max: Some(0.0),
+        num_open_ports_required: Some(0),
+        open_port_count: Some(0),
+        required_hacking_skill: Some(1.0),
+        server_growth: Some(1.0),
+        ..Server::default()
+    }
+}
+
+/// Runs a future of a [`MockNs`] to completion. Its futures never wait, so
+/// this panics if the future is not ready right away.
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    match future
+        .as_mut()
+        .poll(&mut Context::from_waker(Waker::noop()))
+    {
+        Poll::Ready(output) => output,
+        Poll::Pending => {
+            panic!("MockNs futures are always ready, this one is waiting on something else")
+        }
+    }
+}
+
+/// A script started by [`NetscriptApi::exec`] on a [`MockNs`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct MockProcess {
+    pub pid: Pid,
+    pub script: String,
+    pub host: String,
+    pub threads: u32,
+    pub args: Vec<ScriptArg>,
+}
+
+/// A fake game holding a network of servers, the player's money and hacking
+/// level, and what the script printed.
+#[derive(Debug, Default)]
+pub struct MockNs {
+    state: RefCell<State>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    servers: BTreeMap<String, Server>,
+    /// Connections of every server, in the order they were made
+    links: BTreeMap<String, Vec<String>>,
+    /// Files of every server that has any
+    files: BTreeMap<String, BTreeSet<String>>,
+    /// RAM (GB) of a thread of every known script
+    script_ram: BTreeMap<String, f64>,
+    processes: Vec<MockProcess>,
+    /// PID of the last script started
+    last_pid: u32,
+    host: String,
+    hacking_level: f64,
+    money: f64,
+    time: f64,
+    logs: Vec<String>,
+    log_toggles: LogToggles,
+    terminal: Vec<String>,
+    toasts: Vec<(String, ToastVariant)>,
+}
+
+/// Which functions write to the script log, like `disableLog` sets them up
+#[derive(Debug, Default)]
+struct LogToggles {
+    /// Whether the functions not in `functions` are disabled
+    all_disabled: bool,
+    /// Functions enabled or disabled since `"ALL"` last was
+    functions: BTreeMap<String, bool>,
+}
+
+impl LogToggles {
+    fn enabled(&self, function: &str) -> bool {
+        self.functions
+            .get(function)
+            .copied()
+            .unwrap_or(!self.all_disabled)
+    }
+
+    fn set(&mut self, function: &str, enabled: bool) {
+        if function == "ALL" {
+            self.all_disabled = !enabled;
+            self.functions.clear();
+        } else {
+            self.functions.insert(function.to_owned(), enabled);
+        }
+    }
+}
+
+impl MockNs {
+    /// A network with only `home`, which the script runs on.
+    pub fn new() -> Self {
+        let home = Server {
+            has_admin_rights: true,
+            max_ram: 8.0,
+            purchased_by_player: true,
+            is_connected_to: true,
+            ..server("home")
+        };
+        let ns = MockNs::default();
+        {
+            let mut state = ns.state.borrow_mut();
+            state.host = "home".to_owned();
+            state.hacking_level = 1.0;
+            state.links.insert("home".to_owned(), vec![]);
+            state.servers.insert("home".to_owned(), home);
+        }
+        ns
+    }
+
+    /// Adds a server connected to `neighbours`, which must already exist.
+    pub fn add_server(&mut self, server: Server, neighbours: &[&str]) {
+        let hostname = server.hostname.clone();
+        let state = self.state.get_mut();
+        state.servers.insert(hostname.clone(), server);
+        state.links.entry(hostname.clone()).or_default();
+        for neighbour in neighbours {
+            self.connect(&hostname, neighbour);
+        }
+    }
+
+    /// Connects two existing servers.
+    pub fn connect(&mut self, a: &str, b: &str) {
+        let links = &mut self.state.get_mut().links;
+        for (from, to) in [(a, b), (b, a)] {
+            links
+                .get_mut(from)
+                .unwrap_or_else(|| panic!("{from} is not in the network"))
+                .push(to.to_owned());
+        }
+    }
+
+    /// Puts a file on `host`, e.g. a port opener like `BruteSSH.exe` on
+    /// `home`.
+    pub fn add_file(&mut self, host: &str, filename: &str) {
+        let files = &mut self.state.get_mut().files;
+        files
+            .entry(host.to_owned())
+            .or_default()
+            .insert(filename.to_owned());
+    }
+
+    /// Puts a script needing `ram` GB per thread on `host`.
+    pub fn add_script(&mut self, host: &str, filename: &str, ram: f64) {
+        self.add_file(host, filename);
+        let script_ram = &mut self.state.get_mut().script_ram;
+        script_ram.insert(filename.to_owned(), ram);
+    }
+
+    /// Scripts running, in the order they were started.
+    pub fn processes(&self) -> Vec<MockProcess> {
+        self.state.borrow().processes.clone()
+    }
+
+    /// The current state of `host`.
+    pub fn server(&self, host: &str) -> Option<Server> {
+        self.state.borrow().servers.get(host).cloned()
+    }
+
+    /// Changes the server the script runs on.
+    pub fn set_host(&mut self, host: &str) {
+        self.state.get_mut().host = host.to_owned();
+    }
+
+    pub fn set_hacking_level(&mut self, level: f64) {
+        self.state.get_mut().hacking_level = level;
+    }
+
+    pub fn money(&self) -> f64 {
+        self.state.borrow().money
+    }
+
+    pub fn set_money(&mut self, money: f64) {
+        self.state.get_mut().money = money;
+    }
+
+    /// Milliseconds spent in hacks, grows, weakens and sleeps so far.
+    pub fn elapsed(&self) -> f64 {
+        self.state.borrow().time
+    }
+
+    /// Lines written to the script log.
+    pub fn logs(&self) -> Vec<String> {
+        self.state.borrow().logs.clone()
+    }
+
+    /// Lines written to the terminal.
+    pub fn terminal(&self) -> Vec<String> {
+        self.state.borrow().terminal.clone()
+    }
+
+    /// Toasts shown so far, in order.
+    pub fn toasts(&self) -> Vec<(String, ToastVariant)> {
+        self.state.borrow().toasts.clone()
+    }
+
+    /// Reads a property of `host`.
+    fn read<T>(&self, host: &str, read: impl FnOnce(&Server) -> T) -> Result<T, String> {
+        let state = self.state.borrow();
+        state
+            .servers
+            .get(host)
+            .map(read)
+            .ok_or_else(|| invalid_host(host))
+    }
+
+    /// Runs `action` on a server the player has root access to, then
+    /// advances the clock by the time it took.
+    fn act(
+        &self,
+        host: &str,
+        action: &str,
+        duration: f64,
+        act: impl FnOnce(&mut Server, &mut f64) -> f64,
+    ) -> Result<f64, String> {
+        let mut state = self.state.borrow_mut();
+        let State {
+            servers,
+            money,
+            time,
+            logs,
+            log_toggles,
+            ..
+        } = &mut *state;
+        let server = servers.get_mut(host).ok_or_else(|| invalid_host(host))?;
+        if !server.has_admin_rights {
+            return Err(format!(
+                "{action}: Insufficient permissions to {action} {host}"
+            ));
+        }
+        let result = act(server, money);
+        *time += duration;
+        if log_toggles.enabled(action) {
+            logs.push(format!("{action}: {host} => {result}"));
+        }
+        Ok(result)
+    }
+
+    /// Opens a port of `host` with a program found on `home`.
+    fn open_port(
+        &self,
+        host: &str,
+        program: &str,
+        port: impl FnOnce(&mut Server) -> &mut bool,
+    ) -> Result<(), String> {
+        let mut state = self.state.borrow_mut();
+        let State { servers, files, .. } = &mut *state;
+        let has_program = files
+            .get("home")
+            .is_some_and(|files| files.contains(program));
+        let name = program.trim_end_matches(".exe").to_lowercase();
+        if !has_program {
+            return Err(format!("{name}: You do not have the {program} program"));
+        }
+        let server = servers.get_mut(host).ok_or_else(|| invalid_host(host))?;
+        let open = port(server);
+        if !*open {
+            *open = true;
+            server.open_port_count = Some(server.open_port_count.unwrap_or(0) + 1);
+        }
+        Ok(())
+    }
+}
+
+fn invalid_host(host: &str) -> String {
+    format!("Invalid hostname: '{host}'")
+}
+
+fn security(server: &Server) -> f64 {
+    server.hack_difficulty.unwrap_or(1.0)
+}
+
+/// Share of the money a thread of hack steals.
+fn hack_fraction(server: &Server) -> f64 {
+    (HACK_FRACTION * (100.0 - security(server)) / 100.0).max(0.0)
+}
+
+/// Factor a thread of grow multiplies the money by.
+fn grow_factor(server: &Server) -> f64 {
+    let rate = server.server_growth.unwrap_or(1.0) / 100.0;
+    1.0 + rate * (100.0 - security(server)) / 100.0
+}
+
+fn raise_security(server: &mut Server, amount: f64) {
+    server.hack_difficulty = Some((security(server) + amount).min(100.0));
+}
+
+impl NetscriptApi for MockNs {
+    fn scan(&self, host: Option<&str>) -> Result<Vec<String>, String> {
+        let state = self.state.borrow();
+        let host = host.unwrap_or(&state.host);
+        state
+            .links
+            .get(host)
+            .cloned()
+            .ok_or_else(|| invalid_host(host))
+    }
+
+    fn get_server(&self, host: Option<&str>) -> Result<Server, String> {
+        let host = host.map_or_else(|| self.state.borrow().host.clone(), str::to_owned);
+        self.read(&host, Server::clone)
+    }
+
+    fn has_root_access(&self, host: &str) -> Result<bool, String> {
+        self.read(host, |server| server.has_admin_rights)
+    }
+
+    fn get_hacking_level(&self) -> Result<f64, String> {
+        Ok(self.state.borrow().hacking_level)
+    }
+
+    fn get_server_money_available(&self, host: &str) -> Result<f64, String> {
+        // like the game, home holds the player's money
+        if host == "home" {
+            return Ok(self.money());
+        }
+        self.read(host, |server| server.money_available.unwrap_or(0.0))
+    }
+
+    fn get_server_max_money(&self, host: &str) -> Result<f64, String> {
+        self.read(host, |server| server.money_max.unwrap_or(0.0))
+    }
+
+    fn get_server_security_level(&self, host: &str) -> Result<f64, String> {
+        self.read(host, security)
+    }
+
+    fn get_server_min_security_level(&self, host: &str) -> Result<f64, String> {
+        self.read(host, |server| server.min_difficulty.unwrap_or(1.0))
+    }
+
+    fn get_server_required_hacking_level(&self, host: &str) -> Result<f64, String> {
+        self.read(host, |server| server.required_hacking_skill.unwrap_or(1.0))
+    }
+
+    fn get_server_num_ports_required(&self, host: &str) -> Result<u32, String> {
+        self.read(host, |server| server.num_open_ports_required.unwrap_or(0))
+    }
+
+    fn get_server_max_ram(&self, host: &str) -> Result<f64, String> {
+        self.read(host, |server| server.max_ram)
+    }
+
+    fn get_server_used_ram(&self, host: &str) -> Result<f64, String> {
+        self.read(host, |server| server.ram_used)
+    }
+
+    fn get_hack_time(&self, host: &str) -> Result<f64, String> {
+        self.read(host, |server| security(server) * HACK_TIME_PER_SECURITY)
+    }
+
+    fn get_grow_time(&self, host: &str) -> Result<f64, String> {
+        Ok(self.get_hack_time(host)? * 3.2)
+    }
+
+    fn get_weaken_time(&self, host: &str) -> Result<f64, String> {
+        Ok(self.get_hack_time(host)? * 4.0)
+    }
+
+    fn hack_analyze_threads(&self, host: &str, amount: f64) -> Result<f64, String> {
+        self.read(host, |server| {
+            let available = server.money_available.unwrap_or(0.0);
+            if !(0.0..=available).contains(&amount) {
+                return -1.0;
+            }
+            amount / (available * hack_fraction(server))
+        })
+    }
+
+    fn growth_analyze(&self, host: &str, multiplier: f64, _cores: u32) -> Result<f64, String> {
+        self.read(host, |server| {
+            if multiplier <= 1.0 {
+                return 0.0;
+            }
+            multiplier.ln() / grow_factor(server).ln()
+        })
+    }
+
+    fn weaken_analyze(&self, threads: u32, _cores: u32) -> Result<f64, String> {
+        Ok(f64::from(threads) * WEAKEN_SECURITY)
+    }
+
+    fn hack_analyze_security(&self, threads: u32, _host: &str) -> Result<f64, String> {
+        Ok(f64::from(threads) * HACK_SECURITY)
+    }
+
+    fn growth_analyze_security(
+        &self,
+        threads: u32,
+        _host: &str,
+        _cores: u32,
+    ) -> Result<f64, String> {
+        Ok(f64::from(threads) * GROW_SECURITY)
+    }
+
+    fn file_exists(&self, filename: &str, host: Option<&str>) -> Result<bool, String> {
+        let state = self.state.borrow();
+        let host = host.unwrap_or(&state.host);
+        if !state.servers.contains_key(host) {
+            return Err(invalid_host(host));
+        }
+        let files = state.files.get(host);
+        Ok(files.is_some_and(|files| files.contains(filename)))
+    }
+
+    fn ls(&self, host: &str, substring: Option<&str>) -> Result<Vec<String>, String> {
+        let state = self.state.borrow();
+        if !state.servers.contains_key(host) {
+            return Err(invalid_host(host));
+        }
+        let files = state.files.get(host).into_iter().flatten();
+        Ok(files
+            .filter(|file| substring.is_none_or(|substring| file.contains(substring)))
+            .cloned()
+            .collect())
+    }
+
+    fn scp(&self, files: &[&str], destination: &str, source: Option<&str>) -> Result<bool, String> {
+        let mut state = self.state.borrow_mut();
+        let source = source.map_or_else(|| state.host.clone(), str::to_owned);
+        for host in [&source, destination] {
+            if !state.servers.contains_key(host) {
+                return Err(invalid_host(host));
+            }
+        }
+        let available = state.files.get(&source).cloned().unwrap_or_default();
+        let copied = state.files.entry(destination.to_owned()).or_default();
+        let mut all = true;
+        for file in files {
+            if available.contains(*file) {
+                copied.insert((*file).to_owned());
+            } else {
+                all = false;
+            }
+        }
+        Ok(all)
+    }
+
+    fn get_script_ram(&self, script: &str, host: Option<&str>) -> Result<f64, String> {
+        if !self.file_exists(script, host)? {
+            return Ok(0.0);
+        }
+        let state = self.state.borrow();
+        Ok(state.script_ram.get(script).copied().unwrap_or(0.0))
+    }
+
+    fn exec(
+        &self,
+        script: &str,
+        host: &str,
+        threads: u32,
+        args: &[ScriptArg],
+    ) -> Result<Option<Pid>, String> {
+        if threads == 0 {
+            return Err(format!(
+                "exec: Invalid thread count passed to exec: {threads}"
+            ));
+        }
+        let ram = self.get_script_ram(script, Some(host))? * f64::from(threads);
+        let mut state = self.state.borrow_mut();
+        let State {
+            servers,
+            processes,
+            last_pid,
+            logs,
+            log_toggles,
+            ..
+        } = &mut *state;
+        let server = servers.get_mut(host).ok_or_else(|| invalid_host(host))?;
+        let logged = log_toggles.enabled("exec");
+        if ram == 0.0 {
+            if logged {
+                logs.push(format!(
+                    "exec: Could not find script '{script}' on '{host}'"
+                ));
+            }
+            return Ok(None);
+        }
+        if !server.has_admin_rights || server.max_ram - server.ram_used < ram {
+            if logged {
+                logs.push(format!("exec: Cannot run {script} on {host}"));
+            }
+            return Ok(None);
+        }
+        server.ram_used += ram;
+        *last_pid += 1;
+        let pid = Pid(*last_pid);
+        processes.push(MockProcess {
+            pid,
+            script: script.to_owned(),
+            host: host.to_owned(),
+            threads,
+            args: args.to_vec(),
+        });
+        Ok(Some(pid))
+    }
+
+    /// The calling script is not one of the mock's processes, so
+    /// `safetyguard` changes nothing.
+    fn kill_all(&self, host: Option<&str>, _safetyguard: Option<bool>) -> Result<bool, String> {
+        let mut state = self.state.borrow_mut();
+        let State {
+            servers,
+            processes,
+            script_ram,
+            host: current,
+            ..
+        } = &mut *state;
+        let host = host.unwrap_or(current.as_str());
+        let server = servers.get_mut(host).ok_or_else(|| invalid_host(host))?;
+        let running = processes.len();
+        processes.retain(|process| {
+            if process.host != host {
+                return true;
+            }
+            let ram = script_ram.get(&process.script).copied().unwrap_or(0.0);
+            server.ram_used -= ram * f64::from(process.threads);
+            false
+        });
+        Ok(processes.len() < running)
+    }
+
+    fn brutessh(&self, host: &str) -> Result<(), String> {
+        self.open_port(host, "BruteSSH.exe", |server| &mut server.ssh_port_open)
+    }
+
+    fn ftpcrack(&self, host: &str) -> Result<(), String> {
+        self.open_port(host, "FTPCrack.exe", |server| &mut server.ftp_port_open)
+    }
+
+    fn relaysmtp(&self, host: &str) -> Result<(), String> {
+        self.open_port(host, "relaySMTP.exe", |server| &mut server.smtp_port_open)
+    }
+
+    fn httpworm(&self, host: &str) -> Result<(), String> {
+        self.open_port(host, "HTTPWorm.exe", |server| &mut server.http_port_open)
+    }
+
+    fn sqlinject(&self, host: &str) -> Result<(), String> {
+        self.open_port(host, "SQLInject.exe", |server| &mut server.sql_port_open)
+    }
+
+    fn nuke(&self, host: &str) -> Result<(), String> {
+        let mut state = self.state.borrow_mut();
+        let server = state
+            .servers
+            .get_mut(host)
+            .ok_or_else(|| invalid_host(host))?;
+        let required = server.num_open_ports_required.unwrap_or(0);
+        if server.open_port_count.unwrap_or(0) < required {
+            return Err(format!(
+                "nuke: Not enough ports opened to use NUKE.exe virus on {host}"
+            ));
+        }
+        server.has_admin_rights = true;
+        Ok(())
+    }
+
+    async fn hack(&self, host: &str) -> Result<f64, String> {
+        let level = self.get_hacking_level()?;
+        let required = self.get_server_required_hacking_level(host)?;
+        if required > level {
+            return Err(format!(
+                "hack: Cannot hack {host}, requires hacking level {required}"
+            ));
+        }
+        let duration = self.get_hack_time(host)?;
+        self.act(host, "hack", duration, |server, money| {
+            let available = server.money_available.unwrap_or(0.0);
+            let stolen = available * hack_fraction(server);
+            server.money_available = Some(available - stolen);
+            raise_security(server, HACK_SECURITY);
+            *money += stolen;
+            stolen
+        })
+    }
+
+    async fn grow(&self, host: &str) -> Result<f64, String> {
+        let duration = self.get_grow_time(host)?;
+        self.act(host, "grow", duration, |server, _| {
+            let available = server.money_available.unwrap_or(0.0);
+            let max = server.money_max.unwrap_or(0.0);
+            let grown = ((available + 1.0) * grow_factor(server)).min(max);
+            server.money_available = Some(grown);
+            raise_security(server, GROW_SECURITY);
+            if available > 0.0 {
+                grown / available
+            } else {
+                1.0
+            }
+        })
+    }
+
+    async fn weaken(&self, host: &str) -> Result<f64, String> {
+        let duration = self.get_weaken_time(host)?;
+        self.act(host, "weaken", duration, |server, _| {
+            let current = security(server);
+            let min = server.min_difficulty.unwrap_or(1.0);
+            let weakened = (current - WEAKEN_SECURITY).max(min);
+            server.hack_difficulty = Some(weakened);
+            current - weakened
+        })
+    }
+
+    async fn sleep(&self, millis: f64) -> Result<(), String> {
+        self.state.borrow_mut().time += millis;
+        Ok(())
+    }
+
+    fn print(&self, message: &str) -> Result<(), String> {
+        self.state.borrow_mut().logs.push(message.to_owned());
+        Ok(())
+    }
+
+    fn tprint(&self, message: &str) -> Result<(), String> {
+        self.state.borrow_mut().terminal.push(message.to_owned());
+        Ok(())
+    }
+
+    fn toast(&self, message: &str, variant: ToastVariant) -> Result<(), String> {
+        let toasts = &mut self.state.borrow_mut().toasts;
+        toasts.push((message.to_owned(), variant));
+        Ok(())
+    }
+
+    fn disable_log(&self, function: &str) -> Result<(), String> {
+        self.state.borrow_mut().log_toggles.set(function, false);
+        Ok(())
+    }
+
+    fn enable_log(&self, function: &str) -> Result<(), String> {
+        self.state.borrow_mut().log_toggles.set(function, true);
+        Ok(())
+    }
+
+    fn is_log_enabled(&self, function: &str) -> Result<bool, String> {
+        if function == "ALL" {
+            return Err("isLogEnabled: Invalid argument: ALL.".to_owned());
+        }
+        Ok(self.state.borrow().log_toggles.enabled(function))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn with_runner() -> MockNs {
+        let mut ns = MockNs::new();
+        ns.add_script("home", "hack.js", 1.75);
+        ns.add_script("home", "grow.js", 1.75);
+        ns
+    }
+
+    /// A rooted server with money and security to hack, grow and weaken.
+    fn target() -> Server {
+        Server {
+            has_admin_rights: true,
+            hack_difficulty: Some(10.0),
+            min_difficulty: Some(5.0),
+            money_available: Some(1000.0),
+            money_max: Some(2000.0),
+            server_growth: Some(10.0),
+            ..server("n00dles")
+        }
+    }
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "{actual} is not {expected}"
+        );
+    }
+
+    #[test]
+    fn scan_lists_the_links_in_order() {
+        let mut ns = MockNs::new();
+        ns.add_server(server("n00dles"), &["home"]);
+        ns.add_server(server("foodnstuff"), &["home"]);
+        ns.add_server(server("CSEC"), &["n00dles", "foodnstuff"]);
+
+        assert_eq!(ns.scan(None).unwrap(), ["n00dles", "foodnstuff"]);
+        assert_eq!(ns.scan(Some("n00dles")).unwrap(), ["home", "CSEC"]);
+        assert_eq!(ns.scan(Some("CSEC")).unwrap(), ["n00dles", "foodnstuff"]);
+        assert_eq!(
+            ns.scan(Some("darkweb")).unwrap_err(),
+            "Invalid hostname: 'darkweb'"
+        );
+
+        ns.set_host("CSEC");
+        assert_eq!(ns.scan(None).unwrap(), ["n00dles", "foodnstuff"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "darkweb is not in the network")]
+    fn add_server_needs_known_neighbours() {
+        MockNs::new().add_server(server("n00dles"), &["darkweb"]);
+    }
+
+    #[test]
+    fn hack_steals_money_and_raises_security() {
+        let mut ns = MockNs::new();
+        ns.add_server(target(), &["home"]);
+
+        let stolen = block_on(ns.hack("n00dles")).unwrap();
+        // 10% at zero security, 9% at security 10
+        assert_close(stolen, 90.0);
+        assert_close(ns.money(), 90.0);
+        assert_close(ns.get_server_money_available("n00dles").unwrap(), 910.0);
+        assert_close(
+            ns.get_server_security_level("n00dles").unwrap(),
+            10.0 + HACK_SECURITY,
+        );
+        assert_close(ns.elapsed(), 10.0 * HACK_TIME_PER_SECURITY);
+        assert_eq!(ns.logs(), [format!("hack: n00dles => {stolen}")]);
+    }
+
+    #[test]
+    fn hack_needs_root_and_the_hacking_level() {
+        let mut ns = MockNs::new();
+        ns.add_server(
+            Server {
+                has_admin_rights: false,
+                required_hacking_skill: Some(5.0),
+                ..target()
+            },
+            &["home"],
+        );
+        assert_eq!(
+            block_on(ns.hack("n00dles")).unwrap_err(),
+            "hack: Cannot hack n00dles, requires hacking level 5"
+        );
+
+        ns.set_hacking_level(5.0);
+        assert_eq!(
+            block_on(ns.hack("n00dles")).unwrap_err(),
+            "hack: Insufficient permissions to hack n00dles"
+        );
+
+        ns.nuke("n00dles").unwrap();
+        assert!(block_on(ns.hack("n00dles")).is_ok());
+    }
+
+    #[test]
+    fn grow_multiplies_money_up_to_the_maximum() {
+        let mut ns = MockNs::new();
+        ns.add_server(target(), &["home"]);
+
+        // growth 10 at security 10 grows by 9% per thread
+        let multiplier = block_on(ns.grow("n00dles")).unwrap();
+        assert_close(multiplier, 1001.0 * 1.09 / 1000.0);
+        assert_close(
+            ns.get_server_money_available("n00dles").unwrap(),
+            1001.0 * 1.09,
+        );
+        assert_close(
+            ns.get_server_security_level("n00dles").unwrap(),
+            10.0 + GROW_SECURITY,
+        );
+        assert_close(ns.elapsed(), 10.0 * HACK_TIME_PER_SECURITY * 3.2);
+
+        for _ in 0..20 {
+            block_on(ns.grow("n00dles")).unwrap();
+        }
+        assert_eq!(ns.get_server_money_available("n00dles").unwrap(), 2000.0);
+    }
+
+    #[test]
+    fn grow_from_no_money() {
+        let mut ns = MockNs::new();
+        ns.add_server(
+            Server {
+                money_available: Some(0.0),
+                ..target()
+            },
+            &["home"],
+        );
+        assert_eq!(block_on(ns.grow("n00dles")).unwrap(), 1.0);
+        assert_close(ns.get_server_money_available("n00dles").unwrap(), 1.09);
+    }
+
+    #[test]
+    fn weaken_stops_at_the_minimum_security() {
+        let mut ns = MockNs::new();
+        ns.add_server(
+            Server {
+                hack_difficulty: Some(5.03),
+                ..target()
+            },
+            &["home"],
+        );
+
+        assert_close(block_on(ns.weaken("n00dles")).unwrap(), 0.03);
+        assert_eq!(ns.get_server_security_level("n00dles").unwrap(), 5.0);
+        assert_eq!(block_on(ns.weaken("n00dles")).unwrap(), 0.0);
+        assert_close(ns.elapsed(), (5.03 + 5.0) * HACK_TIME_PER_SECURITY * 4.0);
+    }
+
+    #[test]
+    fn exec_uses_the_ram_of_the_host() {
+        let ns = with_runner();
+        let pid = ns.exec("hack.js", "home", 4, &["n00dles".into()]).unwrap();
+        assert_eq!(pid, Some(Pid(1)));
+        assert_eq!(ns.get_server_used_ram("home").unwrap(), 7.0);
+        assert_eq!(
+            ns.processes(),
+            [MockProcess {
+                pid: Pid(1),
+                script: "hack.js".to_owned(),
+                host: "home".to_owned(),
+                threads: 4,
+                args: vec!["n00dles".into()],
+            }]
+        );
+
+        // 1 GB left, not enough for another thread
+        assert_eq!(ns.exec("grow.js", "home", 1, &[]).unwrap(), None);
+        assert_eq!(ns.logs(), ["exec: Cannot run grow.js on home"]);
+        assert_eq!(ns.get_server_used_ram("home").unwrap(), 7.0);
+    }
+
+    #[test]
+    fn exec_needs_the_script_root_and_threads() {
+        let mut ns = with_runner();
+        ns.add_server(
+            Server {
+                max_ram: 16.0,
+                ..server("n00dles")
+            },
+            &["home"],
+        );
+        assert_eq!(ns.exec("hack.js", "n00dles", 1, &[]).unwrap(), None);
+        assert_eq!(
+            ns.logs(),
+            ["exec: Could not find script 'hack.js' on 'n00dles'"]
+        );
+
+        ns.scp(&["hack.js"], "n00dles", None).unwrap();
+        assert_eq!(ns.exec("hack.js", "n00dles", 1, &[]).unwrap(), None);
+        ns.nuke("n00dles").unwrap();
+        assert_eq!(ns.exec("hack.js", "n00dles", 1, &[]).unwrap(), Some(Pid(1)));
+
+        assert!(ns.exec("hack.js", "home", 0, &[]).is_err());
+        assert!(ns.exec("hack.js", "darkweb", 1, &[]).is_err());
+    }
+
+    #[test]
+    fn kill_all_frees_the_ram_of_the_host() {
+        let mut ns = with_runner();
+        ns.add_server(
+            Server {
+                has_admin_rights: true,
+                max_ram: 16.0,
+                ..server("n00dles")
+            },
+            &["home"],
+        );
+        ns.scp(&["hack.js"], "n00dles", None).unwrap();
+        ns.exec("hack.js", "home", 2, &[]).unwrap();
+        ns.exec("grow.js", "home", 1, &[]).unwrap();
+        let remote = ns.exec("hack.js", "n00dles", 4, &[]).unwrap();
+
+        assert!(ns.kill_all(None, None).unwrap());
+        assert_eq!(ns.get_server_used_ram("home").unwrap(), 0.0);
+        assert_eq!(ns.get_server_used_ram("n00dles").unwrap(), 7.0);
+        let processes = ns.processes();
+        assert_eq!(processes.len(), 1);
+        assert_eq!(Some(processes[0].pid), remote);
+
+        assert!(!ns.kill_all(Some("home"), Some(false)).unwrap());
+        assert!(ns.kill_all(Some("darkweb"), None).is_err());
+    }
+
+    #[test]
+    fn pids_are_not_reused_after_a_kill() {
+        let ns = with_runner();
+        let first = ns.exec("hack.js", "home", 1, &[]).unwrap();
+        ns.kill_all(None, None).unwrap();
+        let second = ns.exec("hack.js", "home", 1, &[]).unwrap();
+        assert_eq!(first, Some(Pid(1)));
+        assert_eq!(second, Some(Pid(2)));
+    }
+}
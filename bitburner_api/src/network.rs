@@ -0,0 +1,191 @@
+//! Discovery of the server network.
+//!
+//! ```
+//! use bitburner_api::{mock, network::Network, MockNs};
+//!
+//! let mut ns = MockNs::new();
+//! ns.add_server(mock::server("n00dles"), &["home"]);
+//! ns.add_server(mock::server("CSEC"), &["n00dles"]);
+//!
+//! let network = Network::crawl(&ns).unwrap();
+//! assert_eq!(network.get("CSEC").unwrap().depth, 2);
+//! assert_eq!(network.path("CSEC").unwrap(), ["home", "n00dles", "CSEC"]);
+//! assert_eq!(
+//!     network.connect_chain("CSEC").unwrap(),
+//!     "home; connect n00dles; connect CSEC"
+//! );
+//! ```
+
+use std::collections::{HashMap, VecDeque};
+
+use crate::NetscriptApi;
+
+/// Hostname of the player's own server, where crawls start.
+pub const HOME: &str = "home";
+
+/// Hostnames of the servers connected to `host`, or to the server the script
+/// runs on if `None`.
+pub fn scan<N: NetscriptApi>(ns: &N, host: Option<&str>) -> Result<Vec<String>, String> {
+    ns.scan(host)
+}
+
+/// Hosts on a shortest path from `home` to `host`, both included, or `None`
+/// if `host` is not in the network.
+pub fn shortest_path<N: NetscriptApi>(ns: &N, host: &str) -> Result<Option<Vec<String>>, String> {
+    Ok(Network::crawl(ns)?.path(host))
+}
+
+/// A server of the [`Network`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Node {
+    pub hostname: String,
+    /// Number of connections from `home`
+    pub depth: u32,
+    /// The server this one was first reached from, `None` for `home`
+    pub parent: Option<String>,
+    /// Servers directly connected to this one
+    pub neighbours: Vec<String>,
+}
+
+/// All the servers reachable from `home`, in breadth first order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Network {
+    nodes: Vec<Node>,
+    index: HashMap<String, usize>,
+}
+
+impl Network {
+    /// Scans the whole network breadth first from `home`, so parents lead
+    /// back to it along shortest paths.
+    pub fn crawl<N: NetscriptApi>(ns: &N) -> Result<Network, String> {
+        let mut network = Network::default();
+        let mut queue = VecDeque::from([(HOME.to_owned(), 0, None)]);
+        network.index.insert(HOME.to_owned(), 0);
+        while let Some((hostname, depth, parent)) = queue.pop_front() {
+            let neighbours = ns.scan(Some(&hostname))?;
+            for neighbour in &neighbours {
+                if !network.index.contains_key(neighbour) {
+                    let position = network.index.len();
+                    network.index.insert(neighbour.clone(), position);
+                    queue.push_back((neighbour.clone(), depth + 1, Some(hostname.clone())));
+                }
+            }
+            network.nodes.push(Node {
+                hostname,
+                depth,
+                parent,
+                neighbours,
+            });
+        }
+        Ok(network)
+    }
+
+    pub fn get(&self, host: &str) -> Option<&Node> {
+        self.index.get(host).map(|&position| &self.nodes[position])
+    }
+
+    pub fn contains(&self, host: &str) -> bool {
+        self.index.contains_key(host)
+    }
+
+    /// All the servers, `home` first and then by depth.
+    pub fn nodes(&self) -> &[Node] {
+        &self.nodes
+    }
+
+    pub fn hostnames(&self) -> impl Iterator<Item = &str> {
+        self.nodes.iter().map(|node| node.hostname.as_str())
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Hosts on a shortest path from `home` to `host`, both included.
+    pub fn path(&self, host: &str) -> Option<Vec<String>> {
+        let mut path = vec![];
+        let mut node = self.get(host)?;
+        loop {
+            path.push(node.hostname.clone());
+            match &node.parent {
+                Some(parent) => node = self.get(parent)?,
+                None => break,
+            }
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Terminal commands going from `home` to `host`, like
+    /// `home; connect n00dles; connect CSEC`.
+    pub fn connect_chain(&self, host: &str) -> Option<String> {
+        let path = self.path(host)?;
+        let connects = path[1..].iter().map(|host| format!("; connect {host}"));
+        Some(HOME.to_owned() + &connects.collect::<String>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{mock::server, MockNs};
+
+    #[test]
+    fn unreachable_hosts_have_no_path() {
+        let mut ns = MockNs::new();
+        ns.add_server(server("n00dles"), &["home"]);
+        ns.add_server(server("darkweb"), &[]);
+
+        let network = Network::crawl(&ns).unwrap();
+        assert!(!network.contains("darkweb"));
+        assert_eq!(network.get("darkweb"), None);
+        assert_eq!(network.path("darkweb"), None);
+        assert_eq!(network.connect_chain("darkweb"), None);
+        assert_eq!(shortest_path(&ns, "darkweb").unwrap(), None);
+    }
+
+    #[test]
+    fn connect_chain_goes_from_home_outwards() {
+        let mut ns = MockNs::new();
+        ns.add_server(server("n00dles"), &["home"]);
+        ns.add_server(server("CSEC"), &["n00dles"]);
+        ns.add_server(server("avmnite-02h"), &["CSEC"]);
+
+        let network = Network::crawl(&ns).unwrap();
+        assert_eq!(
+            network.connect_chain("avmnite-02h").unwrap(),
+            "home; connect n00dles; connect CSEC; connect avmnite-02h"
+        );
+        assert_eq!(
+            network.connect_chain("n00dles").unwrap(),
+            "home; connect n00dles"
+        );
+        assert_eq!(network.connect_chain(HOME).unwrap(), "home");
+        assert_eq!(network.path(HOME).unwrap(), [HOME]);
+    }
+
+    #[test]
+    fn cycles_are_crawled_once() {
+        let mut ns = MockNs::new();
+        ns.add_server(server("n00dles"), &["home"]);
+        ns.add_server(server("foodnstuff"), &["home"]);
+        ns.add_server(server("CSEC"), &["n00dles", "foodnstuff"]);
+        ns.add_server(server("I.I.I.I"), &["CSEC", "home"]);
+
+        let network = Network::crawl(&ns).unwrap();
+        assert_eq!(
+            network.hostnames().collect::<Vec<_>>(),
+            [HOME, "n00dles", "foodnstuff", "I.I.I.I", "CSEC"]
+        );
+        let csec = network.get("CSEC").unwrap();
+        assert_eq!(csec.depth, 2);
+        assert_eq!(csec.parent.as_deref(), Some("n00dles"));
+        assert_eq!(csec.neighbours, ["n00dles", "foodnstuff", "I.I.I.I"]);
+        assert_eq!(network.get("I.I.I.I").unwrap().depth, 1);
+        assert_eq!(network.path("CSEC").unwrap(), [HOME, "n00dles", "CSEC"]);
+    }
+}
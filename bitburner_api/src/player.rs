@@ -0,0 +1,195 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::{JsCast, JsValue};
+
+use crate::{bindings, to_js_object, CityName, CompanyName, JobName, NS};
+
+/// Current and maximum hit points.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct HP {
+    pub current: f64,
+    pub max: f64,
+}
+
+/// Skill levels, also used for the experience accumulated in each skill.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct Skills {
+    pub hacking: f64,
+    pub strength: f64,
+    pub defense: f64,
+    pub dexterity: f64,
+    pub agility: f64,
+    pub charisma: f64,
+    pub intelligence: f64,
+}
+
+/// Multipliers from augmentations, source files and the like.
+///
+/// The skill multipliers apply to levels while the `_exp` ones apply to the
+/// experience gain rate.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Multipliers {
+    pub hacking: f64,
+    pub strength: f64,
+    pub defense: f64,
+    pub dexterity: f64,
+    pub agility: f64,
+    pub charisma: f64,
+    pub hacking_exp: f64,
+    pub strength_exp: f64,
+    pub defense_exp: f64,
+    pub dexterity_exp: f64,
+    pub agility_exp: f64,
+    pub charisma_exp: f64,
+    /// Chance of successfully performing a hack
+    pub hacking_chance: f64,
+    pub hacking_speed: f64,
+    /// Money gained from hacking
+    pub hacking_money: f64,
+    /// Money injected into servers using grow
+    pub hacking_grow: f64,
+    pub company_rep: f64,
+    pub faction_rep: f64,
+    pub crime_money: f64,
+    pub crime_success: f64,
+    pub work_money: f64,
+    pub hacknet_node_money: f64,
+    pub hacknet_node_purchase_cost: f64,
+    pub hacknet_node_ram_cost: f64,
+    pub hacknet_node_core_cost: f64,
+    pub hacknet_node_level_cost: f64,
+    pub bladeburner_max_stamina: f64,
+    pub bladeburner_stamina_gain: f64,
+    pub bladeburner_analysis: f64,
+    pub bladeburner_success_chance: f64,
+}
+
+impl Default for Multipliers {
+    fn default() -> Self {
+        Multipliers {
+            hacking: 1.0,
+            strength: 1.0,
+            defense: 1.0,
+            dexterity: 1.0,
+            agility: 1.0,
+            charisma: 1.0,
+            hacking_exp: 1.0,
+            strength_exp: 1.0,
+            defense_exp: 1.0,
+            dexterity_exp: 1.0,
+            agility_exp: 1.0,
+            charisma_exp: 1.0,
+            hacking_chance: 1.0,
+            hacking_speed: 1.0,
+            hacking_money: 1.0,
+            hacking_grow: 1.0,
+            company_rep: 1.0,
+            faction_rep: 1.0,
+            crime_money: 1.0,
+            crime_success: 1.0,
+            work_money: 1.0,
+            hacknet_node_money: 1.0,
+            hacknet_node_purchase_cost: 1.0,
+            hacknet_node_ram_cost: 1.0,
+            hacknet_node_core_cost: 1.0,
+            hacknet_node_level_cost: 1.0,
+            bladeburner_max_stamina: 1.0,
+            bladeburner_stamina_gain: 1.0,
+            bladeburner_analysis: 1.0,
+            bladeburner_success_chance: 1.0,
+        }
+    }
+}
+
+/// Stats shared by the player and the sleeves, mirrors the `Person`
+/// interface of the definitions.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Person {
+    pub hp: HP,
+    pub skills: Skills,
+    pub exp: Skills,
+    pub mults: Multipliers,
+    pub city: CityName,
+}
+
+/// A person as they start the game: no experience, multipliers of 1, in
+/// Sector-12.
+impl Default for Person {
+    fn default() -> Self {
+        Person {
+            hp: HP {
+                current: 10.0,
+                max: 10.0,
+            },
+            skills: Skills {
+                hacking: 1.0,
+                strength: 1.0,
+                defense: 1.0,
+                dexterity: 1.0,
+                agility: 1.0,
+                charisma: 1.0,
+                intelligence: 0.0,
+            },
+            exp: Skills::default(),
+            mults: Multipliers::default(),
+            city: CityName::Sector12,
+        }
+    }
+}
+
+/// The player, mirrors the `Player` interface of the definitions.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Player {
+    #[serde(flatten)]
+    pub person: Person,
+    pub money: f64,
+    pub num_people_killed: f64,
+    pub entropy: f64,
+    /// Job title held at each company
+    pub jobs: HashMap<CompanyName, JobName>,
+    pub factions: Vec<String>,
+    /// Milliseconds played in total
+    pub total_playtime: f64,
+    pub location: String,
+}
+
+impl TryFrom<bindings::Person> for Person {
+    type Error = JsValue;
+
+    fn try_from(person: bindings::Person) -> Result<Self, Self::Error> {
+        Ok(serde_wasm_bindgen::from_value(person.into())?)
+    }
+}
+
+impl TryFrom<&Person> for bindings::Person {
+    type Error = JsValue;
+
+    fn try_from(person: &Person) -> Result<Self, Self::Error> {
+        Ok(to_js_object(person)?.unchecked_into())
+    }
+}
+
+impl TryFrom<bindings::Player> for Player {
+    type Error = JsValue;
+
+    fn try_from(player: bindings::Player) -> Result<Self, Self::Error> {
+        Ok(serde_wasm_bindgen::from_value(player.into())?)
+    }
+}
+
+impl TryFrom<&Player> for bindings::Player {
+    type Error = JsValue;
+
+    fn try_from(player: &Player) -> Result<Self, Self::Error> {
+        Ok(to_js_object(player)?.unchecked_into())
+    }
+}
+
+impl NS {
+    /// Reads all the player's stats in a single call.
+    pub fn get_player(&self) -> Result<Player, JsValue> {
+        self.getPlayer()?.try_into()
+    }
+}
@@ -0,0 +1,316 @@
+//! Typed messages over Netscript ports.
+//!
+//! Messages are written to the port as JSON strings, so scripts in any
+//! language can read them. Values that JavaScript scripts write without
+//! encoding them are read as they are, strings that are not JSON included
+//! when the message type can be read from a string.
+//!
+//! ```ignore
+//! use bitburner_api::ports::{self, PortSender};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Job {
+//!     target: String,
+//!     threads: u32,
+//! }
+//!
+//! // in the coordinator
+//! let jobs = PortSender::<Job>::new(ns, 1)?;
+//! jobs.send(&Job { target: "n00dles".to_owned(), threads: 8 })?;
+//!
+//! // in a worker
+//! let (_, jobs) = ports::channel::<Job>(ns, 1)?;
+//! let job = jobs.recv().await?;
+//! ```
+
+use std::{fmt, marker::PhantomData};
+
+use serde::{
+    de::{value::StringDeserializer, DeserializeOwned},
+    Serialize,
+};
+use wasm_bindgen::JsValue;
+
+use crate::{report::error_message, NetscriptPort, NS};
+
+/// What the game reads from an empty port.
+pub const NULL_PORT_DATA: &str = "NULL PORT DATA";
+
+/// The sending and receiving halves of port number `port`.
+pub fn channel<T>(ns: &NS, port: u32) -> Result<(PortSender<T>, PortReceiver<T>), JsValue> {
+    let handle = ns.getPortHandle(port.into())?;
+    let sender = PortSender {
+        port: handle.clone(),
+        number: port,
+        message: PhantomData,
+    };
+    let receiver = PortReceiver {
+        port: handle,
+        number: port,
+        message: PhantomData,
+    };
+    Ok((sender, receiver))
+}
+
+/// Writes messages of type `T` to a port.
+pub struct PortSender<T> {
+    port: NetscriptPort,
+    number: u32,
+    message: PhantomData<fn(T)>,
+}
+
+// derives would require `T` to implement the traits as well
+impl<T> Clone for PortSender<T> {
+    fn clone(&self) -> Self {
+        PortSender {
+            port: self.port.clone(),
+            number: self.number,
+            message: PhantomData,
+        }
+    }
+}
+
+impl<T> fmt::Debug for PortSender<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PortSender")
+            .field("number", &self.number)
+            .finish()
+    }
+}
+
+impl<T: Serialize> PortSender<T> {
+    pub fn new(ns: &NS, port: u32) -> Result<Self, JsValue> {
+        channel(ns, port).map(|(sender, _)| sender)
+    }
+
+    pub fn number(&self) -> u32 {
+        self.number
+    }
+
+    /// Writes `message`, returns whether the port was full and dropped its
+    /// oldest message to make room.
+    pub fn send(&self, message: &T) -> Result<bool, PortError> {
+        let data = serde_json::to_string(message)?;
+        let dropped = self.port.write(&data.into())?;
+        Ok(!dropped.is_null() && !dropped.is_undefined())
+    }
+
+    /// Writes `message` unless the port is full, returns whether it was
+    /// written.
+    pub fn try_send(&self, message: &T) -> Result<bool, PortError> {
+        let data = serde_json::to_string(message)?;
+        Ok(self.port.tryWrite(&data.into())?)
+    }
+
+    pub fn is_full(&self) -> Result<bool, JsValue> {
+        self.port.full()
+    }
+
+    /// Drops all the messages waiting in the port.
+    pub fn clear(&self) -> Result<(), JsValue> {
+        self.port.clear()
+    }
+}
+
+/// Reads messages of type `T` from a port.
+pub struct PortReceiver<T> {
+    port: NetscriptPort,
+    number: u32,
+    message: PhantomData<fn() -> T>,
+}
+
+// derives would require `T` to implement the traits as well
+impl<T> Clone for PortReceiver<T> {
+    fn clone(&self) -> Self {
+        PortReceiver {
+            port: self.port.clone(),
+            number: self.number,
+            message: PhantomData,
+        }
+    }
+}
+
+impl<T> fmt::Debug for PortReceiver<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PortReceiver")
+            .field("number", &self.number)
+            .finish()
+    }
+}
+
+impl<T: DeserializeOwned> PortReceiver<T> {
+    pub fn new(ns: &NS, port: u32) -> Result<Self, JsValue> {
+        channel(ns, port).map(|(_, receiver)| receiver)
+    }
+
+    pub fn number(&self) -> u32 {
+        self.number
+    }
+
+    /// Takes the oldest message out of the port, `None` if it is empty.
+    pub fn try_recv(&self) -> Result<Option<T>, PortError> {
+        decode(self.port.read()?)
+    }
+
+    /// Reads the oldest message without taking it out, `None` if the port is
+    /// empty.
+    pub fn peek(&self) -> Result<Option<T>, PortError> {
+        decode(self.port.peek()?)
+    }
+
+    /// Takes the oldest message out of the port, waiting for one to be
+    /// written if it is empty.
+    pub async fn recv(&self) -> Result<T, PortError> {
+        loop {
+            if let Some(message) = self.try_recv()? {
+                return Ok(message);
+            }
+            self.next_write().await?;
+        }
+    }
+
+    /// Waits until something is written to the port.
+    pub async fn next_write(&self) -> Result<(), JsValue> {
+        self.port.nextWrite().await
+    }
+
+    pub fn is_empty(&self) -> Result<bool, JsValue> {
+        self.port.empty()
+    }
+
+    /// Drops all the messages waiting in the port.
+    pub fn clear(&self) -> Result<(), JsValue> {
+        self.port.clear()
+    }
+}
+
+fn decode<T: DeserializeOwned>(data: JsValue) -> Result<Option<T>, PortError> {
+    match data.as_string() {
+        Some(string) => decode_string(string),
+        None => Ok(Some(serde_wasm_bindgen::from_value(data)?)),
+    }
+}
+
+/// Decodes a string read from a port, as JSON or else as the plain string a
+/// JavaScript script wrote.
+fn decode_string<T: DeserializeOwned>(string: String) -> Result<Option<T>, PortError> {
+    if string == NULL_PORT_DATA {
+        return Ok(None);
+    }
+    match serde_json::from_str(&string) {
+        Ok(message) => Ok(Some(message)),
+        Err(err) => {
+            let plain = StringDeserializer::<serde::de::value::Error>::new(string);
+            // the JSON error tells more about what was expected
+            T::deserialize(plain).map(Some).map_err(|_| err.into())
+        }
+    }
+}
+
+/// Failure to pass a message through a port.
+#[derive(Debug)]
+pub enum PortError {
+    /// A port function threw
+    Netscript(JsValue),
+    /// A message could not be converted to or from JSON
+    Json(serde_json::Error),
+    /// A value written without JSON did not match the message type
+    Value(serde_wasm_bindgen::Error),
+}
+
+impl fmt::Display for PortError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PortError::Netscript(err) => f.write_str(&error_message(err)),
+            PortError::Json(err) => write!(f, "invalid port message: {err}"),
+            PortError::Value(err) => write!(f, "invalid port message: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for PortError {}
+
+impl From<JsValue> for PortError {
+    fn from(err: JsValue) -> Self {
+        PortError::Netscript(err)
+    }
+}
+
+impl From<serde_json::Error> for PortError {
+    fn from(err: serde_json::Error) -> Self {
+        PortError::Json(err)
+    }
+}
+
+impl From<serde_wasm_bindgen::Error> for PortError {
+    fn from(err: serde_wasm_bindgen::Error) -> Self {
+        PortError::Value(err)
+    }
+}
+
+impl From<PortError> for JsValue {
+    fn from(err: PortError) -> Self {
+        match err {
+            PortError::Netscript(err) => err,
+            err => JsValue::from_str(&err.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Job {
+        target: String,
+        threads: u32,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    #[serde(rename_all = "lowercase")]
+    enum Command {
+        Stop,
+    }
+
+    #[test]
+    fn empty_port() {
+        let message = decode_string::<String>(NULL_PORT_DATA.to_owned()).unwrap();
+        assert_eq!(message, None);
+    }
+
+    #[test]
+    fn json_messages() {
+        let data = r#"{"target":"n00dles","threads":8}"#.to_owned();
+        let job = decode_string::<Job>(data).unwrap();
+        let expected = Job {
+            target: "n00dles".to_owned(),
+            threads: 8,
+        };
+        assert_eq!(job, Some(expected));
+
+        let message = decode_string::<String>(r#""hello""#.to_owned()).unwrap();
+        assert_eq!(message.as_deref(), Some("hello"));
+    }
+
+    #[test]
+    fn plain_strings() {
+        let message = decode_string::<String>("hello".to_owned()).unwrap();
+        assert_eq!(message.as_deref(), Some("hello"));
+        // valid JSON of another type is still the string that was written
+        let message = decode_string::<String>("42".to_owned()).unwrap();
+        assert_eq!(message.as_deref(), Some("42"));
+        let command = decode_string::<Command>("stop".to_owned()).unwrap();
+        assert_eq!(command, Some(Command::Stop));
+    }
+
+    #[test]
+    fn plain_strings_of_other_types() {
+        let err = decode_string::<Job>("hello".to_owned()).unwrap_err();
+        assert!(matches!(err, PortError::Json(_)), "{err:?}");
+        let err = decode_string::<u32>("many".to_owned()).unwrap_err();
+        assert!(matches!(err, PortError::Json(_)), "{err:?}");
+    }
+}
@@ -0,0 +1,100 @@
+//! Reports panics and errors of Rust scripts inside the game.
+//!
+//! A panic traps the WASM instance, and the game would only show it as an
+//! opaque "unreachable" error. The generated JavaScript calls [`start`] before
+//! `main_rs`, which installs a panic hook writing the panic message and its
+//! location to the script log and to the terminal, or to a toast, see
+//! [`set_report_target`]. Errors returned from `main_rs` are reported the same
+//! way by [`report_error`] before being thrown to the game.
+
+use std::{
+    cell::{Cell, RefCell},
+    panic::{self, PanicHookInfo},
+    sync::Once,
+};
+
+use wasm_bindgen::{prelude::*, JsCast};
+
+use crate::{ToastVariant, NS};
+
+/// Where panics and errors are shown besides the script log.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ReportTarget {
+    /// Printed to the terminal with `ns.tprint`
+    #[default]
+    Terminal,
+    /// Shown as an error toast with `ns.toast`
+    Toast,
+}
+
+thread_local! {
+    /// The script that started last, the one a panic gets reported to. Runs
+    /// of the same script share the WASM instance, so with several of them
+    /// awaiting at once a panic may be shown in the log of a sibling.
+    static CURRENT_NS: RefCell<Option<NS>> = const { RefCell::new(None) };
+    static TARGET: Cell<ReportTarget> = const { Cell::new(ReportTarget::Terminal) };
+}
+
+/// Chooses where panics and errors are shown besides the script log.
+pub fn set_report_target(target: ReportTarget) {
+    TARGET.with(|current| current.set(target));
+}
+
+/// Installs the panic hook and makes `ns` the script panics are reported to.
+/// The generated JavaScript calls this before `main_rs`.
+#[wasm_bindgen(js_name = __bitburner_api_start)]
+pub fn start(ns: &NS) {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| panic::set_hook(Box::new(panic_hook)));
+    CURRENT_NS.with(|current| *current.borrow_mut() = Some(ns.clone()));
+}
+
+fn panic_hook(info: &PanicHookInfo) {
+    let message = info.to_string();
+    // the default hook has nowhere to write to, so fall back to the console
+    let reported = CURRENT_NS.with(|current| match &*current.borrow() {
+        Some(ns) => report(ns, &message),
+        None => false,
+    });
+    if !reported {
+        console_error(&message);
+    }
+}
+
+/// Reports an error returned from `main_rs`. The generated JavaScript calls
+/// this before throwing the error to the game.
+#[wasm_bindgen(js_name = __bitburner_api_report_error)]
+pub fn report_error(ns: &NS, error: &JsValue) {
+    report(ns, &format!("error: {}", error_message(error)));
+}
+
+/// Turns a thrown value into text: strings as they are, `Error`s by their
+/// name and message and anything else through its debug representation.
+pub fn error_message(error: &JsValue) -> String {
+    if let Some(message) = error.as_string() {
+        return message;
+    }
+    match error.dyn_ref::<js_sys::Error>() {
+        Some(error) => String::from(error.to_string()),
+        None => format!("{error:?}"),
+    }
+}
+
+/// Writes `message` to the script log and to the report target, returns
+/// whether it got anywhere.
+fn report(ns: &NS, message: &str) -> bool {
+    let script = ns.getScriptName().unwrap_or_default();
+    let message = format!("ERROR {script} (PID {}): {message}", ns.pid());
+    let logged = ns.print(&[message.as_str().into()]).is_ok();
+    let shown = match TARGET.with(Cell::get) {
+        ReportTarget::Terminal => ns.tprint(&[message.as_str().into()]),
+        ReportTarget::Toast => ns.toast(&message, Some(ToastVariant::Error), None),
+    };
+    logged || shown.is_ok()
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = console, js_name = error)]
+    fn console_error(message: &str);
+}
@@ -0,0 +1,295 @@
+//! Gaining root access with the port opener programs found on `home`.
+//!
+//! ```
+//! use bitburner_api::{mock, rooting, MockNs, Server};
+//!
+//! let mut ns = MockNs::new();
+//! ns.add_file("home", "BruteSSH.exe");
+//! let one_port = |hostname| Server {
+//!     num_open_ports_required: Some(1),
+//!     ..mock::server(hostname)
+//! };
+//! ns.add_server(one_port("foodnstuff"), &["home"]);
+//! ns.add_server(
+//!     Server {
+//!         num_open_ports_required: Some(2),
+//!         ..mock::server("phantasy")
+//!     },
+//!     &["foodnstuff"],
+//! );
+//!
+//! let report = rooting::root_all(&ns).unwrap();
+//! assert_eq!(report.rooted, ["foodnstuff"]);
+//! assert_eq!(report.failed[0].0, "phantasy");
+//! ```
+
+use std::fmt;
+
+use crate::{
+    network::{Network, HOME},
+    NetscriptApi,
+};
+
+/// A program opening one port of a server.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PortOpener {
+    BruteSsh,
+    FtpCrack,
+    RelaySmtp,
+    HttpWorm,
+    SqlInject,
+}
+
+impl PortOpener {
+    /// All the openers, in the order the game unlocks them
+    pub const ALL: [PortOpener; 5] = [
+        PortOpener::BruteSsh,
+        PortOpener::FtpCrack,
+        PortOpener::RelaySmtp,
+        PortOpener::HttpWorm,
+        PortOpener::SqlInject,
+    ];
+
+    /// Filename of the program on `home`.
+    pub fn program(self) -> &'static str {
+        match self {
+            PortOpener::BruteSsh => "BruteSSH.exe",
+            PortOpener::FtpCrack => "FTPCrack.exe",
+            PortOpener::RelaySmtp => "relaySMTP.exe",
+            PortOpener::HttpWorm => "HTTPWorm.exe",
+            PortOpener::SqlInject => "SQLInject.exe",
+        }
+    }
+
+    /// Runs the program against `host`.
+    pub fn open<N: NetscriptApi>(self, ns: &N, host: &str) -> Result<(), String> {
+        match self {
+            PortOpener::BruteSsh => ns.brutessh(host),
+            PortOpener::FtpCrack => ns.ftpcrack(host),
+            PortOpener::RelaySmtp => ns.relaysmtp(host),
+            PortOpener::HttpWorm => ns.httpworm(host),
+            PortOpener::SqlInject => ns.sqlinject(host),
+        }
+    }
+}
+
+/// The openers whose program exists on `home`.
+pub fn available_openers<N: NetscriptApi>(ns: &N) -> Result<Vec<PortOpener>, String> {
+    let mut available = vec![];
+    for opener in PortOpener::ALL {
+        if ns.file_exists(opener.program(), Some(HOME))? {
+            available.push(opener);
+        }
+    }
+    Ok(available)
+}
+
+/// How a server came to have root access.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rooted {
+    /// Nuked just now
+    Nuked,
+    /// The player already had root access
+    Already,
+}
+
+/// Why a server could not be rooted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RootFailure {
+    /// More ports are needed than there are opener programs on `home`
+    NotEnoughOpeners { required: u32, available: u32 },
+    /// A Netscript function threw
+    Netscript(String),
+}
+
+impl fmt::Display for RootFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RootFailure::NotEnoughOpeners {
+                required,
+                available,
+            } => write!(
+                f,
+                "{required} open ports required but only {available} port openers available"
+            ),
+            RootFailure::Netscript(message) => f.write_str(message),
+        }
+    }
+}
+
+impl std::error::Error for RootFailure {}
+
+impl From<String> for RootFailure {
+    fn from(message: String) -> Self {
+        RootFailure::Netscript(message)
+    }
+}
+
+/// Opens as many ports of `host` as it requires and nukes it.
+pub fn root_server<N: NetscriptApi>(ns: &N, host: &str) -> Result<Rooted, RootFailure> {
+    let openers = available_openers(ns)?;
+    root_with(ns, host, &openers)
+}
+
+fn root_with<N: NetscriptApi>(
+    ns: &N,
+    host: &str,
+    openers: &[PortOpener],
+) -> Result<Rooted, RootFailure> {
+    if ns.has_root_access(host)? {
+        return Ok(Rooted::Already);
+    }
+    let required = ns.get_server_num_ports_required(host)?;
+    if openers.len() < required as usize {
+        return Err(RootFailure::NotEnoughOpeners {
+            required,
+            available: openers.len() as u32,
+        });
+    }
+    for opener in &openers[..required as usize] {
+        opener.open(ns, host)?;
+    }
+    ns.nuke(host)?;
+    Ok(Rooted::Nuked)
+}
+
+/// Outcome of [`root_all`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RootReport {
+    /// Hosts nuked by this run
+    pub rooted: Vec<String>,
+    /// Hosts the player already had root access to, `home` included
+    pub already_rooted: Vec<String>,
+    /// Hosts that are still not rooted and why
+    pub failed: Vec<(String, RootFailure)>,
+}
+
+/// Roots every server of the network that the opener programs on `home`
+/// allow. Only fails if the network cannot be crawled, the failures of
+/// single servers are part of the report.
+pub fn root_all<N: NetscriptApi>(ns: &N) -> Result<RootReport, String> {
+    let network = Network::crawl(ns)?;
+    let openers = available_openers(ns)?;
+    let mut report = RootReport::default();
+    for host in network.hostnames() {
+        match root_with(ns, host, &openers) {
+            Ok(Rooted::Nuked) => report.rooted.push(host.to_owned()),
+            Ok(Rooted::Already) => report.already_rooted.push(host.to_owned()),
+            Err(failure) => report.failed.push((host.to_owned(), failure)),
+        }
+    }
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{mock::server, MockNs, Server};
+
+    fn ports(hostname: &str, required: u32) -> Server {
+        Server {
+            num_open_ports_required: Some(required),
+            ..server(hostname)
+        }
+    }
+
+    #[test]
+    fn root_server_opens_the_required_ports() {
+        let mut ns = MockNs::new();
+        ns.add_file(HOME, "BruteSSH.exe");
+        ns.add_file(HOME, "FTPCrack.exe");
+        ns.add_file(HOME, "SQLInject.exe");
+        ns.add_server(ports("phantasy", 2), &[HOME]);
+
+        assert_eq!(root_server(&ns, "phantasy"), Ok(Rooted::Nuked));
+        let phantasy = ns.server("phantasy").unwrap();
+        assert!(phantasy.has_admin_rights);
+        // openers are used in the order the game unlocks them
+        assert!(phantasy.ssh_port_open && phantasy.ftp_port_open);
+        assert!(!phantasy.sql_port_open);
+        assert_eq!(phantasy.open_port_count, Some(2));
+    }
+
+    #[test]
+    fn too_few_openers() {
+        let mut ns = MockNs::new();
+        ns.add_file(HOME, "BruteSSH.exe");
+        ns.add_server(ports("phantasy", 2), &[HOME]);
+
+        let failure = root_server(&ns, "phantasy").unwrap_err();
+        assert_eq!(
+            failure,
+            RootFailure::NotEnoughOpeners {
+                required: 2,
+                available: 1,
+            }
+        );
+        assert_eq!(
+            failure.to_string(),
+            "2 open ports required but only 1 port openers available"
+        );
+        // nothing is opened when the ports cannot all be
+        let phantasy = ns.server("phantasy").unwrap();
+        assert!(!phantasy.has_admin_rights);
+        assert!(!phantasy.ssh_port_open);
+    }
+
+    #[test]
+    fn already_rooted_hosts_are_left_alone() {
+        let mut ns = MockNs::new();
+        ns.add_server(
+            Server {
+                has_admin_rights: true,
+                ..ports("CSEC", 1)
+            },
+            &[HOME],
+        );
+
+        assert_eq!(root_server(&ns, "CSEC"), Ok(Rooted::Already));
+        assert!(!ns.server("CSEC").unwrap().ssh_port_open);
+        assert_eq!(root_server(&ns, HOME), Ok(Rooted::Already));
+    }
+
+    #[test]
+    fn unknown_hosts_report_the_game_error() {
+        let ns = MockNs::new();
+        assert_eq!(
+            root_server(&ns, "darkweb"),
+            Err(RootFailure::Netscript(
+                "Invalid hostname: 'darkweb'".to_owned()
+            ))
+        );
+    }
+
+    #[test]
+    fn root_all_reports_every_host() {
+        let mut ns = MockNs::new();
+        ns.add_file(HOME, "BruteSSH.exe");
+        ns.add_server(ports("n00dles", 0), &[HOME]);
+        ns.add_server(ports("foodnstuff", 1), &[HOME]);
+        ns.add_server(
+            Server {
+                has_admin_rights: true,
+                ..ports("CSEC", 1)
+            },
+            &["n00dles"],
+        );
+        ns.add_server(ports("phantasy", 2), &["foodnstuff"]);
+        ns.add_server(ports("darkweb", 0), &[]);
+
+        let report = root_all(&ns).unwrap();
+        assert_eq!(report.rooted, ["n00dles", "foodnstuff"]);
+        assert_eq!(report.already_rooted, [HOME, "CSEC"]);
+        assert_eq!(
+            report.failed,
+            [(
+                "phantasy".to_owned(),
+                RootFailure::NotEnoughOpeners {
+                    required: 2,
+                    available: 1,
+                }
+            )]
+        );
+        assert!(!ns.server("darkweb").unwrap().has_admin_rights);
+    }
+}
@@ -0,0 +1,561 @@
+//! Runtime support for `#[derive(ScriptArgs)]`.
+//!
+//! The derive describes a struct as an [`ArgsSpec`], which parses the values
+//! from [`parse_args`] the same way `ns.flags` would: `--name value`,
+//! `--name=value`, bare `--name` for booleans, repeated `--name` for lists and
+//! everything else as positional arguments, with `--` ending the flags.
+
+use std::{collections::VecDeque, fmt};
+
+use js_sys::Array;
+use wasm_bindgen::JsValue;
+
+use crate::{parse_args, Args, AutocompleteData, NS};
+
+/// Arguments of a script, usually implemented with `#[derive(ScriptArgs)]`.
+///
+/// ```ignore
+/// use bitburner_api::{wasm_bindgen, JsValue, ScriptArgs, NS};
+///
+/// /// Weakens a server until it reaches its minimum security
+/// #[derive(ScriptArgs)]
+/// #[script(autocomplete)]
+/// struct Weaken {
+///     /// Server to weaken
+///     #[arg(positional, complete = "servers")]
+///     target: String,
+///     /// Threads to run with
+///     #[arg(default = 1)]
+///     threads: u32,
+///     /// Keep going forever
+///     #[arg(long = "loop")]
+///     repeat: bool,
+/// }
+///
+/// #[wasm_bindgen]
+/// pub fn main_rs(ns: &NS) -> Result<(), JsValue> {
+///     let Some(args) = Weaken::parse(ns) else {
+///         return Ok(());
+///     };
+///     // ...
+///     Ok(())
+/// }
+/// ```
+///
+/// Fields are flags unless marked `#[arg(positional)]`. `bool` fields are
+/// switches, `Vec` fields collect repeated flags or the remaining positional
+/// arguments, `Option` fields and fields with a `default` may be omitted and
+/// any other field is required. Doc comments become the help text printed
+/// for `--help`.
+///
+/// `#[script(autocomplete)]` additionally exports an `autocomplete_rs`
+/// function which `cargo xtask codegen` turns into the script's
+/// `autocomplete` export, so the terminal can complete the flags and the
+/// positional arguments marked with `complete = "servers"`, `"scripts"` or
+/// `"txts"`. Only one struct per script can do so.
+pub trait ScriptArgs: Sized {
+    /// Describes the flags and positional arguments of the script.
+    fn spec() -> ArgsSpec;
+
+    /// Builds the arguments out of the values picked by [`ArgsSpec::parse`].
+    fn from_parsed(parsed: ParsedArgs) -> Result<Self, ArgsError>;
+
+    /// Parses arguments that were already converted by [`parse_args`], this
+    /// does not need the game to run.
+    fn try_parse_from(args: Vec<Args>) -> Result<Self, ArgsError> {
+        Self::from_parsed(Self::spec().parse(args)?)
+    }
+
+    /// Parses the arguments the script was started with.
+    fn try_parse(ns: &NS) -> Result<Self, ArgsError> {
+        let args = parse_args(ns.args()).map_err(ArgsError::InvalidArgument)?;
+        Self::try_parse_from(args)
+    }
+
+    /// Parses the arguments the script was started with, printing the help
+    /// or the parsing error to the terminal if there is one. The script
+    /// should exit when this returns `None`.
+    fn parse(ns: &NS) -> Option<Self> {
+        let err = match Self::try_parse(ns) {
+            Ok(args) => return Some(args),
+            Err(err) => err,
+        };
+        let script = ns.getScriptName().unwrap_or_default();
+        let usage = Self::spec().usage(&script);
+        let message = match err {
+            ArgsError::Help => usage,
+            err => format!("error: {err}\n\n{usage}"),
+        };
+        // nothing left to report to if even printing fails
+        let _ = ns.tprint(&[message.into()]);
+        None
+    }
+
+    /// Registers the flags with the terminal and suggests values for the
+    /// positional arguments.
+    fn autocomplete(data: &AutocompleteData) -> Vec<String> {
+        let spec = Self::spec();
+        let _ = data.flags(spec.flags_schema());
+        spec.completions(data)
+    }
+}
+
+/// How a flag takes its value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FlagKind {
+    /// A switch taking no value
+    Bool,
+    Number,
+    String,
+    /// Collects the values of every occurrence
+    List,
+}
+
+/// Source of suggestions for a positional argument in the terminal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Completion {
+    Servers,
+    Scripts,
+    Txts,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlagSpec {
+    pub name: &'static str,
+    pub kind: FlagKind,
+    pub help: &'static str,
+    pub required: bool,
+    /// Default value as written in the source, shown in the help
+    pub default: Option<&'static str>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PositionalSpec {
+    pub name: &'static str,
+    pub help: &'static str,
+    pub required: bool,
+    /// Takes all the remaining positional arguments
+    pub rest: bool,
+    pub default: Option<&'static str>,
+    pub complete: Option<Completion>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArgsSpec {
+    pub about: &'static str,
+    pub flags: Vec<FlagSpec>,
+    pub positionals: Vec<PositionalSpec>,
+}
+
+impl ArgsSpec {
+    /// Splits arguments into flag values and positional arguments.
+    pub fn parse(&self, args: Vec<Args>) -> Result<ParsedArgs, ArgsError> {
+        let mut flags: Vec<(&'static str, Args)> = vec![];
+        let mut positionals = VecDeque::new();
+        let mut args = args.into_iter();
+        let mut flags_ended = false;
+        while let Some(arg) = args.next() {
+            let name = match &arg {
+                Args::String(string) if !flags_ended && string == "--" => {
+                    flags_ended = true;
+                    continue;
+                }
+                Args::String(string) if !flags_ended => flag_name(string),
+                _ => None,
+            };
+            let Some((name, inline_value)) = name else {
+                positionals.push_back(arg);
+                continue;
+            };
+            if name == "help" {
+                return Err(ArgsError::Help);
+            }
+            let Some(flag) = self.flags.iter().find(|flag| flag.name == name) else {
+                return Err(ArgsError::UnknownFlag(name.to_owned()));
+            };
+            let value = match (flag.kind, inline_value) {
+                (_, Some(value)) => Args::String(value.to_owned()),
+                (FlagKind::Bool, None) => Args::Bool(true),
+                (_, None) => args
+                    .next()
+                    .ok_or_else(|| ArgsError::MissingValue(flag.name.to_owned()))?,
+            };
+            flags.push((flag.name, value));
+        }
+        Ok(ParsedArgs { flags, positionals })
+    }
+
+    /// The `ns.flags` schema of the script, pairs of flag names and defaults
+    /// of the matching type.
+    pub fn flags_schema(&self) -> Vec<JsValue> {
+        let help = ("help", JsValue::FALSE);
+        self.flags
+            .iter()
+            .map(|flag| {
+                let default = match flag.kind {
+                    FlagKind::Bool => JsValue::FALSE,
+                    FlagKind::Number => JsValue::from_f64(0.0),
+                    FlagKind::String => JsValue::from_str(""),
+                    FlagKind::List => Array::new().into(),
+                };
+                (flag.name, default)
+            })
+            .chain([help])
+            .map(|(name, default)| Array::of2(&name.into(), &default).into())
+            .collect()
+    }
+
+    /// Terminal suggestions for the positional arguments.
+    pub fn completions(&self, data: &AutocompleteData) -> Vec<String> {
+        let mut completions = vec![];
+        for positional in &self.positionals {
+            let values = match positional.complete {
+                Some(Completion::Servers) => data.servers(),
+                Some(Completion::Scripts) => data.scripts(),
+                Some(Completion::Txts) => data.txts(),
+                None => continue,
+            };
+            completions.extend(values);
+        }
+        completions.sort();
+        completions.dedup();
+        completions
+    }
+
+    /// Help text in the style of clap.
+    pub fn usage(&self, script: &str) -> String {
+        let mut usage = format!("Usage: run {script}");
+        if !self.flags.is_empty() {
+            usage += " [OPTIONS]";
+        }
+        for positional in &self.positionals {
+            usage += " ";
+            usage += &positional_display(positional);
+        }
+
+        let mut text = String::new();
+        if !self.about.is_empty() {
+            text += self.about;
+            text += "\n\n";
+        }
+        text += &usage;
+
+        let arguments: Vec<(String, String)> = self
+            .positionals
+            .iter()
+            .map(|positional| {
+                let help = with_default(positional.help, positional.default);
+                (positional_display(positional), help)
+            })
+            .collect();
+        if !arguments.is_empty() {
+            text += "\n\nArguments:";
+            text += &help_table(&arguments);
+        }
+
+        let help = ("--help".to_owned(), "Print help".to_owned());
+        let options: Vec<(String, String)> = self
+            .flags
+            .iter()
+            .map(|flag| {
+                let help = with_default(flag.help, flag.default);
+                (flag_display(flag), help)
+            })
+            .chain([help])
+            .collect();
+        text += "\n\nOptions:";
+        text += &help_table(&options);
+        text
+    }
+}
+
+/// Returns the name of a flag and its inline `=value` if `arg` is a flag.
+fn flag_name(arg: &str) -> Option<(&str, Option<&str>)> {
+    let name = match arg.strip_prefix("--") {
+        Some(name) => name,
+        // single dash flags only have a single letter
+        None => arg
+            .strip_prefix('-')
+            .filter(|name| name.len() == 1 && name.chars().all(char::is_alphabetic))?,
+    };
+    if name.is_empty() {
+        return None;
+    }
+    Some(match name.split_once('=') {
+        Some((name, value)) => (name, Some(value)),
+        None => (name, None),
+    })
+}
+
+fn flag_display(flag: &FlagSpec) -> String {
+    let dashes = if flag.name.len() == 1 { "-" } else { "--" };
+    let value = match flag.kind {
+        FlagKind::Bool => "",
+        FlagKind::Number => " <NUMBER>",
+        FlagKind::String | FlagKind::List => " <VALUE>",
+    };
+    format!("{dashes}{}{value}", flag.name)
+}
+
+fn positional_display(positional: &PositionalSpec) -> String {
+    let name = positional.name.to_uppercase();
+    match (positional.required, positional.rest) {
+        (true, false) => format!("<{name}>"),
+        (true, true) => format!("<{name}>..."),
+        (false, false) => format!("[{name}]"),
+        (false, true) => format!("[{name}]..."),
+    }
+}
+
+fn with_default(help: &str, default: Option<&str>) -> String {
+    match default {
+        Some(default) if help.is_empty() => format!("[default: {default}]"),
+        Some(default) => format!("{help} [default: {default}]"),
+        None => help.to_owned(),
+    }
+}
+
+fn help_table(rows: &[(String, String)]) -> String {
+    let width = rows.iter().map(|(name, _)| name.len()).max().unwrap_or(0);
+    rows.iter()
+        .map(|(name, help)| format!("\n  {name:width$}  {help}").trim_end().to_owned())
+        .collect()
+}
+
+/// Flag values and positional arguments, taken out by the derived
+/// [`ScriptArgs::from_parsed`] field by field.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedArgs {
+    flags: Vec<(&'static str, Args)>,
+    positionals: VecDeque<Args>,
+}
+
+impl ParsedArgs {
+    /// The last value given for a flag.
+    pub fn flag<T: ArgValue>(&mut self, name: &str) -> Result<Option<T>, ArgsError> {
+        self.flag_list(name).map(|mut values| values.pop())
+    }
+
+    pub fn required_flag<T: ArgValue>(&mut self, name: &str) -> Result<T, ArgsError> {
+        self.flag(name)?
+            .ok_or_else(|| ArgsError::MissingFlag(name.to_owned()))
+    }
+
+    /// All the values given for a flag, in order.
+    pub fn flag_list<T: ArgValue>(&mut self, name: &str) -> Result<Vec<T>, ArgsError> {
+        let (matching, rest) = self.flags.drain(..).partition(|(flag, _)| *flag == name);
+        self.flags = rest;
+        matching
+            .into_iter()
+            .map(|(_, value)| convert(name, value))
+            .collect()
+    }
+
+    /// The next positional argument.
+    pub fn positional<T: ArgValue>(&mut self, name: &str) -> Result<Option<T>, ArgsError> {
+        self.positionals
+            .pop_front()
+            .map(|value| convert(name, value))
+            .transpose()
+    }
+
+    pub fn required_positional<T: ArgValue>(&mut self, name: &str) -> Result<T, ArgsError> {
+        self.positional(name)?
+            .ok_or_else(|| ArgsError::MissingArgument(name.to_owned()))
+    }
+
+    /// All the remaining positional arguments.
+    pub fn rest<T: ArgValue>(&mut self, name: &str) -> Result<Vec<T>, ArgsError> {
+        self.positionals
+            .drain(..)
+            .map(|value| convert(name, value))
+            .collect()
+    }
+
+    /// Fails if there are positional arguments left that no field took.
+    pub fn finish(mut self) -> Result<(), ArgsError> {
+        match self.positionals.pop_front() {
+            Some(arg) => Err(ArgsError::UnexpectedArgument(arg_string(&arg))),
+            None => Ok(()),
+        }
+    }
+}
+
+fn convert<T: ArgValue>(name: &str, value: Args) -> Result<T, ArgsError> {
+    let shown = arg_string(&value);
+    T::from_arg(value).map_err(|err| match err {
+        ValueError::Expected(expected) => ArgsError::InvalidValue {
+            name: name.to_owned(),
+            value: shown,
+            expected,
+        },
+        ValueError::OutOfRange { min, max } => ArgsError::OutOfRange {
+            name: name.to_owned(),
+            value: shown,
+            min,
+            max,
+        },
+    })
+}
+
+fn arg_string(arg: &Args) -> String {
+    match arg {
+        Args::Bool(bool) => bool.to_string(),
+        Args::F64(float) => float.to_string(),
+        Args::String(string) => string.clone(),
+    }
+}
+
+/// A type a single script argument can be converted to.
+pub trait ArgValue: Sized {
+    /// Converts the argument, or tells why it could not.
+    fn from_arg(arg: Args) -> Result<Self, ValueError>;
+}
+
+/// Why [`ArgValue::from_arg`] could not convert an argument.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValueError {
+    /// Describes what was expected instead
+    Expected(&'static str),
+    /// A number outside of the bounds of the type
+    OutOfRange { min: String, max: String },
+}
+
+impl From<&'static str> for ValueError {
+    fn from(expected: &'static str) -> Self {
+        ValueError::Expected(expected)
+    }
+}
+
+impl ArgValue for Args {
+    fn from_arg(arg: Args) -> Result<Self, ValueError> {
+        Ok(arg)
+    }
+}
+
+impl ArgValue for String {
+    fn from_arg(arg: Args) -> Result<Self, ValueError> {
+        Ok(arg_string(&arg))
+    }
+}
+
+impl ArgValue for bool {
+    fn from_arg(arg: Args) -> Result<Self, ValueError> {
+        match arg {
+            Args::Bool(bool) => Ok(bool),
+            Args::String(string) => string.parse().map_err(|_| "true or false".into()),
+            Args::F64(_) => Err("true or false".into()),
+        }
+    }
+}
+
+impl ArgValue for f64 {
+    fn from_arg(arg: Args) -> Result<Self, ValueError> {
+        match arg {
+            Args::F64(float) => Ok(float),
+            Args::String(string) => string.parse().map_err(|_| "a number".into()),
+            Args::Bool(_) => Err("a number".into()),
+        }
+    }
+}
+
+impl ArgValue for f32 {
+    fn from_arg(arg: Args) -> Result<Self, ValueError> {
+        f64::from_arg(arg).map(|float| float as f32)
+    }
+}
+
+macro_rules! integer_arg_value {
+    ($($int:ty),*) => {
+        $(
+            impl ArgValue for $int {
+                fn from_arg(arg: Args) -> Result<Self, ValueError> {
+                    let float = f64::from_arg(arg).map_err(|_| "an integer")?;
+                    if float.fract() != 0.0 {
+                        return Err("an integer".into());
+                    }
+                    if float < <$int>::MIN as f64 || float > <$int>::MAX as f64 {
+                        return Err(ValueError::OutOfRange {
+                            min: <$int>::MIN.to_string(),
+                            max: <$int>::MAX.to_string(),
+                        });
+                    }
+                    Ok(float as $int)
+                }
+            }
+        )*
+    };
+}
+
+integer_arg_value!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+/// Why the arguments did not match the [`ArgsSpec`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ArgsError {
+    /// `--help` was given, not an actual error
+    Help,
+    UnknownFlag(String),
+    MissingValue(String),
+    MissingFlag(String),
+    MissingArgument(String),
+    UnexpectedArgument(String),
+    InvalidValue {
+        name: String,
+        value: String,
+        expected: &'static str,
+    },
+    /// A number outside of the bounds of the field's type
+    OutOfRange {
+        name: String,
+        value: String,
+        min: String,
+        max: String,
+    },
+    /// An argument of a type other than string, number or boolean
+    InvalidArgument(String),
+}
+
+impl fmt::Display for ArgsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArgsError::Help => write!(f, "help requested"),
+            ArgsError::UnknownFlag(name) => write!(f, "unexpected argument '--{name}' found"),
+            ArgsError::MissingValue(name) => {
+                write!(
+                    f,
+                    "a value is required for '--{name}' but none was supplied"
+                )
+            }
+            ArgsError::MissingFlag(name) => {
+                write!(f, "the required argument '--{name}' was not provided")
+            }
+            ArgsError::MissingArgument(name) => {
+                let name = name.to_uppercase();
+                write!(f, "the required argument '<{name}>' was not provided")
+            }
+            ArgsError::UnexpectedArgument(value) => {
+                write!(f, "unexpected argument '{value}' found")
+            }
+            ArgsError::InvalidValue {
+                name,
+                value,
+                expected,
+            } => write!(
+                f,
+                "invalid value '{value}' for '{name}': expected {expected}"
+            ),
+            ArgsError::OutOfRange {
+                name,
+                value,
+                min,
+                max,
+            } => write!(
+                f,
+                "invalid value '{value}' for '{name}': {value} is not in {min}..={max}"
+            ),
+            ArgsError::InvalidArgument(message) => f.write_str(message),
+        }
+    }
+}
+
+impl std::error::Error for ArgsError {}
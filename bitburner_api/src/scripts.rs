@@ -0,0 +1,319 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsValue;
+
+use crate::{bindings, to_js_object, Args, NS};
+
+/// An argument passed to a script, the same values [`parse_args`] reads.
+///
+/// [`parse_args`]: crate::parse_args
+pub type ScriptArg = Args;
+
+impl From<bool> for Args {
+    fn from(value: bool) -> Self {
+        Args::Bool(value)
+    }
+}
+
+impl From<f64> for Args {
+    fn from(value: f64) -> Self {
+        Args::F64(value)
+    }
+}
+
+impl From<u32> for Args {
+    fn from(value: u32) -> Self {
+        Args::F64(value.into())
+    }
+}
+
+impl From<&str> for Args {
+    fn from(value: &str) -> Self {
+        Args::String(value.to_owned())
+    }
+}
+
+impl From<String> for Args {
+    fn from(value: String) -> Self {
+        Args::String(value)
+    }
+}
+
+impl From<&Args> for JsValue {
+    fn from(arg: &Args) -> Self {
+        match arg {
+            Args::Bool(bool) => JsValue::from_bool(*bool),
+            Args::F64(float) => JsValue::from_f64(*float),
+            Args::String(string) => JsValue::from_str(string),
+        }
+    }
+}
+
+fn js_args(args: &[ScriptArg]) -> Vec<JsValue> {
+    args.iter().map(JsValue::from).collect()
+}
+
+/// Process ID of a running script.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Pid(pub u32);
+
+impl fmt::Display for Pid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl From<Pid> for JsValue {
+    fn from(pid: Pid) -> Self {
+        JsValue::from_f64(pid.0.into())
+    }
+}
+
+/// The game returns 0 when a script could not be started.
+fn started(pid: f64) -> Option<Pid> {
+    (pid > 0.0).then_some(Pid(pid as u32))
+}
+
+/// A script, by the PID of one of its instances or by its filename.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilenameOrPid<'a> {
+    Pid(Pid),
+    Filename(&'a str),
+}
+
+impl From<Pid> for FilenameOrPid<'_> {
+    fn from(pid: Pid) -> Self {
+        FilenameOrPid::Pid(pid)
+    }
+}
+
+impl<'a> From<&'a str> for FilenameOrPid<'a> {
+    fn from(filename: &'a str) -> Self {
+        FilenameOrPid::Filename(filename)
+    }
+}
+
+impl From<FilenameOrPid<'_>> for JsValue {
+    fn from(script: FilenameOrPid) -> Self {
+        match script {
+            FilenameOrPid::Pid(pid) => pid.into(),
+            FilenameOrPid::Filename(filename) => filename.into(),
+        }
+    }
+}
+
+/// How to start a script, mirrors the `RunOptions` interface of the
+/// definitions. Unset options take the game's defaults.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RunOptions {
+    /// Defaults to 1
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub threads: Option<u32>,
+    /// Excludes the script from saves, defaults to false
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temporary: Option<bool>,
+    /// RAM (GB) each thread is launched with, at least the static RAM cost
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ram_override: Option<f64>,
+    /// Fails to start if the script already runs with the same arguments,
+    /// defaults to false
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prevent_duplicates: Option<bool>,
+}
+
+impl RunOptions {
+    pub fn threads(threads: u32) -> Self {
+        RunOptions {
+            threads: Some(threads),
+            ..RunOptions::default()
+        }
+    }
+}
+
+/// How to restart the current script with [`NS::spawn_script`], mirrors the
+/// `SpawnOptions` interface of the definitions.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpawnOptions {
+    #[serde(flatten)]
+    pub run: RunOptions,
+    /// Milliseconds to wait before spawning, defaults to 10 seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spawn_delay: Option<f64>,
+}
+
+/// A running script, mirrors the `RunningScript` interface of the
+/// definitions without its tail window properties and title.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RunningScript {
+    pub pid: Pid,
+    pub filename: String,
+    pub args: Vec<ScriptArg>,
+    /// Hostname of the server the script runs on
+    pub server: String,
+    pub threads: u32,
+    /// RAM (GB) used by a single thread
+    pub ram_usage: f64,
+    /// Whether the script is excluded from saves
+    pub temporary: bool,
+    pub logs: Vec<String>,
+
+    pub online_running_time: f64,
+    pub online_money_made: f64,
+    pub online_exp_gained: f64,
+    pub offline_running_time: f64,
+    pub offline_money_made: f64,
+    pub offline_exp_gained: f64,
+}
+
+impl TryFrom<bindings::RunningScript> for RunningScript {
+    type Error = JsValue;
+
+    fn try_from(script: bindings::RunningScript) -> Result<Self, Self::Error> {
+        Ok(serde_wasm_bindgen::from_value(script.into())?)
+    }
+}
+
+impl NS {
+    /// Starts `script` on the server this script runs on. Returns `None` if
+    /// the game could not start it, e.g. for lack of RAM.
+    pub fn run_script(
+        &self,
+        script: &str,
+        options: &RunOptions,
+        args: &[ScriptArg],
+    ) -> Result<Option<Pid>, JsValue> {
+        let options = to_js_object(options)?;
+        self.run(script, Some(&options), &js_args(args))
+            .map(started)
+    }
+
+    /// Starts `script` on `host`. Returns `None` if the game could not start
+    /// it, e.g. for lack of RAM.
+    pub fn exec_script(
+        &self,
+        script: &str,
+        host: &str,
+        options: &RunOptions,
+        args: &[ScriptArg],
+    ) -> Result<Option<Pid>, JsValue> {
+        let options = to_js_object(options)?;
+        self.exec(script, host, Some(&options), &js_args(args))
+            .map(started)
+    }
+
+    /// Kills the current script and starts `script` in its place after the
+    /// spawn delay.
+    pub fn spawn_script(
+        &self,
+        script: &str,
+        options: &SpawnOptions,
+        args: &[ScriptArg],
+    ) -> Result<(), JsValue> {
+        let options = to_js_object(options)?;
+        self.spawn(script, Some(&options), &js_args(args))
+    }
+
+    /// Kills the script with `pid`, returns whether it was running.
+    pub fn kill_pid(&self, pid: Pid) -> Result<bool, JsValue> {
+        self.kill(pid.0.into())
+    }
+
+    /// Kills the instance of `filename` running on `host`, or on the current
+    /// server if `None`, with exactly `args`. Returns whether it was running.
+    pub fn kill_script(
+        &self,
+        filename: &str,
+        host: Option<&str>,
+        args: &[ScriptArg],
+    ) -> Result<bool, JsValue> {
+        self.kill_1(filename, host, &js_args(args))
+    }
+
+    /// Kills every script on `host`, or on the current server if `None`,
+    /// except the calling script if `safetyguard` is `Some(true)`, the
+    /// default. Returns whether any script was killed.
+    pub fn kill_all(&self, host: Option<&str>, safetyguard: Option<bool>) -> Result<bool, JsValue> {
+        self.killall(host, safetyguard)
+    }
+
+    /// Whether a script is running, by PID or by filename with exactly
+    /// `args` on `host`, or on the current server if `None`.
+    pub fn is_running<'a>(
+        &self,
+        script: impl Into<FilenameOrPid<'a>>,
+        host: Option<&str>,
+        args: &[ScriptArg],
+    ) -> Result<bool, JsValue> {
+        self.isRunning(&script.into().into(), host, &js_args(args))
+    }
+
+    /// Reads a running script, by PID or by filename with exactly `args` on
+    /// `host`. `None` reads the current script.
+    pub fn get_running_script(
+        &self,
+        script: Option<FilenameOrPid>,
+        host: Option<&str>,
+        args: &[ScriptArg],
+    ) -> Result<Option<RunningScript>, JsValue> {
+        let script = script.map(JsValue::from);
+        self.getRunningScript(script.as_ref(), host, &js_args(args))?
+            .map(RunningScript::try_from)
+            .transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn unset_run_options_are_left_out() {
+        let options = serde_json::to_value(RunOptions::default()).unwrap();
+        assert_eq!(options, json!({}));
+
+        let options = serde_json::to_value(RunOptions::threads(4)).unwrap();
+        assert_eq!(options, json!({ "threads": 4 }));
+    }
+
+    #[test]
+    fn run_options_use_the_game_names() {
+        let options = RunOptions {
+            threads: Some(2),
+            temporary: Some(true),
+            ram_override: Some(2.5),
+            prevent_duplicates: Some(false),
+        };
+        let json = json!({
+            "threads": 2,
+            "temporary": true,
+            "ramOverride": 2.5,
+            "preventDuplicates": false,
+        });
+        assert_eq!(serde_json::to_value(&options).unwrap(), json);
+        assert_eq!(serde_json::from_value::<RunOptions>(json).unwrap(), options);
+    }
+
+    #[test]
+    fn spawn_options_flatten_the_run_options() {
+        let options = SpawnOptions {
+            run: RunOptions::threads(3),
+            spawn_delay: Some(500.0),
+        };
+        let json = json!({ "threads": 3, "spawnDelay": 500.0 });
+        assert_eq!(serde_json::to_value(&options).unwrap(), json);
+        assert_eq!(
+            serde_json::from_value::<SpawnOptions>(json).unwrap(),
+            options
+        );
+
+        let options = serde_json::to_value(SpawnOptions::default()).unwrap();
+        assert_eq!(options, json!({}));
+    }
+}
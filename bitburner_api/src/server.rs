@@ -0,0 +1,87 @@
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::{JsCast, JsValue};
+
+use crate::{bindings, to_js_object, NS};
+
+/// A server, mirrors the `Server` interface of the definitions.
+///
+/// Not all servers have all of the properties, the optional ones are missing
+/// on purchased servers and on `home`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Server {
+    /// Hostname, unique across the network
+    pub hostname: String,
+    /// IP address, unique across the network
+    pub ip: String,
+
+    pub ssh_port_open: bool,
+    pub ftp_port_open: bool,
+    pub smtp_port_open: bool,
+    pub http_port_open: bool,
+    pub sql_port_open: bool,
+
+    /// Whether the player has root access to this server
+    pub has_admin_rights: bool,
+    /// Affects the magnitude of grow and weaken run from this server
+    pub cpu_cores: u32,
+    /// Whether the player's terminal is currently connected to this server
+    pub is_connected_to: bool,
+    /// RAM (GB) unavailable to new scripts
+    pub ram_used: f64,
+    /// RAM (GB) of this server
+    pub max_ram: f64,
+    /// Name of the company, faction, etc. owning this server
+    pub organization_name: String,
+    pub purchased_by_player: bool,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub backdoor_installed: Option<bool>,
+    /// Security level at creation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base_difficulty: Option<f64>,
+    /// Current security level
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hack_difficulty: Option<f64>,
+    /// Lowest security level the server can be weakened to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_difficulty: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub money_available: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub money_max: Option<f64>,
+    /// Number of open ports needed to nuke the server
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_open_ports_required: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub open_port_count: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required_hacking_skill: Option<f64>,
+    /// Growth effectiveness, higher values make `grow` more effective
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub server_growth: Option<f64>,
+}
+
+impl TryFrom<bindings::Server> for Server {
+    type Error = JsValue;
+
+    fn try_from(server: bindings::Server) -> Result<Self, Self::Error> {
+        Ok(serde_wasm_bindgen::from_value(server.into())?)
+    }
+}
+
+impl TryFrom<&Server> for bindings::Server {
+    type Error = JsValue;
+
+    fn try_from(server: &Server) -> Result<Self, Self::Error> {
+        Ok(to_js_object(server)?.unchecked_into())
+    }
+}
+
+impl NS {
+    /// Reads all the properties of `host`, or of the server the script runs on
+    /// if `None`, in a single call.
+    pub fn get_server(&self, host: Option<&str>) -> Result<Server, JsValue> {
+        self.getServer(host)?.try_into()
+    }
+}
@@ -0,0 +1,189 @@
+//! Typed access to the Singularity API at `ns.singularity()`.
+//!
+//! Most of its functions are bound directly with the game enums, like
+//! `travelToCity(CityName)`, `commitCrime(CrimeType, ..)` or
+//! `workForFaction(.., FactionWorkType, ..)`. This module adds the structured
+//! return values and the functions the definitions only type as strings.
+//!
+//! ```ignore
+//! use bitburner_api::singularity::Work;
+//! use bitburner_api::{CrimeType, GymType, LocationName};
+//!
+//! let singularity = ns.singularity();
+//! match singularity.get_current_work()? {
+//!     Some(Work::Crime { .. }) => {}
+//!     _ => {
+//!         singularity.commitCrime(CrimeType::Mug, Some(false))?;
+//!     }
+//! }
+//! singularity.gym_workout(LocationName::Sector12PowerhouseGym, GymType::Strength, None)?;
+//! ```
+
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsValue;
+
+use crate::{
+    CompanyName, CrimeType, FactionWorkType, GymType, JobField, JobName, LocationName, Multipliers,
+    Singularity, Skills, UniversityClassType,
+};
+
+/// Rewards and requirements of a crime, mirrors the `CrimeStats` interface
+/// of the definitions.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CrimeStats {
+    /// Number that helps determine the chance of success
+    pub difficulty: f64,
+    pub karma: f64,
+    pub kills: f64,
+    pub money: f64,
+    /// Milliseconds it takes
+    pub time: f64,
+    /// Description of the crime
+    #[serde(rename = "type")]
+    pub description: String,
+
+    pub hacking_success_weight: f64,
+    pub strength_success_weight: f64,
+    pub defense_success_weight: f64,
+    pub dexterity_success_weight: f64,
+    pub agility_success_weight: f64,
+    pub charisma_success_weight: f64,
+
+    pub hacking_exp: f64,
+    pub strength_exp: f64,
+    pub defense_exp: f64,
+    pub dexterity_exp: f64,
+    pub agility_exp: f64,
+    pub charisma_exp: f64,
+    pub intelligence_exp: f64,
+}
+
+/// Level of an owned source file, mirrors the `SourceFileLvl` interface of
+/// the definitions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SourceFileLvl {
+    /// BitNode number
+    pub n: u32,
+    pub lvl: u32,
+}
+
+/// A job at a company, mirrors the `CompanyPositionInfo` interface of the
+/// definitions.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompanyPositionInfo {
+    pub name: JobName,
+    pub field: JobField,
+    /// The promotion of this job, `None` at the top
+    pub next_position: Option<JobName>,
+    pub salary: f64,
+    pub required_reputation: f64,
+    pub required_skills: Skills,
+}
+
+/// A university class or a gym workout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ClassType {
+    University(UniversityClassType),
+    Gym(GymType),
+}
+
+/// What the player is working on, as returned by `getCurrentWork`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(
+    tag = "type",
+    rename_all = "SCREAMING_SNAKE_CASE",
+    rename_all_fields = "camelCase"
+)]
+pub enum Work {
+    Crime {
+        crime_type: CrimeType,
+        cycles_worked: f64,
+    },
+    Class {
+        class_type: ClassType,
+        location: LocationName,
+        cycles_worked: f64,
+    },
+    CreateProgram {
+        program_name: String,
+        cycles_worked: f64,
+    },
+    Faction {
+        faction_work_type: FactionWorkType,
+        faction_name: String,
+        cycles_worked: f64,
+    },
+    Company {
+        company_name: CompanyName,
+        cycles_worked: f64,
+    },
+    Grafting {
+        augmentation: String,
+        cycles_worked: f64,
+    },
+    /// Work added to the game after these bindings
+    #[serde(other)]
+    Other,
+}
+
+impl Singularity {
+    /// Studies at a university, which must be in the current city.
+    pub fn university_course(
+        &self,
+        university: LocationName,
+        course: UniversityClassType,
+        focus: Option<bool>,
+    ) -> Result<bool, JsValue> {
+        self.universityCourse(university.as_str(), course.as_str(), focus)
+    }
+
+    /// Trains at a gym, which must be in the current city.
+    pub fn gym_workout(
+        &self,
+        gym: LocationName,
+        stat: GymType,
+        focus: Option<bool>,
+    ) -> Result<bool, JsValue> {
+        self.gymWorkout(gym.as_str(), stat.as_str(), focus)
+    }
+
+    /// Goes to a location of the current city.
+    pub fn go_to_location(&self, location: LocationName) -> Result<bool, JsValue> {
+        self.goToLocation(location.as_str())
+    }
+
+    pub fn get_crime_stats(&self, crime: CrimeType) -> Result<CrimeStats, JsValue> {
+        Ok(serde_wasm_bindgen::from_value(
+            self.getCrimeStats(crime)?.into(),
+        )?)
+    }
+
+    pub fn get_owned_source_files(&self) -> Result<Vec<SourceFileLvl>, JsValue> {
+        self.getOwnedSourceFiles()?
+            .into_iter()
+            .map(|source_file| Ok(serde_wasm_bindgen::from_value(source_file.into())?))
+            .collect()
+    }
+
+    pub fn get_company_position_info(
+        &self,
+        company: CompanyName,
+        position: JobName,
+    ) -> Result<CompanyPositionInfo, JsValue> {
+        let info = self.getCompanyPositionInfo(company, position)?;
+        Ok(serde_wasm_bindgen::from_value(info.into())?)
+    }
+
+    /// Multipliers granted by an augmentation.
+    pub fn get_augmentation_stats(&self, augmentation: &str) -> Result<Multipliers, JsValue> {
+        let stats = self.getAugmentationStats(augmentation)?;
+        Ok(serde_wasm_bindgen::from_value(stats.into())?)
+    }
+
+    /// The current work of the player, `None` when idle.
+    pub fn get_current_work(&self) -> Result<Option<Work>, JsValue> {
+        Ok(serde_wasm_bindgen::from_value(self.getCurrentWork()?)?)
+    }
+}
@@ -0,0 +1,401 @@
+//! Typed access to sleeves at `ns.sleeve()` and to grafting at
+//! `ns.grafting()`.
+//!
+//! Sleeve tasks are a [`SleeveTask`] enum, so they can be read and assigned
+//! with the same values:
+//!
+//! ```ignore
+//! use bitburner_api::sleeve::SleeveTask;
+//! use bitburner_api::CrimeType;
+//!
+//! let sleeve = ns.sleeve();
+//! for number in 0..sleeve.get_num_sleeves()? {
+//!     let task = if sleeve.get_sleeve(number)?.shock > 0.0 {
+//!         SleeveTask::Recovery
+//!     } else {
+//!         SleeveTask::crime(CrimeType::Homicide)
+//!     };
+//!     let current = sleeve.get_task(number)?;
+//!     if !current.is_some_and(|current| current.is_same_task(&task)) {
+//!         sleeve.set_task(number, &task)?;
+//!     }
+//! }
+//! ```
+
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsValue;
+
+use crate::{
+    bladeburner::BladeburnerActionType, singularity::ClassType, CompanyName, CrimeType,
+    FactionWorkType, Grafting, GymType, LocationName, Person, Sleeve, UniversityClassType,
+};
+
+/// A sleeve, mirrors the `SleevePerson` interface of the definitions.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SleevePerson {
+    #[serde(flatten)]
+    pub person: Person,
+    /// From 0 to 100, lowers the experience earned
+    pub shock: f64,
+    /// From 1 to 100, share of the experience earned given to the player
+    pub sync: f64,
+    /// Synchronization at the start of a BitNode
+    pub memory: f64,
+    /// 200ms cycles stored as bonus time
+    pub stored_cycles: f64,
+}
+
+/// What a sleeve is doing, mirrors the `SleeveTask` union of the
+/// definitions.
+///
+/// Progress fields are ignored by [`Sleeve::set_task`], so they can be left
+/// at zero when assigning tasks, and read as zero when missing.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(
+    tag = "type",
+    rename_all = "SCREAMING_SNAKE_CASE",
+    rename_all_fields = "camelCase"
+)]
+pub enum SleeveTask {
+    Bladeburner {
+        /// General actions or contracts only
+        action_type: BladeburnerActionType,
+        action_name: String,
+        #[serde(default)]
+        cycles_worked: f64,
+        #[serde(default)]
+        cycles_needed: f64,
+    },
+    Class {
+        class_type: ClassType,
+        location: LocationName,
+    },
+    Company {
+        company_name: CompanyName,
+    },
+    Crime {
+        crime_type: CrimeType,
+        #[serde(default)]
+        cycles_worked: f64,
+        #[serde(default)]
+        cycles_needed: f64,
+    },
+    Faction {
+        faction_work_type: FactionWorkType,
+        faction_name: String,
+    },
+    /// Infiltrating synthoid communities for Bladeburner
+    Infiltrate {
+        #[serde(default)]
+        cycles_worked: f64,
+        #[serde(default)]
+        cycles_needed: f64,
+    },
+    /// Recovering from shock
+    Recovery,
+    /// Supporting the player's Bladeburner actions
+    Support,
+    /// Synchronizing with the player
+    Synchro,
+}
+
+impl SleeveTask {
+    pub fn crime(crime_type: CrimeType) -> Self {
+        SleeveTask::Crime {
+            crime_type,
+            cycles_worked: 0.0,
+            cycles_needed: 0.0,
+        }
+    }
+
+    pub fn university(location: LocationName, class: UniversityClassType) -> Self {
+        SleeveTask::Class {
+            class_type: ClassType::University(class),
+            location,
+        }
+    }
+
+    pub fn gym(location: LocationName, stat: GymType) -> Self {
+        SleeveTask::Class {
+            class_type: ClassType::Gym(stat),
+            location,
+        }
+    }
+
+    pub fn faction(faction_name: &str, faction_work_type: FactionWorkType) -> Self {
+        SleeveTask::Faction {
+            faction_work_type,
+            faction_name: faction_name.to_owned(),
+        }
+    }
+
+    /// A Bladeburner contract, by name.
+    pub fn contract(name: &str) -> Self {
+        SleeveTask::Bladeburner {
+            action_type: BladeburnerActionType::Contract,
+            action_name: name.to_owned(),
+            cycles_worked: 0.0,
+            cycles_needed: 0.0,
+        }
+    }
+
+    /// Whether both tasks do the same thing, whatever their progress.
+    pub fn is_same_task(&self, other: &SleeveTask) -> bool {
+        self.without_progress() == other.without_progress()
+    }
+
+    fn without_progress(&self) -> SleeveTask {
+        let mut task = self.clone();
+        match &mut task {
+            SleeveTask::Bladeburner {
+                cycles_worked,
+                cycles_needed,
+                ..
+            }
+            | SleeveTask::Crime {
+                cycles_worked,
+                cycles_needed,
+                ..
+            }
+            | SleeveTask::Infiltrate {
+                cycles_worked,
+                cycles_needed,
+            } => {
+                *cycles_worked = 0.0;
+                *cycles_needed = 0.0;
+            }
+            _ => {}
+        }
+        task
+    }
+}
+
+/// An augmentation on sale, mirrors the `AugmentPair` interface of the
+/// definitions.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AugmentPair {
+    pub name: String,
+    pub cost: f64,
+}
+
+impl Sleeve {
+    pub fn get_num_sleeves(&self) -> Result<u32, JsValue> {
+        Ok(self.getNumSleeves()? as u32)
+    }
+
+    pub fn get_sleeve(&self, number: u32) -> Result<SleevePerson, JsValue> {
+        let sleeve = self.getSleeve(number.into())?;
+        Ok(serde_wasm_bindgen::from_value(sleeve.into())?)
+    }
+
+    /// What sleeve `number` is doing, `None` when idle.
+    pub fn get_task(&self, number: u32) -> Result<Option<SleeveTask>, JsValue> {
+        Ok(serde_wasm_bindgen::from_value(
+            self.getTask(number.into())?,
+        )?)
+    }
+
+    /// Assigns `task` to sleeve `number`, returns whether it was assigned.
+    pub fn set_task(&self, number: u32, task: &SleeveTask) -> Result<bool, JsValue> {
+        let number = f64::from(number);
+        match task {
+            SleeveTask::Bladeburner {
+                action_type: BladeburnerActionType::Contract,
+                action_name,
+                ..
+            } => self.setToBladeburnerAction(number, "Take on contracts", Some(action_name)),
+            SleeveTask::Bladeburner { action_name, .. } => {
+                self.setToBladeburnerAction(number, action_name, None)
+            }
+            SleeveTask::Class {
+                class_type: ClassType::University(class),
+                location,
+            } => self.setToUniversityCourse(number, location.as_str(), class.as_str()),
+            SleeveTask::Class {
+                class_type: ClassType::Gym(stat),
+                location,
+            } => self.setToGymWorkout(number, location.as_str(), stat.as_str()),
+            SleeveTask::Company { company_name } => self.setToCompanyWork(number, *company_name),
+            SleeveTask::Crime { crime_type, .. } => self.setToCommitCrime(number, *crime_type),
+            SleeveTask::Faction {
+                faction_work_type,
+                faction_name,
+            } => Ok(self
+                .setToFactionWork(number, faction_name, *faction_work_type)?
+                .unwrap_or(false)),
+            SleeveTask::Infiltrate { .. } => {
+                self.setToBladeburnerAction(number, "Infiltrate synthoids", None)
+            }
+            SleeveTask::Recovery => self.setToShockRecovery(number),
+            SleeveTask::Support => self.setToBladeburnerAction(number, "Support main sleeve", None),
+            SleeveTask::Synchro => self.setToSynchronize(number),
+        }
+    }
+
+    /// Augmentations sleeve `number` can buy.
+    pub fn get_sleeve_purchasable_augs(&self, number: u32) -> Result<Vec<AugmentPair>, JsValue> {
+        self.getSleevePurchasableAugs(number.into())?
+            .into_iter()
+            .map(|pair| Ok(serde_wasm_bindgen::from_value(pair.into())?))
+            .collect()
+    }
+}
+
+/// An augmentation that can be grafted.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GraftOffer {
+    pub name: String,
+    pub price: f64,
+    /// Milliseconds grafting takes
+    pub time: f64,
+}
+
+impl Grafting {
+    /// Price and time of every augmentation that can be grafted.
+    pub fn get_graft_offers(&self) -> Result<Vec<GraftOffer>, JsValue> {
+        self.getGraftableAugmentations()?
+            .into_iter()
+            .map(|name| {
+                Ok(GraftOffer {
+                    price: self.getAugmentationGraftPrice(&name)?,
+                    time: self.getAugmentationGraftTime(&name)?,
+                    name,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    /// Checks that `json`, as the game returns it, reads as `task` and that
+    /// `task` writes it back. `json!` tells integers from floats, unlike
+    /// JavaScript, so the numbers are written as floats.
+    fn round_trips(json: Value, task: SleeveTask) {
+        assert_eq!(
+            serde_json::from_value::<SleeveTask>(json.clone()).unwrap(),
+            task
+        );
+        assert_eq!(serde_json::to_value(&task).unwrap(), json);
+    }
+
+    #[test]
+    fn bladeburner() {
+        round_trips(
+            json!({
+                "type": "BLADEBURNER",
+                "actionType": "Contracts",
+                "actionName": "Tracking",
+                "cyclesWorked": 12.0,
+                "cyclesNeeded": 300.0,
+            }),
+            SleeveTask::Bladeburner {
+                action_type: BladeburnerActionType::Contract,
+                action_name: "Tracking".to_owned(),
+                cycles_worked: 12.0,
+                cycles_needed: 300.0,
+            },
+        );
+    }
+
+    #[test]
+    fn class() {
+        round_trips(
+            json!({
+                "type": "CLASS",
+                "classType": "Computer Science",
+                "location": "Rothman University",
+            }),
+            SleeveTask::university(
+                LocationName::Sector12RothmanUniversity,
+                UniversityClassType::ComputerScience,
+            ),
+        );
+        round_trips(
+            json!({ "type": "CLASS", "classType": "str", "location": "Powerhouse Gym" }),
+            SleeveTask::gym(LocationName::Sector12PowerhouseGym, GymType::Strength),
+        );
+    }
+
+    #[test]
+    fn company() {
+        round_trips(
+            json!({ "type": "COMPANY", "companyName": "ECorp" }),
+            SleeveTask::Company {
+                company_name: CompanyName::ECorp,
+            },
+        );
+    }
+
+    #[test]
+    fn crime() {
+        round_trips(
+            json!({
+                "type": "CRIME",
+                "crimeType": "Mug",
+                "cyclesWorked": 0.0,
+                "cyclesNeeded": 20.0,
+            }),
+            SleeveTask::Crime {
+                crime_type: CrimeType::Mug,
+                cycles_worked: 0.0,
+                cycles_needed: 20.0,
+            },
+        );
+        let task: SleeveTask =
+            serde_json::from_str(r#"{"type":"CRIME","crimeType":"Mug","cyclesWorked":0}"#).unwrap();
+        assert_eq!(task, SleeveTask::crime(CrimeType::Mug));
+    }
+
+    #[test]
+    fn faction() {
+        round_trips(
+            json!({
+                "type": "FACTION",
+                "factionWorkType": "hacking",
+                "factionName": "CyberSec",
+            }),
+            SleeveTask::faction("CyberSec", FactionWorkType::Hacking),
+        );
+    }
+
+    #[test]
+    fn infiltrate() {
+        round_trips(
+            json!({ "type": "INFILTRATE", "cyclesWorked": 5.0, "cyclesNeeded": 50.0 }),
+            SleeveTask::Infiltrate {
+                cycles_worked: 5.0,
+                cycles_needed: 50.0,
+            },
+        );
+    }
+
+    #[test]
+    fn tasks_without_fields() {
+        round_trips(json!({ "type": "RECOVERY" }), SleeveTask::Recovery);
+        round_trips(json!({ "type": "SUPPORT" }), SleeveTask::Support);
+        round_trips(json!({ "type": "SYNCHRO" }), SleeveTask::Synchro);
+    }
+
+    #[test]
+    fn unknown_tasks_are_rejected() {
+        assert!(serde_json::from_value::<SleeveTask>(json!({ "type": "IDLE" })).is_err());
+        assert!(serde_json::from_value::<SleeveTask>(json!({ "type": "crime" })).is_err());
+    }
+
+    #[test]
+    fn same_task_ignores_progress() {
+        let working = SleeveTask::Crime {
+            crime_type: CrimeType::Mug,
+            cycles_worked: 7.0,
+            cycles_needed: 20.0,
+        };
+        assert!(working.is_same_task(&SleeveTask::crime(CrimeType::Mug)));
+        assert!(!working.is_same_task(&SleeveTask::crime(CrimeType::Larceny)));
+    }
+}
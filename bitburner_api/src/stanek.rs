@@ -0,0 +1,420 @@
+//! Typed access to Stanek's Gift at `ns.stanek()`, a [`placement`] solver
+//! and a [`ChargeScheduler`] charging the placed fragments with the free RAM
+//! of the network.
+//!
+//! Charging runs worker scripts like batches do, so planning the charges
+//! works natively against a [`MockNs`](crate::MockNs):
+//!
+//! ```
+//! use bitburner_api::stanek::ChargeScheduler;
+//! use bitburner_api::{mock, MockNs, Server};
+//!
+//! let mut ns = MockNs::new();
+//! ns.add_server(
+//!     Server {
+//!         has_admin_rights: true,
+//!         max_ram: 16.0,
+//!         ..mock::server("pserv-0")
+//!     },
+//!     &["home"],
+//! );
+//! let scheduler = ChargeScheduler::new();
+//! ns.add_script("home", scheduler.worker_script(), 2.0);
+//!
+//! // two fragments share the 12 threads home and pserv-0 hold
+//! let jobs = scheduler.plan(&ns, &[(0, 0), (3, 1)]).unwrap();
+//! let threads = |root| -> u32 {
+//!     jobs.iter()
+//!         .filter(|job| (job.x, job.y) == root)
+//!         .map(|job| job.threads)
+//!         .sum()
+//! };
+//! assert_eq!(threads((0, 0)), 6);
+//! assert_eq!(threads((3, 1)), 6);
+//! assert_eq!(scheduler.dispatch(&ns, &jobs).unwrap().len(), jobs.len());
+//! ```
+//!
+//! In the game, a layout is searched, placed and then charged forever:
+//!
+//! ```ignore
+//! use bitburner_api::stanek::{self, ChargeScheduler, FragmentType};
+//!
+//! let gift = ns.stanek();
+//! let layout = gift
+//!     .placement_solver()?
+//!     .weight(FragmentType::HackingMoney, 1.0)
+//!     .weight(FragmentType::HackingSpeed, 1.0)
+//!     .solve();
+//! gift.place_layout(&layout)?;
+//!
+//! ns.write(stanek::CHARGE_WORKER, Some(stanek::CHARGE_WORKER_SOURCE), Some("w"))?;
+//! ChargeScheduler::new().home_reserve(32.0).run(ns).await?;
+//! ```
+
+pub mod placement;
+
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsValue;
+
+use crate::{
+    batch::RamPool,
+    network::HOME,
+    stanek::placement::{Layout, PlacementSolver},
+    NetscriptApi, Pid, ScriptArg, Stanek, NS,
+};
+
+/// Default filename of the charge worker, on `home`.
+pub const CHARGE_WORKER: &str = "/stanek/charge.js";
+
+/// Source of the charge worker. It takes the root of the fragment as
+/// arguments, and a tag keeping its arguments unique.
+pub const CHARGE_WORKER_SOURCE: &str =
+    "export async function main(ns) {\n  await ns.stanek.chargeFragment(ns.args[0], ns.args[1]);\n}\n";
+
+/// What a fragment boosts, the `type` of the definitions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "u32", into = "u32")]
+pub enum FragmentType {
+    None,
+    Delete,
+    HackingChance,
+    HackingSpeed,
+    HackingMoney,
+    HackingGrow,
+    Hacking,
+    Strength,
+    Defense,
+    Dexterity,
+    Agility,
+    Charisma,
+    HacknetMoney,
+    HacknetCost,
+    Rep,
+    WorkMoney,
+    Crime,
+    Bladeburner,
+    /// Boosts the fragments next to it instead of a stat
+    Booster,
+}
+
+impl FragmentType {
+    pub const ALL: [FragmentType; 19] = [
+        FragmentType::None,
+        FragmentType::Delete,
+        FragmentType::HackingChance,
+        FragmentType::HackingSpeed,
+        FragmentType::HackingMoney,
+        FragmentType::HackingGrow,
+        FragmentType::Hacking,
+        FragmentType::Strength,
+        FragmentType::Defense,
+        FragmentType::Dexterity,
+        FragmentType::Agility,
+        FragmentType::Charisma,
+        FragmentType::HacknetMoney,
+        FragmentType::HacknetCost,
+        FragmentType::Rep,
+        FragmentType::WorkMoney,
+        FragmentType::Crime,
+        FragmentType::Bladeburner,
+        FragmentType::Booster,
+    ];
+}
+
+impl TryFrom<u32> for FragmentType {
+    type Error = String;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        FragmentType::ALL
+            .get(value as usize)
+            .copied()
+            .ok_or_else(|| format!("{value} is not a valid FragmentType"))
+    }
+}
+
+impl From<FragmentType> for u32 {
+    fn from(fragment_type: FragmentType) -> Self {
+        fragment_type as u32
+    }
+}
+
+/// A fragment that can be placed on the gift.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Fragment {
+    pub id: u32,
+    /// Cells of the fragment by row, unrotated
+    pub shape: Vec<Vec<bool>>,
+    #[serde(rename = "type")]
+    pub fragment_type: FragmentType,
+    pub power: f64,
+    /// How many of it can be placed
+    pub limit: u32,
+    pub effect: String,
+}
+
+/// A fragment placed on the gift.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActiveFragment {
+    pub id: u32,
+    pub highest_charge: f64,
+    pub num_charge: f64,
+    pub rotation: u32,
+    pub x: u32,
+    pub y: u32,
+}
+
+impl TryFrom<crate::bindings::Fragment> for Fragment {
+    type Error = JsValue;
+
+    fn try_from(fragment: crate::bindings::Fragment) -> Result<Self, Self::Error> {
+        Ok(serde_wasm_bindgen::from_value(fragment.into())?)
+    }
+}
+
+impl TryFrom<crate::bindings::ActiveFragment> for ActiveFragment {
+    type Error = JsValue;
+
+    fn try_from(fragment: crate::bindings::ActiveFragment) -> Result<Self, Self::Error> {
+        Ok(serde_wasm_bindgen::from_value(fragment.into())?)
+    }
+}
+
+impl Stanek {
+    pub fn gift_width(&self) -> Result<u32, JsValue> {
+        Ok(self.giftWidth()? as u32)
+    }
+
+    pub fn gift_height(&self) -> Result<u32, JsValue> {
+        Ok(self.giftHeight()? as u32)
+    }
+
+    pub fn fragment_definitions(&self) -> Result<Vec<Fragment>, JsValue> {
+        self.fragmentDefinitions()?
+            .into_iter()
+            .map(Fragment::try_from)
+            .collect()
+    }
+
+    pub fn active_fragments(&self) -> Result<Vec<ActiveFragment>, JsValue> {
+        self.activeFragments()?
+            .into_iter()
+            .map(ActiveFragment::try_from)
+            .collect()
+    }
+
+    /// The fragment covering the cell at `x`, `y`, if any.
+    pub fn get_fragment(&self, x: u32, y: u32) -> Result<Option<ActiveFragment>, JsValue> {
+        self.getFragment(x.into(), y.into())?
+            .map(ActiveFragment::try_from)
+            .transpose()
+    }
+
+    pub fn can_place_fragment(
+        &self,
+        x: u32,
+        y: u32,
+        rotation: u32,
+        fragment_id: u32,
+    ) -> Result<bool, JsValue> {
+        self.canPlaceFragment(x.into(), y.into(), rotation.into(), fragment_id.into())
+    }
+
+    /// Places a fragment with its root at `x`, `y`, returns whether it fit.
+    pub fn place_fragment(
+        &self,
+        x: u32,
+        y: u32,
+        rotation: u32,
+        fragment_id: u32,
+    ) -> Result<bool, JsValue> {
+        self.placeFragment(x.into(), y.into(), rotation.into(), fragment_id.into())
+    }
+
+    pub fn remove_fragment(&self, x: u32, y: u32) -> Result<bool, JsValue> {
+        self.removeFragment(x.into(), y.into())
+    }
+
+    /// Charges the fragment rooted at `x`, `y` with the threads of the
+    /// current script.
+    pub async fn charge_fragment(&self, x: u32, y: u32) -> Result<(), JsValue> {
+        self.chargeFragment(x.into(), y.into()).await
+    }
+
+    /// A solver for the size of the gift and all the fragment definitions.
+    pub fn placement_solver(&self) -> Result<PlacementSolver, JsValue> {
+        Ok(PlacementSolver::new(
+            self.gift_width()?,
+            self.gift_height()?,
+            self.fragment_definitions()?,
+        ))
+    }
+
+    /// Clears the gift and places the fragments of `layout`, returns
+    /// whether all of them fit.
+    pub fn place_layout(&self, layout: &Layout) -> Result<bool, JsValue> {
+        self.clearGift()?;
+        let mut all = true;
+        for placement in &layout.placements {
+            all &=
+                self.place_fragment(placement.x, placement.y, placement.rotation, placement.id)?;
+        }
+        Ok(all)
+    }
+
+    /// Roots of the placed fragments that take charges, i.e. all but the
+    /// boosters.
+    pub fn chargeable_fragments(&self) -> Result<Vec<(u32, u32)>, JsValue> {
+        let boosters: Vec<u32> = self
+            .fragment_definitions()?
+            .into_iter()
+            .filter(|fragment| fragment.fragment_type == FragmentType::Booster)
+            .map(|fragment| fragment.id)
+            .collect();
+        Ok(self
+            .active_fragments()?
+            .into_iter()
+            .filter(|fragment| !boosters.contains(&fragment.id))
+            .map(|fragment| (fragment.x, fragment.y))
+            .collect())
+    }
+}
+
+/// Threads of the charge worker charging a fragment from a server.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChargeJob {
+    pub host: String,
+    pub threads: u32,
+    /// Root of the fragment
+    pub x: u32,
+    pub y: u32,
+}
+
+/// Charges fragments with all the free RAM of the rooted servers, split
+/// evenly between the fragments.
+#[derive(Debug, Clone)]
+pub struct ChargeScheduler {
+    worker: String,
+    home_reserve: f64,
+    poll_interval: f64,
+}
+
+impl Default for ChargeScheduler {
+    fn default() -> Self {
+        ChargeScheduler::new()
+    }
+}
+
+impl ChargeScheduler {
+    pub fn new() -> Self {
+        ChargeScheduler {
+            worker: CHARGE_WORKER.to_owned(),
+            home_reserve: 0.0,
+            poll_interval: 200.0,
+        }
+    }
+
+    /// Filename of the charge worker on `home`, [`CHARGE_WORKER`] by
+    /// default.
+    pub fn worker(mut self, script: &str) -> Self {
+        self.worker = script.to_owned();
+        self
+    }
+
+    /// RAM (GB) left free on `home`, none by default.
+    pub fn home_reserve(mut self, ram: f64) -> Self {
+        self.home_reserve = ram;
+        self
+    }
+
+    /// Milliseconds between checks whether the workers are done, 200 by
+    /// default.
+    pub fn poll_interval(mut self, millis: f64) -> Self {
+        self.poll_interval = millis;
+        self
+    }
+
+    pub fn worker_script(&self) -> &str {
+        &self.worker
+    }
+
+    /// Splits the threads every rooted server holds between the fragments
+    /// rooted at `roots`.
+    pub fn plan<N: NetscriptApi>(
+        &self,
+        ns: &N,
+        roots: &[(u32, u32)],
+    ) -> Result<Vec<ChargeJob>, String> {
+        let ram = ns.get_script_ram(&self.worker, Some(HOME))?;
+        if ram <= 0.0 {
+            return Err(format!("{} is not on {HOME}", self.worker));
+        }
+        if roots.is_empty() {
+            return Ok(vec![]);
+        }
+        let mut jobs = vec![];
+        for (host, free) in RamPool::rooted(ns, self.home_reserve)?.hosts() {
+            let threads = (free / ram).floor() as u32;
+            let share = threads / roots.len() as u32;
+            let extra = threads as usize % roots.len();
+            for (index, &(x, y)) in roots.iter().enumerate() {
+                let threads = share + u32::from(index < extra);
+                if threads > 0 {
+                    jobs.push(ChargeJob {
+                        host: host.to_owned(),
+                        threads,
+                        x,
+                        y,
+                    });
+                }
+            }
+        }
+        Ok(jobs)
+    }
+
+    /// Starts the charge workers of `jobs`, copying the worker where needed.
+    pub fn dispatch<N: NetscriptApi>(
+        &self,
+        ns: &N,
+        jobs: &[ChargeJob],
+    ) -> Result<Vec<Pid>, String> {
+        let mut deployed: Vec<&str> = vec![];
+        let mut pids = vec![];
+        for (index, job) in jobs.iter().enumerate() {
+            if job.host != HOME && !deployed.contains(&job.host.as_str()) {
+                ns.scp(&[&self.worker], &job.host, Some(HOME))?;
+                deployed.push(&job.host);
+            }
+            let args = [
+                ScriptArg::from(job.x),
+                ScriptArg::from(job.y),
+                ScriptArg::from(format!("charge-{index}")),
+            ];
+            match ns.exec(&self.worker, &job.host, job.threads, &args)? {
+                Some(pid) => pids.push(pid),
+                None => return Err(format!("could not start {} on {}", self.worker, job.host)),
+            }
+        }
+        Ok(pids)
+    }
+
+    /// Charges the placed fragments round after round, waiting for the
+    /// workers of a round to finish before starting the next. Never returns
+    /// unless something fails.
+    pub async fn run(&self, ns: &NS) -> Result<(), JsValue> {
+        let gift = ns.stanek();
+        loop {
+            let roots = gift.chargeable_fragments()?;
+            let pids = self.dispatch(ns, &self.plan(ns, &roots)?)?;
+            if pids.is_empty() {
+                ns.sleep(self.poll_interval).await?;
+                continue;
+            }
+            for pid in pids {
+                while ns.is_running(pid, None, &[])? {
+                    ns.sleep(self.poll_interval).await?;
+                }
+            }
+        }
+    }
+}
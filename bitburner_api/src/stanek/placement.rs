@@ -0,0 +1,427 @@
+//! Layouts of fragments on Stanek's Gift, searched natively.
+//!
+//! A [`PlacementSolver`] fills the gift with fragments, scoring a layout as
+//! the sum of the weighted power of its fragments, each multiplied by the
+//! power of the boosters touching it:
+//!
+//! ```
+//! use bitburner_api::stanek::{placement::PlacementSolver, Fragment, FragmentType};
+//!
+//! let fragment = |id, fragment_type, power, limit, shape: &[&[bool]]| Fragment {
+//!     id,
+//!     shape: shape.iter().map(|row| row.to_vec()).collect(),
+//!     fragment_type,
+//!     power,
+//!     limit,
+//!     effect: String::new(),
+//! };
+//! let fragments = vec![
+//!     fragment(0, FragmentType::Hacking, 1.0, 1, &[&[true, true, true], &[false, true, false]]),
+//!     fragment(1, FragmentType::Strength, 1.0, 1, &[&[true, true], &[true, true]]),
+//!     fragment(100, FragmentType::Booster, 1.1, 99, &[&[true, true, true]]),
+//! ];
+//!
+//! let layout = PlacementSolver::new(3, 3, fragments)
+//!     .weight(FragmentType::Hacking, 2.0)
+//!     .weight(FragmentType::Strength, 1.0)
+//!     .solve();
+//! // the strength fragment does not fit next to the hacking one, a booster
+//! // does
+//! assert_eq!(layout.placements.len(), 2);
+//! assert!((layout.score - 2.2).abs() < 1e-9);
+//! ```
+
+use std::collections::HashMap;
+
+use super::{Fragment, FragmentType};
+
+/// Cells a fragment covers at `rotation` quarter turns, relative to its
+/// root, in reading order. This follows the game's rotation of shapes.
+pub fn rotated_cells(shape: &[Vec<bool>], rotation: u32) -> Vec<(u32, u32)> {
+    let rows = shape.len() as i64;
+    let columns = shape.first().map_or(0, Vec::len) as i64;
+    let (width, height) = if rotation.is_multiple_of(2) {
+        (columns, rows)
+    } else {
+        (rows, columns)
+    };
+    let (start_x, start_y, step_x, step_y) = match rotation % 4 {
+        1 => (width - 1, 0, -1, 1),
+        2 => (width - 1, height - 1, -1, -1),
+        3 => (0, height - 1, 1, -1),
+        _ => (0, 0, 1, 1),
+    };
+    let mut cells = vec![];
+    for y in 0..height {
+        for x in 0..width {
+            let (mut qx, mut qy) = (start_x + step_x * x, start_y + step_y * y);
+            if rotation % 2 == 1 {
+                (qx, qy) = (qy, qx);
+            }
+            if shape[qy as usize][qx as usize] {
+                cells.push((x as u32, y as u32));
+            }
+        }
+    }
+    cells
+}
+
+/// A fragment placed on the gift, as `placeFragment` takes it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Placement {
+    pub id: u32,
+    pub x: u32,
+    pub y: u32,
+    pub rotation: u32,
+}
+
+/// Placements found by a [`PlacementSolver`] and their score.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Layout {
+    pub placements: Vec<Placement>,
+    pub score: f64,
+}
+
+/// A distinct rotation of a fragment.
+struct Orientation {
+    rotation: u32,
+    cells: Vec<(u32, u32)>,
+}
+
+/// Searches the layout of fragments with the highest weighted power.
+///
+/// The search is depth first over the cells in reading order, each either
+/// covered by a fragment or left empty, trying the heaviest fragments
+/// first. It is exhaustive on small gifts and stops after
+/// [`max_steps`](PlacementSolver::max_steps) on larger ones, keeping the
+/// best layout found so far.
+#[derive(Debug, Clone)]
+pub struct PlacementSolver {
+    width: u32,
+    height: u32,
+    fragments: Vec<Fragment>,
+    weights: HashMap<FragmentType, f64>,
+    max_steps: usize,
+}
+
+impl PlacementSolver {
+    pub fn new(width: u32, height: u32, fragments: Vec<Fragment>) -> Self {
+        PlacementSolver {
+            width,
+            height,
+            fragments,
+            weights: HashMap::new(),
+            max_steps: 1_000_000,
+        }
+    }
+
+    /// How much the power of fragments of `fragment_type` counts, 0 by
+    /// default. Fragments without weight are not placed.
+    pub fn weight(mut self, fragment_type: FragmentType, weight: f64) -> Self {
+        self.weights.insert(fragment_type, weight);
+        self
+    }
+
+    /// Search steps before settling for the best layout so far, 1 000 000
+    /// by default.
+    pub fn max_steps(mut self, steps: usize) -> Self {
+        self.max_steps = steps;
+        self
+    }
+
+    fn weight_of(&self, fragment: &Fragment) -> f64 {
+        self.weights
+            .get(&fragment.fragment_type)
+            .copied()
+            .unwrap_or(0.0)
+    }
+
+    pub fn solve(&self) -> Layout {
+        // weighted fragments by decreasing weighted power, then boosters
+        let mut candidates: Vec<&Fragment> = self
+            .fragments
+            .iter()
+            .filter(|fragment| {
+                fragment.fragment_type == FragmentType::Booster || self.weight_of(fragment) > 0.0
+            })
+            .collect();
+        candidates.sort_by(|a, b| {
+            let value = |fragment: &Fragment| match fragment.fragment_type {
+                FragmentType::Booster => f64::NEG_INFINITY,
+                _ => self.weight_of(fragment) * fragment.power,
+            };
+            value(b).total_cmp(&value(a))
+        });
+        let orientations: Vec<Vec<Orientation>> = candidates
+            .iter()
+            .map(|fragment| {
+                let mut orientations: Vec<Orientation> = vec![];
+                for rotation in 0..4 {
+                    let cells = rotated_cells(&fragment.shape, rotation);
+                    if !cells.is_empty() && orientations.iter().all(|other| other.cells != cells) {
+                        orientations.push(Orientation { rotation, cells });
+                    }
+                }
+                orientations
+            })
+            .collect();
+
+        let mut search = Search {
+            solver: self,
+            counts: vec![0; candidates.len()],
+            candidates,
+            orientations,
+            board: vec![false; (self.width * self.height) as usize],
+            placed: vec![],
+            covered: vec![],
+            steps: 0,
+            best: Layout::default(),
+        };
+        search.visit(0);
+        search.best
+    }
+
+    /// Weighted power of a layout, each fragment multiplied by the power of
+    /// the boosters next to it.
+    pub fn score(&self, placements: &[Placement]) -> f64 {
+        let placed: Vec<(&Fragment, Vec<(u32, u32)>)> = placements
+            .iter()
+            .filter_map(|placement| {
+                let fragment = self
+                    .fragments
+                    .iter()
+                    .find(|fragment| fragment.id == placement.id)?;
+                let cells = rotated_cells(&fragment.shape, placement.rotation)
+                    .into_iter()
+                    .map(|(x, y)| (placement.x + x, placement.y + y))
+                    .collect();
+                Some((fragment, cells))
+            })
+            .collect();
+        self.placed_score(&placed)
+    }
+
+    /// [`score`](Self::score) of fragments and the cells they cover.
+    fn placed_score(&self, placed: &[(&Fragment, Vec<(u32, u32)>)]) -> f64 {
+        let touches = |a: &[(u32, u32)], b: &[(u32, u32)]| {
+            a.iter().any(|&(ax, ay)| {
+                b.iter()
+                    .any(|&(bx, by)| ax.abs_diff(bx) + ay.abs_diff(by) == 1)
+            })
+        };
+
+        let mut score = 0.0;
+        for (fragment, cells) in placed {
+            if fragment.fragment_type == FragmentType::Booster {
+                continue;
+            }
+            let boost: f64 = placed
+                .iter()
+                .filter(|(booster, booster_cells)| {
+                    booster.fragment_type == FragmentType::Booster && touches(cells, booster_cells)
+                })
+                .map(|(booster, _)| booster.power)
+                .product();
+            score += self.weight_of(fragment) * fragment.power * boost;
+        }
+        score
+    }
+}
+
+struct Search<'a> {
+    solver: &'a PlacementSolver,
+    candidates: Vec<&'a Fragment>,
+    orientations: Vec<Vec<Orientation>>,
+    /// Whether each cell is covered
+    board: Vec<bool>,
+    placed: Vec<Placement>,
+    /// Fragments of `placed` and the cells they cover
+    covered: Vec<(&'a Fragment, Vec<(u32, u32)>)>,
+    /// Placements of each candidate
+    counts: Vec<u32>,
+    steps: usize,
+    best: Layout,
+}
+
+impl Search<'_> {
+    fn visit(&mut self, cell: usize) {
+        self.steps += 1;
+        let score = self.solver.placed_score(&self.covered);
+        if score > self.best.score {
+            self.best = Layout {
+                placements: self.placed.clone(),
+                score,
+            };
+        }
+        let Some(cell) = (cell..self.board.len()).find(|&cell| !self.board[cell]) else {
+            return;
+        };
+        let width = self.solver.width;
+        let (cell_x, cell_y) = (cell as u32 % width, cell as u32 / width);
+
+        for candidate in 0..self.candidates.len() {
+            let fragment = self.candidates[candidate];
+            if self.counts[candidate] >= fragment.limit {
+                continue;
+            }
+            for orientation in 0..self.orientations[candidate].len() {
+                if self.steps >= self.solver.max_steps {
+                    return;
+                }
+                let Orientation { rotation, cells } = &self.orientations[candidate][orientation];
+                // the first cell of the fragment covers the first free cell
+                let (anchor_x, _) = cells[0];
+                let Some(x) = cell_x.checked_sub(anchor_x) else {
+                    continue;
+                };
+                let covered: Option<Vec<(u32, u32)>> = cells
+                    .iter()
+                    .map(|&(dx, dy)| {
+                        let (x, y) = (x + dx, cell_y + dy);
+                        (x < width
+                            && y < self.solver.height
+                            && !self.board[(y * width + x) as usize])
+                            .then_some((x, y))
+                    })
+                    .collect();
+                let Some(covered) = covered else {
+                    continue;
+                };
+
+                for &(x, y) in &covered {
+                    self.board[(y * width + x) as usize] = true;
+                }
+                self.placed.push(Placement {
+                    id: fragment.id,
+                    x,
+                    y: cell_y,
+                    rotation: *rotation,
+                });
+                self.covered.push((fragment, covered));
+                self.counts[candidate] += 1;
+                self.visit(cell + 1);
+                self.counts[candidate] -= 1;
+                let (_, covered) = self.covered.pop().unwrap();
+                self.placed.pop();
+                for (x, y) in covered {
+                    self.board[(y * width + x) as usize] = false;
+                }
+            }
+        }
+
+        if self.steps < self.solver.max_steps {
+            self.visit(cell + 1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fragment(id: u32, fragment_type: FragmentType, power: f64, shape: &[&[bool]]) -> Fragment {
+        Fragment {
+            id,
+            shape: shape.iter().map(|row| row.to_vec()).collect(),
+            fragment_type,
+            power,
+            limit: 1,
+            effect: String::new(),
+        }
+    }
+
+    fn fragments() -> Vec<Fragment> {
+        vec![
+            fragment(
+                0,
+                FragmentType::Hacking,
+                1.0,
+                &[&[true, true, true], &[false, true, false]],
+            ),
+            fragment(
+                1,
+                FragmentType::Strength,
+                1.0,
+                &[&[true, true], &[true, true]],
+            ),
+            fragment(5, FragmentType::Defense, 1.0, &[&[true, true, true, true]]),
+        ]
+    }
+
+    fn solver(width: u32, height: u32) -> PlacementSolver {
+        PlacementSolver::new(width, height, fragments())
+            .weight(FragmentType::Hacking, 3.0)
+            .weight(FragmentType::Strength, 2.0)
+            .weight(FragmentType::Defense, 1.0)
+    }
+
+    /// Whether the placements stay on the gift without overlapping.
+    fn fits(solver: &PlacementSolver, layout: &Layout) -> bool {
+        let mut covered = vec![];
+        for placement in &layout.placements {
+            let fragment = solver
+                .fragments
+                .iter()
+                .find(|fragment| fragment.id == placement.id)
+                .unwrap();
+            for (x, y) in rotated_cells(&fragment.shape, placement.rotation) {
+                let cell = (placement.x + x, placement.y + y);
+                if cell.0 >= solver.width || cell.1 >= solver.height || covered.contains(&cell) {
+                    return false;
+                }
+                covered.push(cell);
+            }
+        }
+        true
+    }
+
+    #[test]
+    fn rotations() {
+        let shape = [vec![true, true, true], vec![false, true, false]];
+        assert_eq!(rotated_cells(&shape, 0), [(0, 0), (1, 0), (2, 0), (1, 1)]);
+        assert_eq!(rotated_cells(&shape, 1), [(1, 0), (0, 1), (1, 1), (1, 2)]);
+        assert_eq!(rotated_cells(&shape, 4), rotated_cells(&shape, 0));
+        assert!(rotated_cells(&[], 1).is_empty());
+    }
+
+    #[test]
+    fn gift_smaller_than_any_fragment() {
+        for (width, height) in [(0, 0), (1, 1), (2, 1), (1, 3)] {
+            let layout = solver(width, height).solve();
+            assert_eq!(layout, Layout::default(), "{width}x{height}");
+        }
+    }
+
+    #[test]
+    fn unweighted_fragments_are_not_placed() {
+        let layout = PlacementSolver::new(4, 4, fragments()).solve();
+        assert_eq!(layout, Layout::default());
+    }
+
+    #[test]
+    fn exhaustive_search() {
+        // the three fragments cover 12 cells but do not tile a 4x3 gift
+        for (height, score) in [(3, 5.0), (4, 6.0)] {
+            let solver = solver(4, height);
+            let layout = solver.solve();
+            assert!(fits(&solver, &layout));
+            assert_eq!(layout.score, score);
+            assert_eq!(solver.score(&layout.placements), layout.score);
+        }
+    }
+
+    #[test]
+    fn max_steps_cutoff() {
+        let full = solver(5, 5).solve();
+        for steps in [0, 1, 10, 100] {
+            let solver = solver(5, 5).max_steps(steps);
+            let layout = solver.solve();
+            assert!(fits(&solver, &layout), "{steps}");
+            assert!(layout.score <= full.score, "{steps}");
+            assert_eq!(solver.score(&layout.placements), layout.score);
+        }
+        assert_eq!(solver(5, 5).max_steps(0).solve(), Layout::default());
+        // the first branches already place the heaviest fragments
+        assert!(solver(5, 5).max_steps(10).solve().score > 0.0);
+    }
+}
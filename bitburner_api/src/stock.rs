@@ -0,0 +1,173 @@
+//! Typed access to the stock market, through the `TIX` interface at
+//! `ns.stock()`.
+//!
+//! ```ignore
+//! use bitburner_api::stock::PositionType;
+//!
+//! let stock = ns.stock();
+//! for symbol in stock.getSymbols()? {
+//!     let position = stock.get_position(&symbol)?;
+//!     if position.long_shares > 0.0 && stock.getForecast(&symbol)? < 0.5 {
+//!         let gain = stock.get_sale_gain(&symbol, position.long_shares, PositionType::Long)?;
+//!         stock.sellStock(&symbol, position.long_shares)?;
+//!         ns.print(&[format!("sold {symbol} for {gain}").into()])?;
+//!     }
+//! }
+//! ```
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsValue;
+
+use crate::{bindings, TIX};
+pub use crate::{OrderType, PositionType};
+
+/// Shares owned of a stock, as returned by `getPosition`.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct StockPosition {
+    pub long_shares: f64,
+    /// Average price paid per long share
+    pub long_price: f64,
+    pub short_shares: f64,
+    /// Average price paid per short share
+    pub short_price: f64,
+}
+
+impl StockPosition {
+    pub fn is_empty(&self) -> bool {
+        self.long_shares == 0.0 && self.short_shares == 0.0
+    }
+
+    pub fn shares(&self, position: PositionType) -> f64 {
+        match position {
+            PositionType::Short => self.short_shares,
+            _ => self.long_shares,
+        }
+    }
+}
+
+impl TryFrom<Vec<f64>> for StockPosition {
+    type Error = JsValue;
+
+    fn try_from(position: Vec<f64>) -> Result<Self, Self::Error> {
+        match position[..] {
+            [long_shares, long_price, short_shares, short_price] => Ok(StockPosition {
+                long_shares,
+                long_price,
+                short_shares,
+                short_price,
+            }),
+            _ => Err(JsValue::from_str(&format!(
+                "expected 4 numbers in a stock position, got {position:?}"
+            ))),
+        }
+    }
+}
+
+/// A limit or stop order, mirrors the `StockOrderObject` interface of the
+/// definitions.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StockOrderObject {
+    pub shares: f64,
+    /// Price per share
+    pub price: f64,
+    #[serde(rename = "type")]
+    pub order_type: OrderType,
+    pub position: PositionType,
+}
+
+/// Orders by stock symbol, mirrors the `StockOrder` interface of the
+/// definitions.
+pub type StockOrder = HashMap<String, Vec<StockOrderObject>>;
+
+/// Constants of the stock market, mirrors the `StockMarketConstants`
+/// interface of the definitions.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StockMarketConstants {
+    /// Normal time in ms between stock market updates
+    pub ms_per_stock_update: f64,
+    /// Minimum time in ms between updates while there is bonus time
+    pub ms_per_stock_update_min: f64,
+    /// Used while determining when to flip a stock's forecast
+    #[serde(rename = "TicksPerCycle")]
+    pub ticks_per_cycle: f64,
+    #[serde(rename = "WSEAccountCost")]
+    pub wse_account_cost: f64,
+    #[serde(rename = "TIXAPICost")]
+    pub tix_api_cost: f64,
+    #[serde(rename = "MarketData4SCost")]
+    pub market_data_4s_cost: f64,
+    #[serde(rename = "MarketDataTixApi4SCost")]
+    pub market_data_tix_api_4s_cost: f64,
+    /// Commission fee for every transaction
+    #[serde(rename = "StockMarketCommission")]
+    pub stock_market_commission: f64,
+}
+
+impl TryFrom<bindings::StockMarketConstants> for StockMarketConstants {
+    type Error = JsValue;
+
+    fn try_from(constants: bindings::StockMarketConstants) -> Result<Self, Self::Error> {
+        Ok(serde_wasm_bindgen::from_value(constants.into())?)
+    }
+}
+
+impl TIX {
+    pub fn get_constants(&self) -> Result<StockMarketConstants, JsValue> {
+        self.getConstants()?.try_into()
+    }
+
+    /// Shares owned of `symbol`, long and short.
+    pub fn get_position(&self, symbol: &str) -> Result<StockPosition, JsValue> {
+        self.getPosition(symbol)?.try_into()
+    }
+
+    /// Cost of buying `shares` of `symbol`, commission included.
+    pub fn get_purchase_cost(
+        &self,
+        symbol: &str,
+        shares: f64,
+        position: PositionType,
+    ) -> Result<f64, JsValue> {
+        self.getPurchaseCost(symbol, shares, position.as_str())
+    }
+
+    /// Money gained by selling `shares` of `symbol`, commission included.
+    pub fn get_sale_gain(
+        &self,
+        symbol: &str,
+        shares: f64,
+        position: PositionType,
+    ) -> Result<f64, JsValue> {
+        self.getSaleGain(symbol, shares, position.as_str())
+    }
+
+    /// Places a limit or stop order, returns whether it was placed.
+    pub fn place_order(&self, symbol: &str, order: &StockOrderObject) -> Result<bool, JsValue> {
+        self.placeOrder(
+            symbol,
+            order.shares,
+            order.price,
+            order.order_type.as_str(),
+            order.position.as_str(),
+        )
+    }
+
+    /// Cancels an order placed with the same values.
+    pub fn cancel_order(&self, symbol: &str, order: &StockOrderObject) -> Result<(), JsValue> {
+        self.cancelOrder(
+            symbol,
+            order.shares,
+            order.price,
+            order.order_type.as_str(),
+            order.position.as_str(),
+        )
+    }
+
+    /// All the open orders, by stock symbol.
+    pub fn get_orders(&self) -> Result<StockOrder, JsValue> {
+        Ok(serde_wasm_bindgen::from_value(self.getOrders()?.into())?)
+    }
+}
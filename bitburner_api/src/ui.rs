@@ -0,0 +1,192 @@
+//! Typed access to the user interface at `ns.ui()`: the theme, the styles
+//! and the version of the game.
+//!
+//! The bindings are generated from the definitions of one version of the
+//! game, [`DEFINITIONS_VERSION`]. Scripts can stop early when the game runs
+//! another one, rather than fail on a function that changed:
+//!
+//! ```
+//! use bitburner_api::ui;
+//!
+//! assert!(ui::version_matches(ui::DEFINITIONS_VERSION));
+//! assert!(ui::version_matches(&format!("{}.9", ui::DEFINITIONS_VERSION)));
+//! assert!(!ui::version_matches("1.7.0"));
+//! ```
+//!
+//! In the game, a dashboard checks the version, clears the terminal and
+//! follows the player's theme:
+//!
+//! ```ignore
+//! let ui = ns.ui();
+//! ui.check_version()?;
+//! ui.clearTerminal()?;
+//! let theme = ui.get_theme()?;
+//! ns.tprint(&[format!("money is shown in {}", theme.money).into()])?;
+//! ```
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::{JsCast, JsValue};
+
+use crate::{bindings, to_js_object, UserInterface};
+
+/// Major and minor version of the game `bitburner.d.ts` was taken from.
+pub const DEFINITIONS_VERSION: &str = "2.5";
+
+/// Whether a game version, like `2.5.2`, has the same major and minor
+/// version as the definitions. Patch versions keep the API.
+pub fn version_matches(version: &str) -> bool {
+    let major_minor = |version: &str| -> Vec<String> {
+        version
+            .trim_start_matches('v')
+            .split('.')
+            .take(2)
+            .map(str::to_owned)
+            .collect()
+    };
+    major_minor(version) == major_minor(DEFINITIONS_VERSION)
+}
+
+/// Colors of the interface as CSS colors, mirrors the `UserInterfaceTheme`
+/// interface of the definitions.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UserInterfaceTheme {
+    pub primarylight: String,
+    pub primary: String,
+    pub primarydark: String,
+    pub successlight: String,
+    pub success: String,
+    pub successdark: String,
+    pub errorlight: String,
+    pub error: String,
+    pub errordark: String,
+    pub secondarylight: String,
+    pub secondary: String,
+    pub secondarydark: String,
+    pub warninglight: String,
+    pub warning: String,
+    pub warningdark: String,
+    pub infolight: String,
+    pub info: String,
+    pub infodark: String,
+    pub welllight: String,
+    pub well: String,
+    pub white: String,
+    pub black: String,
+    pub hp: String,
+    pub money: String,
+    pub hack: String,
+    pub combat: String,
+    pub cha: String,
+    pub int: String,
+    pub rep: String,
+    pub disabled: String,
+    pub backgroundprimary: String,
+    pub backgroundsecondary: String,
+    pub button: String,
+    /// Colors the definitions do not list, kept so that setting a theme read
+    /// from the game does not drop them
+    #[serde(flatten)]
+    pub other: BTreeMap<String, String>,
+}
+
+/// Text styles of the interface, mirrors the `IStyleSettings` interface of
+/// the definitions.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IStyleSettings {
+    pub font_family: String,
+    pub line_height: f64,
+}
+
+/// The running game, mirrors the `GameInfo` interface of the definitions.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GameInfo {
+    pub version: String,
+    pub commit: String,
+    pub platform: String,
+}
+
+impl TryFrom<bindings::UserInterfaceTheme> for UserInterfaceTheme {
+    type Error = JsValue;
+
+    fn try_from(theme: bindings::UserInterfaceTheme) -> Result<Self, Self::Error> {
+        Ok(serde_wasm_bindgen::from_value(theme.into())?)
+    }
+}
+
+impl TryFrom<&UserInterfaceTheme> for bindings::UserInterfaceTheme {
+    type Error = JsValue;
+
+    fn try_from(theme: &UserInterfaceTheme) -> Result<Self, Self::Error> {
+        Ok(to_js_object(theme)?.unchecked_into())
+    }
+}
+
+impl TryFrom<bindings::IStyleSettings> for IStyleSettings {
+    type Error = JsValue;
+
+    fn try_from(styles: bindings::IStyleSettings) -> Result<Self, Self::Error> {
+        Ok(serde_wasm_bindgen::from_value(styles.into())?)
+    }
+}
+
+impl TryFrom<&IStyleSettings> for bindings::IStyleSettings {
+    type Error = JsValue;
+
+    fn try_from(styles: &IStyleSettings) -> Result<Self, Self::Error> {
+        Ok(to_js_object(styles)?.unchecked_into())
+    }
+}
+
+impl TryFrom<bindings::GameInfo> for GameInfo {
+    type Error = JsValue;
+
+    fn try_from(info: bindings::GameInfo) -> Result<Self, Self::Error> {
+        Ok(serde_wasm_bindgen::from_value(info.into())?)
+    }
+}
+
+impl UserInterface {
+    /// Width and height of the game window in pixels.
+    pub fn window_size(&self) -> Result<(u32, u32), JsValue> {
+        match self.windowSize()?[..] {
+            [width, height] => Ok((width as u32, height as u32)),
+            _ => Err(JsValue::from_str("windowSize did not return 2 values")),
+        }
+    }
+
+    pub fn get_theme(&self) -> Result<UserInterfaceTheme, JsValue> {
+        self.getTheme()?.try_into()
+    }
+
+    pub fn set_theme(&self, theme: &UserInterfaceTheme) -> Result<(), JsValue> {
+        self.setTheme(&theme.try_into()?)
+    }
+
+    pub fn get_styles(&self) -> Result<IStyleSettings, JsValue> {
+        self.getStyles()?.try_into()
+    }
+
+    pub fn set_styles(&self, styles: &IStyleSettings) -> Result<(), JsValue> {
+        self.setStyles(&styles.try_into()?)
+    }
+
+    pub fn get_game_info(&self) -> Result<GameInfo, JsValue> {
+        self.getGameInfo()?.try_into()
+    }
+
+    /// Fails unless the game runs the version the bindings were generated
+    /// for, see [`version_matches`].
+    pub fn check_version(&self) -> Result<GameInfo, JsValue> {
+        let info = self.get_game_info()?;
+        if !version_matches(&info.version) {
+            return Err(JsValue::from_str(&format!(
+                "the game runs version {} but the bindings are for {DEFINITIONS_VERSION}",
+                info.version
+            )));
+        }
+        Ok(info)
+    }
+}
@@ -0,0 +1,216 @@
+use bitburner_api::{script_args::ArgsError, Args, ScriptArgs};
+
+/// Weakens a server until it reaches its minimum security
+#[derive(Debug, PartialEq, ScriptArgs)]
+struct Weaken {
+    /// Server to weaken
+    #[arg(positional)]
+    target: String,
+    /// Threads to run with
+    #[arg(default = 1)]
+    threads: u8,
+    /// Money to keep, as a share of the maximum
+    #[arg(default = 0.5)]
+    keep: f64,
+    /// Keep going forever
+    #[arg(long = "loop")]
+    repeat: bool,
+    /// Print every step
+    #[arg(long = "v")]
+    verbose: bool,
+    tag: Option<String>,
+    exclude: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, ScriptArgs)]
+struct Deploy {
+    script: String,
+    #[arg(positional)]
+    hosts: Vec<String>,
+}
+
+fn args(args: &[&str]) -> Vec<Args> {
+    args.iter()
+        .map(|arg| Args::String(arg.to_string()))
+        .collect()
+}
+
+#[test]
+fn defaults() {
+    let weaken = Weaken::try_parse_from(args(&["n00dles"])).unwrap();
+    assert_eq!(
+        weaken,
+        Weaken {
+            target: "n00dles".to_owned(),
+            threads: 1,
+            keep: 0.5,
+            repeat: false,
+            verbose: false,
+            tag: None,
+            exclude: vec![],
+        }
+    );
+}
+
+#[test]
+fn inline_and_separate_values() {
+    let weaken = Weaken::try_parse_from(args(&[
+        "--threads=4",
+        "n00dles",
+        "--keep",
+        "0.25",
+        "--tag=a=b",
+        "--loop",
+    ]))
+    .unwrap();
+    assert_eq!(weaken.target, "n00dles");
+    assert_eq!(weaken.threads, 4);
+    assert_eq!(weaken.keep, 0.25);
+    assert_eq!(weaken.tag.as_deref(), Some("a=b"));
+    assert!(weaken.repeat);
+}
+
+#[test]
+fn values_from_the_game_keep_their_types() {
+    let weaken = Weaken::try_parse_from(vec![
+        Args::String("n00dles".to_owned()),
+        Args::String("--threads".to_owned()),
+        Args::F64(3.0),
+    ])
+    .unwrap();
+    assert_eq!(weaken.threads, 3);
+}
+
+#[test]
+fn repeated_flags_and_last_value() {
+    let weaken = Weaken::try_parse_from(args(&[
+        "n00dles",
+        "--exclude",
+        "home",
+        "--exclude=darkweb",
+        "--threads=2",
+        "--threads=5",
+    ]))
+    .unwrap();
+    assert_eq!(weaken.exclude, ["home", "darkweb"]);
+    assert_eq!(weaken.threads, 5);
+}
+
+#[test]
+fn terminator_ends_the_flags() {
+    let deploy =
+        Deploy::try_parse_from(args(&["--script", "hack.js", "--", "--weird", "-x"])).unwrap();
+    assert_eq!(deploy.script, "hack.js");
+    assert_eq!(deploy.hosts, ["--weird", "-x"]);
+
+    let weaken = Weaken::try_parse_from(args(&["--", "--loop"])).unwrap();
+    assert_eq!(weaken.target, "--loop");
+    assert!(!weaken.repeat);
+}
+
+#[test]
+fn short_flags() {
+    let weaken = Weaken::try_parse_from(args(&["-v", "n00dles"])).unwrap();
+    assert!(weaken.verbose);
+
+    // only single letters make short flags, negative numbers stay values
+    let err = Weaken::try_parse_from(args(&["n00dles", "-vv"])).unwrap_err();
+    assert_eq!(err, ArgsError::UnexpectedArgument("-vv".to_owned()));
+    let weaken = Weaken::try_parse_from(args(&["n00dles", "--keep", "-1"])).unwrap();
+    assert_eq!(weaken.keep, -1.0);
+}
+
+#[test]
+fn help() {
+    let err = Weaken::try_parse_from(args(&["n00dles", "--help"])).unwrap_err();
+    assert_eq!(err, ArgsError::Help);
+
+    let usage = Weaken::spec().usage("weaken.js");
+    assert!(usage.starts_with(
+        "Weakens a server until it reaches its minimum security\n\n\
+         Usage: run weaken.js [OPTIONS] <TARGET>\n\n\
+         Arguments:\n  <TARGET>  Server to weaken\n\n\
+         Options:\n"
+    ));
+    assert!(usage.contains("\n  --threads <NUMBER>  Threads to run with [default: 1]\n"));
+    assert!(usage.contains("\n  -v                  Print every step\n"));
+    assert!(usage.ends_with("\n  --help              Print help"));
+}
+
+#[test]
+fn missing_arguments() {
+    let err = Weaken::try_parse_from(args(&[])).unwrap_err();
+    assert_eq!(err, ArgsError::MissingArgument("target".to_owned()));
+    assert_eq!(
+        err.to_string(),
+        "the required argument '<TARGET>' was not provided"
+    );
+
+    let err = Deploy::try_parse_from(args(&["home"])).unwrap_err();
+    assert_eq!(err, ArgsError::MissingFlag("script".to_owned()));
+    assert_eq!(
+        err.to_string(),
+        "the required argument '--script' was not provided"
+    );
+
+    let err = Weaken::try_parse_from(args(&["n00dles", "--threads"])).unwrap_err();
+    assert_eq!(err, ArgsError::MissingValue("threads".to_owned()));
+}
+
+#[test]
+fn unknown_and_unexpected_arguments() {
+    let err = Weaken::try_parse_from(args(&["n00dles", "--force"])).unwrap_err();
+    assert_eq!(err, ArgsError::UnknownFlag("force".to_owned()));
+    assert_eq!(err.to_string(), "unexpected argument '--force' found");
+
+    let err = Weaken::try_parse_from(args(&["n00dles", "joesguns"])).unwrap_err();
+    assert_eq!(err, ArgsError::UnexpectedArgument("joesguns".to_owned()));
+}
+
+#[test]
+fn invalid_values() {
+    let err = Weaken::try_parse_from(args(&["n00dles", "--threads=many"])).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid value 'many' for 'threads': expected an integer"
+    );
+
+    let err = Weaken::try_parse_from(args(&["n00dles", "--threads=1.5"])).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid value '1.5' for 'threads': expected an integer"
+    );
+
+    let err = Weaken::try_parse_from(args(&["n00dles", "--keep=all"])).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid value 'all' for 'keep': expected a number"
+    );
+}
+
+#[test]
+fn integers_out_of_range() {
+    let err = Weaken::try_parse_from(args(&["n00dles", "--threads=256"])).unwrap_err();
+    assert_eq!(
+        err,
+        ArgsError::OutOfRange {
+            name: "threads".to_owned(),
+            value: "256".to_owned(),
+            min: "0".to_owned(),
+            max: "255".to_owned(),
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        "invalid value '256' for 'threads': 256 is not in 0..=255"
+    );
+
+    let err = Weaken::try_parse_from(args(&["n00dles", "--threads", "-1"])).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid value '-1' for 'threads': -1 is not in 0..=255"
+    );
+
+    let weaken = Weaken::try_parse_from(args(&["n00dles", "--threads=255"])).unwrap();
+    assert_eq!(weaken.threads, 255);
+}
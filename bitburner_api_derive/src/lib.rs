@@ -0,0 +1,369 @@
+//! Derive macros for the `bitburner_api` crate, use them through its
+//! re-exports.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    parse_macro_input, Attribute, Data, DeriveInput, Expr, Fields, GenericArgument, Ident, LitStr,
+    PathArguments, Type,
+};
+
+/// Implements `bitburner_api::ScriptArgs` for a struct with named fields,
+/// see the trait for the accepted attributes.
+#[proc_macro_derive(ScriptArgs, attributes(arg, script))]
+pub fn derive_script_args(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_script_args(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// How many values a field takes.
+enum Shape {
+    Single,
+    Option,
+    Vec,
+}
+
+enum Kind {
+    Bool,
+    Number,
+    String,
+}
+
+struct Field {
+    ident: Ident,
+    help: String,
+    shape: Shape,
+    kind: Kind,
+    /// Type of a single value, without the `Option` or `Vec`
+    value_ty: Type,
+    positional: bool,
+    long: String,
+    default: Option<Expr>,
+    complete: Option<Ident>,
+}
+
+fn expand_script_args(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "ScriptArgs can only be derived for structs",
+        ));
+    };
+    let Fields::Named(named) = &data.fields else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "ScriptArgs needs a struct with named fields",
+        ));
+    };
+
+    let mut autocomplete = false;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("script"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("autocomplete") {
+                autocomplete = true;
+                Ok(())
+            } else {
+                Err(meta.error("expected `autocomplete`"))
+            }
+        })?;
+    }
+
+    let fields = named
+        .named
+        .iter()
+        .map(parse_field)
+        .collect::<syn::Result<Vec<_>>>()?;
+    check_positionals(&fields)?;
+
+    let about = doc_string(&input.attrs);
+    let flag_specs = fields
+        .iter()
+        .filter(|field| !field.positional)
+        .map(flag_spec);
+    let positional_specs = fields
+        .iter()
+        .filter(|field| field.positional)
+        .map(positional_spec);
+    let takes = fields.iter().map(take_field);
+    let idents = fields.iter().map(|field| &field.ident);
+
+    let args = quote!(::bitburner_api::script_args);
+    let autocomplete = autocomplete.then(|| {
+        quote! {
+            #[::bitburner_api::wasm_bindgen::prelude::wasm_bindgen(
+                wasm_bindgen = ::bitburner_api::wasm_bindgen
+            )]
+            pub fn autocomplete_rs(data: &::bitburner_api::AutocompleteData) -> Vec<String> {
+                <#name as ::bitburner_api::ScriptArgs>::autocomplete(data)
+            }
+        }
+    });
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::bitburner_api::ScriptArgs for #name #ty_generics #where_clause {
+            fn spec() -> #args::ArgsSpec {
+                #args::ArgsSpec {
+                    about: #about,
+                    flags: vec![#(#flag_specs),*],
+                    positionals: vec![#(#positional_specs),*],
+                }
+            }
+
+            fn from_parsed(
+                mut parsed: #args::ParsedArgs,
+            ) -> Result<Self, #args::ArgsError> {
+                #(#takes)*
+                parsed.finish()?;
+                Ok(Self { #(#idents),* })
+            }
+        }
+
+        #autocomplete
+    })
+}
+
+fn parse_field(field: &syn::Field) -> syn::Result<Field> {
+    let ident = field.ident.clone().expect("named fields have names");
+    let (shape, value_ty) = match container(&field.ty, "Option") {
+        Some(inner) => (Shape::Option, inner.clone()),
+        None => match container(&field.ty, "Vec") {
+            Some(inner) => (Shape::Vec, inner.clone()),
+            None => (Shape::Single, field.ty.clone()),
+        },
+    };
+    let kind = match &value_ty {
+        Type::Path(path) if path.path.is_ident("bool") => Kind::Bool,
+        Type::Path(path) if NUMBERS.iter().any(|number| path.path.is_ident(number)) => Kind::Number,
+        _ => Kind::String,
+    };
+
+    let mut parsed = Field {
+        long: ident.to_string().trim_start_matches("r#").replace('_', "-"),
+        ident,
+        help: doc_string(&field.attrs),
+        shape,
+        kind,
+        value_ty,
+        positional: false,
+        default: None,
+        complete: None,
+    };
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("arg"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("positional") {
+                parsed.positional = true;
+            } else if meta.path.is_ident("long") {
+                parsed.long = meta.value()?.parse::<LitStr>()?.value();
+            } else if meta.path.is_ident("default") {
+                parsed.default = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("complete") {
+                let source = meta.value()?.parse::<LitStr>()?;
+                let variant = match source.value().as_str() {
+                    "servers" => "Servers",
+                    "scripts" => "Scripts",
+                    "txts" => "Txts",
+                    _ => {
+                        return Err(syn::Error::new_spanned(
+                            source,
+                            "expected \"servers\", \"scripts\" or \"txts\"",
+                        ))
+                    }
+                };
+                parsed.complete = Some(Ident::new(variant, source.span()));
+            } else {
+                return Err(meta.error("expected `positional`, `long`, `default` or `complete`"));
+            }
+            Ok(())
+        })?;
+    }
+
+    if parsed.default.is_some() && !matches!(parsed.shape, Shape::Single) {
+        return Err(syn::Error::new_spanned(
+            &field.ty,
+            "only fields that are not `Option` or `Vec` can have a default",
+        ));
+    }
+    if parsed.complete.is_some() && !parsed.positional {
+        return Err(syn::Error::new_spanned(
+            &field.ty,
+            "`complete` only applies to positional arguments",
+        ));
+    }
+    Ok(parsed)
+}
+
+const NUMBERS: [&str; 12] = [
+    "f32", "f64", "i8", "i16", "i32", "i64", "isize", "u8", "u16", "u32", "u64", "usize",
+];
+
+/// Returns `T` out of `Container<T>`.
+fn container<'a>(ty: &'a Type, container: &str) -> Option<&'a Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != container {
+        return None;
+    }
+    let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+        return None;
+    };
+    match arguments.args.first()? {
+        GenericArgument::Type(inner) => Some(inner),
+        _ => None,
+    }
+}
+
+/// Positional arguments are taken in order, so nothing can follow a `Vec`
+/// and required ones cannot follow optional ones.
+fn check_positionals(fields: &[Field]) -> syn::Result<()> {
+    let mut optional_seen = false;
+    let mut positionals = fields.iter().filter(|field| field.positional).peekable();
+    while let Some(field) = positionals.next() {
+        if matches!(field.shape, Shape::Vec) && positionals.peek().is_some() {
+            return Err(syn::Error::new_spanned(
+                &field.ident,
+                "a `Vec` positional argument must be the last one",
+            ));
+        }
+        let required = matches!(field.shape, Shape::Single) && field.default.is_none();
+        if required && optional_seen {
+            return Err(syn::Error::new_spanned(
+                &field.ident,
+                "a required positional argument cannot follow an optional one",
+            ));
+        }
+        optional_seen |= !required;
+    }
+    Ok(())
+}
+
+fn doc_string(attrs: &[Attribute]) -> String {
+    let lines: Vec<String> = attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| match &attr.meta.require_name_value().ok()?.value {
+            Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(doc),
+                ..
+            }) => Some(doc.value().trim().to_owned()),
+            _ => None,
+        })
+        .collect();
+    lines.join(" ").trim().to_owned()
+}
+
+fn default_string(field: &Field) -> TokenStream2 {
+    match &field.default {
+        Some(default) => {
+            let shown = quote!(#default).to_string();
+            let shown = shown.trim_matches('"');
+            quote!(Some(#shown))
+        }
+        None => quote!(None),
+    }
+}
+
+fn flag_spec(field: &Field) -> TokenStream2 {
+    let args = quote!(::bitburner_api::script_args);
+    let name = &field.long;
+    let help = &field.help;
+    let kind = match (&field.shape, &field.kind) {
+        (Shape::Vec, _) => quote!(List),
+        (_, Kind::Bool) => quote!(Bool),
+        (_, Kind::Number) => quote!(Number),
+        (_, Kind::String) => quote!(String),
+    };
+    let required = matches!(field.shape, Shape::Single)
+        && !matches!(field.kind, Kind::Bool)
+        && field.default.is_none();
+    let default = default_string(field);
+    quote! {
+        #args::FlagSpec {
+            name: #name,
+            kind: #args::FlagKind::#kind,
+            help: #help,
+            required: #required,
+            default: #default,
+        }
+    }
+}
+
+fn positional_spec(field: &Field) -> TokenStream2 {
+    let args = quote!(::bitburner_api::script_args);
+    let name = field.ident.to_string().trim_start_matches("r#").to_owned();
+    let help = &field.help;
+    let required = matches!(field.shape, Shape::Single) && field.default.is_none();
+    let rest = matches!(field.shape, Shape::Vec);
+    let default = default_string(field);
+    let complete = match &field.complete {
+        Some(variant) => quote!(Some(#args::Completion::#variant)),
+        None => quote!(None),
+    };
+    quote! {
+        #args::PositionalSpec {
+            name: #name,
+            help: #help,
+            required: #required,
+            rest: #rest,
+            default: #default,
+            complete: #complete,
+        }
+    }
+}
+
+/// Statement binding the field to its value taken out of `parsed`.
+fn take_field(field: &Field) -> TokenStream2 {
+    let ident = &field.ident;
+    let ty = &field.value_ty;
+    let name = if field.positional {
+        field.ident.to_string().trim_start_matches("r#").to_owned()
+    } else {
+        field.long.clone()
+    };
+    let (single, required, list) = if field.positional {
+        (
+            quote!(positional),
+            quote!(required_positional),
+            quote!(rest),
+        )
+    } else {
+        (quote!(flag), quote!(required_flag), quote!(flag_list))
+    };
+
+    let value = match (&field.shape, &field.default, &field.kind) {
+        (Shape::Option, _, _) => quote!(parsed.#single::<#ty>(#name)?),
+        (Shape::Vec, _, _) => quote!(parsed.#list::<#ty>(#name)?),
+        // integer literals would not convert into floats otherwise
+        (Shape::Single, Some(default), Kind::Number) => quote! {
+            match parsed.#single::<#ty>(#name)? {
+                Some(value) => value,
+                None => (#default) as #ty,
+            }
+        },
+        (Shape::Single, Some(default), _) => quote! {
+            match parsed.#single::<#ty>(#name)? {
+                Some(value) => value,
+                None => ::core::convert::Into::into(#default),
+            }
+        },
+        (Shape::Single, None, Kind::Bool) if !field.positional => {
+            quote!(parsed.#single::<#ty>(#name)?.unwrap_or(false))
+        }
+        (Shape::Single, None, _) => quote!(parsed.#required::<#ty>(#name)?),
+    };
+    quote!(let #ident = #value;)
+}
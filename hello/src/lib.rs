@@ -1,12 +1,13 @@
 use bitburner_api::parse_args;
 use bitburner_api::wasm_bindgen;
 use bitburner_api::Args;
+use bitburner_api::JsValue;
 
 #[wasm_bindgen]
-pub fn main_rs(ns: &bitburner_api::NS) {
+pub fn main_rs(ns: &bitburner_api::NS) -> Result<(), JsValue> {
     let args: Vec<Args> = parse_args(ns.args()).unwrap();
 
     let mut buffer = String::new();
     buffer += &format!("Hello, world! I said {:?}", args);
-    ns.tprint(&buffer);
+    ns.tprint(&[buffer.into()])
 }
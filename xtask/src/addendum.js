@@ -14,5 +14,15 @@ async function __wbg_init() {
 
 export async function main(ns) {
     await __wbg_init();
-    await main_rs(ns);
+    __bitburner_api_start(ns);
+    try {
+        await main_rs(ns);
+    } catch (error) {
+        // panics are already reported by the panic hook, and the trapped
+        // instance should not be called into again
+        if (!(error instanceof WebAssembly.RuntimeError)) {
+            __bitburner_api_report_error(ns, error);
+        }
+        throw error;
+    }
 }
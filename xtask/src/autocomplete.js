@@ -0,0 +1,8 @@
+
+// The game calls autocomplete synchronously, so the module only finishes
+// loading once the WASM is ready.
+await __wbg_init();
+
+export function autocomplete(data, args) {
+    return autocomplete_rs(data, args);
+}
@@ -21,7 +21,7 @@ fn wasm_to_js(wasm_path: &Path, output_path: &Path, debug: bool) {
         if debug { "with" } else { "without" }
     );
     let crate_name = wasm_path.file_stem().unwrap().to_str().unwrap().to_owned();
-    let wasm_b64 = encode_wasm_js_decl(&*wasm_path, &*output_path, &*crate_name, debug);
+    let wasm_b64 = encode_wasm_js_decl(wasm_path, output_path, &crate_name, debug);
     join_with_binder(wasm_b64, output_path, &crate_name);
 }
 
@@ -49,7 +49,7 @@ fn encode_wasm_js_decl(
         fn finish(mut self) -> String {
             self.contents.push(b'\"');
             self.contents.push(b';');
-            return String::from_utf8(self.contents).unwrap();
+            String::from_utf8(self.contents).unwrap()
         }
     }
 
@@ -92,9 +92,9 @@ fn encode_wasm_js_decl(
         .arg("--target")
         .arg("web")
         .arg("--no-typescript")
-        .arg(&wasm_path)
+        .arg(wasm_path)
         .arg("--out-dir")
-        .arg(&wasm_output);
+        .arg(wasm_output);
 
     if debug {
         command.arg("--debug");
@@ -106,7 +106,7 @@ fn encode_wasm_js_decl(
     let wasm_file = OpenOptions::new()
         .read(true)
         .open(wasm_output.join(format!("{}_bg.wasm", crate_name)))
-        .map(|file| BufReader::new(file))
+        .map(BufReader::new)
         .expect("Cannot read the wasm file.");
 
     // encode
@@ -134,15 +134,16 @@ fn join_with_binder(mut js_str: String, wasm_output: &Path, crate_name: &str) {
     let mut reader = BufReader::new(&js_file);
 
     let mut buffer = String::new();
+    let mut has_autocomplete = false;
     js_str += "\n";
     loop {
         buffer.clear();
-        match reader
+        if reader
             .read_line(&mut buffer)
             .expect("Cannot read the js file.")
+            == 0
         {
-            0 => break,
-            _ => {}
+            break;
         }
 
         // stop reading from here. we'll have our own initializer.
@@ -150,10 +151,14 @@ fn join_with_binder(mut js_str: String, wasm_output: &Path, crate_name: &str) {
             break;
         }
 
+        has_autocomplete |= buffer.contains("export function autocomplete_rs(");
         js_str += &buffer;
     }
 
     js_str += include_str!("./addendum.js");
+    if has_autocomplete {
+        js_str += include_str!("./autocomplete.js");
+    }
     js_file
         .rewind()
         .expect("Failed to rewind to start of js file");
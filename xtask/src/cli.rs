@@ -1,4 +1,4 @@
-use std::path::PathBuf;
+use std::{fmt, path::PathBuf};
 
 use clap::{Parser, Subcommand, ValueEnum};
 
@@ -35,6 +35,17 @@ pub enum Commands {
         /// Output file for the definitions
         output: PathBuf,
     },
+
+    /// Generate Rust bindings for the bitburner_api package from TypeScript definitions
+    GenBindings {
+        #[arg(short, long, default_value_os_t = crate::definitions_path())]
+        /// TypeScript definition file to read
+        input: PathBuf,
+
+        #[arg(short, long, default_value_os_t = crate::bindings_path())]
+        /// Output file for the generated Rust module
+        output: PathBuf,
+    },
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
@@ -54,11 +65,11 @@ impl Profile {
     }
 }
 
-impl ToString for Profile {
-    fn to_string(&self) -> String {
+impl fmt::Display for Profile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Profile::Release => "release".to_owned(),
-            Profile::Dev => "dev".to_owned(),
+            Profile::Release => f.write_str("release"),
+            Profile::Dev => f.write_str("dev"),
         }
     }
 }
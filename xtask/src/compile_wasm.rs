@@ -3,7 +3,7 @@ use std::process::{Command, ExitStatus};
 use crate::Profile;
 
 pub fn compile_wasm_packages(profile: Profile) -> ExitStatus {
-    let ignored_packages = vec!["bitoxide", "xtask", "bitburner_api"];
+    let ignored_packages = vec!["bitoxide", "xtask", "bitburner_api", "bitburner_api_derive"];
 
     let mut command = Command::new("cargo");
     command
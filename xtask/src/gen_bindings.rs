@@ -0,0 +1,32 @@
+use std::{fs, path::Path, process::Command, process::ExitCode};
+
+mod emit;
+use emit::Emitter;
+
+mod typescript;
+
+pub fn generate_bindings(definitions: &Path, output: &Path) -> ExitCode {
+    log::info!("Reading definitions from {definitions:?}");
+    let source = fs::read_to_string(definitions).expect("Failed to read the definition file");
+    let items = match typescript::parse(&source) {
+        Ok(items) => items,
+        Err(err) => {
+            log::error!("Failed to parse {definitions:?}: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let bindings = Emitter::new(&items).emit();
+    fs::write(output, bindings).expect("Failed to write the bindings");
+    let formatted = Command::new("rustfmt")
+        .arg("--edition")
+        .arg("2021")
+        .arg(output)
+        .status()
+        .is_ok_and(|status| status.success());
+    if !formatted {
+        log::warn!("Could not run rustfmt on {output:?}, leaving it unformatted");
+    }
+    log::info!("Bindings written to {output:?}");
+    ExitCode::SUCCESS
+}
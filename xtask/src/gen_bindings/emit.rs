@@ -0,0 +1,629 @@
+//! Turns parsed definitions into a module of `#[wasm_bindgen]` extern types.
+//!
+//! Every interface becomes an opaque imported type with getters and setters
+//! for its properties and methods for its function members. All methods are
+//! imported with `catch` since any Netscript call may throw, and methods
+//! returning a `Promise` are imported as `async fn`s. Enums become
+//! wasm-bindgen string enums.
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Write as _,
+};
+
+use super::typescript::{Alias, Enum, Interface, Item, Member, Method, Param, Property, TsType};
+
+const HEADER: &str = "\
+// This file is generated by `cargo xtask gen-bindings` from `bitburner.d.ts`.
+// Do not edit it by hand, change the definitions or the generator instead.
+#![allow(non_snake_case, clippy::too_many_arguments)]
+
+use wasm_bindgen::prelude::*;
+";
+
+#[derive(Debug, Clone, PartialEq)]
+enum RustType {
+    Unit,
+    Bool,
+    Number,
+    Str,
+    Interface(String),
+    Enum(String),
+    JsValue,
+    Array(Box<RustType>),
+    Option(Box<RustType>),
+    Function,
+    Promise(Box<RustType>),
+    Map,
+    Date,
+}
+
+impl RustType {
+    fn param(&self) -> String {
+        match self {
+            RustType::Unit | RustType::JsValue => "&JsValue".to_owned(),
+            RustType::Bool => "bool".to_owned(),
+            RustType::Number => "f64".to_owned(),
+            RustType::Str => "&str".to_owned(),
+            RustType::Interface(name) => format!("&{name}"),
+            RustType::Enum(name) => name.clone(),
+            RustType::Array(inner) => match inner.as_ref() {
+                RustType::Number => "&[f64]".to_owned(),
+                // wasm-bindgen cannot pass vectors of string enums
+                RustType::Str | RustType::Enum(_) => "Vec<String>".to_owned(),
+                RustType::Interface(name) => format!("Vec<{name}>"),
+                _ => "Vec<JsValue>".to_owned(),
+            },
+            RustType::Option(inner) => format!("Option<{}>", inner.param()),
+            RustType::Function => "&js_sys::Function".to_owned(),
+            RustType::Promise(_) => "&js_sys::Promise".to_owned(),
+            RustType::Map => "&js_sys::Map".to_owned(),
+            RustType::Date => "&js_sys::Date".to_owned(),
+        }
+    }
+
+    fn ret(&self) -> String {
+        match self {
+            RustType::Unit => "()".to_owned(),
+            RustType::JsValue => "JsValue".to_owned(),
+            RustType::Bool => "bool".to_owned(),
+            RustType::Number => "f64".to_owned(),
+            RustType::Str => "String".to_owned(),
+            RustType::Interface(name) | RustType::Enum(name) => name.clone(),
+            RustType::Array(inner) => match inner.as_ref() {
+                RustType::Number => "Vec<f64>".to_owned(),
+                RustType::Str | RustType::Enum(_) => "Vec<String>".to_owned(),
+                RustType::Interface(name) => format!("Vec<{name}>"),
+                _ => "Vec<JsValue>".to_owned(),
+            },
+            RustType::Option(inner) => match inner.as_ref() {
+                RustType::Bool
+                | RustType::Number
+                | RustType::Str
+                | RustType::Interface(_)
+                | RustType::Enum(_) => format!("Option<{}>", inner.ret()),
+                _ => "JsValue".to_owned(),
+            },
+            RustType::Function => "js_sys::Function".to_owned(),
+            RustType::Promise(_) => "js_sys::Promise".to_owned(),
+            RustType::Map => "js_sys::Map".to_owned(),
+            RustType::Date => "js_sys::Date".to_owned(),
+        }
+    }
+
+    fn optional(self) -> RustType {
+        match self {
+            RustType::Option(_) => self,
+            ty => RustType::Option(Box::new(ty)),
+        }
+    }
+}
+
+pub struct Emitter<'a> {
+    interfaces: HashMap<&'a str, &'a Interface>,
+    aliases: HashMap<&'a str, &'a Alias>,
+    enums: HashMap<&'a str, &'a Enum>,
+    items: &'a [Item],
+}
+
+impl<'a> Emitter<'a> {
+    pub fn new(items: &'a [Item]) -> Emitter<'a> {
+        let mut interfaces = HashMap::new();
+        let mut aliases = HashMap::new();
+        let mut enums = HashMap::new();
+        for item in items {
+            match item {
+                Item::Interface(interface) => {
+                    interfaces.insert(interface.name.as_str(), interface);
+                }
+                Item::Alias(alias) => {
+                    aliases.insert(alias.name.as_str(), alias);
+                }
+                Item::Enum(enumeration) => {
+                    enums.insert(enumeration.name.as_str(), enumeration);
+                }
+            }
+        }
+        Emitter {
+            interfaces,
+            aliases,
+            enums,
+            items,
+        }
+    }
+
+    pub fn emit(&self) -> String {
+        let mut out = HEADER.to_owned();
+        for item in self.items {
+            match item {
+                Item::Interface(interface) => self.emit_interface(&mut out, interface),
+                Item::Enum(enumeration) => emit_enum(&mut out, enumeration),
+                Item::Alias(_) => {}
+            }
+        }
+        out
+    }
+
+    fn emit_interface(&self, out: &mut String, interface: &Interface) {
+        let name = &interface.name;
+        out.push_str("\n#[wasm_bindgen]\nextern \"C\" {\n");
+        write_doc(out, interface.doc.as_deref());
+        let extends: Vec<String> = interface
+            .extends
+            .iter()
+            .filter(|parent| self.interfaces.contains_key(parent.as_str()))
+            .map(|parent| format!("extends = {parent}"))
+            .collect();
+        if !extends.is_empty() {
+            writeln!(out, "#[wasm_bindgen({})]", extends.join(", ")).unwrap();
+        }
+        writeln!(out, "#[derive(Debug, Clone, PartialEq)]\npub type {name};").unwrap();
+
+        let mut names = HashSet::new();
+        for member in &interface.members {
+            match member {
+                Member::Property(property) => self.emit_property(out, name, property, &mut names),
+                Member::Method(method) => self.emit_method(out, name, method, &mut names),
+            }
+        }
+        out.push_str("}\n");
+    }
+
+    fn emit_property(
+        &self,
+        out: &mut String,
+        this: &str,
+        property: &Property,
+        names: &mut HashSet<String>,
+    ) {
+        let mut ty = self.resolve(&property.ty, &mut vec![]);
+        if property.optional {
+            ty = ty.optional();
+        }
+
+        let getter = unique_name(names, &rust_ident(&property.name));
+        out.push('\n');
+        write_doc(out, property.doc.as_deref());
+        let attribute = if getter == property.name {
+            "getter".to_owned()
+        } else {
+            format!("getter = \"{}\"", property.name)
+        };
+        writeln!(
+            out,
+            "#[wasm_bindgen(method, {attribute})]\npub fn {getter}(this: &{this}) -> {};",
+            ty.ret()
+        )
+        .unwrap();
+
+        if !property.readonly {
+            let inferred = format!("set_{}", property.name);
+            let setter = unique_name(names, &inferred);
+            let attribute = if setter == inferred {
+                "setter".to_owned()
+            } else {
+                format!("setter = \"{}\"", property.name)
+            };
+            writeln!(
+                out,
+                "\n#[wasm_bindgen(method, {attribute})]\npub fn {setter}(this: &{this}, value: {});",
+                ty.param()
+            )
+            .unwrap();
+        }
+    }
+
+    fn emit_method(
+        &self,
+        out: &mut String,
+        this: &str,
+        method: &Method,
+        names: &mut HashSet<String>,
+    ) {
+        let rust_name = unique_name(names, &rust_ident(&method.name));
+        let variadic = method.params.last().is_some_and(|param| param.rest);
+        let mut attributes = vec!["method", "catch"];
+        if variadic {
+            attributes.push("variadic");
+        }
+        let js_name = format!("js_name = \"{}\"", method.name);
+        if rust_name != method.name {
+            attributes.push(&js_name);
+        }
+
+        let mut params = vec![format!("this: &{this}")];
+        params.extend(method.params.iter().map(|param| self.param(param)));
+        // promises are awaited through wasm-bindgen-futures
+        let (asyncness, ret) = match self.resolve(&method.ret, &mut vec![]) {
+            RustType::Promise(inner) => ("async ", *inner),
+            ret => ("", ret),
+        };
+
+        out.push('\n');
+        write_doc(out, method.doc.as_deref());
+        writeln!(
+            out,
+            "#[wasm_bindgen({})]\npub {asyncness}fn {rust_name}({}) -> Result<{}, JsValue>;",
+            attributes.join(", "),
+            params.join(", "),
+            ret.ret(),
+        )
+        .unwrap();
+    }
+
+    fn param(&self, param: &Param) -> String {
+        let name = rust_ident(&param.name);
+        if param.rest {
+            return format!("{name}: &[JsValue]");
+        }
+        let mut ty = self.resolve(&param.ty, &mut vec![]);
+        if param.optional {
+            ty = ty.optional();
+        }
+        format!("{name}: {}", ty.param())
+    }
+
+    /// Maps a TypeScript type onto the closest type wasm-bindgen can pass
+    /// across the boundary, falling back on `JsValue`.
+    fn resolve(&self, ty: &TsType, aliases_seen: &mut Vec<String>) -> RustType {
+        match ty {
+            TsType::Named(name, args) => self.resolve_named(name, args, aliases_seen),
+            TsType::Template(Some(name)) if self.enums.contains_key(name.as_str()) => {
+                RustType::Enum(name.clone())
+            }
+            TsType::StringLiteral(_) | TsType::Template(_) => RustType::Str,
+            TsType::NumberLiteral => RustType::Number,
+            TsType::BooleanLiteral => RustType::Bool,
+            TsType::Array(inner) => RustType::Array(Box::new(self.resolve(inner, aliases_seen))),
+            TsType::Tuple(elements) => {
+                let all_numbers = elements
+                    .iter()
+                    .all(|element| self.resolve(element, aliases_seen) == RustType::Number);
+                if all_numbers {
+                    RustType::Array(Box::new(RustType::Number))
+                } else {
+                    RustType::JsValue
+                }
+            }
+            TsType::Union(variants) => self.resolve_union(variants, aliases_seen),
+            TsType::Function => RustType::Function,
+            TsType::Intersection(_) | TsType::Object | TsType::Query => RustType::JsValue,
+        }
+    }
+
+    fn resolve_named(
+        &self,
+        name: &str,
+        args: &[TsType],
+        aliases_seen: &mut Vec<String>,
+    ) -> RustType {
+        match name {
+            "string" => return RustType::Str,
+            "number" => return RustType::Number,
+            "boolean" => return RustType::Bool,
+            "void" | "undefined" | "null" | "never" => return RustType::Unit,
+            "Promise" => {
+                let inner = args
+                    .first()
+                    .map_or(RustType::Unit, |arg| self.resolve(arg, aliases_seen));
+                return RustType::Promise(Box::new(inner));
+            }
+            "Array" | "ReadonlyArray" => {
+                let inner = args
+                    .first()
+                    .map_or(RustType::JsValue, |arg| self.resolve(arg, aliases_seen));
+                return RustType::Array(Box::new(inner));
+            }
+            "Map" => return RustType::Map,
+            "Date" => return RustType::Date,
+            _ => {}
+        }
+        if self.interfaces.contains_key(name) {
+            return RustType::Interface(name.to_owned());
+        }
+        if self.enums.contains_key(name) {
+            return RustType::Enum(name.to_owned());
+        }
+        match self.aliases.get(name) {
+            Some(alias) if !aliases_seen.iter().any(|seen| seen == name) => {
+                aliases_seen.push(name.to_owned());
+                let resolved = self.resolve(&alias.ty, aliases_seen);
+                aliases_seen.pop();
+                resolved
+            }
+            _ => RustType::JsValue,
+        }
+    }
+
+    fn resolve_union(&self, variants: &[TsType], aliases_seen: &mut Vec<String>) -> RustType {
+        let mut nullable = false;
+        let mut types: Vec<RustType> = vec![];
+        for variant in variants {
+            match self.resolve(variant, aliases_seen) {
+                RustType::Unit => nullable = true,
+                ty if !types.contains(&ty) => types.push(ty),
+                _ => {}
+            }
+        }
+        let ty = match types.len() {
+            0 => return RustType::Unit,
+            1 => types.pop().unwrap(),
+            _ => return RustType::JsValue,
+        };
+        if nullable {
+            ty.optional()
+        } else {
+            ty
+        }
+    }
+}
+
+/// Emits a string enum along with the list of its variants, the
+/// `string_enum!` macro of the crate derives the rest from those.
+fn emit_enum(out: &mut String, enumeration: &Enum) {
+    let name = &enumeration.name;
+    let variants: Vec<(String, &str)> = enumeration
+        .variants
+        .iter()
+        .map(|variant| (variant_ident(&variant.name), variant.value.as_str()))
+        .collect();
+
+    out.push('\n');
+    write_doc(out, enumeration.doc.as_deref());
+    writeln!(
+        out,
+        "#[wasm_bindgen]\n#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]\npub enum {name} {{"
+    )
+    .unwrap();
+    for (ident, value) in &variants {
+        writeln!(out, "{ident} = {value:?},").unwrap();
+    }
+    writeln!(out, "}}\n\nimpl {name} {{").unwrap();
+    writeln!(out, "/// All the variants in declaration order").unwrap();
+    writeln!(out, "pub const ALL: [{name}; {}] = [", variants.len()).unwrap();
+    for (ident, _) in &variants {
+        writeln!(out, "{name}::{ident},").unwrap();
+    }
+    writeln!(out, "];\n").unwrap();
+    writeln!(out, "/// The value of this variant in JavaScript").unwrap();
+    writeln!(
+        out,
+        "pub fn as_str(&self) -> &'static str {{\nself.to_str()\n}}"
+    )
+    .unwrap();
+    writeln!(out, "}}\n\nstring_enum!({name});").unwrap();
+}
+
+/// Turns a TypeScript enum member name into an UpperCamelCase variant name.
+fn variant_ident(name: &str) -> String {
+    let is_upper = name.chars().all(|c| c.is_uppercase() || !c.is_alphabetic());
+    let mut chars = name.chars();
+    let first = chars.next().map(|c| c.to_ascii_uppercase());
+    let rest: String = if is_upper {
+        chars.as_str().to_lowercase()
+    } else {
+        chars.collect()
+    };
+    first.into_iter().chain(rest.chars()).collect()
+}
+
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do", "dyn",
+    "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl", "in", "let",
+    "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return",
+    "static", "struct", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use",
+    "virtual", "where", "while", "yield",
+];
+
+/// Makes a TypeScript name usable as a Rust identifier.
+fn rust_ident(name: &str) -> String {
+    let mut ident: String = name
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '_' })
+        .collect();
+    if ident.starts_with(|c: char| c.is_ascii_digit()) {
+        ident.insert(0, '_');
+    }
+    match ident.as_str() {
+        "self" | "Self" | "super" | "crate" | "_" => ident + "_",
+        keyword if KEYWORDS.contains(&keyword) => format!("r#{ident}"),
+        _ => ident,
+    }
+}
+
+/// Disambiguates overloads and members whose names collide after sanitizing.
+fn unique_name(names: &mut HashSet<String>, name: &str) -> String {
+    let mut candidate = name.to_owned();
+    let mut index = 1;
+    while !names.insert(candidate.clone()) {
+        candidate = format!("{}_{index}", name.trim_start_matches("r#"));
+        index += 1;
+    }
+    candidate
+}
+
+/// Converts the body of a JSDoc comment into `///` lines.
+fn write_doc(out: &mut String, doc: Option<&str>) {
+    let Some(doc) = doc else {
+        return;
+    };
+    let mut lines: Vec<String> = vec![];
+    let mut in_fence = false;
+    for raw in doc.lines() {
+        let line = raw.trim_start();
+        let line = line.strip_prefix('*').unwrap_or(line);
+        let line = line.strip_prefix(' ').unwrap_or(line).trim_end();
+        let line = line.strip_suffix("@public").unwrap_or(line).trim_end();
+        if line.trim_start().starts_with("```") {
+            let fence = line.trim_start();
+            lines.push(if !in_fence && fence == "```" {
+                "```text".to_owned()
+            } else {
+                fence.to_owned()
+            });
+            in_fence = !in_fence;
+        } else if in_fence {
+            lines.push(line.to_owned());
+        } else {
+            // leading whitespace would turn prose into an indented code block
+            lines.push(line.trim_start().to_owned());
+        }
+    }
+    while lines.first().is_some_and(|line| line.is_empty()) {
+        lines.remove(0);
+    }
+    while lines.last().is_some_and(|line| line.is_empty()) {
+        lines.pop();
+    }
+    for line in lines {
+        if line.is_empty() {
+            out.push_str("///\n");
+        } else {
+            writeln!(out, "/// {line}").unwrap();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gen_bindings::typescript::parse;
+
+    fn emit(source: &str) -> String {
+        Emitter::new(&parse(source).unwrap()).emit()
+    }
+
+    #[test]
+    fn enums_list_their_variants_for_string_enum() {
+        let out = emit(
+            r#"
+            /** A city */
+            declare enum CityName {
+              Aevum = "Aevum",
+              Sector12 = "Sector-12",
+            }
+            "#,
+        );
+        assert!(out.contains(
+            "/// A city\n\
+             #[wasm_bindgen]\n\
+             #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]\n\
+             pub enum CityName {\n\
+             Aevum = \"Aevum\",\n\
+             Sector12 = \"Sector-12\",\n\
+             }\n"
+        ));
+        assert!(out.contains(
+            "pub const ALL: [CityName; 2] = [\nCityName::Aevum,\nCityName::Sector12,\n];\n"
+        ));
+        assert!(out.contains("pub fn as_str(&self) -> &'static str {\nself.to_str()\n}"));
+        assert!(out.ends_with("string_enum!(CityName);\n"));
+    }
+
+    #[test]
+    fn enum_variants_are_upper_camel_case() {
+        let out = emit(
+            r#"
+            declare enum ToastVariant { SUCCESS = "success", INFO = "info" }
+            type CorpStateName = "START" | "PURCHASE";
+            "#,
+        );
+        assert!(out.contains("Success = \"success\",\nInfo = \"info\",\n"));
+        assert!(out.contains("Start = \"START\",\nPurchase = \"PURCHASE\",\n"));
+        assert!(out.contains("string_enum!(CorpStateName);"));
+    }
+
+    #[test]
+    fn enums_are_passed_by_value() {
+        let out = emit(
+            r#"
+            declare enum CityName { Aevum = "Aevum" }
+            interface Player {
+              city: CityName;
+              travel(city: CityName | `${CityName}`): boolean;
+            }
+            "#,
+        );
+        assert!(out.contains("pub fn city(this: &Player) -> CityName;"));
+        assert!(out.contains("pub fn set_city(this: &Player, value: CityName);"));
+        assert!(
+            out.contains("pub fn travel(this: &Player, city: CityName) -> Result<bool, JsValue>;")
+        );
+    }
+
+    #[test]
+    fn methods_catch_and_overloads_get_suffixes() {
+        let out = emit(
+            r#"
+            interface NS {
+              /** Kills a script */
+              kill(pid: number): boolean;
+              kill(filename: string, hostname?: string, ...args: ScriptArg[]): boolean;
+            }
+            "#,
+        );
+        assert!(out.contains(
+            "/// Kills a script\n\
+             #[wasm_bindgen(method, catch)]\n\
+             pub fn kill(this: &NS, pid: f64) -> Result<bool, JsValue>;\n"
+        ));
+        assert!(out.contains(
+            "#[wasm_bindgen(method, catch, variadic, js_name = \"kill\")]\n\
+             pub fn kill_1(this: &NS, filename: &str, hostname: Option<&str>, \
+             args: &[JsValue]) -> Result<bool, JsValue>;\n"
+        ));
+    }
+
+    #[test]
+    fn promises_become_async_fns() {
+        let out = emit(
+            r#"
+            interface NS {
+              hack(host: string): Promise<number>;
+              sleep(millis: number): Promise<true>;
+              asleep(millis: number): Promise<void>;
+            }
+            "#,
+        );
+        assert!(out.contains(
+            "#[wasm_bindgen(method, catch)]\n\
+             pub async fn hack(this: &NS, host: &str) -> Result<f64, JsValue>;"
+        ));
+        assert!(
+            out.contains("pub async fn sleep(this: &NS, millis: f64) -> Result<bool, JsValue>;")
+        );
+        assert!(out.contains("pub async fn asleep(this: &NS, millis: f64) -> Result<(), JsValue>;"));
+    }
+
+    #[test]
+    fn properties_and_readonly_namespaces() {
+        let out = emit(
+            r#"
+            interface Hacknet {}
+            interface NS {
+              readonly hacknet: Hacknet;
+              type?: string;
+            }
+            "#,
+        );
+        assert!(out
+            .contains("#[wasm_bindgen(method, getter)]\npub fn hacknet(this: &NS) -> Hacknet;\n"));
+        assert!(!out.contains("set_hacknet"));
+        assert!(out.contains(
+            "#[wasm_bindgen(method, getter = \"type\")]\n\
+             pub fn r#type(this: &NS) -> Option<String>;"
+        ));
+        assert!(out.contains(
+            "#[wasm_bindgen(method, setter)]\n\
+             pub fn set_type(this: &NS, value: Option<&str>);"
+        ));
+    }
+
+    #[test]
+    fn variant_idents() {
+        assert_eq!(variant_ident("SUCCESS"), "Success");
+        assert_eq!(variant_ident("Sector12"), "Sector12");
+        assert_eq!(variant_ident("hacking"), "Hacking");
+        assert_eq!(variant_ident("IT"), "It");
+    }
+}
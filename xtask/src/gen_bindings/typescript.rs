@@ -0,0 +1,908 @@
+//! A parser for the subset of TypeScript used by the Bitburner definition file:
+//! interfaces, type aliases and `declare enum`s, with their doc comments.
+//! Aliases of a union of string literals are read as enums.
+
+use std::fmt;
+
+#[derive(Debug)]
+pub enum Item {
+    Interface(Interface),
+    Alias(Alias),
+    Enum(Enum),
+}
+
+#[derive(Debug)]
+pub struct Interface {
+    pub name: String,
+    pub doc: Option<String>,
+    pub extends: Vec<String>,
+    pub members: Vec<Member>,
+}
+
+#[derive(Debug)]
+pub enum Member {
+    Property(Property),
+    Method(Method),
+}
+
+#[derive(Debug)]
+pub struct Property {
+    pub name: String,
+    pub doc: Option<String>,
+    pub readonly: bool,
+    pub optional: bool,
+    pub ty: TsType,
+}
+
+#[derive(Debug)]
+pub struct Method {
+    pub name: String,
+    pub doc: Option<String>,
+    pub params: Vec<Param>,
+    pub ret: TsType,
+}
+
+#[derive(Debug)]
+pub struct Param {
+    pub name: String,
+    pub optional: bool,
+    pub rest: bool,
+    pub ty: TsType,
+}
+
+#[derive(Debug)]
+pub struct Alias {
+    pub name: String,
+    pub ty: TsType,
+}
+
+#[derive(Debug)]
+pub struct Enum {
+    pub name: String,
+    pub doc: Option<String>,
+    pub variants: Vec<EnumVariant>,
+}
+
+#[derive(Debug)]
+pub struct EnumVariant {
+    pub name: String,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TsType {
+    /// A type reference such as `number`, `Server` or `Promise<void>`
+    Named(String, Vec<TsType>),
+    StringLiteral(String),
+    NumberLiteral,
+    BooleanLiteral,
+    /// A template literal type, holds the referenced type if it is a plain
+    /// `${Type}` interpolation
+    Template(Option<String>),
+    Array(Box<TsType>),
+    Tuple(Vec<TsType>),
+    Union(Vec<TsType>),
+    Intersection(Vec<TsType>),
+    Object,
+    Function,
+    /// `typeof` and `keyof` queries
+    Query,
+}
+
+#[derive(Debug)]
+pub struct ParseError {
+    message: String,
+    line: usize,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+type ParseResult<T> = Result<T, ParseError>;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    Str(String),
+    Template(String),
+    Number,
+    Punct(char),
+    Arrow,
+    Ellipsis,
+    Doc(String),
+}
+
+pub fn parse(source: &str) -> ParseResult<Vec<Item>> {
+    let tokens = tokenize(source)?;
+    Parser {
+        tokens,
+        pos: 0,
+        doc: None,
+    }
+    .parse_items()
+}
+
+fn tokenize(source: &str) -> ParseResult<Vec<(Token, usize)>> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut tokens = vec![];
+    let mut line = 1;
+    let mut i = 0;
+    let error = |message: &str, line| ParseError {
+        message: message.to_owned(),
+        line,
+    };
+    while i < chars.len() {
+        let c = chars[i];
+        let start_line = line;
+        match c {
+            '\n' => {
+                line += 1;
+                i += 1;
+            }
+            c if c.is_whitespace() => i += 1,
+            '/' if chars.get(i + 1) == Some(&'/') => {
+                while i < chars.len() && chars[i] != '\n' {
+                    i += 1;
+                }
+            }
+            '/' if chars.get(i + 1) == Some(&'*') => {
+                let is_doc = chars.get(i + 2) == Some(&'*') && chars.get(i + 3) != Some(&'/');
+                let body_start = i + if is_doc { 3 } else { 2 };
+                let mut end = body_start;
+                while end + 1 < chars.len() && !(chars[end] == '*' && chars[end + 1] == '/') {
+                    end += 1;
+                }
+                if end + 1 >= chars.len() {
+                    return Err(error("unterminated comment", start_line));
+                }
+                let body: String = chars[body_start..end].iter().collect();
+                line += body.matches('\n').count();
+                if is_doc {
+                    tokens.push((Token::Doc(body), start_line));
+                }
+                i = end + 2;
+            }
+            '"' | '\'' => {
+                let mut value = String::new();
+                i += 1;
+                while i < chars.len() && chars[i] != c {
+                    if chars[i] == '\\' {
+                        i += 1;
+                    }
+                    if let Some(&ch) = chars.get(i) {
+                        value.push(ch);
+                    }
+                    i += 1;
+                }
+                if i >= chars.len() {
+                    return Err(error("unterminated string literal", start_line));
+                }
+                tokens.push((Token::Str(value), start_line));
+                i += 1;
+            }
+            '`' => {
+                let mut value = String::new();
+                i += 1;
+                while i < chars.len() && chars[i] != '`' {
+                    if chars[i] == '\n' {
+                        line += 1;
+                    }
+                    value.push(chars[i]);
+                    i += 1;
+                }
+                if i >= chars.len() {
+                    return Err(error("unterminated template literal", start_line));
+                }
+                tokens.push((Token::Template(value), start_line));
+                i += 1;
+            }
+            c if c.is_ascii_digit() => {
+                while i < chars.len() && (chars[i].is_ascii_alphanumeric() || chars[i] == '.') {
+                    i += 1;
+                }
+                tokens.push((Token::Number, start_line));
+            }
+            c if c.is_alphabetic() || c == '_' || c == '$' => {
+                let start = i;
+                while i < chars.len()
+                    && (chars[i].is_alphanumeric() || chars[i] == '_' || chars[i] == '$')
+                {
+                    i += 1;
+                }
+                let ident: String = chars[start..i].iter().collect();
+                tokens.push((Token::Ident(ident), start_line));
+            }
+            '=' if chars.get(i + 1) == Some(&'>') => {
+                tokens.push((Token::Arrow, start_line));
+                i += 2;
+            }
+            '.' if chars.get(i + 1) == Some(&'.') && chars.get(i + 2) == Some(&'.') => {
+                tokens.push((Token::Ellipsis, start_line));
+                i += 3;
+            }
+            c => {
+                tokens.push((Token::Punct(c), start_line));
+                i += 1;
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    pos: usize,
+    doc: Option<String>,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.peek_nth(0)
+    }
+
+    fn peek_nth(&self, n: usize) -> Option<&Token> {
+        self.tokens.get(self.pos + n).map(|(token, _)| token)
+    }
+
+    fn next(&mut self) -> ParseResult<Token> {
+        let token = self.tokens.get(self.pos).map(|(token, _)| token.clone());
+        self.pos += 1;
+        token.ok_or_else(|| self.error("unexpected end of file"))
+    }
+
+    fn error(&self, message: &str) -> ParseError {
+        let line = self
+            .tokens
+            .get(self.pos.min(self.tokens.len().saturating_sub(1)))
+            .map_or(0, |(_, line)| *line);
+        ParseError {
+            message: message.to_owned(),
+            line,
+        }
+    }
+
+    fn is_punct(&self, c: char) -> bool {
+        self.peek() == Some(&Token::Punct(c))
+    }
+
+    fn is_ident(&self, ident: &str) -> bool {
+        matches!(self.peek(), Some(Token::Ident(name)) if name == ident)
+    }
+
+    fn eat_punct(&mut self, c: char) -> bool {
+        let found = self.is_punct(c);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    fn expect_punct(&mut self, c: char) -> ParseResult<()> {
+        if self.eat_punct(c) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected `{c}`, found {:?}", self.peek())))
+        }
+    }
+
+    fn expect_ident(&mut self) -> ParseResult<String> {
+        match self.next()? {
+            Token::Ident(ident) => Ok(ident),
+            token => {
+                self.pos -= 1;
+                Err(self.error(&format!("expected identifier, found {token:?}")))
+            }
+        }
+    }
+
+    fn take_doc(&mut self) -> Option<String> {
+        self.doc.take()
+    }
+
+    /// Skips a balanced `<...>`, `(...)`, `[...]` or `{...}` group if one
+    /// starts at the current token.
+    fn skip_group(&mut self) -> ParseResult<()> {
+        let (open, close) = match self.peek() {
+            Some(Token::Punct('<')) => ('<', '>'),
+            Some(Token::Punct('(')) => ('(', ')'),
+            Some(Token::Punct('[')) => ('[', ']'),
+            Some(Token::Punct('{')) => ('{', '}'),
+            _ => return Ok(()),
+        };
+        let mut depth = 0;
+        loop {
+            match self.next()? {
+                Token::Punct(c) if c == open => depth += 1,
+                Token::Punct(c) if c == close => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Ok(());
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn parse_items(mut self) -> ParseResult<Vec<Item>> {
+        let mut items = vec![];
+        while let Some(token) = self.peek().cloned() {
+            match token {
+                Token::Doc(doc) => {
+                    self.doc = Some(doc);
+                    self.pos += 1;
+                }
+                Token::Ident(ident) => match ident.as_str() {
+                    "export" | "declare" => self.pos += 1,
+                    "interface" => items.push(Item::Interface(self.parse_interface()?)),
+                    "type" => items.push(self.parse_alias()?),
+                    "enum" => items.push(Item::Enum(self.parse_enum()?)),
+                    _ => return Err(self.error(&format!("unexpected `{ident}`"))),
+                },
+                token => return Err(self.error(&format!("unexpected {token:?}"))),
+            }
+        }
+        Ok(items)
+    }
+
+    fn parse_interface(&mut self) -> ParseResult<Interface> {
+        let doc = self.take_doc();
+        self.pos += 1;
+        let name = self.expect_ident()?;
+        self.skip_group_if('<')?;
+        let mut extends = vec![];
+        if self.is_ident("extends") {
+            self.pos += 1;
+            loop {
+                extends.push(self.expect_ident()?);
+                self.skip_group_if('<')?;
+                if !self.eat_punct(',') {
+                    break;
+                }
+            }
+        }
+        let members = self.parse_members()?;
+        Ok(Interface {
+            name,
+            doc,
+            extends,
+            members,
+        })
+    }
+
+    /// Parses a type alias, or an enum if it only unites string literals
+    /// like `type CorpStateName = "START" | "PURCHASE" | ...`.
+    fn parse_alias(&mut self) -> ParseResult<Item> {
+        let doc = self.take_doc();
+        self.pos += 1;
+        let name = self.expect_ident()?;
+        self.skip_group_if('<')?;
+        self.expect_punct('=')?;
+        let ty = self.parse_type()?;
+        self.eat_punct(';');
+        let values: Option<Vec<&String>> = match &ty {
+            TsType::Union(variants) => variants
+                .iter()
+                .map(|variant| match variant {
+                    TsType::StringLiteral(value) => Some(value),
+                    _ => None,
+                })
+                .collect(),
+            _ => None,
+        };
+        let Some(values) = values else {
+            return Ok(Item::Alias(Alias { name, ty }));
+        };
+        let variants = values
+            .into_iter()
+            .map(|value| EnumVariant {
+                name: literal_variant_name(value),
+                value: value.clone(),
+            })
+            .collect();
+        Ok(Item::Enum(Enum {
+            name,
+            doc,
+            variants,
+        }))
+    }
+
+    fn parse_enum(&mut self) -> ParseResult<Enum> {
+        let doc = self.take_doc();
+        self.pos += 1;
+        let name = self.expect_ident()?;
+        self.expect_punct('{')?;
+        let mut variants = vec![];
+        while !self.eat_punct('}') {
+            if let Some(Token::Doc(_)) = self.peek() {
+                self.pos += 1;
+                continue;
+            }
+            let variant = self.expect_ident()?;
+            self.expect_punct('=')?;
+            let value = match self.next()? {
+                Token::Str(value) => value,
+                _ => return Err(self.error("only string enums are supported")),
+            };
+            variants.push(EnumVariant {
+                name: variant,
+                value,
+            });
+            self.eat_punct(',');
+        }
+        Ok(Enum {
+            name,
+            doc,
+            variants,
+        })
+    }
+
+    /// Parses a `{ ... }` block of interface or object literal members.
+    fn parse_members(&mut self) -> ParseResult<Vec<Member>> {
+        self.expect_punct('{')?;
+        let mut members = vec![];
+        self.doc = None;
+        while !self.eat_punct('}') {
+            if let Some(member) = self.parse_member()? {
+                members.push(member);
+            }
+            if !self.eat_punct(';') {
+                self.eat_punct(',');
+            }
+        }
+        Ok(members)
+    }
+
+    fn parse_member(&mut self) -> ParseResult<Option<Member>> {
+        if let Some(Token::Doc(doc)) = self.peek().cloned() {
+            self.doc = Some(doc);
+            self.pos += 1;
+            return Ok(None);
+        }
+        let doc = self.take_doc();
+        // index, call and construct signatures have no Rust counterpart
+        if self.is_punct('[') || self.is_punct('(') || self.is_punct('<') {
+            self.skip_group()?;
+            self.skip_signature_rest()?;
+            return Ok(None);
+        }
+        if self.is_ident("new") && self.peek_nth(1) == Some(&Token::Punct('(')) {
+            self.pos += 1;
+            self.skip_group()?;
+            self.skip_signature_rest()?;
+            return Ok(None);
+        }
+        let readonly = self.is_ident("readonly")
+            && matches!(self.peek_nth(1), Some(Token::Ident(_) | Token::Str(_)));
+        if readonly {
+            self.pos += 1;
+        }
+        let name = match self.next()? {
+            Token::Ident(name) | Token::Str(name) => name,
+            token => return Err(self.error(&format!("unexpected member token {token:?}"))),
+        };
+        let optional = self.eat_punct('?');
+        if self.is_punct('<') || self.is_punct('(') {
+            self.skip_group_if('<')?;
+            let params = self.parse_params()?;
+            let ret = if self.eat_punct(':') {
+                self.parse_type()?
+            } else {
+                TsType::Named("any".to_owned(), vec![])
+            };
+            return Ok(Some(Member::Method(Method {
+                name,
+                doc,
+                params,
+                ret,
+            })));
+        }
+        self.expect_punct(':')?;
+        let ty = self.parse_type()?;
+        Ok(Some(Member::Property(Property {
+            name,
+            doc,
+            readonly,
+            optional,
+            ty,
+        })))
+    }
+
+    fn skip_group_if(&mut self, open: char) -> ParseResult<()> {
+        if self.is_punct(open) {
+            self.skip_group()?;
+        }
+        Ok(())
+    }
+
+    fn skip_signature_rest(&mut self) -> ParseResult<()> {
+        self.eat_punct('?');
+        if self.is_punct('(') {
+            self.skip_group()?;
+        }
+        if self.eat_punct(':') {
+            self.parse_type()?;
+        }
+        Ok(())
+    }
+
+    fn parse_params(&mut self) -> ParseResult<Vec<Param>> {
+        self.expect_punct('(')?;
+        let mut params = vec![];
+        while !self.eat_punct(')') {
+            let rest = self.peek() == Some(&Token::Ellipsis);
+            if rest {
+                self.pos += 1;
+            }
+            let name = self.expect_ident()?;
+            let optional = self.eat_punct('?');
+            let ty = if self.eat_punct(':') {
+                self.parse_type()?
+            } else {
+                TsType::Named("any".to_owned(), vec![])
+            };
+            params.push(Param {
+                name,
+                optional,
+                rest,
+                ty,
+            });
+            self.eat_punct(',');
+        }
+        Ok(params)
+    }
+
+    fn parse_type(&mut self) -> ParseResult<TsType> {
+        self.eat_punct('|');
+        let mut variants = vec![self.parse_intersection()?];
+        while self.eat_punct('|') {
+            variants.push(self.parse_intersection()?);
+        }
+        Ok(if variants.len() == 1 {
+            variants.pop().unwrap()
+        } else {
+            TsType::Union(variants)
+        })
+    }
+
+    fn parse_intersection(&mut self) -> ParseResult<TsType> {
+        self.eat_punct('&');
+        let mut parts = vec![self.parse_postfix()?];
+        while self.eat_punct('&') {
+            parts.push(self.parse_postfix()?);
+        }
+        Ok(if parts.len() == 1 {
+            parts.pop().unwrap()
+        } else {
+            TsType::Intersection(parts)
+        })
+    }
+
+    fn parse_postfix(&mut self) -> ParseResult<TsType> {
+        let mut ty = self.parse_primary()?;
+        while self.is_punct('[') && self.peek_nth(1) == Some(&Token::Punct(']')) {
+            self.pos += 2;
+            ty = TsType::Array(Box::new(ty));
+        }
+        Ok(ty)
+    }
+
+    fn parse_primary(&mut self) -> ParseResult<TsType> {
+        match self.next()? {
+            Token::Ident(ident) => match ident.as_str() {
+                "true" | "false" => Ok(TsType::BooleanLiteral),
+                "typeof" => {
+                    self.expect_ident()?;
+                    Ok(TsType::Query)
+                }
+                "keyof" => {
+                    self.parse_postfix()?;
+                    Ok(TsType::Query)
+                }
+                "new" => {
+                    self.parse_params()?;
+                    self.parse_function_return()
+                }
+                _ => {
+                    let mut name = ident;
+                    while self.eat_punct('.') {
+                        name = format!("{name}.{}", self.expect_ident()?);
+                    }
+                    let mut args = vec![];
+                    if self.eat_punct('<') {
+                        while !self.eat_punct('>') {
+                            args.push(self.parse_type()?);
+                            self.eat_punct(',');
+                        }
+                    }
+                    Ok(TsType::Named(name, args))
+                }
+            },
+            Token::Str(value) => Ok(TsType::StringLiteral(value)),
+            Token::Number => Ok(TsType::NumberLiteral),
+            Token::Punct('-') => match self.next()? {
+                Token::Number => Ok(TsType::NumberLiteral),
+                _ => Err(self.error("expected a number after `-`")),
+            },
+            Token::Template(template) => Ok(TsType::Template(template_reference(&template))),
+            Token::Punct('{') => {
+                self.pos -= 1;
+                self.parse_members()?;
+                Ok(TsType::Object)
+            }
+            Token::Punct('[') => {
+                let mut elements = vec![];
+                while !self.eat_punct(']') {
+                    if self.peek() == Some(&Token::Ellipsis) {
+                        self.pos += 1;
+                    }
+                    let is_named = matches!(self.peek(), Some(Token::Ident(_)))
+                        && matches!(self.peek_nth(1), Some(Token::Punct(':' | '?')));
+                    if is_named {
+                        self.pos += 1;
+                        self.eat_punct('?');
+                        self.expect_punct(':')?;
+                    }
+                    elements.push(self.parse_type()?);
+                    self.eat_punct('?');
+                    self.eat_punct(',');
+                }
+                Ok(TsType::Tuple(elements))
+            }
+            Token::Punct('(') => {
+                if self.is_function_params() {
+                    self.pos -= 1;
+                    self.parse_params()?;
+                    self.parse_function_return()
+                } else {
+                    let ty = self.parse_type()?;
+                    self.expect_punct(')')?;
+                    Ok(ty)
+                }
+            }
+            token => {
+                self.pos -= 1;
+                Err(self.error(&format!("unexpected type token {token:?}")))
+            }
+        }
+    }
+
+    /// Decides whether the `(` just consumed opens a function type's parameter
+    /// list rather than a parenthesized type.
+    fn is_function_params(&self) -> bool {
+        match self.peek() {
+            Some(Token::Punct(')')) | Some(Token::Ellipsis) => true,
+            Some(Token::Ident(_)) => {
+                matches!(self.peek_nth(1), Some(Token::Punct(':' | '?' | ',')))
+            }
+            _ => false,
+        }
+    }
+
+    fn parse_function_return(&mut self) -> ParseResult<TsType> {
+        match self.next()? {
+            Token::Arrow => {
+                self.parse_type()?;
+                Ok(TsType::Function)
+            }
+            _ => Err(self.error("expected `=>` in function type")),
+        }
+    }
+}
+
+/// Returns the referenced type name of a `${Type}` template literal.
+fn template_reference(template: &str) -> Option<String> {
+    let inner = template.strip_prefix("${")?.strip_suffix('}')?;
+    let is_ident = inner.chars().all(|c| c.is_alphanumeric() || c == '_');
+    is_ident.then(|| inner.to_owned())
+}
+
+/// Names the enum variant of a string literal by joining its words, as in
+/// `"Research & Development"` to `ResearchDevelopment`.
+fn literal_variant_name(value: &str) -> String {
+    value
+        .split(|c: char| !c.is_alphanumeric())
+        .flat_map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase())
+                .into_iter()
+                .chain(chars)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn named(name: &str) -> TsType {
+        TsType::Named(name.to_owned(), vec![])
+    }
+
+    fn interface(source: &str) -> Interface {
+        match parse(source).unwrap().pop() {
+            Some(Item::Interface(interface)) => interface,
+            item => panic!("expected an interface, found {item:?}"),
+        }
+    }
+
+    fn method(member: &Member) -> &Method {
+        match member {
+            Member::Method(method) => method,
+            member => panic!("expected a method, found {member:?}"),
+        }
+    }
+
+    fn property(member: &Member) -> &Property {
+        match member {
+            Member::Property(property) => property,
+            member => panic!("expected a property, found {member:?}"),
+        }
+    }
+
+    #[test]
+    fn interfaces() {
+        let server = interface(
+            r#"
+            /** A server */
+            export interface Server extends BaseServer, Other<number> {
+              /** Hostname */
+              hostname: string;
+              ramUsed: number,
+              [key: string]: unknown;
+            }
+            "#,
+        );
+        assert_eq!(server.name, "Server");
+        assert_eq!(server.doc.as_deref(), Some(" A server "));
+        assert_eq!(server.extends, ["BaseServer", "Other"]);
+        assert_eq!(server.members.len(), 2);
+        let hostname = property(&server.members[0]);
+        assert_eq!(hostname.name, "hostname");
+        assert_eq!(hostname.doc.as_deref(), Some(" Hostname "));
+        assert_eq!(hostname.ty, named("string"));
+        assert_eq!(property(&server.members[1]).doc, None);
+    }
+
+    #[test]
+    fn optional_members() {
+        let ns = interface(
+            r#"
+            interface NS {
+              backdoorInstalled?: boolean;
+              scan(host?: string): string[];
+              run(script: string, ...args: ScriptArg[]): number;
+            }
+            "#,
+        );
+        assert!(property(&ns.members[0]).optional);
+        let scan = method(&ns.members[1]);
+        assert!(scan.params[0].optional);
+        assert_eq!(scan.ret, TsType::Array(Box::new(named("string"))));
+        let run = method(&ns.members[2]);
+        assert!(!run.params[0].optional && !run.params[0].rest);
+        assert!(run.params[1].rest);
+        assert_eq!(
+            run.params[1].ty,
+            TsType::Array(Box::new(named("ScriptArg")))
+        );
+    }
+
+    #[test]
+    fn overloads() {
+        let ns = interface(
+            r#"
+            interface NS {
+              kill(pid: number): boolean;
+              kill(filename: string, hostname?: string, ...args: ScriptArg[]): boolean;
+            }
+            "#,
+        );
+        let [first, second] = [method(&ns.members[0]), method(&ns.members[1])];
+        assert_eq!(
+            (first.name.as_str(), second.name.as_str()),
+            ("kill", "kill")
+        );
+        assert_eq!(first.params.len(), 1);
+        assert_eq!(second.params.len(), 3);
+    }
+
+    #[test]
+    fn unions_of_string_literals() {
+        let items = parse(
+            r#"
+            /** State of the corporation */
+            type CorpStateName = "START" | "PURCHASE" | "Research & Development";
+            type FilenameOrPID = number | string;
+            "#,
+        )
+        .unwrap();
+        let Item::Enum(states) = &items[0] else {
+            panic!("expected an enum, found {:?}", items[0]);
+        };
+        assert_eq!(states.name, "CorpStateName");
+        assert_eq!(states.doc.as_deref(), Some(" State of the corporation "));
+        let variants: Vec<(&str, &str)> = states
+            .variants
+            .iter()
+            .map(|variant| (variant.name.as_str(), variant.value.as_str()))
+            .collect();
+        assert_eq!(
+            variants,
+            [
+                ("START", "START"),
+                ("PURCHASE", "PURCHASE"),
+                ("ResearchDevelopment", "Research & Development"),
+            ]
+        );
+        let Item::Alias(alias) = &items[1] else {
+            panic!("expected an alias, found {:?}", items[1]);
+        };
+        assert_eq!(
+            alias.ty,
+            TsType::Union(vec![named("number"), named("string")])
+        );
+    }
+
+    #[test]
+    fn promises() {
+        let ns = interface(
+            r#"
+            interface NS {
+              hack(host: string, opts?: BasicHGWOptions): Promise<number>;
+              sleep(millis: number): Promise<true>;
+              asleep(millis: number): Promise<void>;
+            }
+            "#,
+        );
+        let promise = |inner| TsType::Named("Promise".to_owned(), vec![inner]);
+        assert_eq!(method(&ns.members[0]).ret, promise(named("number")));
+        assert_eq!(method(&ns.members[1]).ret, promise(TsType::BooleanLiteral));
+        assert_eq!(method(&ns.members[2]).ret, promise(named("void")));
+    }
+
+    #[test]
+    fn readonly_namespaces() {
+        let ns = interface(
+            r#"
+            interface NS {
+              readonly hacknet: Hacknet;
+              readonly args: (string | number | boolean)[];
+              readonly: boolean;
+            }
+            "#,
+        );
+        let hacknet = property(&ns.members[0]);
+        assert!(hacknet.readonly);
+        assert_eq!(
+            (hacknet.name.as_str(), &hacknet.ty),
+            ("hacknet", &named("Hacknet"))
+        );
+        assert!(property(&ns.members[1]).readonly);
+        // a property named `readonly` is not a modifier
+        let readonly = property(&ns.members[2]);
+        assert_eq!(readonly.name, "readonly");
+        assert!(!readonly.readonly);
+    }
+
+    #[test]
+    fn string_enums() {
+        let items =
+            parse(r#"declare enum ToastVariant { SUCCESS = "success", ERROR = "error" }"#).unwrap();
+        let Item::Enum(toast) = &items[0] else {
+            panic!("expected an enum, found {:?}", items[0]);
+        };
+        assert_eq!(toast.variants[1].name, "ERROR");
+        assert_eq!(toast.variants[1].value, "error");
+
+        let err = parse("declare enum Numbers {\n  One = 1,\n}").unwrap_err();
+        assert_eq!(err.to_string(), "line 2: only string enums are supported");
+    }
+}
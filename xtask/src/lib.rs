@@ -1,6 +1,7 @@
 pub mod bindgen;
 pub mod cli;
 pub mod compile_wasm;
+pub mod gen_bindings;
 pub mod server;
 
 use std::{
@@ -30,6 +31,20 @@ pub fn js_output_path() -> PathBuf {
     project_root().join("target").join("wasm_output")
 }
 
+pub fn definitions_path() -> PathBuf {
+    project_root()
+        .join("bitburner_api")
+        .join("src")
+        .join("bitburner.d.ts")
+}
+
+pub fn bindings_path() -> PathBuf {
+    project_root()
+        .join("bitburner_api")
+        .join("src")
+        .join("bindings.rs")
+}
+
 pub fn get_wasm_artifact_paths(profile: Profile) -> Vec<PathBuf> {
     let artifact_path = artifact_path(profile);
     let artifacts = artifact_path
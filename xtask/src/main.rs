@@ -1,6 +1,8 @@
 use clap::Parser;
 use std::process::ExitCode;
-use xtask::{bindgen, cli, compile_wasm, get_wasm_artifact_paths, js_output_path, server};
+use xtask::{
+    bindgen, cli, compile_wasm, gen_bindings, get_wasm_artifact_paths, js_output_path, server,
+};
 
 fn main() -> ExitCode {
     env_logger::builder()
@@ -12,6 +14,9 @@ fn main() -> ExitCode {
         cli::Commands::Codegen { profile } => codegen(profile),
         cli::Commands::Serve { port } => server::launch_server(port, &js_output_path()),
         cli::Commands::GetDefinitions { port, output } => server::get_definitions(port, output),
+        cli::Commands::GenBindings { input, output } => {
+            gen_bindings::generate_bindings(&input, &output)
+        }
     }
 }
 
@@ -29,11 +29,11 @@ pub fn launch_server(port: u16, watch_path: &Path) -> ExitCode {
 
     if !watch_path.exists() {
         log::info!("Directory {watch_path:?} does not exist, creating it");
-        create_dir_all(&watch_path).expect("Failed to create wasm_output dir");
+        create_dir_all(watch_path).expect("Failed to create wasm_output dir");
     }
 
     log::info!("Setting up file watch on {watch_path:?}...");
-    let (_watcher, watch_event_rx) = debouncing_file_watcher(&watch_path);
+    let (_watcher, watch_event_rx) = debouncing_file_watcher(watch_path);
 
     let address = SocketAddr::new(Ipv4Addr::LOCALHOST.into(), port);
     let serve = async move {
@@ -77,7 +77,7 @@ fn set_ctrl_handler() -> (Sender<()>, Receiver<()>) {
     let (quit_tx, quit_rx) = channel::<()>(1);
     let mut ctrlc_tx = quit_tx.clone();
     ctrlc::set_handler(move || {
-        println!("");
+        println!();
         ctrlc_tx
             .try_send(())
             .expect("Failed to send shutdown command")
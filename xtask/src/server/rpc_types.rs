@@ -10,10 +10,7 @@ pub struct RpcRequest<T> {
 
 #[derive(Deserialize)]
 pub struct RpcResponse<T> {
-    pub jsonrpc: String,
-    pub id: u64,
     pub result: T,
-    pub error: Option<String>,
 }
 
 #[derive(Serialize)]
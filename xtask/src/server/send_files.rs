@@ -32,7 +32,7 @@ async fn send_single_file(
     websocket: &mut WebSocketStream<TcpStream>,
     path: &Path,
 ) -> Result<(), DynError> {
-    let mut file = File::open(&path)?;
+    let mut file = File::open(path)?;
     let mut contents = String::new();
     file.read_to_string(&mut contents)?;
     drop(file);